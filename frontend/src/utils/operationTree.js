@@ -4,14 +4,23 @@ export const OPERATION_LABELS = {
   SUBTRACT: 'aftrekken',
   MULTIPLY: 'vermenigvuldigen',
   DIVIDE: 'delen',
+  INT_DIVIDE: 'gehele deling',
+  COMPOUND_INDEX: 'geïndexeerd bedrag',
+  ROUND_TO_MULTIPLE: 'afgerond op veelvoud',
+  LINEAR_TAPER: 'lineair afbouwen',
+  CLAMP: 'begrensd tussen',
+  PROGRESSIVE_BRACKETS: 'progressief over schijven',
+  NEGATE: 'tegengesteld van',
   MIN: 'minimum',
   MAX: 'maximum',
+  WEIGHTED_AVERAGE: 'gewogen gemiddelde',
   // Vergelijking
   EQUALS: 'gelijk aan',
   GREATER_THAN: 'groter dan',
   GREATER_THAN_OR_EQUAL: 'groter dan of gelijk',
   LESS_THAN: 'kleiner dan',
   LESS_THAN_OR_EQUAL: 'kleiner dan of gelijk',
+  APPROX_EQUALS: 'ongeveer gelijk aan',
   IN: 'in lijst',
   // Logisch
   AND: 'en',
@@ -21,19 +30,70 @@ export const OPERATION_LABELS = {
   IF: 'als/dan',
   // Datum
   AGE: 'leeftijd',
+  HAS_REACHED_AGE: 'leeftijd bereikt',
+  AGE_IN_MONTHS: 'leeftijd in maanden',
   DATE_ADD: 'datum optellen',
   DATE: 'datum',
   DAY_OF_WEEK: 'dag van de week',
+  SUBTRACT_DATE: 'verschil tussen datums',
+  DATE_BEFORE: 'datum voor',
+  DATE_AFTER: 'datum na',
+  DATE_ON_OR_BEFORE: 'datum op of voor',
+  DATE_ON_OR_AFTER: 'datum op of na',
+  PRO_RATA: 'naar rato over periode',
+  CLAMP_DATE: 'datum begrensd tot periode',
+  NTH_WEEKDAY: 'n-de weekdag van de maand',
+  NEXT_BUSINESS_DAY: 'eerstvolgende werkdag',
+  YEAR_FRACTION: 'jaarfractie',
+  DATE_IN_PERIODS: 'datum binnen perioden',
+  PERIOD_LOOKUP: 'waarde voor periode',
+  PERIOD_OVERLAP_DAYS: 'overlappende dagen',
+  MIN_DATE: 'vroegste datum',
+  MAX_DATE: 'laatste datum',
+  ISO_WEEK: 'ISO-weeknummer',
+  QUARTER: 'kwartaal',
+  CONVERT_DURATION: 'omgerekende duur',
   // Verzameling
   LIST: 'lijst',
+  COUNT_WHERE: 'aantal waarvoor geldt',
+  FLATTEN: 'lijsten samenvoegen',
+  DISTINCT: 'unieke waarden',
+  IS_SUBSET: 'deelverzameling van',
+  IS_SUPERSET: 'omvat alle waarden van',
+  SET_EQUALS: 'bevat dezelfde waarden als',
+  LABEL: 'omschrijving bij code',
+  SLICE: 'deel van lijst',
+  SPLIT: 'tekst opsplitsen',
+  SORT: 'gesorteerde lijst',
+  CUMULATIVE_SUM: 'lopend totaal',
+  SUM_FIELD: 'som van veld',
+  // Wetsverwijzing
+  URI_CALL: 'uitkomst andere wet',
+  ANY_ENTITLED: 'recht op een van',
+  FIRST_RESOLVED: 'eerste beschikbare waarde',
+  DELEGATED_LOOKUP: 'waarde uit gedelegeerde tabel',
+  DELEGATED_OR_DEFAULT: 'gedelegeerde waarde of standaard',
+  APPLICABLE_VERSION: 'toegepaste versie',
+  // Validatie
+  VALIDATE_BSN: 'BSN geldig (elfproef)',
+  NORMALIZE_POSTCODE: 'genormaliseerde postcode',
+  MATCHES: 'voldoet aan patroon',
+  IS_EMPTY: 'is leeg',
+  NOT_EMPTY: 'is niet leeg',
+  // Typeconversie
+  CAST: 'omzetten naar type',
+  PARSE_BOOL: 'lezen als ja/nee',
+  CALL_FORMULA: 'formule',
 };
 
 export function collectAvailableVariables(article) {
   // Engine built-in context variables — always available regardless of
   // article content. `referencedate` is the calculation date injected by
   // the engine from the scenario's "Given the calculation date is ..."
+  // and `regulatory_layer` is the layer of the law being executed.
   const vars = [
     { name: 'referencedate', ref: '$referencedate', category: 'Context' },
+    { name: 'regulatory_layer', ref: '$regulatory_layer', category: 'Context' },
   ];
 
   if (!article?.machine_readable) return vars;
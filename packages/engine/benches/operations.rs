@@ -63,6 +63,7 @@ fn bench_operations(c: &mut Criterion) {
     // ADD with values
     let add_op = ActionOperation::Add {
         values: vec![var_ref("x"), var_ref("y"), literal(Value::Int(100))],
+        allow_empty: false,
     };
     group.bench_function("add_three_values", |b| {
         b.iter(|| execute_operation(black_box(&add_op), &resolver, 0))
@@ -71,6 +72,7 @@ fn bench_operations(c: &mut Criterion) {
     // MULTIPLY
     let mul_op = ActionOperation::Multiply {
         values: vec![var_ref("income"), literal(Value::Float(0.1345))],
+        allow_empty: false,
     };
     group.bench_function("multiply", |b| {
         b.iter(|| execute_operation(black_box(&mul_op), &resolver, 0))
@@ -7,12 +7,14 @@ fn make_law(id: &str, layer: RegulatoryLayer, valid_from: &str) -> ArticleBasedL
     ArticleBasedLaw {
         id: id.to_string(),
         name: Some(id.to_string()),
+        aliases: None,
         regulatory_layer: layer,
         valid_from: Some(valid_from.to_string()),
         publication_date: valid_from.to_string(),
         uuid: None,
         schema: None,
         competent_authority: None,
+        authority: None,
         bwb_id: None,
         url: None,
         identifiers: None,
@@ -13,11 +13,13 @@
 
 use crate::config;
 use crate::error::{EngineError, Result};
-use crate::types::{Operation, ParameterType, RegulatoryLayer, Value};
+use crate::trace::PathNode;
+use crate::types::{Operation, ParameterType, PathNodeType, RegulatoryLayer, Value};
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs;
 use std::path::Path;
+use std::sync::{Arc, LazyLock, OnceLock};
 
 /// Represents a competent authority - can be a simple string or a structured object
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -49,6 +51,14 @@ pub struct TypeSpec {
     pub unit: Option<String>,
 }
 
+/// Character range within an article's text, counted in Unicode scalar
+/// values from the start of the text; `end` is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
 /// Source specification for input fields
 ///
 /// Defines where an input value comes from. Can be:
@@ -66,6 +76,10 @@ pub struct Source {
     /// Parameters to pass to the source execution
     #[serde(default)]
     pub parameters: Option<BTreeMap<String, String>>,
+    /// Pin the source regulation to the version with this `valid_from` date,
+    /// instead of the version valid on the calculation date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
 }
 
 /// Parameter definition in execution spec
@@ -104,6 +118,16 @@ pub struct Output {
     pub type_spec: Option<TypeSpec>,
     #[serde(default)]
     pub description: Option<String>,
+    /// Advisory output that must not be treated as a decision
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub indicative: bool,
+    /// Declared set of allowed values (`enum:` in YAML), checked after evaluation
+    #[serde(default, rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub allowed_values: Option<Vec<Value>>,
+    /// Part of the article text this output implements, e.g. from harvested
+    /// annotations; traces cite the whole article text without it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_span: Option<TextSpan>,
 }
 
 /// Produces specification for execution.
@@ -133,6 +157,16 @@ pub struct Case {
     pub then: ActionValue,
 }
 
+/// A reference to another law's output, as used by ANY_ENTITLED and FIRST_RESOLVED
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UriReference {
+    /// `regelrecht://` URI of the referenced output
+    pub uri: String,
+    /// Parameters to pass to the referenced article
+    #[serde(default)]
+    pub parameters: BTreeMap<String, ActionValue>,
+}
+
 /// Represents a value in an action - can be a literal, variable reference, or nested operation.
 ///
 /// Uses `#[serde(untagged)]` for flexible YAML parsing. The Operation variant is tried first,
@@ -186,22 +220,92 @@ pub enum ActionOperation {
         subject: ActionValue,
         value: ActionValue,
     },
+    #[serde(rename = "APPROX_EQUALS")]
+    ApproxEquals {
+        subject: ActionValue,
+        value: ActionValue,
+        /// Largest allowed absolute difference; defaults to 0 (exact)
+        #[serde(default)]
+        tolerance: Option<ActionValue>,
+    },
 
     // Arithmetic (values)
     #[serde(rename = "ADD")]
-    Add { values: Vec<ActionValue> },
+    Add {
+        values: Vec<ActionValue>,
+        /// Return the identity (0) for empty `values` instead of an error
+        #[serde(default)]
+        allow_empty: bool,
+    },
     #[serde(rename = "SUBTRACT")]
     Subtract { values: Vec<ActionValue> },
     #[serde(rename = "MULTIPLY")]
-    Multiply { values: Vec<ActionValue> },
+    Multiply {
+        values: Vec<ActionValue>,
+        /// Return the identity (1) for empty `values` instead of an error
+        #[serde(default)]
+        allow_empty: bool,
+    },
     #[serde(rename = "DIVIDE")]
     Divide { values: Vec<ActionValue> },
+    #[serde(rename = "INT_DIVIDE")]
+    IntDivide { values: Vec<ActionValue> },
+    #[serde(rename = "COMPOUND_INDEX")]
+    CompoundIndex {
+        /// Base amount
+        value: ActionValue,
+        /// Array of yearly indexation factors, e.g. `[1.02, 1.035]`
+        factors: ActionValue,
+    },
+    #[serde(rename = "ROUND_TO_MULTIPLE")]
+    RoundToMultiple {
+        subject: ActionValue,
+        /// The multiple to round to, e.g. `5` or `0.10`
+        value: ActionValue,
+        /// `nearest` (default, ties away from zero), `half_away` (same as
+        /// `nearest`), `half_even`, `floor` or `ceil`
+        #[serde(default)]
+        rounding: Option<ActionValue>,
+    },
+    #[serde(rename = "LINEAR_TAPER")]
+    LinearTaper {
+        /// Full amount before tapering
+        amount: ActionValue,
+        income: ActionValue,
+        /// Income above which the amount starts to taper
+        threshold: ActionValue,
+        /// Reduction per unit of income above the threshold
+        rate: ActionValue,
+    },
+    #[serde(rename = "CLAMP")]
+    Clamp {
+        subject: ActionValue,
+        /// Floor; null leaves the subject unbounded below
+        min: ActionValue,
+        /// Cap; null leaves the subject unbounded above
+        max: ActionValue,
+    },
+    #[serde(rename = "PROGRESSIVE_BRACKETS")]
+    ProgressiveBrackets {
+        /// Income to tax
+        subject: ActionValue,
+        /// `{upper, rate}` objects in ascending order; the last `upper` may
+        /// be null (unbounded)
+        brackets: ActionValue,
+    },
+    #[serde(rename = "NEGATE")]
+    Negate { subject: ActionValue },
 
     // Aggregate (values)
     #[serde(rename = "MAX")]
     Max { values: Vec<ActionValue> },
     #[serde(rename = "MIN")]
     Min { values: Vec<ActionValue> },
+    #[serde(rename = "WEIGHTED_AVERAGE")]
+    WeightedAverage {
+        values: ActionValue,
+        weights: ActionValue,
+    },
 
     // Logical
     #[serde(rename = "AND")]
@@ -224,6 +328,20 @@ pub enum ActionOperation {
     IsNull { subject: ActionValue },
     #[serde(rename = "NOT_NULL")]
     NotNull { subject: ActionValue },
+    #[serde(rename = "IS_EMPTY")]
+    IsEmpty {
+        subject: ActionValue,
+        /// Also count whitespace-only strings as empty (default false)
+        #[serde(default)]
+        ignore_whitespace: Option<ActionValue>,
+    },
+    #[serde(rename = "NOT_EMPTY")]
+    NotEmpty {
+        subject: ActionValue,
+        /// Also count whitespace-only strings as empty (default false)
+        #[serde(default)]
+        ignore_whitespace: Option<ActionValue>,
+    },
 
     // Collection
     #[serde(rename = "IN")]
@@ -244,6 +362,79 @@ pub enum ActionOperation {
     },
     #[serde(rename = "LIST")]
     List { items: Vec<ActionValue> },
+    #[serde(rename = "COUNT_WHERE")]
+    CountWhere {
+        value: ActionValue,
+        condition: ActionValue,
+    },
+    #[serde(rename = "FLATTEN")]
+    Flatten { value: ActionValue },
+    #[serde(rename = "DISTINCT")]
+    Distinct { value: ActionValue },
+    #[serde(rename = "SLICE")]
+    Slice {
+        value: ActionValue,
+        start: ActionValue,
+        #[serde(default)]
+        length: Option<ActionValue>,
+    },
+    #[serde(rename = "SPLIT")]
+    Split {
+        subject: ActionValue,
+        /// Delimiter string
+        value: ActionValue,
+        /// Maximum number of parts; the last part holds the remainder
+        #[serde(default)]
+        limit: Option<ActionValue>,
+    },
+    #[serde(rename = "SORT")]
+    Sort {
+        value: ActionValue,
+        #[serde(default)]
+        descending: Option<ActionValue>,
+        /// `numeric` (default) or `string`
+        #[serde(default)]
+        mode: Option<ActionValue>,
+    },
+    #[serde(rename = "CUMULATIVE_SUM")]
+    CumulativeSum { value: ActionValue },
+    /// Total of `field` across an array of objects
+    #[serde(rename = "SUM_FIELD")]
+    SumField {
+        value: ActionValue,
+        field: String,
+        /// Skip elements without the field instead of failing (default: false)
+        #[serde(default)]
+        skip_missing: Option<ActionValue>,
+    },
+    /// Every element of `subject` also occurs in `value`
+    #[serde(rename = "IS_SUBSET")]
+    IsSubset {
+        subject: ActionValue,
+        value: ActionValue,
+    },
+    /// Every element of `value` also occurs in `subject`
+    #[serde(rename = "IS_SUPERSET")]
+    IsSuperset {
+        subject: ActionValue,
+        value: ActionValue,
+    },
+    /// `subject` and `value` hold the same elements, in any order
+    #[serde(rename = "SET_EQUALS")]
+    SetEquals {
+        subject: ActionValue,
+        value: ActionValue,
+        /// Require each element to occur equally often in both (default: false)
+        #[serde(default)]
+        count_duplicates: Option<ActionValue>,
+    },
+    #[serde(rename = "LABEL")]
+    Label {
+        subject: ActionValue,
+        table: ActionValue,
+        #[serde(default)]
+        default: Option<ActionValue>,
+    },
 
     // Date
     #[serde(rename = "AGE")]
@@ -251,6 +442,22 @@ pub enum ActionOperation {
         date_of_birth: ActionValue,
         reference_date: ActionValue,
     },
+    #[serde(rename = "HAS_REACHED_AGE")]
+    HasReachedAge {
+        date_of_birth: ActionValue,
+        /// Age in whole years to test for
+        age: ActionValue,
+        /// Date to test on; defaults to `$referencedate`
+        #[serde(default)]
+        reference_date: Option<ActionValue>,
+    },
+    #[serde(rename = "AGE_IN_MONTHS")]
+    AgeInMonths {
+        date_of_birth: ActionValue,
+        /// Date to calculate the age at; defaults to `$referencedate`
+        #[serde(default)]
+        reference_date: Option<ActionValue>,
+    },
     #[serde(rename = "DATE_ADD")]
     DateAdd {
         date: ActionValue,
@@ -271,11 +478,250 @@ pub enum ActionOperation {
     },
     #[serde(rename = "DAY_OF_WEEK")]
     DayOfWeek { date: ActionValue },
+    #[serde(rename = "SUBTRACT_DATE")]
+    SubtractDate {
+        values: Vec<ActionValue>,
+        unit: ActionValue,
+        /// `floor` (default), `ceil` or `nearest`; applies to months and years
+        #[serde(default)]
+        rounding: Option<ActionValue>,
+    },
+    #[serde(rename = "DATE_BEFORE")]
+    DateBefore {
+        subject: ActionValue,
+        value: ActionValue,
+    },
+    #[serde(rename = "DATE_AFTER")]
+    DateAfter {
+        subject: ActionValue,
+        value: ActionValue,
+    },
+    #[serde(rename = "DATE_ON_OR_BEFORE")]
+    DateOnOrBefore {
+        subject: ActionValue,
+        value: ActionValue,
+    },
+    #[serde(rename = "DATE_ON_OR_AFTER")]
+    DateOnOrAfter {
+        subject: ActionValue,
+        value: ActionValue,
+    },
+    #[serde(rename = "PRO_RATA")]
+    ProRata {
+        value: ActionValue,
+        start_date: ActionValue,
+        end_date: ActionValue,
+        period: ActionValue,
+    },
+    #[serde(rename = "CLAMP_DATE")]
+    ClampDate {
+        subject: ActionValue,
+        min: ActionValue,
+        max: ActionValue,
+    },
+    #[serde(rename = "NTH_WEEKDAY")]
+    NthWeekday {
+        date: ActionValue,
+        weekday: ActionValue,
+        ordinal: ActionValue,
+    },
+    #[serde(rename = "NEXT_BUSINESS_DAY")]
+    NextBusinessDay {
+        subject: ActionValue,
+        /// Dates that are not business days besides weekends
+        #[serde(default)]
+        holidays: Option<ActionValue>,
+    },
+    #[serde(rename = "YEAR_FRACTION")]
+    YearFraction {
+        start: ActionValue,
+        end: ActionValue,
+        /// Day-count convention: `ACT/365` (default), `ACT/360` or `ACT/ACT`
+        #[serde(default)]
+        convention: Option<ActionValue>,
+    },
+    #[serde(rename = "ISO_WEEK")]
+    IsoWeek { subject: ActionValue },
+    #[serde(rename = "QUARTER")]
+    Quarter { subject: ActionValue },
+    #[serde(rename = "CONVERT_DURATION")]
+    ConvertDuration {
+        value: ActionValue,
+        /// Unit of `value`: `days`, `weeks`, `months` or `years`
+        from: ActionValue,
+        /// Unit to convert to (same units as `from`)
+        to: ActionValue,
+    },
+    #[serde(rename = "DATE_IN_PERIODS")]
+    DateInPeriods {
+        subject: ActionValue,
+        /// List of `{from, to}` objects; `to` may be null or absent (open-ended)
+        periods: ActionValue,
+    },
+    #[serde(rename = "PERIOD_LOOKUP")]
+    PeriodLookup {
+        subject: ActionValue,
+        /// List of `{from, to, value}` objects; `to` may be null or absent
+        periods: ActionValue,
+        /// Result when no period contains the date (null when absent)
+        #[serde(default)]
+        default: Option<ActionValue>,
+    },
+    #[serde(rename = "PERIOD_OVERLAP_DAYS")]
+    PeriodOverlapDays {
+        /// Start of the first period; null means open at the start
+        a_from: ActionValue,
+        /// End of the first period; null means open-ended
+        a_to: ActionValue,
+        /// Start of the second period; null means open at the start
+        b_from: ActionValue,
+        /// End of the second period; null means open-ended
+        b_to: ActionValue,
+    },
+    /// Earliest date in an array, as an ISO string
+    #[serde(rename = "MIN_DATE")]
+    MinDate { value: ActionValue },
+    /// Latest date in an array, as an ISO string
+    #[serde(rename = "MAX_DATE")]
+    MaxDate { value: ActionValue },
+
+    // Cross-law
+    #[serde(rename = "URI_CALL")]
+    UriCall {
+        uri: String,
+        #[serde(default)]
+        parameters: BTreeMap<String, ActionValue>,
+    },
+    #[serde(rename = "ANY_ENTITLED")]
+    AnyEntitled {
+        /// Outputs to try in order; evaluation stops at the first truthy one
+        references: Vec<UriReference>,
+    },
+    #[serde(rename = "FIRST_RESOLVED")]
+    FirstResolved {
+        /// Outputs to try in order; evaluation stops at the first non-null one
+        references: Vec<UriReference>,
+        /// Result when every reference resolves to null (null when absent)
+        #[serde(default)]
+        default: Option<ActionValue>,
+    },
+    #[serde(rename = "DELEGATED_LOOKUP")]
+    DelegatedLookup {
+        /// Keyed table produced by a delegated regulation (an object)
+        subject: ActionValue,
+        /// Key to select, e.g. `$gemeente_code`
+        select_on: ActionValue,
+    },
+    #[serde(rename = "DELEGATED_OR_DEFAULT")]
+    DelegatedOrDefault {
+        /// Optional open term, e.g. `$percentage`; null when not delegated
+        subject: ActionValue,
+        /// Statutory fallback used when no regulation fills the open term
+        default: ActionValue,
+    },
+    #[serde(rename = "APPLICABLE_VERSION")]
+    ApplicableVersion {
+        /// Law whose version applicable on the calculation date is reported
+        law_id: String,
+    },
+
+    // Formula
+    /// Evaluate a named expression from a `formulas` section
+    #[serde(rename = "CALL_FORMULA")]
+    CallFormula { name: String },
+
+    // Validation
+    #[serde(rename = "VALIDATE_BSN")]
+    ValidateBsn { subject: ActionValue },
+    #[serde(rename = "NORMALIZE_POSTCODE")]
+    NormalizePostcode { subject: ActionValue },
+    /// Whether the subject string matches the regex pattern in `value`
+    #[serde(rename = "MATCHES")]
+    Matches {
+        subject: ActionValue,
+        value: ActionValue,
+    },
+
+    // Type conversion
+    #[serde(rename = "CAST")]
+    Cast {
+        subject: ActionValue,
+        value: ActionValue,
+    },
+    #[serde(rename = "PARSE_BOOL")]
+    ParseBool {
+        subject: ActionValue,
+        /// Tokens read as true (default: Dutch and English forms)
+        #[serde(default)]
+        true_values: Option<ActionValue>,
+        /// Tokens read as false (default: Dutch and English forms)
+        #[serde(default)]
+        false_values: Option<ActionValue>,
+    },
+
+    // Custom (must stay last: tried only when no built-in operation matches)
+    /// Operation executed by a handler registered in an
+    /// [`OperationRegistry`](crate::OperationRegistry)
+    #[serde(untagged)]
+    Custom(CustomOperation),
+}
+
+/// An operation that is not built into the engine, such as `operation: DOUBLE`.
+///
+/// Only names that are not built-in operations deserialize as custom ones, so
+/// a malformed built-in operation is not mistaken for a custom operation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawCustomOperation")]
+pub struct CustomOperation {
+    /// Name of the operation, used to look up its handler
+    pub operation: String,
+    /// Remaining fields of the operation, by name
+    #[serde(flatten)]
+    pub fields: BTreeMap<String, ActionValue>,
+}
+
+impl CustomOperation {
+    /// Get an operand of this operation by field name.
+    pub fn field(&self, name: &str) -> Option<&ActionValue> {
+        self.fields.get(name)
+    }
+}
+
+#[derive(Deserialize)]
+struct RawCustomOperation {
+    operation: String,
+    #[serde(flatten)]
+    fields: BTreeMap<String, ActionValue>,
+}
+
+impl TryFrom<RawCustomOperation> for CustomOperation {
+    type Error = String;
+
+    fn try_from(raw: RawCustomOperation) -> std::result::Result<Self, Self::Error> {
+        if is_builtin_operation(&raw.operation) {
+            return Err(format!(
+                "malformed {} operation is not a custom operation",
+                raw.operation
+            ));
+        }
+        Ok(CustomOperation {
+            operation: raw.operation,
+            fields: raw.fields,
+        })
+    }
+}
+
+/// Whether `name` is one of the engine's built-in operations.
+fn is_builtin_operation(name: &str) -> bool {
+    use serde::de::IntoDeserializer;
+    let builtin: std::result::Result<Operation, serde::de::value::Error> =
+        Operation::deserialize(name.into_deserializer());
+    builtin.is_ok()
 }
 
 impl ActionOperation {
-    /// Get the operation name as a static uppercase string (for tracing).
-    pub fn operation_name(&self) -> &'static str {
+    /// Get the operation name as an uppercase string (for tracing).
+    pub fn operation_name(&self) -> &str {
         match self {
             ActionOperation::Equals { .. } => "EQUALS",
             ActionOperation::NotEquals { .. } => "NOT_EQUALS",
@@ -283,25 +729,81 @@ impl ActionOperation {
             ActionOperation::LessThan { .. } => "LESS_THAN",
             ActionOperation::GreaterThanOrEqual { .. } => "GREATER_THAN_OR_EQUAL",
             ActionOperation::LessThanOrEqual { .. } => "LESS_THAN_OR_EQUAL",
+            ActionOperation::ApproxEquals { .. } => "APPROX_EQUALS",
             ActionOperation::Add { .. } => "ADD",
             ActionOperation::Subtract { .. } => "SUBTRACT",
             ActionOperation::Multiply { .. } => "MULTIPLY",
             ActionOperation::Divide { .. } => "DIVIDE",
+            ActionOperation::IntDivide { .. } => "INT_DIVIDE",
+            ActionOperation::CompoundIndex { .. } => "COMPOUND_INDEX",
+            ActionOperation::RoundToMultiple { .. } => "ROUND_TO_MULTIPLE",
+            ActionOperation::LinearTaper { .. } => "LINEAR_TAPER",
+            ActionOperation::Clamp { .. } => "CLAMP",
+            ActionOperation::ProgressiveBrackets { .. } => "PROGRESSIVE_BRACKETS",
+            ActionOperation::Negate { .. } => "NEGATE",
             ActionOperation::Max { .. } => "MAX",
             ActionOperation::Min { .. } => "MIN",
+            ActionOperation::WeightedAverage { .. } => "WEIGHTED_AVERAGE",
             ActionOperation::And { .. } => "AND",
             ActionOperation::Or { .. } => "OR",
             ActionOperation::Not { .. } => "NOT",
             ActionOperation::If { .. } => "IF",
             ActionOperation::IsNull { .. } => "IS_NULL",
             ActionOperation::NotNull { .. } => "NOT_NULL",
+            ActionOperation::IsEmpty { .. } => "IS_EMPTY",
+            ActionOperation::NotEmpty { .. } => "NOT_EMPTY",
             ActionOperation::In { .. } => "IN",
             ActionOperation::NotIn { .. } => "NOT_IN",
             ActionOperation::List { .. } => "LIST",
+            ActionOperation::CountWhere { .. } => "COUNT_WHERE",
+            ActionOperation::Flatten { .. } => "FLATTEN",
+            ActionOperation::Distinct { .. } => "DISTINCT",
+            ActionOperation::Slice { .. } => "SLICE",
+            ActionOperation::Split { .. } => "SPLIT",
+            ActionOperation::Sort { .. } => "SORT",
+            ActionOperation::CumulativeSum { .. } => "CUMULATIVE_SUM",
+            ActionOperation::SumField { .. } => "SUM_FIELD",
+            ActionOperation::IsSubset { .. } => "IS_SUBSET",
+            ActionOperation::IsSuperset { .. } => "IS_SUPERSET",
+            ActionOperation::SetEquals { .. } => "SET_EQUALS",
+            ActionOperation::Label { .. } => "LABEL",
             ActionOperation::Age { .. } => "AGE",
+            ActionOperation::HasReachedAge { .. } => "HAS_REACHED_AGE",
+            ActionOperation::AgeInMonths { .. } => "AGE_IN_MONTHS",
             ActionOperation::DateAdd { .. } => "DATE_ADD",
             ActionOperation::Date { .. } => "DATE",
             ActionOperation::DayOfWeek { .. } => "DAY_OF_WEEK",
+            ActionOperation::SubtractDate { .. } => "SUBTRACT_DATE",
+            ActionOperation::DateBefore { .. } => "DATE_BEFORE",
+            ActionOperation::DateAfter { .. } => "DATE_AFTER",
+            ActionOperation::DateOnOrBefore { .. } => "DATE_ON_OR_BEFORE",
+            ActionOperation::DateOnOrAfter { .. } => "DATE_ON_OR_AFTER",
+            ActionOperation::ProRata { .. } => "PRO_RATA",
+            ActionOperation::ClampDate { .. } => "CLAMP_DATE",
+            ActionOperation::NthWeekday { .. } => "NTH_WEEKDAY",
+            ActionOperation::NextBusinessDay { .. } => "NEXT_BUSINESS_DAY",
+            ActionOperation::YearFraction { .. } => "YEAR_FRACTION",
+            ActionOperation::DateInPeriods { .. } => "DATE_IN_PERIODS",
+            ActionOperation::PeriodLookup { .. } => "PERIOD_LOOKUP",
+            ActionOperation::PeriodOverlapDays { .. } => "PERIOD_OVERLAP_DAYS",
+            ActionOperation::MinDate { .. } => "MIN_DATE",
+            ActionOperation::MaxDate { .. } => "MAX_DATE",
+            ActionOperation::IsoWeek { .. } => "ISO_WEEK",
+            ActionOperation::Quarter { .. } => "QUARTER",
+            ActionOperation::ConvertDuration { .. } => "CONVERT_DURATION",
+            ActionOperation::UriCall { .. } => "URI_CALL",
+            ActionOperation::AnyEntitled { .. } => "ANY_ENTITLED",
+            ActionOperation::FirstResolved { .. } => "FIRST_RESOLVED",
+            ActionOperation::DelegatedLookup { .. } => "DELEGATED_LOOKUP",
+            ActionOperation::DelegatedOrDefault { .. } => "DELEGATED_OR_DEFAULT",
+            ActionOperation::ApplicableVersion { .. } => "APPLICABLE_VERSION",
+            ActionOperation::CallFormula { .. } => "CALL_FORMULA",
+            ActionOperation::ValidateBsn { .. } => "VALIDATE_BSN",
+            ActionOperation::NormalizePostcode { .. } => "NORMALIZE_POSTCODE",
+            ActionOperation::Matches { .. } => "MATCHES",
+            ActionOperation::Cast { .. } => "CAST",
+            ActionOperation::ParseBool { .. } => "PARSE_BOOL",
+            ActionOperation::Custom(custom) => &custom.operation,
         }
     }
 }
@@ -325,6 +827,9 @@ pub struct Action {
     /// Conditions for AND/OR operations
     #[serde(default)]
     pub conditions: Option<Vec<ActionValue>>,
+    /// For ADD/MULTIPLY: return the identity for empty `values` instead of an error
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_empty: bool,
 }
 
 /// Execution specification within machine_readable section
@@ -342,6 +847,9 @@ pub struct Execution {
     pub actions: Option<Vec<Action>>,
 }
 
+/// An article's definitions section, by name.
+pub type Definitions = HashMap<String, Definition>;
+
 /// Definition value in definitions section
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -548,7 +1056,10 @@ pub struct UntranslatableEntry {
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct MachineReadable {
     #[serde(default)]
-    pub definitions: Option<HashMap<String, Definition>>,
+    pub definitions: Option<Definitions>,
+    /// Named reusable expressions, evaluated with CALL_FORMULA
+    #[serde(default)]
+    pub formulas: Option<BTreeMap<String, ActionValue>>,
     #[serde(default)]
     pub execution: Option<Execution>,
     #[serde(default)]
@@ -570,10 +1081,33 @@ pub struct MachineReadable {
     /// Legal constructs that cannot be expressed with the current operation set (RFC-012)
     #[serde(default)]
     pub untranslatables: Option<Vec<UntranslatableEntry>>,
+    /// Legal concept tags for this article (e.g., `definition`, `input`, `output`, `logic`)
+    #[serde(default)]
+    pub classifications: Option<Vec<String>>,
+}
+
+/// Definition values derived from an article's YAML once and reused by every evaluation.
+///
+/// Built lazily on first evaluation or up front by
+/// [`LawExecutionService::warm_up`](crate::LawExecutionService::warm_up).
+/// Actions and inputs need no such step: they are parsed into typed
+/// operations when the law is loaded. Not part of the article's identity:
+/// ignored by equality and serialization.
+#[derive(Clone, Default)]
+pub(crate) struct DefinitionCache {
+    values: OnceLock<Arc<BTreeMap<String, Value>>>,
+}
+
+impl std::fmt::Debug for DefinitionCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DefinitionCache")
+            .field("built", &self.values.get().is_some())
+            .finish()
+    }
 }
 
 /// Represents a single article in a law
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Article {
     pub number: String,
     pub text: String,
@@ -582,6 +1116,21 @@ pub struct Article {
     pub url: Option<String>,
     #[serde(default)]
     pub machine_readable: Option<MachineReadable>,
+    /// Cached definition values, see [`Article::definition_values`]
+    #[serde(skip)]
+    pub(crate) definition_cache: DefinitionCache,
+}
+
+// The cache only holds values derived from `machine_readable`, so it is left
+// out: articles with the same content are equal whether or not either was
+// evaluated or warmed up.
+impl PartialEq for Article {
+    fn eq(&self, other: &Self) -> bool {
+        self.number == other.number
+            && self.text == other.text
+            && self.url == other.url
+            && self.machine_readable == other.machine_readable
+    }
 }
 
 impl Article {
@@ -590,6 +1139,40 @@ impl Article {
         self.machine_readable.as_ref()?.execution.as_ref()
     }
 
+    /// Render the structure of this article's actions as a box-drawing tree,
+    /// without executing anything.
+    ///
+    /// Each action is shown with its operation tree; operands are listed by
+    /// field name, in declaration order, with their literal value or
+    /// `$variable` reference.
+    pub fn render_plan(&self) -> String {
+        let nodes: Vec<PathNode> = self
+            .get_execution_spec()
+            .and_then(|exec| exec.actions.as_deref())
+            .unwrap_or(&[])
+            .iter()
+            .map(|action| {
+                let name = action.output.as_deref().unwrap_or("(no output)");
+                let mut node = PathNode::new(PathNodeType::Action, name);
+                // YAML mappings keep insertion order, so fields are listed as
+                // declared rather than sorted like a serde_json object
+                let mut fields = match serde_yaml_ng::to_value(action) {
+                    Ok(serde_yaml_ng::Value::Mapping(fields)) => fields,
+                    _ => serde_yaml_ng::Mapping::new(),
+                };
+                fields.remove("output");
+                if fields.get("operation").is_some_and(|op| !op.is_null()) {
+                    node.children
+                        .push(plan_node(None, &serde_yaml_ng::Value::Mapping(fields)));
+                } else {
+                    node.children.extend(plan_fields(&fields));
+                }
+                node
+            })
+            .collect();
+        PathNode::render_box_drawing_forest(&format!("Article {}", self.number), &nodes)
+    }
+
     /// Get definitions from this article.
     ///
     /// Returns a reference to avoid unnecessary allocations.
@@ -599,6 +1182,38 @@ impl Article {
             .and_then(|mr| mr.definitions.as_ref())
     }
 
+    /// Get this article's declared definitions, empty when it has none.
+    pub fn definitions(&self) -> &Definitions {
+        static NONE: LazyLock<Definitions> = LazyLock::new(HashMap::new);
+        self.get_definitions().unwrap_or(&NONE)
+    }
+
+    /// Get the named formulas declared by this article.
+    pub fn get_formulas(&self) -> Option<&BTreeMap<String, ActionValue>> {
+        self.machine_readable
+            .as_ref()
+            .and_then(|mr| mr.formulas.as_ref())
+    }
+
+    /// Values of this article's definitions, converted on first use and
+    /// shared by every evaluation afterwards.
+    pub fn definition_values(&self) -> Arc<BTreeMap<String, Value>> {
+        Arc::clone(self.definition_cache.values.get_or_init(|| {
+            let values = self
+                .get_definitions()
+                .into_iter()
+                .flatten()
+                .map(|(name, definition)| (name.clone(), definition.value().clone()))
+                .collect();
+            Arc::new(values)
+        }))
+    }
+
+    /// Whether [`Self::definition_values`] has been built for this article.
+    pub fn has_definition_values(&self) -> bool {
+        self.definition_cache.values.get().is_some()
+    }
+
     /// Get required URI dependencies
     pub fn get_requires(&self) -> Vec<&str> {
         self.machine_readable
@@ -686,6 +1301,21 @@ impl Article {
         self.get_execution_spec()
             .and_then(|exec| exec.produces.as_ref())
     }
+
+    /// Get the legal concept tags of this article.
+    pub fn get_classifications(&self) -> &[String] {
+        self.machine_readable
+            .as_ref()
+            .and_then(|mr| mr.classifications.as_deref())
+            .unwrap_or(&[])
+    }
+
+    /// Check whether this article is tagged with the given classification.
+    pub fn has_classification(&self, classification: &str) -> bool {
+        self.get_classifications()
+            .iter()
+            .any(|c| c == classification)
+    }
 }
 
 /// Represents an article-based law document
@@ -710,9 +1340,15 @@ pub struct ArticleBasedLaw {
     /// Law name (can be a reference like "#wet_naam")
     #[serde(default)]
     pub name: Option<String>,
+    /// Alternative IDs this law can be referenced by (e.g. its formal name)
+    #[serde(default)]
+    pub aliases: Option<Vec<String>>,
     /// Competent authority
     #[serde(default)]
     pub competent_authority: Option<CompetentAuthority>,
+    /// Organisation that executes the law (e.g. "Belastingdienst")
+    #[serde(default, alias = "uitvoerder")]
+    pub authority: Option<String>,
     /// BWB identifier for national laws
     #[serde(default)]
     pub bwb_id: Option<String>,
@@ -781,6 +1417,31 @@ impl ArticleBasedLaw {
             (Some(a), Some(b), Some(c), None) if valid(a) && valid(b) && valid(c))
     }
 
+    /// Compute a SHA-256 hash of the parsed law, for cache keys and change detection.
+    ///
+    /// Unlike the `content_hash` field (a hash of the raw YAML bytes), this hashes
+    /// the canonicalized structure: the law is serialized to JSON with object keys
+    /// sorted, so YAML key order, quoting, comments and whitespace do not affect it.
+    pub fn content_hash(&self) -> Result<String> {
+        use sha2::Digest;
+        // serde_json::Value objects are BTreeMap-backed, which sorts every key
+        let canonical = serde_json::to_vec(&serde_json::to_value(self)?)?;
+        let hash = sha2::Sha256::digest(&canonical);
+        Ok(format!("sha256:{}", hex::encode(hash)))
+    }
+
+    /// Serialize the parsed law to YAML in a canonical, byte-stable form.
+    ///
+    /// Object keys are sorted and formatting is the serializer's own, so two
+    /// documents that parse to the same law produce identical output whatever
+    /// their key order, quoting, comments or whitespace. Meant for golden and
+    /// snapshot tests; the result parses back to an equal law.
+    pub fn to_canonical_yaml(&self) -> Result<String> {
+        // serde_json::Value objects are BTreeMap-backed, which sorts every key
+        let canonical = serde_json::to_value(self)?;
+        Ok(serde_yaml_ng::to_string(&canonical)?)
+    }
+
     /// Load a law from a YAML file.
     ///
     /// # Security
@@ -800,8 +1461,12 @@ impl ArticleBasedLaw {
     /// - The file size exceeds the maximum limit
     /// - The path contains traversal sequences
     pub fn from_yaml_file<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let path_ref = path.as_ref();
+        Self::from_yaml_str(&Self::read_yaml_file(path.as_ref())?)
+    }
 
+    /// Read a law file's YAML, enforcing the same size limit as
+    /// [`from_yaml_file`](Self::from_yaml_file).
+    pub(crate) fn read_yaml_file(path_ref: &Path) -> Result<String> {
         // Log the load attempt (without exposing full path in errors)
         tracing::debug!(path = %path_ref.display(), "Loading law from YAML file");
 
@@ -835,12 +1500,10 @@ impl ArticleBasedLaw {
             )));
         }
 
-        let content = fs::read_to_string(path_ref).map_err(|_| {
+        fs::read_to_string(path_ref).map_err(|_| {
             // Sanitized error message
             EngineError::LoadError("Failed to read law file".to_string())
-        })?;
-
-        Self::from_yaml_str(&content)
+        })
     }
 
     /// Parse a law from a YAML string.
@@ -901,6 +1564,37 @@ impl ArticleBasedLaw {
         Ok(law)
     }
 
+    /// List operation fields in a law's YAML that the engine would silently ignore.
+    ///
+    /// Operations are deserialized leniently: a field that does not belong to the
+    /// operation type (e.g. `cases` on an `EQUALS`, or `values` next to `value`)
+    /// is dropped without notice. This walks the raw document and reports each
+    /// such field as `"<OPERATION>.<field>"`. A field counts as used when
+    /// replacing its value changes how the operation deserializes; objects that
+    /// don't deserialize as an operation at all are left to the regular loader.
+    ///
+    /// # Errors
+    /// Returns a YAML error when the document cannot be parsed.
+    pub fn find_ignored_operation_fields(content: &str) -> Result<Vec<String>> {
+        let document: serde_json::Value =
+            serde_yaml_ng::from_str(content).map_err(EngineError::YamlError)?;
+        let mut ignored = Vec::new();
+        collect_ignored_operation_fields(&document, &mut ignored);
+        Ok(ignored)
+    }
+
+    /// Names of the custom operations (see [`CustomOperation`]) this law uses.
+    ///
+    /// Any operation name that is not built in deserializes as a custom one, so
+    /// a misspelled built-in operation shows up here too.
+    pub fn custom_operation_names(&self) -> BTreeSet<String> {
+        let mut names = BTreeSet::new();
+        if let Ok(document) = serde_json::to_value(self) {
+            collect_custom_operation_names(&document, &mut names);
+        }
+        names
+    }
+
     /// Validate that all arrays in the law are within size limits.
     ///
     /// This prevents DoS attacks via YAML documents with extremely large arrays.
@@ -1001,6 +1695,29 @@ impl ArticleBasedLaw {
         Ok(())
     }
 
+    /// Validate that every output's `source_span` lies within its article text.
+    pub(crate) fn validate_source_spans(&self) -> Result<()> {
+        for article in &self.articles {
+            let len = article.text.chars().count();
+            let outputs = article
+                .get_execution_spec()
+                .and_then(|exec| exec.output.as_deref())
+                .unwrap_or(&[]);
+            for output in outputs {
+                let Some(span) = output.source_span else {
+                    continue;
+                };
+                if span.start >= span.end || span.end > len {
+                    return Err(EngineError::LoadError(format!(
+                        "source_span {}..{} of output '{}' in article {} is outside its text ({} characters)",
+                        span.start, span.end, output.name, article.number, len
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Validate arrays within an action.
     fn validate_action_arrays(action: &Action, article_number: &str) -> Result<()> {
         if let Some(values) = &action.values {
@@ -1028,6 +1745,21 @@ impl ArticleBasedLaw {
         Ok(())
     }
 
+    /// Formulas that CALL_FORMULA can reach from `article`.
+    ///
+    /// These are the article's own formulas plus those declared by the other
+    /// articles of this law; the article's own take precedence on a name clash.
+    pub fn formulas_for(&self, article: &Article) -> Arc<BTreeMap<String, ActionValue>> {
+        let others = self.articles.iter().filter(|a| a.number != article.number);
+        let formulas = others
+            .chain(std::iter::once(article))
+            .filter_map(Article::get_formulas)
+            .flatten()
+            .map(|(name, formula)| (name.clone(), formula.clone()))
+            .collect();
+        Arc::new(formulas)
+    }
+
     /// Find article that produces the given output.
     ///
     /// Uses allocation-free search via `Article::has_output()`.
@@ -1078,6 +1810,127 @@ impl ArticleBasedLaw {
     }
 }
 
+/// Build a plan node for an operand (or operation) serialized to JSON.
+///
+/// Objects with an `operation` field become operation nodes; other objects
+/// (such as IF cases) group their fields; arrays list their items under the
+/// same label; scalars become leaf lines.
+fn plan_node(label: Option<&str>, value: &serde_yaml_ng::Value) -> PathNode {
+    let prefix = label.map(|l| format!("{}: ", l)).unwrap_or_default();
+    match value {
+        serde_yaml_ng::Value::Mapping(fields) => {
+            let (message, node_type) = match fields.get("operation") {
+                Some(op) => (
+                    format!("{}{}", prefix, op.as_str().unwrap_or("?")),
+                    PathNodeType::Operation,
+                ),
+                None => (label.unwrap_or("").to_string(), PathNodeType::Operation),
+            };
+            let mut node = PathNode::new(node_type, message.clone()).with_message(message);
+            node.children = plan_fields(fields);
+            node
+        }
+        serde_yaml_ng::Value::Tagged(tagged) => plan_node(label, &tagged.value),
+        other => {
+            let text = plan_scalar(other);
+            PathNode::new(PathNodeType::Resolve, text.clone())
+                .with_message(format!("{}{}", prefix, text))
+        }
+    }
+}
+
+/// Text of a scalar operand in a plan.
+fn plan_scalar(value: &serde_yaml_ng::Value) -> String {
+    match value {
+        serde_yaml_ng::Value::String(s) => s.clone(),
+        serde_yaml_ng::Value::Number(n) => n.to_string(),
+        serde_yaml_ng::Value::Bool(b) => b.to_string(),
+        serde_yaml_ng::Value::Null => "null".to_string(),
+        other => serde_yaml_ng::to_string(other)
+            .map(|text| text.trim_end().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+/// Plan nodes for the operand fields of an operation (skipping metadata).
+fn plan_fields(fields: &serde_yaml_ng::Mapping) -> Vec<PathNode> {
+    fields
+        .iter()
+        .filter_map(|(key, value)| Some((key.as_str()?, value)))
+        .filter(|(key, value)| match *key {
+            "operation" | "legal_basis" => false,
+            "allow_empty" => value.as_bool() == Some(true),
+            _ => !value.is_null(),
+        })
+        .flat_map(|(key, value)| match value {
+            serde_yaml_ng::Value::Sequence(items) => items
+                .iter()
+                .map(|item| plan_node(Some(key), item))
+                .collect(),
+            _ => vec![plan_node(Some(key), value)],
+        })
+        .collect()
+}
+
+/// Recursive worker for [`ArticleBasedLaw::custom_operation_names`].
+fn collect_custom_operation_names(value: &serde_json::Value, names: &mut BTreeSet<String>) {
+    match value {
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_custom_operation_names(item, names);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            if let Some(serde_json::Value::String(name)) = map.get("operation") {
+                if !is_builtin_operation(name) {
+                    names.insert(name.clone());
+                }
+            }
+            for child in map.values() {
+                collect_custom_operation_names(child, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Recursive worker for [`ArticleBasedLaw::find_ignored_operation_fields`].
+fn collect_ignored_operation_fields(value: &serde_json::Value, ignored: &mut Vec<String>) {
+    // Fields that are valid next to any operation and never reach `ActionOperation`
+    const PASSTHROUGH: &[&str] = &["operation", "output", "legal_basis"];
+    const PROBE: &str = "\u{0}unused-field-probe";
+
+    match value {
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_ignored_operation_fields(item, ignored);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            if let Some(serde_json::Value::String(name)) = map.get("operation") {
+                let mut operation = map.clone();
+                operation.retain(|key, _| key != "output" && key != "legal_basis");
+                let parse = |candidate: serde_json::Map<String, serde_json::Value>| {
+                    serde_json::from_value::<ActionOperation>(serde_json::Value::Object(candidate))
+                };
+                if let Ok(parsed) = parse(operation.clone()) {
+                    for key in map.keys().filter(|k| !PASSTHROUGH.contains(&k.as_str())) {
+                        let mut probed = operation.clone();
+                        probed.insert(key.clone(), serde_json::Value::String(PROBE.to_string()));
+                        if parse(probed).is_ok_and(|p| p == parsed) {
+                            ignored.push(format!("{}.{}", name, key));
+                        }
+                    }
+                }
+            }
+            for child in map.values() {
+                collect_ignored_operation_fields(child, ignored);
+            }
+        }
+        _ => {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1132,6 +1985,149 @@ articles:
         assert_eq!(law.articles[0].text, "Test article text");
     }
 
+    #[test]
+    fn test_find_ignored_operation_fields() {
+        let yaml = r#"
+$id: stray_fields
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Test
+    machine_readable:
+      execution:
+        output:
+          - name: is_adult
+            type: boolean
+        actions:
+          - output: is_adult
+            value:
+              operation: EQUALS
+              subject: $age
+              value: 18
+              cases:
+                - when: true
+                  then: 1
+          - output: total
+            operation: ADD
+            values: [1, 2]
+            allow_empty: false
+            legal_basis:
+              law: test
+"#;
+        let ignored = ArticleBasedLaw::find_ignored_operation_fields(yaml).unwrap();
+        assert_eq!(ignored, vec!["EQUALS.cases"]);
+    }
+
+    #[test]
+    fn test_canonical_yaml_is_stable_and_reparses() {
+        let original = ArticleBasedLaw::from_yaml_str(LAW_WITH_OUTPUTS_YAML).unwrap();
+        let canonical = original.to_canonical_yaml().unwrap();
+        assert!(canonical.starts_with("$id: law_with_outputs\n"));
+
+        let mut reparsed = ArticleBasedLaw::from_yaml_str(&canonical).unwrap();
+        assert_eq!(reparsed.to_canonical_yaml().unwrap(), canonical);
+        // Only the raw-bytes hash differs between the two parses
+        reparsed.content_hash = original.content_hash.clone();
+        assert_eq!(reparsed, original);
+    }
+
+    #[test]
+    fn test_content_hash_ignores_key_order_and_whitespace() {
+        let reordered = r#"
+articles:
+  -   text:   First article
+      number: '1'
+      machine_readable:
+        execution:
+          actions:
+            - value: true
+              output: test_output
+          output:
+            - type: boolean
+              name: test_output
+        definitions:
+          CONSTANT_VALUE: { value: 100 }
+  - number: "2"
+    machine_readable:
+      execution:
+        output: [{ name: another_output, type: number }]
+        actions: [{ output: another_output, value: 42 }]
+    text: Second article
+publication_date: "2025-01-01"
+regulatory_layer: WET
+$id: law_with_outputs
+"#;
+        let original = ArticleBasedLaw::from_yaml_str(LAW_WITH_OUTPUTS_YAML).unwrap();
+        let shuffled = ArticleBasedLaw::from_yaml_str(reordered).unwrap();
+        assert_ne!(original.content_hash, shuffled.content_hash);
+        assert_eq!(
+            original.content_hash().unwrap(),
+            shuffled.content_hash().unwrap()
+        );
+
+        let changed = ArticleBasedLaw::from_yaml_str(
+            &LAW_WITH_OUTPUTS_YAML.replace("value: 42", "value: 43"),
+        )
+        .unwrap();
+        assert_ne!(
+            original.content_hash().unwrap(),
+            changed.content_hash().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_render_plan_nested_if_multiply() {
+        let law = ArticleBasedLaw::from_yaml_str(
+            r#"
+$id: plan_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '2'
+    text: Plan article
+    machine_readable:
+      execution:
+        output:
+          - name: hoogte_toeslag
+            type: amount
+        actions:
+          - output: hoogte_toeslag
+            value:
+              operation: IF
+              cases:
+                - when:
+                    operation: GREATER_THAN
+                    subject: $inkomen
+                    value: 0
+                  then:
+                    operation: MULTIPLY
+                    values:
+                      - $inkomen
+                      - 0.5
+              default: 0
+"#,
+        )
+        .unwrap();
+
+        let plan = law.articles[0].render_plan();
+
+        let expected = [
+            "Article 2",
+            "└──Computing hoogte_toeslag",
+            "    └──value: IF",
+            "        ├──cases",
+            "        │   ├──when: GREATER_THAN",
+            "        │   │   ├──subject: $inkomen",
+            "        │   │   └──value: 0",
+            "        │   └──then: MULTIPLY",
+            "        │       ├──values: $inkomen",
+            "        │       └──values: 0.5",
+            "        └──default: 0",
+        ];
+        assert_eq!(plan.lines().collect::<Vec<_>>(), expected, "{}", plan);
+    }
+
     #[test]
     fn test_find_article_by_output() {
         let law = ArticleBasedLaw::from_yaml_str(LAW_WITH_OUTPUTS_YAML).unwrap();
@@ -0,0 +1,60 @@
+//! Audit log of top-level evaluations.
+//!
+//! Regulated deployments must be able to account for every decision. A
+//! service with an [`AuditSink`] installed (see
+//! [`LawExecutionService::set_audit_sink`](crate::LawExecutionService::set_audit_sink))
+//! hands the sink one [`AuditRecord`] per top-level evaluation, whichever
+//! `evaluate_law*` entry point it came through and whether it succeeded or
+//! failed. Parameters configured as sensitive
+//! are redacted before the record is built.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use crate::types::Value;
+
+/// One top-level evaluation, as handed to an [`AuditSink`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditRecord {
+    /// Law that was evaluated
+    pub law_id: String,
+    /// Outputs that were requested
+    pub requested_outputs: Vec<String>,
+    /// Parameters of the request, with redacted values replaced by `[REDACTED]`
+    pub parameters: BTreeMap<String, Value>,
+    /// Calculation date of the request (YYYY-MM-DD)
+    pub calculation_date: String,
+    /// Date law versions were selected on (YYYY-MM-DD), which differs from
+    /// `calculation_date` for an evaluation as of a publication date
+    pub version_date: Option<String>,
+    /// Computed outputs; `None` when the evaluation failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outputs: Option<BTreeMap<String, Value>>,
+    /// Error message when the evaluation failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Engine version that evaluated the request
+    pub engine_version: String,
+    /// valid_from of the evaluated law version, when the evaluation succeeded
+    pub regulation_valid_from: Option<String>,
+    /// SHA-256 hash of the evaluated law version's YAML
+    pub regulation_hash: Option<String>,
+    /// valid_from of every law version an article was evaluated from, by law
+    /// id: the evaluated law and each regulation reached through cross-law
+    /// references, open terms, hooks and overrides
+    pub law_versions: BTreeMap<String, BTreeSet<String>>,
+    /// Wall-clock duration of the evaluation in microseconds
+    pub duration_us: u64,
+    /// When the evaluation finished (RFC 3339, UTC)
+    pub timestamp: String,
+}
+
+/// Receiver for audit records, e.g. a writer to an append-only log.
+///
+/// Sinks receive records through `&self` and may be called from several
+/// threads; use interior mutability to store them.
+pub trait AuditSink: Send + Sync {
+    /// Handle the record of one evaluation.
+    fn record(&self, record: &AuditRecord);
+}
@@ -0,0 +1,34 @@
+//! Clock abstraction for resolving "today".
+//!
+//! Anything that needs the current date (a context without an explicit
+//! calculation date, or a service call without one) asks a [`Clock`] instead
+//! of reading the system time directly. Tests and reproducible audits inject a
+//! [`FixedClock`] so the result does not depend on when they run.
+
+use chrono::NaiveDate;
+
+/// Source of the current date.
+pub trait Clock: Send + Sync {
+    /// The current date.
+    fn today(&self) -> NaiveDate;
+}
+
+/// Clock backed by the system's local time. This is the default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn today(&self) -> NaiveDate {
+        chrono::Local::now().date_naive()
+    }
+}
+
+/// Clock that always returns the same date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub NaiveDate);
+
+impl Clock for FixedClock {
+    fn today(&self) -> NaiveDate {
+        self.0
+    }
+}
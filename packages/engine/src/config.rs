@@ -56,6 +56,34 @@ pub const MAX_CROSS_LAW_DEPTH: usize = 20;
 /// 100 levels is sufficient for complex calculations while preventing abuse.
 pub const MAX_OPERATION_DEPTH: usize = 100;
 
+/// Maximum length in bytes of a MATCHES pattern.
+///
+/// The regex engine runs in linear time, so patterns cannot cause
+/// catastrophic backtracking; this bounds their size instead. Shape checks
+/// such as IBAN, BSN or postcode patterns are well under 100 bytes.
+pub const MAX_REGEX_PATTERN_LENGTH: usize = 256;
+
+/// Maximum size in bytes of a compiled MATCHES pattern.
+///
+/// Rejects patterns like `a{1000}{1000}` that are short but compile to a
+/// very large automaton.
+pub const MAX_REGEX_COMPILED_SIZE: usize = 1 << 20;
+
+/// Maximum number of cross-law calls in one evaluation.
+///
+/// The depth limits catch long chains, not fan-out: a law referencing many
+/// distinct regulations (or one regulation with many parameter sets) stays
+/// shallow but can still exhaust resources. Counts cross-law references,
+/// URI_CALLs and open-term delegations, cache hits included. The default can
+/// be lowered per service with `LawExecutionService::set_max_cross_law_calls`.
+pub const MAX_CROSS_LAW_CALLS: u64 = 10_000;
+
+/// Number of recent steps kept by a failure-only trace.
+///
+/// Enough to show the failing operation and the resolutions leading up to it
+/// without materializing the trace of a whole cross-law evaluation.
+pub const FAILURE_TRACE_STEPS: usize = 512;
+
 /// Schema versions supported by this engine version (RFC-013).
 ///
 /// A regulation referencing a schema version outside this list will be
@@ -5,17 +5,23 @@
 //! # Resolution Priority
 //!
 //! Variables are resolved in the following order (first match wins):
-//! 1. **Context variables** - Built-in variables like `referencedate`
+//! 1. **Context variables** - Built-in variables like `referencedate` and
+//!    `regulatory_layer` (the layer of the executing law, e.g. `"WET"`)
 //! 2. **Local scope** - Loop variables from FOREACH operations
 //! 3. **Outputs** - Previously calculated output values
 //! 4. **Resolved inputs** - Cached results from cross-law references
-//! 5. **Definitions** - Article-level constants
-//! 6. **Parameters** - Direct input parameters (e.g., BSN)
+//! 5. **Parameters** - Direct input parameters (e.g., BSN)
+//! 6. **Definitions** - Article-level constants
+//!
+//! A parameter named like a definition shadows it. Set
+//! [`ShadowPrecedence::Definition`] to resolve the definition instead; in both
+//! cases the shadowed name is recorded (see [`RuleContext::shadowed_definitions`]).
 //!
 //! # Dot Notation
 //!
 //! Supports nested property access using dot notation:
-//! - `referencedate.year` - Get year from reference date
+//! - `referencedate.year` - Get year from reference date (also `month`, `day`,
+//!   `quarter`, `iso_week`, `iso_year` and `iso`)
 //! - `person.address.city` - Navigate nested objects
 //!
 //! # Child Context Behavior
@@ -30,16 +36,21 @@
 //! If you need to pass values between iterations, use parameters or store them
 //! in outputs rather than relying on local scope inheritance.
 
-use crate::article::Definition;
+use crate::article::{ActionValue, Definition};
+use crate::clock::{Clock, SystemClock};
 use crate::config;
 use crate::error::{EngineError, Result};
-use crate::operations::ValueResolver;
-use crate::trace::TraceBuilder;
-use crate::types::{PathNodeType, ResolveType, Value};
+use crate::operations::{compile_regex, OperationHandler, OperationRegistry, ValueResolver};
+use crate::trace::{SourceSpan, TraceBuilder, TraceSink};
+use crate::types::{
+    EvaluationOptions, PathNodeType, RegulatoryLayer, ResolveType, ShadowPrecedence, Value,
+};
 use chrono::{Datelike, NaiveDate};
-use std::cell::RefCell;
-use std::collections::{BTreeMap, HashMap};
+use regex::Regex;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::rc::Rc;
+use std::sync::Arc;
 
 /// Execution context for article evaluation.
 ///
@@ -50,11 +61,12 @@ use std::rc::Rc;
 ///
 /// Variables in higher-priority scopes shadow those in lower scopes.
 /// For example, a local variable named "x" will shadow a parameter "x".
-/// The priority order is: local > outputs > resolved_inputs > definitions > parameters.
+/// The priority order is: local > outputs > resolved_inputs > parameters > definitions
+/// (definitions before parameters under [`ShadowPrecedence::Definition`]).
 #[derive(Debug, Clone)]
 pub struct RuleContext {
     /// Article-level definitions (constants)
-    definitions: Rc<BTreeMap<String, Value>>,
+    definitions: Arc<BTreeMap<String, Value>>,
 
     /// Input parameters (e.g., BSN, income)
     parameters: Rc<BTreeMap<String, Value>>,
@@ -74,8 +86,37 @@ pub struct RuleContext {
     /// Cached Value representation of reference_date (avoids repeated allocation)
     reference_date_value: Value,
 
+    /// Regulatory layer of the law being executed, exposed as `$regulatory_layer`
+    regulatory_layer: Option<RegulatoryLayer>,
+
     /// Optional shared trace builder for execution tracing
     trace: Option<Rc<RefCell<TraceBuilder>>>,
+
+    /// Evaluation options exposed to operations
+    options: EvaluationOptions,
+
+    /// Number of operations executed (shared with child contexts)
+    operations_evaluated: Rc<Cell<u64>>,
+
+    /// Number of AND, OR, ANY_ENTITLED and FIRST_RESOLVED operations that
+    /// stopped before their last operand (shared with child contexts)
+    short_circuits: Rc<Cell<u64>>,
+
+    /// Definitions resolved while a parameter of the same name was supplied
+    /// (shared with child contexts)
+    shadowed: Rc<RefCell<BTreeSet<String>>>,
+
+    /// Named formulas reachable through CALL_FORMULA
+    formulas: Arc<BTreeMap<String, ActionValue>>,
+
+    /// Formulas currently being evaluated, innermost last (for cycle detection)
+    formula_stack: Rc<RefCell<Vec<String>>>,
+
+    /// Handlers for custom operations
+    operations: Option<Arc<OperationRegistry>>,
+
+    /// MATCHES patterns compiled during this evaluation, by pattern
+    regexes: Rc<RefCell<HashMap<String, Regex>>>,
 }
 
 impl RuleContext {
@@ -84,38 +125,67 @@ impl RuleContext {
     /// # Arguments
     /// * `parameters` - Input parameters for the execution
     /// * `calculation_date` - Reference date for calculations (YYYY-MM-DD format)
+    ///
+    /// The date may instead be passed as a `referencedate` object parameter
+    /// (with an `iso` string, or `year`, `month` and `day`), leaving
+    /// `calculation_date` empty. When both are given they must agree.
     pub fn new(parameters: BTreeMap<String, Value>, calculation_date: &str) -> Result<Self> {
-        let reference_date = NaiveDate::parse_from_str(calculation_date, "%Y-%m-%d")
-            .map_err(|e| EngineError::InvalidDate(format!("{}: {}", calculation_date, e)))?;
+        let reference_date = reference_date_of(&parameters, calculation_date)?;
+        Ok(Self::from_date(parameters, reference_date))
+    }
 
+    /// Create a context with a default date (today, according to the system clock).
+    ///
+    /// Useful for testing.
+    pub fn with_defaults(parameters: BTreeMap<String, Value>) -> Self {
+        Self::with_clock(parameters, &SystemClock)
+    }
+
+    /// Create a context whose reference date is `clock`'s today.
+    pub fn with_clock(parameters: BTreeMap<String, Value>, clock: &dyn Clock) -> Self {
+        Self::from_date(parameters, clock.today())
+    }
+
+    /// Create a context for an already-parsed reference date.
+    fn from_date(parameters: BTreeMap<String, Value>, reference_date: NaiveDate) -> Self {
         let reference_date_value = date_to_value(reference_date);
 
-        Ok(Self {
-            definitions: Rc::new(BTreeMap::new()),
+        Self {
+            definitions: Arc::new(BTreeMap::new()),
             parameters: Rc::new(parameters),
             outputs: Rc::new(BTreeMap::new()),
             local: BTreeMap::new(),
             resolved_inputs: Rc::new(BTreeMap::new()),
             reference_date,
             reference_date_value,
+            regulatory_layer: None,
             trace: None,
-        })
+            options: EvaluationOptions::DEFAULT,
+            operations_evaluated: Rc::new(Cell::new(0)),
+            short_circuits: Rc::new(Cell::new(0)),
+            shadowed: Rc::new(RefCell::new(BTreeSet::new())),
+            formulas: Arc::new(BTreeMap::new()),
+            formula_stack: Rc::new(RefCell::new(Vec::new())),
+            operations: None,
+            regexes: Rc::new(RefCell::new(HashMap::new())),
+        }
     }
 
-    /// Create a context with a default date (today).
-    ///
-    /// Useful for testing.
-    #[allow(clippy::expect_used)]
-    pub fn with_defaults(parameters: BTreeMap<String, Value>) -> Self {
-        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
-        Self::new(parameters, &today).expect("today's date should always be valid")
+    /// Set the regulatory layer of the executing law, resolvable as `$regulatory_layer`.
+    pub fn set_regulatory_layer(&mut self, layer: RegulatoryLayer) {
+        self.regulatory_layer = Some(layer);
+    }
+
+    /// Set the evaluation options used by operations in this context.
+    pub fn set_options(&mut self, options: EvaluationOptions) {
+        self.options = options;
     }
 
     /// Set definitions from an article's definitions section.
     ///
     /// Processes the Definition enum to extract actual values.
     pub fn set_definitions(&mut self, definitions: &HashMap<String, Definition>) {
-        self.definitions = Rc::new(
+        self.definitions = Arc::new(
             definitions
                 .iter()
                 .map(|(k, v)| (k.clone(), v.value().clone()))
@@ -125,7 +195,24 @@ impl RuleContext {
 
     /// Set definitions directly from a Value HashMap.
     pub fn set_definitions_raw(&mut self, definitions: BTreeMap<String, Value>) {
-        self.definitions = Rc::new(definitions);
+        self.definitions = Arc::new(definitions);
+    }
+
+    /// Set definitions from already converted values, such as
+    /// [`Article::definition_values`](crate::article::Article::definition_values).
+    pub fn set_definition_values(&mut self, definitions: Arc<BTreeMap<String, Value>>) {
+        self.definitions = definitions;
+    }
+
+    /// Set the named formulas reachable through CALL_FORMULA, such as
+    /// [`ArticleBasedLaw::formulas_for`](crate::article::ArticleBasedLaw::formulas_for).
+    pub fn set_formulas(&mut self, formulas: Arc<BTreeMap<String, ActionValue>>) {
+        self.formulas = formulas;
+    }
+
+    /// Set the handlers used for custom operations.
+    pub fn set_operations(&mut self, operations: Arc<OperationRegistry>) {
+        self.operations = Some(operations);
     }
 
     /// Set an output value.
@@ -208,14 +295,23 @@ impl RuleContext {
     /// needed values explicitly via parameters before evaluation.
     pub fn create_child(&self) -> Self {
         Self {
-            definitions: Rc::clone(&self.definitions),
+            definitions: Arc::clone(&self.definitions),
             parameters: Rc::clone(&self.parameters),
             outputs: Rc::clone(&self.outputs),
             local: BTreeMap::new(), // Child starts with empty local scope
             resolved_inputs: Rc::clone(&self.resolved_inputs),
             reference_date: self.reference_date,
             reference_date_value: self.reference_date_value.clone(),
+            regulatory_layer: self.regulatory_layer,
             trace: self.trace.clone(), // Share the same trace builder
+            options: self.options,
+            operations_evaluated: Rc::clone(&self.operations_evaluated),
+            short_circuits: Rc::clone(&self.short_circuits),
+            shadowed: Rc::clone(&self.shadowed),
+            formulas: Arc::clone(&self.formulas),
+            formula_stack: Rc::clone(&self.formula_stack),
+            operations: self.operations.clone(),
+            regexes: Rc::clone(&self.regexes),
         }
     }
 
@@ -233,6 +329,19 @@ impl RuleContext {
         self.trace.as_ref()
     }
 
+    /// Set a sink that receives every trace event of this context (and its children).
+    ///
+    /// The sink is attached to the shared trace builder, replacing any sink it
+    /// had, so the builder's redaction applies. Without a trace builder, a
+    /// [`TraceBuilder::sink_only`] builder is installed. A later
+    /// [`set_trace`](Self::set_trace) replaces the builder and with it the sink.
+    pub fn set_trace_sink(&mut self, sink: Rc<dyn TraceSink>) {
+        match self.trace {
+            Some(ref trace) => trace.borrow_mut().set_sink(sink),
+            None => self.trace = Some(Rc::new(RefCell::new(TraceBuilder::sink_only(sink)))),
+        }
+    }
+
     /// Push a new node onto the trace stack. No-op if trace is None.
     pub fn trace_push(&self, name: &str, node_type: PathNodeType) {
         if let Some(ref trace) = self.trace {
@@ -254,6 +363,21 @@ impl RuleContext {
         }
     }
 
+    /// Mark the current trace node's result as money at the configured money scale.
+    /// No-op if trace is None.
+    pub fn trace_mark_money(&self) {
+        if let Some(ref trace) = self.trace {
+            trace.borrow_mut().mark_money(self.options.money_scale);
+        }
+    }
+
+    /// Attach the legal text span to the current trace node. No-op if trace is None.
+    pub fn trace_set_source_span(&self, span: SourceSpan) {
+        if let Some(ref trace) = self.trace {
+            trace.borrow_mut().set_source_span(span);
+        }
+    }
+
     /// Set the resolve type on the current trace node. No-op if trace is None.
     pub fn trace_set_resolve_type(&self, rt: ResolveType) {
         if let Some(ref trace) = self.trace {
@@ -280,6 +404,22 @@ impl RuleContext {
         self.trace.is_some()
     }
 
+    /// Number of operations executed in this context so far.
+    pub fn operations_evaluated(&self) -> u64 {
+        self.operations_evaluated.get()
+    }
+
+    /// Number of short-circuited AND, OR, ANY_ENTITLED and FIRST_RESOLVED
+    /// operations in this context so far.
+    pub fn short_circuits(&self) -> u64 {
+        self.short_circuits.get()
+    }
+
+    /// Names resolved so far that are both a definition and a parameter.
+    pub fn shadowed_definitions(&self) -> Vec<String> {
+        self.shadowed.borrow().iter().cloned().collect()
+    }
+
     /// Resolve a variable name using the priority chain.
     ///
     /// # Resolution Priority
@@ -287,13 +427,15 @@ impl RuleContext {
     /// 2. Local scope (loop variables)
     /// 3. Outputs (calculated values)
     /// 4. Resolved inputs (cached cross-law results)
-    /// 5. Definitions (constants)
-    /// 6. Parameters (direct inputs)
+    /// 5. Parameters (direct inputs)
+    /// 6. Definitions (constants)
+    ///
+    /// Steps 5 and 6 swap under [`ShadowPrecedence::Definition`].
     ///
     /// # Dot Notation
     /// Supports nested property access: `referencedate.year`, `person.name`
     fn resolve_variable(&self, path: &str) -> Result<Value> {
-        if self.trace.is_none() {
+        if !self.has_trace() {
             return self.resolve_variable_internal(path);
         }
 
@@ -336,6 +478,18 @@ impl RuleContext {
                         self.trace_set_resolve_type(ResolveType::Context);
                         Ok(Value::Int(i64::from(self.reference_date.day())))
                     }
+                    "quarter" => {
+                        self.trace_set_resolve_type(ResolveType::Context);
+                        Ok(Value::Int(i64::from(self.reference_date.month0() / 3 + 1)))
+                    }
+                    "iso_week" => {
+                        self.trace_set_resolve_type(ResolveType::Context);
+                        Ok(Value::Int(i64::from(self.reference_date.iso_week().week())))
+                    }
+                    "iso_year" => {
+                        self.trace_set_resolve_type(ResolveType::Context);
+                        Ok(Value::Int(i64::from(self.reference_date.iso_week().year())))
+                    }
                     "iso" => {
                         self.trace_set_resolve_type(ResolveType::Context);
                         Ok(Value::String(
@@ -344,12 +498,12 @@ impl RuleContext {
                     }
                     _ => {
                         let base_value = self.resolve_variable(base)?;
-                        get_property(&base_value, property, 0)
+                        get_path_property(&base_value, path, property)
                     }
                 };
             }
             let base_value = self.resolve_variable(base)?;
-            return get_property(&base_value, property, 0);
+            return get_path_property(&base_value, path, property);
         }
 
         // 1. Context variables (cached)
@@ -357,6 +511,12 @@ impl RuleContext {
             self.trace_set_resolve_type(ResolveType::Context);
             return Ok(self.reference_date_value.clone());
         }
+        if path == "regulatory_layer" {
+            if let Some(layer) = self.regulatory_layer {
+                self.trace_set_resolve_type(ResolveType::Context);
+                return Ok(Value::String(layer.as_str().to_string()));
+            }
+        }
 
         // 2. Local scope (FOREACH loop variables)
         if let Some(value) = self.local.get(path) {
@@ -376,20 +536,32 @@ impl RuleContext {
             return Ok(value.clone());
         }
 
-        // 5. Definitions (constants)
-        if let Some(value) = self.definitions.get(path) {
-            self.trace_set_resolve_type(ResolveType::Definition);
-            return Ok(value.clone());
-        }
-
-        // 6. Parameters (direct inputs)
-        if let Some(value) = self.parameters.get(path) {
-            self.trace_set_resolve_type(ResolveType::Parameter);
-            return Ok(value.clone());
+        // 5./6. Parameters (direct inputs) and definitions (constants)
+        match (self.parameters.get(path), self.definitions.get(path)) {
+            (Some(parameter), Some(definition)) => {
+                self.shadowed.borrow_mut().insert(path.to_string());
+                match self.options.shadow_precedence {
+                    ShadowPrecedence::Parameter => {
+                        self.trace_set_resolve_type(ResolveType::Parameter);
+                        Ok(parameter.clone())
+                    }
+                    ShadowPrecedence::Definition => {
+                        self.trace_set_resolve_type(ResolveType::Definition);
+                        Ok(definition.clone())
+                    }
+                }
+            }
+            (Some(parameter), None) => {
+                self.trace_set_resolve_type(ResolveType::Parameter);
+                Ok(parameter.clone())
+            }
+            (None, Some(definition)) => {
+                self.trace_set_resolve_type(ResolveType::Definition);
+                Ok(definition.clone())
+            }
+            // Not found
+            (None, None) => Err(EngineError::VariableNotFound(path.to_string())),
         }
-
-        // Not found
-        Err(EngineError::VariableNotFound(path.to_string()))
     }
 }
 
@@ -421,14 +593,144 @@ impl ValueResolver for RuleContext {
     fn has_trace(&self) -> bool {
         RuleContext::has_trace(self)
     }
+
+    fn options(&self) -> &EvaluationOptions {
+        &self.options
+    }
+
+    fn record_operation(&self) {
+        self.operations_evaluated
+            .set(self.operations_evaluated.get() + 1);
+    }
+
+    fn record_short_circuit(&self) {
+        self.short_circuits.set(self.short_circuits.get() + 1);
+    }
+
+    fn formula(&self, name: &str) -> Option<&ActionValue> {
+        self.formulas.get(name)
+    }
+
+    fn enter_formula(&self, name: &str) -> Result<()> {
+        let mut stack = self.formula_stack.borrow_mut();
+        if let Some(start) = stack.iter().position(|active| active == name) {
+            return Err(EngineError::CircularReference(format!(
+                "Circular formula reference: {} -> {}",
+                stack[start..].join(" -> "),
+                name
+            )));
+        }
+        stack.push(name.to_string());
+        Ok(())
+    }
+
+    fn leave_formula(&self) {
+        self.formula_stack.borrow_mut().pop();
+    }
+
+    fn custom_operation(&self, name: &str) -> Option<&OperationHandler> {
+        self.operations.as_ref()?.get(name)
+    }
+
+    fn regex(&self, pattern: &str) -> Result<Regex> {
+        if let Some(regex) = self.regexes.borrow().get(pattern) {
+            return Ok(regex.clone());
+        }
+        let regex = compile_regex(pattern)?;
+        self.regexes
+            .borrow_mut()
+            .insert(pattern.to_string(), regex.clone());
+        Ok(regex)
+    }
 }
 
-/// Convert a NaiveDate to a Value object with year, month, day properties.
+/// The reference date of a request: `calculation_date`, or the date of a
+/// `referencedate` parameter object when `calculation_date` is empty. When
+/// both are given they must agree.
+pub(crate) fn reference_date_of(
+    parameters: &BTreeMap<String, Value>,
+    calculation_date: &str,
+) -> Result<NaiveDate> {
+    let from_parameter = parameters
+        .get("referencedate")
+        .and_then(Value::as_object)
+        .map(reference_date_from_object)
+        .transpose()?;
+
+    match from_parameter {
+        Some(date) if calculation_date.is_empty() => Ok(date),
+        Some(date) => {
+            let given = parse_calculation_date(calculation_date)?;
+            if given != date {
+                return Err(EngineError::InvalidDate(format!(
+                    "calculation date {} conflicts with referencedate parameter {}",
+                    given, date
+                )));
+            }
+            Ok(date)
+        }
+        None => parse_calculation_date(calculation_date),
+    }
+}
+
+/// Parse a `YYYY-MM-DD` calculation date.
+fn parse_calculation_date(calculation_date: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(calculation_date, "%Y-%m-%d")
+        .map_err(|e| EngineError::InvalidDate(format!("{}: {}", calculation_date, e)))
+}
+
+/// Read a date from a `referencedate` parameter object: its `iso` string if
+/// present, otherwise its `year`, `month` and `day` fields.
+fn reference_date_from_object(obj: &BTreeMap<String, Value>) -> Result<NaiveDate> {
+    if let Some(iso) = obj.get("iso") {
+        return match iso {
+            Value::String(iso) => parse_calculation_date(iso),
+            other => Err(EngineError::InvalidDate(format!(
+                "referencedate.iso must be a string, got {}",
+                other
+            ))),
+        };
+    }
+    let int = |name: &str| match obj.get(name) {
+        Some(Value::Int(n)) => Some(*n),
+        _ => None,
+    };
+    match (
+        int("year").and_then(|y| i32::try_from(y).ok()),
+        int("month").and_then(|m| u32::try_from(m).ok()),
+        int("day").and_then(|d| u32::try_from(d).ok()),
+    ) {
+        (Some(year), Some(month), Some(day)) => NaiveDate::from_ymd_opt(year, month, day)
+            .ok_or_else(|| {
+                EngineError::InvalidDate(format!("{}-{}-{} is not a valid date", year, month, day))
+            }),
+        _ => Err(EngineError::InvalidDate(
+            "referencedate parameter needs an iso string or integer year, month and day"
+                .to_string(),
+        )),
+    }
+}
+
+/// Convert a NaiveDate to a Value object with year, month, day, quarter, ISO
+/// week and ISO week-numbering year properties.
 fn date_to_value(date: NaiveDate) -> Value {
     let mut obj = BTreeMap::new();
     obj.insert("year".to_string(), Value::Int(date.year() as i64));
     obj.insert("month".to_string(), Value::Int(date.month() as i64));
     obj.insert("day".to_string(), Value::Int(date.day() as i64));
+    obj.insert(
+        "quarter".to_string(),
+        Value::Int((date.month0() / 3 + 1) as i64),
+    );
+    obj.insert(
+        "iso_week".to_string(),
+        Value::Int(date.iso_week().week() as i64),
+    );
+    // The year the ISO week belongs to, which differs from `year` around New Year
+    obj.insert(
+        "iso_year".to_string(),
+        Value::Int(date.iso_week().year() as i64),
+    );
     // Also include ISO format string for direct use
     obj.insert(
         "iso".to_string(),
@@ -488,10 +790,27 @@ fn get_property(value: &Value, property_path: &str, depth: usize) -> Result<Valu
     }
 }
 
+/// Get a property of a variable's value for the variable reference `full_path`.
+///
+/// Like [`get_property`], but a missing segment anywhere along the path is
+/// reported as `VariableNotFound` carrying the full path (e.g.
+/// `household.partner.income`), so the error names the reference as written.
+pub(crate) fn get_path_property(
+    value: &Value,
+    full_path: &str,
+    property_path: &str,
+) -> Result<Value> {
+    get_property(value, property_path, 0).map_err(|e| match e {
+        EngineError::VariableNotFound(_) => EngineError::VariableNotFound(full_path.to_string()),
+        other => other,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config;
+    use crate::trace::TraceEvent;
 
     fn make_context() -> RuleContext {
         let mut params = BTreeMap::new();
@@ -501,6 +820,102 @@ mod tests {
         RuleContext::new(params, "2025-06-15").unwrap()
     }
 
+    // -------------------------------------------------------------------------
+    // Trace Sink Tests
+    // -------------------------------------------------------------------------
+
+    #[derive(Debug, Default)]
+    struct RecordingSink {
+        events: RefCell<Vec<String>>,
+    }
+
+    impl TraceSink for RecordingSink {
+        fn record(&self, event: TraceEvent<'_>) {
+            let entry = match event {
+                TraceEvent::Push { name, .. } => format!("push {}", name),
+                TraceEvent::Pop => "pop".to_string(),
+                TraceEvent::Result(value) => format!("result {}", value),
+                TraceEvent::Message(_) => "message".to_string(),
+                TraceEvent::ResolveType(_) => "resolve_type".to_string(),
+                TraceEvent::SourceSpan(_) => "source_span".to_string(),
+            };
+            self.events.borrow_mut().push(entry);
+        }
+    }
+
+    #[test]
+    fn test_trace_sink_receives_balanced_events() {
+        use crate::article::{ActionOperation, ActionValue};
+        use crate::operations::execute_operation;
+
+        let sink = Rc::new(RecordingSink::default());
+        let mut ctx = make_context();
+        ctx.set_trace_sink(sink.clone());
+        assert!(ctx.has_trace());
+
+        let op = ActionOperation::Add {
+            values: vec![
+                ActionValue::Literal(Value::String("$income".to_string())),
+                ActionValue::Literal(Value::Int(1)),
+            ],
+            allow_empty: false,
+        };
+        let result = execute_operation(&op, &ctx, 0).unwrap();
+        assert_eq!(result, Value::Int(30001));
+
+        let events = sink.events.borrow();
+        assert_eq!(events.first().map(String::as_str), Some("push ADD"));
+        assert_eq!(events.last().map(String::as_str), Some("pop"));
+        assert!(events.contains(&"push income".to_string()));
+        assert!(events.contains(&"result 30001".to_string()));
+
+        let mut open = 0i32;
+        for event in events.iter() {
+            if event.starts_with("push ") {
+                open += 1;
+            } else if event == "pop" {
+                open -= 1;
+                assert!(open >= 0, "pop without matching push: {:?}", events);
+            }
+        }
+        assert_eq!(open, 0, "unbalanced push/pop: {:?}", events);
+    }
+
+    #[test]
+    fn test_trace_sink_shares_builder_redaction_without_duplicates() {
+        use crate::article::{ActionOperation, ActionValue};
+        use crate::operations::execute_operation;
+
+        let sink = Rc::new(RecordingSink::default());
+        let mut ctx = make_context();
+        let builder = Rc::new(RefCell::new(
+            TraceBuilder::new_untimed().with_redacted(["bsn"]),
+        ));
+        ctx.set_trace(Rc::clone(&builder));
+        ctx.set_trace_sink(sink.clone());
+
+        let op = ActionOperation::Equals {
+            subject: ActionValue::Literal(Value::String("$BSN".to_string())),
+            value: ActionValue::Literal(Value::String("123456789".to_string())),
+        };
+        let result = execute_operation(&op, &ctx, 0).unwrap();
+        assert_eq!(result, Value::Bool(true));
+
+        let events = sink.events.borrow();
+        assert_eq!(
+            events.iter().filter(|e| *e == "push BSN").count(),
+            1,
+            "{:?}",
+            events
+        );
+        assert!(
+            !events.iter().any(|e| e.contains("123456789")),
+            "redacted value reached the sink: {:?}",
+            events
+        );
+        assert!(events.contains(&"result true".to_string()));
+    }
+
     // -------------------------------------------------------------------------
     // Basic Resolution Tests
     // -------------------------------------------------------------------------
@@ -598,14 +1013,23 @@ mod tests {
     }
 
     #[test]
-    fn test_priority_definition_over_parameter() {
+    fn test_priority_parameter_over_definition() {
         let mut ctx = make_context();
         // "income" exists as parameter (30000)
         let mut defs = BTreeMap::new();
         defs.insert("income".to_string(), Value::Int(50000));
         ctx.set_definitions_raw(defs);
 
-        // Definition should win over parameter
+        // Parameter should win over definition by default
+        let income = ctx.resolve("income").unwrap();
+        assert_eq!(income, Value::Int(30000));
+        assert_eq!(ctx.shadowed_definitions(), vec!["income".to_string()]);
+
+        // ...and the definition under ShadowPrecedence::Definition
+        ctx.set_options(EvaluationOptions {
+            shadow_precedence: ShadowPrecedence::Definition,
+            ..EvaluationOptions::DEFAULT
+        });
         let income = ctx.resolve("income").unwrap();
         assert_eq!(income, Value::Int(50000));
     }
@@ -630,6 +1054,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_referencedate_parameter_sets_calculation_date() {
+        let mut refdate = BTreeMap::new();
+        refdate.insert("year".to_string(), Value::Int(2024));
+        refdate.insert("month".to_string(), Value::Int(2));
+        refdate.insert("day".to_string(), Value::Int(29));
+        let mut params = BTreeMap::new();
+        params.insert("referencedate".to_string(), Value::Object(refdate.clone()));
+
+        let ctx = RuleContext::new(params.clone(), "").unwrap();
+        assert_eq!(ctx.get_calculation_date(), "2024-02-29");
+        assert_eq!(ctx.resolve("referencedate.year").unwrap(), Value::Int(2024));
+        assert_eq!(ctx.resolve("referencedate.quarter").unwrap(), Value::Int(1));
+
+        // Date operations see the same date
+        let age = crate::operations::execute_operation(
+            &crate::article::ActionOperation::Age {
+                date_of_birth: ActionValue::Literal(Value::String("2000-03-01".to_string())),
+                reference_date: ActionValue::Literal(Value::String("$referencedate".to_string())),
+            },
+            &ctx,
+            0,
+        )
+        .unwrap();
+        assert_eq!(age, Value::Int(23));
+
+        // An agreeing calculation date is accepted, a conflicting one is not
+        assert!(RuleContext::new(params.clone(), "2024-02-29").is_ok());
+        assert!(matches!(
+            RuleContext::new(params, "2024-03-01"),
+            Err(EngineError::InvalidDate(_))
+        ));
+
+        // The iso field alone suffices
+        let mut iso = BTreeMap::new();
+        iso.insert("iso".to_string(), Value::String("2025-01-31".to_string()));
+        let mut params = BTreeMap::new();
+        params.insert("referencedate".to_string(), Value::Object(iso));
+        let ctx = RuleContext::new(params, "").unwrap();
+        assert_eq!(ctx.resolve("referencedate.month").unwrap(), Value::Int(1));
+    }
+
     #[test]
     fn test_resolve_referencedate_year() {
         let ctx = make_context();
@@ -644,6 +1110,32 @@ mod tests {
         assert_eq!(month, Value::Int(6));
     }
 
+    #[test]
+    fn test_resolve_referencedate_quarter_and_iso_week() {
+        // 2025-06-15 is a Sunday, the last day of ISO week 24
+        let ctx = make_context();
+        assert_eq!(ctx.resolve("referencedate.quarter").unwrap(), Value::Int(2));
+        assert_eq!(
+            ctx.resolve("referencedate.iso_week").unwrap(),
+            Value::Int(24)
+        );
+
+        // The full object carries the same fields
+        let refdate = ctx.resolve("referencedate").unwrap();
+        let obj = refdate.as_object().unwrap();
+        assert_eq!(obj.get("quarter"), Some(&Value::Int(2)));
+        assert_eq!(obj.get("iso_week"), Some(&Value::Int(24)));
+        assert_eq!(obj.get("iso_year"), Some(&Value::Int(2025)));
+
+        // 2024-12-30 falls in week 1 of ISO year 2025
+        let date = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+        let obj = date_to_value(date);
+        let obj = obj.as_object().unwrap();
+        assert_eq!(obj.get("year"), Some(&Value::Int(2024)));
+        assert_eq!(obj.get("iso_week"), Some(&Value::Int(1)));
+        assert_eq!(obj.get("iso_year"), Some(&Value::Int(2025)));
+    }
+
     // -------------------------------------------------------------------------
     // Dot Notation Tests
     // -------------------------------------------------------------------------
@@ -711,6 +1203,48 @@ mod tests {
         assert!(matches!(result, Err(EngineError::VariableNotFound(_))));
     }
 
+    fn make_household_context() -> RuleContext {
+        let mut partner = BTreeMap::new();
+        partner.insert("income".to_string(), Value::Int(25000));
+        let mut household = BTreeMap::new();
+        household.insert("size".to_string(), Value::Int(2));
+        household.insert("partner".to_string(), Value::Object(partner));
+
+        let mut params = BTreeMap::new();
+        params.insert("household".to_string(), Value::Object(household));
+        RuleContext::new(params, "2025-06-15").unwrap()
+    }
+
+    #[test]
+    fn test_deep_path_variable_reference() {
+        use crate::article::ActionValue;
+        use crate::operations::evaluate_value;
+
+        let ctx = make_household_context();
+        let reference = |path: &str| ActionValue::Literal(Value::String(path.to_string()));
+
+        assert_eq!(
+            evaluate_value(&reference("$household.size"), &ctx, 0).unwrap(),
+            Value::Int(2)
+        );
+        assert_eq!(
+            evaluate_value(&reference("$household.partner.income"), &ctx, 0).unwrap(),
+            Value::Int(25000)
+        );
+    }
+
+    #[test]
+    fn test_deep_path_missing_segment_reports_full_path() {
+        let ctx = make_household_context();
+
+        for path in ["household.partner.salary", "household.child.income"] {
+            match ctx.resolve(path) {
+                Err(EngineError::VariableNotFound(name)) => assert_eq!(name, path),
+                other => panic!("{}: expected VariableNotFound, got {:?}", path, other),
+            }
+        }
+    }
+
     #[test]
     fn test_dot_notation_type_error() {
         let mut ctx = make_context();
@@ -791,6 +1325,30 @@ mod tests {
         assert!(matches!(result, Err(EngineError::InvalidDate(_))));
     }
 
+    #[test]
+    fn test_fixed_clock_makes_today_deterministic() {
+        use crate::article::{ActionOperation, ActionValue};
+        use crate::clock::FixedClock;
+        use crate::operations::execute_operation;
+
+        let clock = FixedClock(NaiveDate::from_ymd_opt(2025, 3, 14).unwrap());
+        let mut params = BTreeMap::new();
+        params.insert(
+            "geboortedatum".to_string(),
+            Value::String("1990-03-15".to_string()),
+        );
+        let age = ActionOperation::Age {
+            date_of_birth: ActionValue::Literal(Value::String("$geboortedatum".to_string())),
+            reference_date: ActionValue::Literal(Value::String("$referencedate".to_string())),
+        };
+
+        for _ in 0..2 {
+            let ctx = RuleContext::with_clock(params.clone(), &clock);
+            assert_eq!(ctx.reference_date(), clock.0);
+            assert_eq!(execute_operation(&age, &ctx, 0).unwrap(), Value::Int(34));
+        }
+    }
+
     #[test]
     fn test_empty_context() {
         let ctx = RuleContext::with_defaults(BTreeMap::new());
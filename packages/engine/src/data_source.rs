@@ -76,6 +76,18 @@ pub trait DataSource: Send + Sync {
     /// The value if found, or None if no matching record exists.
     fn get(&self, field: &str, criteria: &BTreeMap<String, Value>) -> Option<Value>;
 
+    /// Get a value for many records at once.
+    ///
+    /// Returns one value per entry in `keys`, in the same order, with
+    /// `Value::Null` for records that don't exist. The default calls
+    /// [`get`](Self::get) per key; database-backed sources should override
+    /// this to fetch all records in a single round trip.
+    fn get_batch(&self, field: &str, keys: &[BTreeMap<String, Value>]) -> Vec<Value> {
+        keys.iter()
+            .map(|criteria| self.get(field, criteria).unwrap_or(Value::Null))
+            .collect()
+    }
+
     /// Get all available fields in this data source.
     fn fields(&self) -> Vec<&str>;
 }
@@ -270,6 +282,12 @@ impl DataSource for DictDataSource {
 ///
 /// When resolving a value, data sources are queried in priority order
 /// (highest priority first). The first source that provides a value wins.
+///
+/// Sources may overlap: when several can answer the same `(key, field)`, the
+/// higher-priority value is returned and the others are never consulted. A
+/// source that declares the field but has no record for the key is skipped,
+/// so lookup falls through to the next source. Sources with equal priority are
+/// queried in registration order.
 #[derive(Default)]
 pub struct DataSourceRegistry {
     /// Data sources, sorted by priority (highest first)
@@ -286,7 +304,8 @@ impl DataSourceRegistry {
 
     /// Add a data source to the registry.
     ///
-    /// Sources are automatically sorted by priority (highest first).
+    /// Sources are automatically sorted by priority (highest first); the sort
+    /// is stable, so a source never outranks an earlier one of equal priority.
     pub fn add_source(&mut self, source: Box<dyn DataSource>) {
         self.sources.push(source);
         // Sort by priority descending
@@ -346,6 +365,44 @@ impl DataSourceRegistry {
         None
     }
 
+    /// Resolve a field for many records, one bulk lookup per source.
+    ///
+    /// Sources are queried in priority order through
+    /// [`DataSource::get_batch`]; each lower-priority source is only asked for
+    /// the keys still unresolved. Returns one entry per key, in order, with
+    /// `None` where no source had a (non-null) value.
+    pub fn resolve_batch(
+        &self,
+        field: &str,
+        keys: &[BTreeMap<String, Value>],
+    ) -> Vec<Option<DataSourceMatch>> {
+        let mut matches: Vec<Option<DataSourceMatch>> = vec![None; keys.len()];
+        for source in &self.sources {
+            if !source.has_field(field) {
+                continue;
+            }
+
+            let pending: Vec<usize> = (0..keys.len()).filter(|&i| matches[i].is_none()).collect();
+            if pending.is_empty() {
+                break;
+            }
+            let pending_keys: Vec<BTreeMap<String, Value>> =
+                pending.iter().map(|&i| keys[i].clone()).collect();
+
+            let values = source.get_batch(field, &pending_keys);
+            for (&i, value) in pending.iter().zip(values) {
+                if !value.is_null() {
+                    matches[i] = Some(DataSourceMatch {
+                        value,
+                        source_name: source.name().to_string(),
+                        source_type: source.source_type().to_string(),
+                    });
+                }
+            }
+        }
+        matches
+    }
+
     /// Get the number of registered data sources.
     pub fn source_count(&self) -> usize {
         self.sources.len()
@@ -612,6 +669,49 @@ mod tests {
         assert_eq!(result.source_type, "dict");
     }
 
+    #[test]
+    fn test_dict_source_get_batch_marks_missing_as_null() {
+        let source = DictDataSource::new("persons", 10, make_person_data());
+        let keys: Vec<BTreeMap<String, Value>> = ["123", "999", "456"]
+            .iter()
+            .map(|bsn| BTreeMap::from([("BSN".to_string(), Value::String(bsn.to_string()))]))
+            .collect();
+
+        assert_eq!(
+            source.get_batch("income", &keys),
+            vec![Value::Int(50000), Value::Null, Value::Int(40000)]
+        );
+    }
+
+    #[test]
+    fn test_registry_resolve_batch_falls_back_per_key() {
+        let mut registry = DataSourceRegistry::new();
+        registry.add_source(Box::new(DictDataSource::new(
+            "persons",
+            10,
+            make_person_data(),
+        )));
+        let mut fallback = BTreeMap::new();
+        fallback.insert(
+            "999".to_string(),
+            BTreeMap::from([("income".to_string(), Value::Int(1))]),
+        );
+        registry.add_source(Box::new(DictDataSource::new("fallback", 1, fallback)));
+
+        let keys: Vec<BTreeMap<String, Value>> = ["123", "999", "000"]
+            .iter()
+            .map(|bsn| BTreeMap::from([("BSN".to_string(), Value::String(bsn.to_string()))]))
+            .collect();
+        let results = registry.resolve_batch("income", &keys);
+
+        let sources: Vec<Option<&str>> = results
+            .iter()
+            .map(|m| m.as_ref().map(|m| m.source_name.as_str()))
+            .collect();
+        assert_eq!(sources, vec![Some("persons"), Some("fallback"), None]);
+        assert_eq!(results[1].as_ref().unwrap().value, Value::Int(1));
+    }
+
     #[test]
     fn test_registry_priority_order() {
         let mut registry = DataSourceRegistry::new();
@@ -666,6 +766,50 @@ mod tests {
         assert_eq!(result.source_name, "low");
     }
 
+    #[test]
+    fn test_registry_overlapping_sources_by_key() {
+        let mut registry = DataSourceRegistry::new();
+
+        let mut low_data = BTreeMap::new();
+        low_data.insert(
+            "a".to_string(),
+            BTreeMap::from([("value".to_string(), Value::Int(1))]),
+        );
+        low_data.insert(
+            "b".to_string(),
+            BTreeMap::from([("value".to_string(), Value::Int(2))]),
+        );
+        registry.add_source(Box::new(DictDataSource::new("low", 1, low_data)));
+
+        // Same field, but only a record for key "a"
+        let mut high_data = BTreeMap::new();
+        high_data.insert(
+            "a".to_string(),
+            BTreeMap::from([("value".to_string(), Value::Int(10))]),
+        );
+        registry.add_source(Box::new(DictDataSource::new("high", 10, high_data)));
+
+        // Equal priority to "high", registered later: never consulted first
+        let mut tie_data = BTreeMap::new();
+        tie_data.insert(
+            "a".to_string(),
+            BTreeMap::from([("value".to_string(), Value::Int(99))]),
+        );
+        registry.add_source(Box::new(DictDataSource::new("tie", 10, tie_data)));
+
+        let criteria = |key: &str| BTreeMap::from([("k".to_string(), Value::String(key.into()))]);
+
+        let result = registry.resolve("value", &criteria("a")).unwrap();
+        assert_eq!(result.value, Value::Int(10));
+        assert_eq!(result.source_name, "high");
+
+        let result = registry.resolve("value", &criteria("b")).unwrap();
+        assert_eq!(result.value, Value::Int(2));
+        assert_eq!(result.source_name, "low");
+
+        assert!(registry.resolve("value", &criteria("c")).is_none());
+    }
+
     #[test]
     fn test_registry_remove_source() {
         let mut registry = DataSourceRegistry::new();
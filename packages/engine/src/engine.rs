@@ -19,16 +19,26 @@
 //! println!("Output: {:?}", result.outputs);
 //! ```
 
-use crate::article::{Action, ActionOperation, Article, ArticleBasedLaw};
+use crate::article::{Action, ActionOperation, ActionValue, Article, ArticleBasedLaw};
 use crate::config;
 use crate::context::RuleContext;
 use crate::error::{EngineError, Result};
-use crate::operations::{evaluate_value, execute_operation};
-use crate::trace::{PathNode, TraceBuilder};
-use crate::types::{PathNodeType, Value};
+use crate::operations::{
+    evaluate_value, execute_operation, values_equal, OperationHandler, OperationRegistry,
+    ValueResolver,
+};
+use crate::service::ServiceProvider;
+use crate::trace::{PathNode, SourceSpan, TraceBuilder};
+use crate::types::{
+    EnumValidation, EvaluationOptions, ParameterType, PathNodeType, ShadowPrecedence, Value,
+};
+use crate::uri::RegelrechtUri;
+use regex::Regex;
 use std::cell::RefCell;
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Provenance of an output value: how it was produced during execution.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
@@ -46,6 +56,96 @@ pub enum OutputProvenance {
     Override { law_id: String, article: String },
 }
 
+/// Kind of a non-fatal condition noticed during evaluation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningKind {
+    /// An open term had no implementation and its declared default was used.
+    DefaultedInput,
+    /// An integer output exceeds the safe integer range, so comparing it with
+    /// floats or feeding it into arithmetic is not exact.
+    PrecisionLimit,
+    /// An output's value is not in its declared `enum` set (reported instead
+    /// of an error under [`EnumValidation::Warn`]).
+    EnumViolation,
+    /// A parameter has the same name as an article definition; which one was
+    /// used depends on [`ShadowPrecedence`](crate::ShadowPrecedence).
+    ShadowedDefinition,
+    /// A referenced law does not produce the requested output, so the input
+    /// was left null (under [`MissingCrossLawOutput::Warn`](crate::MissingCrossLawOutput::Warn)).
+    MissingCrossLawOutput,
+}
+
+/// A non-fatal condition noticed during evaluation, reported alongside the outputs.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct Warning {
+    pub kind: WarningKind,
+    /// Name of the open term or output the warning is about
+    pub subject: String,
+    pub message: String,
+}
+
+/// Cross-law resolutions triggered by one evaluation, for finding hot paths.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct CallCounts {
+    /// Cross-law references and URI_CALLs resolved (cache hits included)
+    pub uri_calls: u64,
+    /// Open terms resolved through an implementing regulation
+    pub delegation_calls: u64,
+    /// Cross-law results served from the per-evaluation memoization cache
+    pub cache_hits: u64,
+}
+
+impl std::ops::AddAssign for CallCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.uri_calls += other.uri_calls;
+        self.delegation_calls += other.delegation_calls;
+        self.cache_hits += other.cache_hits;
+    }
+}
+
+/// A value a data source supplied for an input during evaluation.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CapturedInput {
+    /// Law whose article declares the input
+    pub law_id: String,
+    /// Name of the input
+    pub input: String,
+    /// Name of the data source that supplied the value
+    pub source: String,
+    /// Article parameters at lookup time, matched again on replay
+    pub parameters: BTreeMap<String, Value>,
+    pub value: Value,
+}
+
+/// The request and every data-source value of one evaluation, so that an
+/// audit can reproduce the decision later.
+///
+/// Captured when [`LawExecutionService::set_capture_resolution_snapshot`]
+/// is on and replayed with [`LawExecutionService::evaluate_from_snapshot`].
+/// Cross-law inputs are not stored: replay recomputes them from the loaded
+/// laws, fed with the same data-source values.
+///
+/// [`LawExecutionService::set_capture_resolution_snapshot`]: crate::LawExecutionService::set_capture_resolution_snapshot
+/// [`LawExecutionService::evaluate_from_snapshot`]: crate::LawExecutionService::evaluate_from_snapshot
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ResolutionSnapshot {
+    /// Law that was evaluated
+    pub law_id: String,
+    /// Requested outputs
+    pub outputs: Vec<String>,
+    /// Parameters of the request
+    pub parameters: BTreeMap<String, Value>,
+    /// Calculation date of the request (YYYY-MM-DD)
+    pub calculation_date: String,
+    /// Date law versions were selected on (YYYY-MM-DD), which differs from
+    /// `calculation_date` for an evaluation as of a publication date
+    #[serde(default)]
+    pub version_date: Option<String>,
+    /// Values taken from data sources, in lookup order
+    pub inputs: Vec<CapturedInput>,
+}
+
 /// Result of article execution
 #[derive(Debug, Clone)]
 pub struct ArticleResult {
@@ -71,6 +171,213 @@ pub struct ArticleResult {
     pub regulation_hash: Option<String>,
     /// valid_from date of the regulation version that was evaluated (RFC-013)
     pub regulation_valid_from: Option<String>,
+    /// Number of operations executed while evaluating this article
+    pub operations_evaluated: u64,
+    /// Number of AND, OR, ANY_ENTITLED and FIRST_RESOLVED operations that
+    /// stopped before their last operand
+    pub short_circuits: u64,
+    /// Names of computed outputs declared `indicative` (advisory, not decisive)
+    pub indicative_outputs: BTreeSet<String>,
+    /// Non-fatal conditions noticed while evaluating this article
+    pub warnings: Vec<Warning>,
+    /// Data-source values used, when resolution snapshots are captured
+    pub resolution_snapshot: Option<ResolutionSnapshot>,
+    /// Cross-law resolutions of the whole evaluation (zero on the results
+    /// of a single article)
+    pub call_counts: CallCounts,
+    /// Wall-clock duration of each computed output's action (empty unless
+    /// [`EvaluationOptions::record_timings`] is set)
+    pub timings: BTreeMap<String, Duration>,
+}
+
+impl ArticleResult {
+    /// Build a result holding only `outputs`, e.g. as a fixture for code that
+    /// consumes results without running the engine.
+    ///
+    /// Law and article identifiers are empty and counters zero; add
+    /// warnings and a trace with [`with_warning`](Self::with_warning) and
+    /// [`with_trace`](Self::with_trace), or set the public fields directly.
+    pub fn from_outputs(outputs: impl IntoIterator<Item = (String, Value)>) -> Self {
+        ArticleResult {
+            outputs: outputs.into_iter().collect(),
+            output_provenance: BTreeMap::new(),
+            resolved_inputs: BTreeMap::new(),
+            article_number: String::new(),
+            law_id: String::new(),
+            law_uuid: None,
+            trace: None,
+            engine_version: crate::VERSION.to_string(),
+            schema_version: None,
+            regulation_hash: None,
+            regulation_valid_from: None,
+            operations_evaluated: 0,
+            short_circuits: 0,
+            indicative_outputs: BTreeSet::new(),
+            warnings: Vec::new(),
+            resolution_snapshot: None,
+            call_counts: CallCounts::default(),
+            timings: BTreeMap::new(),
+        }
+    }
+
+    /// Add a warning to this result.
+    #[must_use]
+    pub fn with_warning(mut self, warning: Warning) -> Self {
+        self.warnings.push(warning);
+        self
+    }
+
+    /// Attach an execution trace to this result.
+    #[must_use]
+    pub fn with_trace(mut self, trace: PathNode) -> Self {
+        self.trace = Some(trace);
+        self
+    }
+
+    /// Whether an output is advisory only and must not be treated as a decision.
+    pub fn is_indicative(&self, output_name: &str) -> bool {
+        self.indicative_outputs.contains(output_name)
+    }
+
+    /// Compare the outputs of this result (the baseline) with `other`.
+    ///
+    /// Returns one change per output that was added, removed or changed in
+    /// `other`, ordered by output name. Values are compared like EQUALS does,
+    /// so `42` and `42.0` count as unchanged.
+    pub fn diff(&self, other: &ArticleResult) -> Vec<OutputChange> {
+        let max_safe_integer = EvaluationOptions::DEFAULT.max_safe_integer;
+        let names: BTreeSet<&String> = self.outputs.keys().chain(other.outputs.keys()).collect();
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let name = name.clone();
+                match (self.outputs.get(&name), other.outputs.get(&name)) {
+                    (None, Some(new)) => Some(OutputChange::Added {
+                        name,
+                        new: new.clone(),
+                    }),
+                    (Some(old), None) => Some(OutputChange::Removed {
+                        name,
+                        old: old.clone(),
+                    }),
+                    (Some(old), Some(new)) if !values_equal(old, new, max_safe_integer) => {
+                        Some(OutputChange::Changed {
+                            name,
+                            old: old.clone(),
+                            new: new.clone(),
+                        })
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Rewrite output names to `case` for front-ends with their own key
+    /// convention, e.g. `doubled_value` to `doubledValue`.
+    ///
+    /// Only the keys of `outputs`, `output_provenance`, `timings` and
+    /// `indicative_outputs` change; values are left as computed. If two
+    /// outputs map to the same key, the later one in name order wins.
+    #[must_use]
+    pub fn with_key_case(mut self, case: KeyCase) -> Self {
+        fn rename<V>(map: BTreeMap<String, V>, case: KeyCase) -> BTreeMap<String, V> {
+            map.into_iter().map(|(k, v)| (case.apply(&k), v)).collect()
+        }
+        self.outputs = rename(self.outputs, case);
+        self.output_provenance = rename(self.output_provenance, case);
+        self.timings = rename(self.timings, case);
+        self.indicative_outputs = self
+            .indicative_outputs
+            .iter()
+            .map(|name| case.apply(name))
+            .collect();
+        self
+    }
+}
+
+/// Casing of output names in a result; see [`ArticleResult::with_key_case`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCase {
+    /// `doubled_value`, as names are written in law YAML
+    Snake,
+    /// `doubledValue`
+    Camel,
+}
+
+impl KeyCase {
+    /// Convert a single name to this case.
+    pub fn apply(self, name: &str) -> String {
+        match self {
+            KeyCase::Camel => {
+                let mut out = String::with_capacity(name.len());
+                let mut upper_next = false;
+                for c in name.chars() {
+                    if c == '_' && !out.is_empty() {
+                        upper_next = true;
+                    } else if upper_next {
+                        out.extend(c.to_uppercase());
+                        upper_next = false;
+                    } else {
+                        out.push(c);
+                    }
+                }
+                out
+            }
+            KeyCase::Snake => {
+                let mut out = String::with_capacity(name.len() + 4);
+                for c in name.chars() {
+                    if c.is_uppercase() {
+                        if !out.is_empty() {
+                            out.push('_');
+                        }
+                        out.extend(c.to_lowercase());
+                    } else {
+                        out.push(c);
+                    }
+                }
+                out
+            }
+        }
+    }
+}
+
+/// A difference in one output between two results; see [`ArticleResult::diff`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum OutputChange {
+    /// The output only exists in the compared result.
+    Added { name: String, new: Value },
+    /// The output only exists in the baseline.
+    Removed { name: String, old: Value },
+    /// The output exists in both, with different values.
+    Changed {
+        name: String,
+        old: Value,
+        new: Value,
+    },
+}
+
+impl std::fmt::Display for OutputChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputChange::Added { name, new } => write!(f, "+ {}: {}", name, new),
+            OutputChange::Removed { name, old } => write!(f, "- {}: {}", name, old),
+            OutputChange::Changed { name, old, new } => {
+                write!(f, "~ {}: {} -> {}", name, old, new)
+            }
+        }
+    }
+}
+
+/// Result of [`ArticleEngine::evaluate_all_outputs_partial`]: successes and
+/// failures per output.
+#[derive(Debug)]
+pub struct PartialArticleResult {
+    /// Outputs that were computed successfully
+    pub outputs: BTreeMap<String, Value>,
+    /// Outputs that could not be computed, with the error for each
+    pub errors: BTreeMap<String, EngineError>,
 }
 
 /// Executes a single article's machine_readable.execution section.
@@ -82,6 +389,12 @@ pub struct ArticleEngine<'a> {
     article: &'a Article,
     /// Law containing the article
     law: &'a ArticleBasedLaw,
+    /// Evaluation options passed to operations
+    options: EvaluationOptions,
+    /// Service used to resolve URI_CALL operations (none: URI_CALL fails)
+    service: Option<&'a dyn ServiceProvider>,
+    /// Handlers for custom operations (none: custom operations fail)
+    operations: Option<Arc<OperationRegistry>>,
 }
 
 impl<'a> ArticleEngine<'a> {
@@ -91,7 +404,34 @@ impl<'a> ArticleEngine<'a> {
     /// * `article` - Article to execute
     /// * `law` - Law containing the article
     pub fn new(article: &'a Article, law: &'a ArticleBasedLaw) -> Self {
-        Self { article, law }
+        Self {
+            article,
+            law,
+            options: EvaluationOptions::DEFAULT,
+            service: None,
+            operations: None,
+        }
+    }
+
+    /// Use the given evaluation options for this article and the internal
+    /// references it resolves.
+    pub fn with_options(mut self, options: EvaluationOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Resolve URI_CALL operations in this article (and the internal
+    /// references it resolves) through the given service provider.
+    pub fn with_service(mut self, service: &'a dyn ServiceProvider) -> Self {
+        self.service = Some(service);
+        self
+    }
+
+    /// Execute custom operations in this article (and the internal
+    /// references it resolves) with the handlers in `operations`.
+    pub fn with_operations(mut self, operations: Arc<OperationRegistry>) -> Self {
+        self.operations = Some(operations);
+        self
     }
 
     /// Execute this article's logic.
@@ -214,24 +554,14 @@ impl<'a> ArticleEngine<'a> {
             )));
         }
 
-        // Create execution context
-        let mut context = RuleContext::new(parameters.clone(), calculation_date)?;
-
-        // Attach trace builder if provided
-        if let Some(ref tb) = trace {
-            context.set_trace(Rc::clone(tb));
-        }
-
-        // Set definitions from article
-        if let Some(definitions) = self.article.get_definitions() {
-            context.set_definitions(definitions);
-        }
-
-        // Resolve inputs with sources (internal references)
-        self.resolve_input_sources(&mut context, &parameters, calculation_date, &visited, depth)?;
+        let mut context =
+            self.prepare_context(&parameters, calculation_date, &visited, depth, trace)?;
 
         // Execute actions (with trace instrumentation)
-        self.execute_actions_traced(&mut context, requested_output)?;
+        let timings = self.execute_actions_traced(&mut context, requested_output)?;
+        if self.options.require_all_outputs {
+            self.check_all_outputs_produced(context.outputs())?;
+        }
 
         // Build result
         // Tag all outputs as Direct (hooks/overrides are tagged by the service layer)
@@ -249,6 +579,51 @@ impl<'a> ArticleEngine<'a> {
             })
             .collect();
 
+        let indicative_outputs: BTreeSet<String> = self
+            .article
+            .get_execution_spec()
+            .and_then(|exec| exec.output.as_deref())
+            .unwrap_or(&[])
+            .iter()
+            .filter(|o| o.indicative && context.outputs().contains_key(&o.name))
+            .map(|o| o.name.clone())
+            .collect();
+
+        let max_safe_integer = self.options.max_safe_integer;
+        let mut warnings: Vec<Warning> = context
+            .outputs()
+            .iter()
+            .filter(|(_, value)| {
+                matches!(value, Value::Int(i) if i.unsigned_abs() > max_safe_integer.unsigned_abs())
+            })
+            .map(|(name, value)| Warning {
+                kind: WarningKind::PrecisionLimit,
+                subject: name.clone(),
+                message: format!(
+                    "Output '{}' = {} exceeds the safe integer range (±{})",
+                    name, value, max_safe_integer
+                ),
+            })
+            .collect();
+        self.check_output_enums(context.outputs(), &mut warnings)?;
+        let used = match self.options.shadow_precedence {
+            ShadowPrecedence::Parameter => "parameter",
+            ShadowPrecedence::Definition => "definition",
+        };
+        warnings.extend(
+            context
+                .shadowed_definitions()
+                .into_iter()
+                .map(|name| Warning {
+                    kind: WarningKind::ShadowedDefinition,
+                    message: format!(
+                        "Parameter '{}' shadows the definition of the same name; the {} was used",
+                        name, used
+                    ),
+                    subject: name,
+                }),
+        );
+
         let result = ArticleResult {
             outputs: context.outputs().clone(),
             output_provenance,
@@ -261,6 +636,13 @@ impl<'a> ArticleEngine<'a> {
             schema_version: self.law.schema_version().map(String::from),
             regulation_hash: self.law.content_hash.clone(),
             regulation_valid_from: self.law.valid_from.clone(),
+            operations_evaluated: context.operations_evaluated(),
+            short_circuits: context.short_circuits(),
+            indicative_outputs,
+            warnings,
+            resolution_snapshot: None,
+            call_counts: CallCounts::default(),
+            timings,
         };
 
         tracing::debug!(
@@ -273,6 +655,184 @@ impl<'a> ArticleEngine<'a> {
         Ok(result)
     }
 
+    /// Evaluate every action of this article independently, collecting failures per output.
+    ///
+    /// Unlike [`evaluate`](Self::evaluate), an error in one action does not abort the
+    /// article: the failing output is recorded in `errors` and evaluation continues with
+    /// the next action. Outputs that depend on a failed output fail in turn, since the
+    /// failed output is never set in the context.
+    ///
+    /// Errors that are not tied to a single output (invalid date, unresolvable input
+    /// sources) are still returned as `Err`.
+    pub fn evaluate_all_outputs_partial(
+        &self,
+        parameters: BTreeMap<String, Value>,
+        calculation_date: &str,
+    ) -> Result<PartialArticleResult> {
+        let visited = HashSet::from([self.article.number.clone()]);
+        let mut context = self.prepare_context(&parameters, calculation_date, &visited, 0, None)?;
+
+        let mut errors = BTreeMap::new();
+        for action in self.get_actions() {
+            let Some(output_name) = &action.output else {
+                continue;
+            };
+            match self.evaluate_action(action, &context) {
+                Ok(value) => context.set_output(output_name, value),
+                Err(e) => {
+                    tracing::debug!(output = %output_name, error = %e, "Output failed");
+                    errors.insert(output_name.clone(), e);
+                }
+            }
+        }
+
+        Ok(PartialArticleResult {
+            outputs: context.outputs().clone(),
+            errors,
+        })
+    }
+
+    /// Fail with `MissingOutputs` naming every declared output that no action produced.
+    fn check_all_outputs_produced(&self, outputs: &BTreeMap<String, Value>) -> Result<()> {
+        let missing: Vec<String> = self
+            .article
+            .get_execution_spec()
+            .and_then(|exec| exec.output.as_deref())
+            .unwrap_or(&[])
+            .iter()
+            .filter(|spec| !outputs.contains_key(&spec.name))
+            .map(|spec| spec.name.clone())
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        Err(EngineError::MissingOutputs {
+            law_id: self.law.id.clone(),
+            article: self.article.number.clone(),
+            outputs: missing,
+        })
+    }
+
+    /// Check outputs against their declared `enum` sets.
+    ///
+    /// Null and untranslatable values are not checked. A value outside its set
+    /// is an `InvalidOperation` error, or a warning under
+    /// [`EnumValidation::Warn`].
+    fn check_output_enums(
+        &self,
+        outputs: &BTreeMap<String, Value>,
+        warnings: &mut Vec<Warning>,
+    ) -> Result<()> {
+        let specs = self
+            .article
+            .get_execution_spec()
+            .and_then(|exec| exec.output.as_deref())
+            .unwrap_or(&[]);
+        for spec in specs {
+            let (Some(allowed), Some(value)) = (&spec.allowed_values, outputs.get(&spec.name))
+            else {
+                continue;
+            };
+            if value.is_null()
+                || value.is_untranslatable()
+                || allowed
+                    .iter()
+                    .any(|a| values_equal(value, a, self.options.max_safe_integer))
+            {
+                continue;
+            }
+            let allowed_list: Vec<String> = allowed.iter().map(|a| a.to_string()).collect();
+            let message = format!(
+                "Output '{}' = {} is not one of the declared values [{}]",
+                spec.name,
+                value,
+                allowed_list.join(", ")
+            );
+            match self.options.enum_validation {
+                EnumValidation::Error => return Err(EngineError::InvalidOperation(message)),
+                EnumValidation::Warn => warnings.push(Warning {
+                    kind: WarningKind::EnumViolation,
+                    subject: spec.name.clone(),
+                    message,
+                }),
+            }
+        }
+        Ok(())
+    }
+
+    /// Build the context this article's actions run in, without running them.
+    pub(crate) fn build_context(
+        &self,
+        parameters: BTreeMap<String, Value>,
+        calculation_date: &str,
+    ) -> Result<RuleContext> {
+        let visited = HashSet::from([self.article.number.clone()]);
+        self.prepare_context(&parameters, calculation_date, &visited, 0, None)
+    }
+
+    /// Create the execution context: parameters, definitions and resolved input sources.
+    fn prepare_context(
+        &self,
+        parameters: &BTreeMap<String, Value>,
+        calculation_date: &str,
+        visited: &HashSet<String>,
+        depth: usize,
+        trace: Option<Rc<RefCell<TraceBuilder>>>,
+    ) -> Result<RuleContext> {
+        let parameters = &self.parse_amount_parameters(parameters)?;
+        let mut context = RuleContext::new(parameters.clone(), calculation_date)?;
+        context.set_options(self.options);
+        context.set_regulatory_layer(self.law.regulatory_layer);
+
+        // Attach trace builder if provided
+        if let Some(tb) = trace {
+            context.set_trace(tb);
+        }
+
+        // Set definitions from article
+        context.set_definition_values(self.article.definition_values());
+        context.set_formulas(self.law.formulas_for(self.article));
+        if let Some(operations) = &self.operations {
+            context.set_operations(Arc::clone(operations));
+        }
+
+        // Resolve inputs with sources (internal references)
+        self.resolve_input_sources(&mut context, parameters, calculation_date, visited, depth)?;
+
+        Ok(context)
+    }
+
+    /// Parse `amount` parameters passed as text into integers at the money scale.
+    ///
+    /// Amounts given as numbers are taken as already scaled and left alone.
+    fn parse_amount_parameters(
+        &self,
+        parameters: &BTreeMap<String, Value>,
+    ) -> Result<BTreeMap<String, Value>> {
+        let mut parsed = parameters.clone();
+        let declared = self
+            .article
+            .get_execution_spec()
+            .and_then(|exec| exec.parameters.as_deref())
+            .unwrap_or(&[]);
+        for param in declared
+            .iter()
+            .filter(|p| p.param_type == ParameterType::Amount)
+        {
+            if let Some(Value::String(text)) = parameters.get(&param.name) {
+                let scale = self.options.money_scale;
+                let minor = regelrecht_shared::parse_money(text, scale).ok_or_else(|| {
+                    EngineError::TypeMismatch {
+                        expected: format!("decimal amount (scale {})", scale),
+                        actual: format!("'{}' for parameter '{}'", text, param.name),
+                    }
+                })?;
+                parsed.insert(param.name.clone(), Value::Int(minor));
+            }
+        }
+        Ok(parsed)
+    }
+
     /// Resolve input sources (internal and external references).
     ///
     /// This processes inputs that have a `source` specification and resolves them
@@ -388,7 +948,8 @@ impl<'a> ArticleEngine<'a> {
         new_visited.insert(article.number.clone());
 
         // Execute the referenced article with updated visited set
-        let engine = ArticleEngine::new(article, self.law);
+        let mut engine = ArticleEngine::new(article, self.law).with_options(self.options);
+        engine.service = self.service;
         let result = engine.evaluate_internal(
             parameters.clone(),
             calculation_date,
@@ -409,13 +970,17 @@ impl<'a> ArticleEngine<'a> {
     }
 
     /// Execute all actions in order, with optional trace instrumentation.
+    ///
+    /// Returns the duration of each action when timings are recorded, and an
+    /// empty map otherwise.
     fn execute_actions_traced(
         &self,
         context: &mut RuleContext,
         _requested_output: Option<&str>,
-    ) -> Result<()> {
+    ) -> Result<BTreeMap<String, Duration>> {
         let actions = self.get_actions();
         let tracing_active = context.has_trace();
+        let mut timings = BTreeMap::new();
 
         for action in actions {
             let output_name = match &action.output {
@@ -426,8 +991,12 @@ impl<'a> ArticleEngine<'a> {
             if tracing_active {
                 context.trace_push(output_name, PathNodeType::Action);
                 context.trace_set_message(format!("Computing {}", output_name));
+                if let Some(span) = self.source_span(output_name) {
+                    context.trace_set_source_span(span);
+                }
             }
 
+            let started = self.options.record_timings.then(Instant::now);
             let value = match self.evaluate_action(action, context) {
                 Ok(v) => v,
                 Err(e) => {
@@ -438,9 +1007,15 @@ impl<'a> ArticleEngine<'a> {
                     return Err(e);
                 }
             };
+            if let Some(started) = started {
+                timings.insert(output_name.clone(), started.elapsed());
+            }
 
             if tracing_active {
                 context.trace_set_result(value.clone());
+                if self.is_money_output(output_name) {
+                    context.trace_mark_money();
+                }
             }
 
             tracing::debug!("Output {} = {}", output_name, value);
@@ -451,7 +1026,7 @@ impl<'a> ArticleEngine<'a> {
             }
         }
 
-        Ok(())
+        Ok(timings)
     }
 
     /// Evaluate a single action.
@@ -463,16 +1038,29 @@ impl<'a> ArticleEngine<'a> {
     /// # Returns
     /// Calculated value
     fn evaluate_action(&self, action: &Action, context: &RuleContext) -> Result<Value> {
+        match self.service {
+            Some(service) => {
+                self.evaluate_action_with(action, &ServiceResolver { context, service })
+            }
+            None => self.evaluate_action_with(action, context),
+        }
+    }
+
+    fn evaluate_action_with<R: ValueResolver>(
+        &self,
+        action: &Action,
+        resolver: &R,
+    ) -> Result<Value> {
         // Check for operation at action level FIRST
         // When an action has an operation, the value/subject fields are operands, not direct results
         if let Some(operation) = &action.operation {
             let action_op = self.action_to_operation(action, operation)?;
-            return execute_operation(&action_op, context, 0);
+            return execute_operation(&action_op, resolver, 0);
         }
 
         // Check for direct value (only when no operation is specified)
         if let Some(value) = &action.value {
-            return evaluate_value(value, context, 0);
+            return evaluate_value(value, resolver, 0);
         }
 
         // No value or operation specified
@@ -558,16 +1146,21 @@ impl<'a> ArticleEngine<'a> {
             // Arithmetic operations (values)
             Operation::Add => Ok(ActionOperation::Add {
                 values: require_values(operation)?,
+                allow_empty: action.allow_empty,
             }),
             Operation::Subtract => Ok(ActionOperation::Subtract {
                 values: require_values(operation)?,
             }),
             Operation::Multiply => Ok(ActionOperation::Multiply {
                 values: require_values(operation)?,
+                allow_empty: action.allow_empty,
             }),
             Operation::Divide => Ok(ActionOperation::Divide {
                 values: require_values(operation)?,
             }),
+            Operation::IntDivide => Ok(ActionOperation::IntDivide {
+                values: require_values(operation)?,
+            }),
 
             // Aggregate operations (values)
             Operation::Max => Ok(ActionOperation::Max {
@@ -596,6 +1189,46 @@ impl<'a> ArticleEngine<'a> {
                 subject: require_subject(operation)?,
             }),
 
+            // Date comparison operations (subject + value)
+            Operation::DateBefore => Ok(ActionOperation::DateBefore {
+                subject: require_subject(operation)?,
+                value: require_value(operation)?,
+            }),
+            Operation::DateAfter => Ok(ActionOperation::DateAfter {
+                subject: require_subject(operation)?,
+                value: require_value(operation)?,
+            }),
+            Operation::DateOnOrBefore => Ok(ActionOperation::DateOnOrBefore {
+                subject: require_subject(operation)?,
+                value: require_value(operation)?,
+            }),
+            Operation::DateOnOrAfter => Ok(ActionOperation::DateOnOrAfter {
+                subject: require_subject(operation)?,
+                value: require_value(operation)?,
+            }),
+
+            // Validation operations (subject only)
+            Operation::ValidateBsn => Ok(ActionOperation::ValidateBsn {
+                subject: require_subject(operation)?,
+            }),
+            Operation::NormalizePostcode => Ok(ActionOperation::NormalizePostcode {
+                subject: require_subject(operation)?,
+            }),
+
+            Operation::Negate => Ok(ActionOperation::Negate {
+                subject: require_subject(operation)?,
+            }),
+            Operation::Matches => Ok(ActionOperation::Matches {
+                subject: require_subject(operation)?,
+                value: require_value(operation)?,
+            }),
+
+            // Type conversion (subject + target type as value)
+            Operation::Cast => Ok(ActionOperation::Cast {
+                subject: require_subject(operation)?,
+                value: require_value(operation)?,
+            }),
+
             // Collection: IN/NOT_IN (subject + value/values)
             Operation::In => Ok(ActionOperation::In {
                 subject: require_subject(operation)?,
@@ -608,19 +1241,133 @@ impl<'a> ArticleEngine<'a> {
                 values: action.values.clone(),
             }),
 
+            // Collection: FLATTEN/DISTINCT/CUMULATIVE_SUM (value only)
+            Operation::Flatten => Ok(ActionOperation::Flatten {
+                value: require_value(operation)?,
+            }),
+            Operation::Distinct => Ok(ActionOperation::Distinct {
+                value: require_value(operation)?,
+            }),
+            Operation::CumulativeSum => Ok(ActionOperation::CumulativeSum {
+                value: require_value(operation)?,
+            }),
+
+            // Collection: IS_SUBSET/IS_SUPERSET/SET_EQUALS (subject + value)
+            Operation::IsSubset => Ok(ActionOperation::IsSubset {
+                subject: require_subject(operation)?,
+                value: require_value(operation)?,
+            }),
+            Operation::IsSuperset => Ok(ActionOperation::IsSuperset {
+                subject: require_subject(operation)?,
+                value: require_value(operation)?,
+            }),
+            Operation::SetEquals => Ok(ActionOperation::SetEquals {
+                subject: require_subject(operation)?,
+                value: require_value(operation)?,
+                count_duplicates: None,
+            }),
+
             // Operations not supported at action level
             Operation::If
+            | Operation::WeightedAverage
             | Operation::List
+            | Operation::CountWhere
+            | Operation::Label
+            | Operation::Slice
+            | Operation::Split
+            | Operation::SumField
+            | Operation::Sort
             | Operation::Age
+            | Operation::HasReachedAge
+            | Operation::AgeInMonths
             | Operation::DateAdd
             | Operation::Date
-            | Operation::DayOfWeek => Err(EngineError::InvalidOperation(format!(
+            | Operation::DayOfWeek
+            | Operation::SubtractDate
+            | Operation::ProRata
+            | Operation::ClampDate
+            | Operation::NthWeekday
+            | Operation::NextBusinessDay
+            | Operation::YearFraction
+            | Operation::DateInPeriods
+            | Operation::PeriodLookup
+            | Operation::IsoWeek
+            | Operation::Quarter
+            | Operation::ConvertDuration
+            | Operation::PeriodOverlapDays
+            | Operation::MinDate
+            | Operation::MaxDate
+            | Operation::UriCall
+            | Operation::AnyEntitled
+            | Operation::FirstResolved
+            | Operation::DelegatedLookup
+            | Operation::DelegatedOrDefault
+            | Operation::ApproxEquals
+            | Operation::ApplicableVersion
+            | Operation::CallFormula
+            | Operation::CompoundIndex
+            | Operation::RoundToMultiple
+            | Operation::LinearTaper
+            | Operation::Clamp
+            | Operation::ProgressiveBrackets
+            | Operation::ParseBool
+            | Operation::IsEmpty
+            | Operation::NotEmpty => Err(EngineError::InvalidOperation(format!(
                 "{} must be nested inside 'value', not used directly at action level",
                 operation.name()
             ))),
         }
     }
 
+    /// The article text an output implements: its declared `source_span`,
+    /// or the whole article text. `None` when the article has no text.
+    ///
+    /// Declared spans are checked to lie within the text when the law is loaded.
+    fn source_span(&self, output_name: &str) -> Option<SourceSpan> {
+        let text = &self.article.text;
+        if text.trim().is_empty() {
+            return None;
+        }
+        let len = text.chars().count();
+        let declared = self
+            .article
+            .get_execution_spec()
+            .and_then(|exec| exec.output.as_deref())
+            .unwrap_or(&[])
+            .iter()
+            .find(|o| o.name == output_name)
+            .and_then(|o| o.source_span);
+        let (start, end) = declared.map_or((0, len), |span| (span.start, span.end));
+        Some(SourceSpan {
+            law_id: self.law.id.clone(),
+            article: self.article.number.clone(),
+            start,
+            end,
+            text: text
+                .chars()
+                .skip(start)
+                .take(end.saturating_sub(start))
+                .collect(),
+        })
+    }
+
+    /// Check whether an output is a euro amount (type `amount`, not in eurocent).
+    ///
+    /// Eurocent amounts are whole numbers at the output edge, so only euro
+    /// amounts need their two decimals preserved when rendered.
+    fn is_money_output(&self, output_name: &str) -> bool {
+        self.article
+            .get_execution_spec()
+            .and_then(|exec| exec.output.as_deref())
+            .unwrap_or(&[])
+            .iter()
+            .any(|o| {
+                o.name == output_name
+                    && o.output_type == ParameterType::Amount
+                    && o.type_spec.as_ref().and_then(|ts| ts.unit.as_deref()) != Some("eurocent")
+            })
+    }
+
     /// Get actions from the article's execution spec.
     fn get_actions(&self) -> &[Action] {
         self.article
@@ -630,27 +1377,127 @@ impl<'a> ArticleEngine<'a> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::article::ArticleBasedLaw;
+/// Resolver that hands URI_CALL operations to a `ServiceProvider`; everything
+/// else is resolved by the rule context.
+struct ServiceResolver<'a> {
+    context: &'a RuleContext,
+    service: &'a dyn ServiceProvider,
+}
 
-    fn make_simple_law() -> ArticleBasedLaw {
-        let yaml = r#"
-$id: test_law
-regulatory_layer: WET
-publication_date: '2025-01-01'
-articles:
-  - number: '1'
-    text: Test article
-    machine_readable:
-      definitions:
-        MAX_AGE:
-          value: 67
-        MIN_AGE:
-          value: 18
-      execution:
-        parameters:
+impl ValueResolver for ServiceResolver<'_> {
+    fn resolve(&self, name: &str) -> Result<Value> {
+        self.context.resolve(name)
+    }
+
+    fn trace_push(&self, name: &str, node_type: PathNodeType) {
+        ValueResolver::trace_push(self.context, name, node_type);
+    }
+
+    fn trace_pop(&self) {
+        ValueResolver::trace_pop(self.context);
+    }
+
+    fn trace_set_result(&self, result: Value) {
+        ValueResolver::trace_set_result(self.context, result);
+    }
+
+    fn trace_set_message(&self, msg: String) {
+        ValueResolver::trace_set_message(self.context, msg);
+    }
+
+    fn trace_get_message(&self) -> Option<String> {
+        ValueResolver::trace_get_message(self.context)
+    }
+
+    fn has_trace(&self) -> bool {
+        ValueResolver::has_trace(self.context)
+    }
+
+    fn options(&self) -> &EvaluationOptions {
+        ValueResolver::options(self.context)
+    }
+
+    fn record_operation(&self) {
+        ValueResolver::record_operation(self.context);
+    }
+
+    fn record_short_circuit(&self) {
+        ValueResolver::record_short_circuit(self.context);
+    }
+
+    fn formula(&self, name: &str) -> Option<&ActionValue> {
+        ValueResolver::formula(self.context, name)
+    }
+
+    fn enter_formula(&self, name: &str) -> Result<()> {
+        ValueResolver::enter_formula(self.context, name)
+    }
+
+    fn leave_formula(&self) {
+        ValueResolver::leave_formula(self.context);
+    }
+
+    fn custom_operation(&self, name: &str) -> Option<&OperationHandler> {
+        ValueResolver::custom_operation(self.context, name)
+    }
+
+    fn regex(&self, pattern: &str) -> Result<Regex> {
+        ValueResolver::regex(self.context, pattern)
+    }
+
+    fn applicable_version(&self, law_id: &str) -> Result<Value> {
+        let valid_from = self
+            .service
+            .applicable_version(law_id, self.context.get_calculation_date())?;
+        Ok(valid_from.map_or(Value::Null, Value::String))
+    }
+
+    fn evaluate_uri(&self, uri: &str, parameters: BTreeMap<String, Value>) -> Result<Value> {
+        let parsed = RegelrechtUri::parse(uri)?;
+        if !parsed.is_external() {
+            return Err(EngineError::InvalidOperation(format!(
+                "URI_CALL requires an external reference, got '{}'",
+                uri
+            )));
+        }
+
+        let result =
+            self.service
+                .evaluate_uri(uri, &parameters, self.context.get_calculation_date())?;
+        let output = parsed.field().unwrap_or(parsed.output());
+        result
+            .outputs
+            .get(output)
+            .cloned()
+            .ok_or_else(|| EngineError::OutputNotFound {
+                law_id: parsed.law_id().to_string(),
+                output: output.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::article::ArticleBasedLaw;
+    use std::collections::HashMap;
+
+    fn make_simple_law() -> ArticleBasedLaw {
+        let yaml = r#"
+$id: test_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Test article
+    machine_readable:
+      definitions:
+        MAX_AGE:
+          value: 67
+        MIN_AGE:
+          value: 18
+      execution:
+        parameters:
           - name: age
             type: number
             required: true
@@ -878,6 +1725,60 @@ articles:
         assert!(matches!(result, Err(EngineError::InvalidDate(_))));
     }
 
+    #[test]
+    fn test_partial_evaluation_keeps_good_outputs() {
+        let yaml = r#"
+$id: partial_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Article with independent outputs
+    machine_readable:
+      execution:
+        parameters:
+          - name: income
+            type: number
+            required: true
+        output:
+          - name: doubled_income
+            type: number
+          - name: broken_output
+            type: number
+        actions:
+          - output: doubled_income
+            operation: MULTIPLY
+            values:
+              - $income
+              - 2
+          - output: broken_output
+            operation: ADD
+            values:
+              - $income
+              - $does_not_exist
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let article = law.find_article_by_number("1").unwrap();
+        let engine = ArticleEngine::new(article, &law);
+
+        let mut params = BTreeMap::new();
+        params.insert("income".to_string(), Value::Int(100));
+
+        // The all-or-nothing evaluation fails on the broken output
+        assert!(engine.evaluate(params.clone(), "2025-01-01").is_err());
+
+        let result = engine
+            .evaluate_all_outputs_partial(params, "2025-01-01")
+            .unwrap();
+        assert_eq!(result.outputs.get("doubled_income"), Some(&Value::Int(200)));
+        assert!(!result.outputs.contains_key("broken_output"));
+        assert!(matches!(
+            result.errors.get("broken_output"),
+            Some(EngineError::VariableNotFound(name)) if name == "does_not_exist"
+        ));
+        assert_eq!(result.errors.len(), 1);
+    }
+
     // -------------------------------------------------------------------------
     // Reference Date Tests
     // -------------------------------------------------------------------------
@@ -909,6 +1810,49 @@ articles:
         assert_eq!(result.outputs.get("current_year"), Some(&Value::Int(2025)));
     }
 
+    #[test]
+    fn test_regulatory_layer_accessible() {
+        let yaml = r#"
+$id: layer_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Layer test
+    machine_readable:
+      execution:
+        output:
+          - name: norm_kind
+            type: string
+        actions:
+          - output: norm_kind
+            value:
+              operation: IF
+              cases:
+                - when:
+                    operation: EQUALS
+                    subject: $regulatory_layer
+                    value: WET
+                  then: formele wet
+                - when:
+                    operation: EQUALS
+                    subject: $regulatory_layer
+                    value: BELEIDSREGEL
+                  then: beleidsregel
+              default: overig
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let article = law.find_article_by_number("1").unwrap();
+        let engine = ArticleEngine::new(article, &law);
+
+        let result = engine.evaluate(BTreeMap::new(), "2025-06-15").unwrap();
+
+        assert_eq!(
+            result.outputs.get("norm_kind"),
+            Some(&Value::String("formele wet".to_string()))
+        );
+    }
+
     // -------------------------------------------------------------------------
     // Internal Reference Resolution Tests
     // -------------------------------------------------------------------------
@@ -1307,6 +2251,584 @@ articles:
         }
     }
 
+    #[test]
+    fn test_operation_metrics_count_short_circuit() {
+        let yaml = r#"
+$id: metrics_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Metrics article
+    machine_readable:
+      execution:
+        output:
+          - name: allowed
+            type: boolean
+        actions:
+          - output: allowed
+            operation: AND
+            conditions:
+              - operation: EQUALS
+                subject: 1
+                value: 2
+              - operation: EQUALS
+                subject: 1
+                value: 1
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let article = law.find_article_by_number("1").unwrap();
+        let engine = ArticleEngine::new(article, &law);
+
+        let result = engine.evaluate(BTreeMap::new(), "2025-01-01").unwrap();
+
+        assert_eq!(result.outputs.get("allowed"), Some(&Value::Bool(false)));
+        // AND plus the first EQUALS; the second EQUALS is skipped
+        assert_eq!(result.operations_evaluated, 2);
+        assert_eq!(result.short_circuits, 1);
+    }
+
+    #[test]
+    fn test_indicative_outputs_are_flagged() {
+        let yaml = r#"
+$id: indicative_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Indicative article
+    machine_readable:
+      execution:
+        output:
+          - name: recht_op_toeslag
+            type: boolean
+          - name: verwachte_hoogte
+            type: amount
+            indicative: true
+        actions:
+          - output: recht_op_toeslag
+            value: true
+          - output: verwachte_hoogte
+            value: 100
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let article = law.find_article_by_number("1").unwrap();
+        let engine = ArticleEngine::new(article, &law);
+
+        let result = engine.evaluate(BTreeMap::new(), "2025-01-01").unwrap();
+
+        assert!(result.is_indicative("verwachte_hoogte"));
+        assert!(!result.is_indicative("recht_op_toeslag"));
+    }
+
+    #[test]
+    fn test_article_result_from_outputs() {
+        let outputs = HashMap::from([("toeslag".to_string(), Value::Int(1_200))]);
+        let result = ArticleResult::from_outputs(outputs).with_warning(Warning {
+            kind: WarningKind::ShadowedDefinition,
+            subject: "toeslag".to_string(),
+            message: "fixture".to_string(),
+        });
+
+        assert_eq!(result.outputs.get("toeslag"), Some(&Value::Int(1_200)));
+        assert_eq!(result.outputs.len(), 1);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.trace.is_none());
+        assert_eq!(result.engine_version, crate::VERSION);
+    }
+
+    #[test]
+    fn test_diff_reports_only_changed_outputs() {
+        let law = make_arithmetic_law();
+        let article = law.find_article_by_number("1").unwrap();
+        let engine = ArticleEngine::new(article, &law);
+        let run = |income: i64| {
+            let params = BTreeMap::from([("income".to_string(), Value::Int(income))]);
+            engine.evaluate(params, "2025-01-01").unwrap()
+        };
+
+        // Both incomes stay under the deduction, so only the raw income differs
+        let baseline = run(500);
+        let what_if = run(800);
+        assert!(baseline.diff(&what_if).is_empty());
+
+        let what_if = run(2000);
+        let changes = baseline.diff(&what_if);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes[1],
+            OutputChange::Changed {
+                name: "taxable_income".to_string(),
+                old: Value::Int(0),
+                new: Value::Int(1000),
+            }
+        );
+        assert_eq!(changes[1].to_string(), "~ taxable_income: 0 -> 1000");
+
+        let mut trimmed = what_if.clone();
+        trimmed.outputs.remove("tax_amount");
+        assert_eq!(
+            what_if.diff(&trimmed),
+            vec![OutputChange::Removed {
+                name: "tax_amount".to_string(),
+                old: Value::Float(210.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_output_beyond_safe_integer_range_is_warned() {
+        let yaml = r#"
+$id: big_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Big numbers
+    machine_readable:
+      execution:
+        output:
+          - name: klein
+            type: number
+          - name: groot
+            type: number
+        actions:
+          - output: klein
+            value: 1
+          - output: groot
+            value: 9007199254740993
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let article = law.find_article_by_number("1").unwrap();
+        let result = ArticleEngine::new(article, &law)
+            .evaluate(BTreeMap::new(), "2025-01-01")
+            .unwrap();
+
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].kind, WarningKind::PrecisionLimit);
+        assert_eq!(result.warnings[0].subject, "groot");
+    }
+
+    #[test]
+    fn test_output_outside_declared_enum() {
+        let yaml = r#"
+$id: enum_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Woonsituatie
+    machine_readable:
+      execution:
+        parameters:
+          - name: code
+            type: string
+        output:
+          - name: woonsituatie
+            type: string
+            enum: [ALLEENSTAAND, SAMENWONEND]
+        actions:
+          - output: woonsituatie
+            value: $code
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let article = law.find_article_by_number("1").unwrap();
+        let evaluate = |code: &str, options: EvaluationOptions| {
+            let mut params = BTreeMap::new();
+            params.insert("code".to_string(), Value::String(code.to_string()));
+            ArticleEngine::new(article, &law)
+                .with_options(options)
+                .evaluate(params, "2025-01-01")
+        };
+
+        assert!(evaluate("SAMENWONEND", EvaluationOptions::DEFAULT).is_ok());
+        let err = evaluate("GEHUWD", EvaluationOptions::DEFAULT).unwrap_err();
+        assert!(matches!(err, EngineError::InvalidOperation(_)));
+        assert!(err.to_string().contains("woonsituatie"), "{}", err);
+
+        // Lenient mode keeps the output and warns instead
+        let lenient = EvaluationOptions {
+            enum_validation: EnumValidation::Warn,
+            ..EvaluationOptions::DEFAULT
+        };
+        let result = evaluate("GEHUWD", lenient).unwrap();
+        assert_eq!(
+            result.outputs.get("woonsituatie"),
+            Some(&Value::String("GEHUWD".to_string()))
+        );
+        assert_eq!(result.warnings[0].kind, WarningKind::EnumViolation);
+    }
+
+    #[test]
+    fn test_require_all_outputs_names_unproduced_outputs() {
+        let yaml = r#"
+$id: outputs_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Two outputs, one without an action
+    machine_readable:
+      execution:
+        output:
+          - name: heeft_recht
+            type: boolean
+          - name: hoogte
+            type: amount
+        actions:
+          - output: heeft_recht
+            value: true
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let article = law.find_article_by_number("1").unwrap();
+
+        // Off by default: the missing output is silently absent
+        let result = ArticleEngine::new(article, &law)
+            .evaluate(BTreeMap::new(), "2025-01-01")
+            .unwrap();
+        assert!(!result.outputs.contains_key("hoogte"));
+
+        let strict = EvaluationOptions {
+            require_all_outputs: true,
+            ..EvaluationOptions::DEFAULT
+        };
+        let err = ArticleEngine::new(article, &law)
+            .with_options(strict)
+            .evaluate(BTreeMap::new(), "2025-01-01")
+            .unwrap_err();
+        let EngineError::MissingOutputs { outputs, .. } = &err else {
+            panic!("expected MissingOutputs, got {:?}", err);
+        };
+        assert_eq!(outputs, &vec!["hoogte".to_string()]);
+        assert!(err.to_string().contains("hoogte"), "{}", err);
+    }
+
+    #[test]
+    fn test_record_timings_covers_each_output() {
+        let yaml = r#"
+$id: timings_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Two outputs
+    machine_readable:
+      execution:
+        output:
+          - name: heeft_recht
+            type: boolean
+          - name: hoogte
+            type: number
+        actions:
+          - output: heeft_recht
+            value: true
+          - output: hoogte
+            value:
+              operation: ADD
+              values: [1, 2]
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let article = law.find_article_by_number("1").unwrap();
+
+        let result = ArticleEngine::new(article, &law)
+            .evaluate(BTreeMap::new(), "2025-01-01")
+            .unwrap();
+        assert!(result.timings.is_empty());
+
+        let timed = EvaluationOptions {
+            record_timings: true,
+            ..EvaluationOptions::DEFAULT
+        };
+        let result = ArticleEngine::new(article, &law)
+            .with_options(timed)
+            .evaluate(BTreeMap::new(), "2025-01-01")
+            .unwrap();
+        let timed_outputs: Vec<&String> = result.timings.keys().collect();
+        let outputs: Vec<&String> = result.outputs.keys().collect();
+        assert_eq!(timed_outputs, outputs);
+    }
+
+    #[test]
+    fn test_parameter_shadowing_a_definition() {
+        let yaml = r#"
+$id: shadow_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Norm
+    machine_readable:
+      definitions:
+        norm:
+          value: 100
+      execution:
+        output:
+          - name: bedrag
+            type: number
+        actions:
+          - output: bedrag
+            value: $norm
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let article = law.find_article_by_number("1").unwrap();
+        let evaluate = |options: EvaluationOptions| {
+            let mut params = BTreeMap::new();
+            params.insert("norm".to_string(), Value::Int(250));
+            ArticleEngine::new(article, &law)
+                .with_options(options)
+                .evaluate(params, "2025-01-01")
+                .unwrap()
+        };
+
+        let result = evaluate(EvaluationOptions::DEFAULT);
+        assert_eq!(result.outputs.get("bedrag"), Some(&Value::Int(250)));
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].kind, WarningKind::ShadowedDefinition);
+        assert_eq!(result.warnings[0].subject, "norm");
+
+        let definition_wins = EvaluationOptions {
+            shadow_precedence: ShadowPrecedence::Definition,
+            ..EvaluationOptions::DEFAULT
+        };
+        let result = evaluate(definition_wins);
+        assert_eq!(result.outputs.get("bedrag"), Some(&Value::Int(100)));
+        assert_eq!(result.warnings[0].kind, WarningKind::ShadowedDefinition);
+
+        // Without the parameter there is nothing to warn about
+        let result = ArticleEngine::new(article, &law)
+            .evaluate(BTreeMap::new(), "2025-01-01")
+            .unwrap();
+        assert_eq!(result.outputs.get("bedrag"), Some(&Value::Int(100)));
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_call_formula_reuses_a_named_expression() {
+        let yaml = r#"
+$id: formula_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Netto inkomen
+    machine_readable:
+      formulas:
+        netto_inkomen:
+          operation: SUBTRACT
+          values:
+            - $bruto
+            - $heffing
+        lus:
+          operation: ADD
+          values:
+            - operation: CALL_FORMULA
+              name: lus
+            - 1
+  - number: '2'
+    text: Toeslag
+    machine_readable:
+      execution:
+        parameters:
+          - name: bruto
+            type: number
+          - name: heffing
+            type: number
+        output:
+          - name: toetsinkomen
+            type: number
+          - name: draagkracht
+            type: number
+        actions:
+          - output: toetsinkomen
+            value:
+              operation: CALL_FORMULA
+              name: netto_inkomen
+          - output: draagkracht
+            value:
+              operation: CALL_FORMULA
+              name: netto_inkomen
+  - number: '3'
+    text: Zelfverwijzing
+    machine_readable:
+      execution:
+        output:
+          - name: oneindig
+            type: number
+        actions:
+          - output: oneindig
+            value:
+              operation: CALL_FORMULA
+              name: lus
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let mut params = BTreeMap::new();
+        params.insert("bruto".to_string(), Value::Int(3_000));
+        params.insert("heffing".to_string(), Value::Int(700));
+        let result = ArticleEngine::new(law.find_article_by_number("2").unwrap(), &law)
+            .evaluate(params, "2025-01-01")
+            .unwrap();
+        assert_eq!(result.outputs.get("toetsinkomen"), Some(&Value::Int(2_300)));
+        assert_eq!(
+            result.outputs.get("toetsinkomen"),
+            result.outputs.get("draagkracht")
+        );
+
+        let result = ArticleEngine::new(law.find_article_by_number("3").unwrap(), &law)
+            .evaluate(BTreeMap::new(), "2025-01-01");
+        assert!(
+            matches!(&result, Err(EngineError::CircularReference(msg)) if msg.contains("lus -> lus")),
+            "expected a formula cycle, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_amount_parameter_text_is_parsed_at_money_scale() {
+        let yaml = r#"
+$id: amount_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Amount article
+    machine_readable:
+      execution:
+        parameters:
+          - name: premie
+            type: amount
+        output:
+          - name: premie_opgeslagen
+            type: amount
+        actions:
+          - output: premie_opgeslagen
+            value: $premie
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let article = law.find_article_by_number("1").unwrap();
+        let params = || BTreeMap::from([("premie".to_string(), Value::from("21.12"))]);
+
+        let result = ArticleEngine::new(article, &law)
+            .evaluate(params(), "2025-01-01")
+            .unwrap();
+        assert_eq!(
+            result.outputs.get("premie_opgeslagen"),
+            Some(&Value::Int(2112))
+        );
+
+        let euros = EvaluationOptions {
+            money_scale: 0,
+            ..EvaluationOptions::DEFAULT
+        };
+        let result = ArticleEngine::new(article, &law)
+            .with_options(euros)
+            .evaluate(params(), "2025-01-01")
+            .unwrap();
+        assert_eq!(
+            result.outputs.get("premie_opgeslagen"),
+            Some(&Value::Int(21))
+        );
+
+        let bad = BTreeMap::from([("premie".to_string(), Value::from("veel"))]);
+        let err = ArticleEngine::new(article, &law).evaluate(bad, "2025-01-01");
+        assert!(matches!(err, Err(EngineError::TypeMismatch { .. })));
+    }
+
+    #[test]
+    fn test_trace_renders_euro_amount_with_cents() {
+        let yaml = r#"
+$id: money_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Money article
+    machine_readable:
+      execution:
+        output:
+          - name: bedrag
+            type: amount
+        actions:
+          - output: bedrag
+            operation: MULTIPLY
+            values: [4.0, 0.5]
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let article = law.find_article_by_number("1").unwrap();
+        let engine = ArticleEngine::new(article, &law);
+        let trace = Rc::new(RefCell::new(TraceBuilder::new()));
+        trace.borrow_mut().push("root", PathNodeType::Article);
+
+        engine
+            .evaluate_with_trace(BTreeMap::new(), "2025-01-01", None, trace.clone())
+            .unwrap();
+
+        let root = trace.borrow_mut().pop().unwrap();
+        let rendered = root.render_box_drawing();
+        assert!(
+            rendered.contains("Result: bedrag = 2.00"),
+            "Expected two decimals in:\n{}",
+            rendered
+        );
+    }
+
+    #[test]
+    fn test_trace_action_nodes_cite_article_text() {
+        let yaml = r#"
+$id: span_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '3'
+    text: De toeslag bedraagt € 100. Het recht bestaat bij een inkomen onder de grens.
+    machine_readable:
+      execution:
+        output:
+          - name: toeslag
+            type: number
+            source_span:
+              start: 3
+              end: 25
+          - name: heeft_recht
+            type: boolean
+        actions:
+          - output: toeslag
+            value: 100
+          - output: heeft_recht
+            value: true
+"#;
+        let law = ArticleBasedLaw::from_yaml_str(yaml).unwrap();
+        let article = law.find_article_by_number("3").unwrap();
+        let trace = Rc::new(RefCell::new(TraceBuilder::new()));
+        trace.borrow_mut().push("root", PathNodeType::Article);
+        ArticleEngine::new(article, &law)
+            .evaluate_with_trace(BTreeMap::new(), "2025-01-01", None, trace.clone())
+            .unwrap();
+        let root = trace.borrow_mut().pop().unwrap();
+
+        let span_of = |name: &str| {
+            root.children
+                .iter()
+                .find(|node| node.node_type == PathNodeType::Action && node.name == name)
+                .and_then(|node| node.source_span.clone())
+                .unwrap()
+        };
+        let declared = span_of("toeslag");
+        assert_eq!(declared.law_id, "span_law");
+        assert_eq!(declared.article, "3");
+        assert_eq!(declared.text, "toeslag bedraagt € 100");
+        let whole = span_of("heeft_recht");
+        assert_eq!(
+            (whole.start, whole.text.as_str()),
+            (0, article.text.as_str())
+        );
+
+        let json = serde_json::to_value(&root).unwrap();
+        assert_eq!(
+            json["children"][0]["source_span"]["article"],
+            serde_json::json!("3")
+        );
+    }
+
     fn get_regulation_path() -> std::path::PathBuf {
         std::env::var("REGULATION_PATH")
             .map(std::path::PathBuf::from)
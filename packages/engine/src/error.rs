@@ -58,6 +58,27 @@ pub enum EngineError {
     #[error("Law not found: {0}")]
     LawNotFound(String),
 
+    /// The law is loaded, but none of its versions is valid on the date
+    #[error("No version of law '{law_id}' is valid on {date}")]
+    NoApplicableVersion { law_id: String, date: String },
+
+    /// A law with the same `$id` and `valid_from` is already loaded
+    #[error("Duplicate law version: '{law_id}' with valid_from {} is already loaded", valid_from.as_deref().unwrap_or("(none)"))]
+    DuplicateLawVersion {
+        law_id: String,
+        valid_from: Option<String>,
+    },
+
+    /// An alias is already declared by another loaded law
+    #[error(
+        "Duplicate law alias: '{alias}' of '{law_id}' is already an alias of '{existing_law_id}'"
+    )]
+    DuplicateLawAlias {
+        alias: String,
+        law_id: String,
+        existing_law_id: String,
+    },
+
     /// Article not found
     #[error("Article not found: {law_id}#{article}")]
     ArticleNotFound { law_id: String, article: String },
@@ -66,6 +87,14 @@ pub enum EngineError {
     #[error("Output '{output}' not found in law '{law_id}'")]
     OutputNotFound { law_id: String, output: String },
 
+    /// Declared outputs left unproduced while all outputs were required
+    #[error("Article {law_id}#{article} did not produce declared outputs: {}", outputs.join(", "))]
+    MissingOutputs {
+        law_id: String,
+        article: String,
+        outputs: Vec<String>,
+    },
+
     /// Circular reference detected
     #[error("Circular reference detected: {0}")]
     CircularReference(String),
@@ -82,6 +111,14 @@ pub enum EngineError {
     #[error("Maximum operation depth exceeded: {0} levels")]
     MaxDepthExceeded(usize),
 
+    /// Evaluation did not finish within the caller's time limit
+    #[error("Evaluation timed out after {} ms", .0.as_millis())]
+    Timeout(std::time::Duration),
+
+    /// Evaluation made more cross-law calls than its budget allows
+    #[error("Cross-law call budget exceeded: more than {0} calls")]
+    CallBudgetExceeded(u64),
+
     /// Resolution error (IoC open term resolution, priority conflicts, etc.)
     #[error("Resolution error: {0}")]
     ResolutionError(String),
@@ -182,6 +219,10 @@ pub enum ExternalError {
     #[error("Law not found: {0}")]
     LawNotFound(String),
 
+    /// No version of the law is valid on the date
+    #[error("No version of law '{law_id}' is valid on {date}")]
+    NoApplicableVersion { law_id: String, date: String },
+
     /// Article not found
     #[error("Article not found in law")]
     ArticleNotFound,
@@ -190,6 +231,10 @@ pub enum ExternalError {
     #[error("Output not found: {0}")]
     OutputNotFound(String),
 
+    /// Declared outputs were not produced
+    #[error("Declared outputs not produced: {}", .0.join(", "))]
+    MissingOutputs(Vec<String>),
+
     /// Circular reference detected
     #[error("Circular reference detected")]
     CircularReference,
@@ -206,6 +251,14 @@ pub enum ExternalError {
     #[error("Maximum nesting depth exceeded")]
     MaxDepthExceeded,
 
+    /// Evaluation timed out
+    #[error("Evaluation timed out")]
+    Timeout,
+
+    /// Too many cross-law calls
+    #[error("Cross-law call budget exceeded")]
+    CallBudgetExceeded,
+
     /// Resolution error (IoC open term resolution failed)
     #[error("Resolution failed")]
     ResolutionError,
@@ -233,7 +286,10 @@ impl From<EngineError> for ExternalError {
         tracing::debug!(internal_error = ?err, "Converting internal error to external");
 
         match err {
-            EngineError::LoadError(_) | EngineError::IoError(_) => ExternalError::LoadError,
+            EngineError::LoadError(_)
+            | EngineError::IoError(_)
+            | EngineError::DuplicateLawVersion { .. }
+            | EngineError::DuplicateLawAlias { .. } => ExternalError::LoadError,
             EngineError::YamlError(_) | EngineError::JsonError(_) => ExternalError::ParseError,
             EngineError::VariableNotFound(name) => ExternalError::VariableNotFound(name),
             EngineError::InvalidOperation(msg) => ExternalError::InvalidOperation(msg),
@@ -243,12 +299,18 @@ impl From<EngineError> for ExternalError {
             EngineError::DivisionByZero => ExternalError::DivisionByZero,
             EngineError::InvalidUri(_) => ExternalError::InvalidUri,
             EngineError::LawNotFound(id) => ExternalError::LawNotFound(id),
+            EngineError::NoApplicableVersion { law_id, date } => {
+                ExternalError::NoApplicableVersion { law_id, date }
+            }
             EngineError::ArticleNotFound { .. } => ExternalError::ArticleNotFound,
             EngineError::OutputNotFound { output, .. } => ExternalError::OutputNotFound(output),
+            EngineError::MissingOutputs { outputs, .. } => ExternalError::MissingOutputs(outputs),
             EngineError::CircularReference(_) => ExternalError::CircularReference,
             EngineError::MissingParameter(name) => ExternalError::MissingParameter(name),
             EngineError::ArithmeticOverflow(_) => ExternalError::ArithmeticOverflow,
             EngineError::MaxDepthExceeded(_) => ExternalError::MaxDepthExceeded,
+            EngineError::Timeout(_) => ExternalError::Timeout,
+            EngineError::CallBudgetExceeded(_) => ExternalError::CallBudgetExceeded,
             EngineError::ResolutionError(_) => ExternalError::ResolutionError,
             EngineError::ExternalReferenceNotResolved { input_name, .. } => {
                 ExternalError::ExternalReferenceNotResolved(input_name)
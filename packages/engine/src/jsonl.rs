@@ -0,0 +1,132 @@
+//! JSON-lines result writer
+//!
+//! Streams batch evaluation results as one JSON object per line, so large
+//! batches never have to be held in memory. Each row records the inputs and
+//! either the outputs of a successful evaluation or the error of a failed one:
+//!
+//! ```text
+//! {"inputs":{"bsn":"999993653"},"outputs":{"hoogte_zorgtoeslag":209692}}
+//! {"inputs":{"bsn":"000000000"},"error":"Division by zero"}
+//! ```
+
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use crate::engine::ArticleResult;
+use crate::error::Result;
+use crate::types::Value;
+
+/// Writes `(inputs, result)` rows as JSON lines, flushing after every row.
+pub struct JsonlWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> JsonlWriter<W> {
+    /// Wrap a writer (file, stdout, socket, ...).
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Write one row and flush it.
+    ///
+    /// A successful result is written with an `outputs` field, a failed one
+    /// with an `error` field holding the error message.
+    pub fn write_row(
+        &mut self,
+        inputs: &BTreeMap<String, Value>,
+        result: &Result<ArticleResult>,
+    ) -> Result<()> {
+        let mut row = serde_json::Map::new();
+        row.insert("inputs".to_string(), to_json_object(inputs.iter()));
+        match result {
+            Ok(article_result) => {
+                row.insert(
+                    "outputs".to_string(),
+                    to_json_object(article_result.outputs.iter()),
+                );
+            }
+            Err(e) => {
+                row.insert(
+                    "error".to_string(),
+                    serde_json::Value::String(e.to_string()),
+                );
+            }
+        }
+
+        serde_json::to_writer(&mut self.inner, &row)?;
+        self.inner.write_all(b"\n")?;
+        self.inner.flush()?;
+        Ok(())
+    }
+
+    /// Return the wrapped writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Convert name/value pairs to a JSON object (keys sorted).
+fn to_json_object<'a>(pairs: impl Iterator<Item = (&'a String, &'a Value)>) -> serde_json::Value {
+    serde_json::Value::Object(pairs.map(|(k, v)| (k.clone(), v.to_json())).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::EngineError;
+
+    fn article_result(outputs: BTreeMap<String, Value>) -> ArticleResult {
+        ArticleResult {
+            outputs,
+            output_provenance: BTreeMap::new(),
+            resolved_inputs: BTreeMap::new(),
+            article_number: "1".to_string(),
+            law_id: "test_law".to_string(),
+            law_uuid: None,
+            trace: None,
+            engine_version: env!("CARGO_PKG_VERSION").to_string(),
+            schema_version: None,
+            regulation_hash: None,
+            regulation_valid_from: None,
+            operations_evaluated: 0,
+            short_circuits: 0,
+            indicative_outputs: Default::default(),
+            warnings: Vec::new(),
+            resolution_snapshot: None,
+            call_counts: Default::default(),
+            timings: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_writes_one_line_per_row_with_errors() {
+        let mut writer = JsonlWriter::new(Vec::new());
+
+        for i in 0..3 {
+            let inputs = BTreeMap::from([("income".to_string(), Value::Int(i))]);
+            let result = if i == 1 {
+                Err(EngineError::DivisionByZero)
+            } else {
+                Ok(article_result(BTreeMap::from([(
+                    "amount".to_string(),
+                    Value::Int(i * 100),
+                )])))
+            };
+            writer.write_row(&inputs, &result).unwrap();
+        }
+
+        let output = String::from_utf8(writer.into_inner()).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["inputs"]["income"], 0);
+        assert_eq!(lines[0]["outputs"]["amount"], 0);
+        assert_eq!(lines[1]["inputs"]["income"], 1);
+        assert!(lines[1]["error"].is_string());
+        assert!(lines[1].get("outputs").is_none());
+        assert_eq!(lines[2]["outputs"]["amount"], 200);
+    }
+}
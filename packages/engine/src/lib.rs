@@ -27,11 +27,14 @@
 //! ```
 
 pub mod article;
+pub mod audit;
+pub mod clock;
 pub mod config;
 pub mod context;
 pub mod data_source;
 pub mod engine;
 pub mod error;
+pub mod jsonl;
 pub mod operations;
 pub mod priority;
 pub mod receipt;
@@ -53,26 +56,44 @@ pub mod telemetry;
 
 // Re-export commonly used items
 pub use article::{
-    Action, ActionOperation, ActionValue, Article, ArticleBasedLaw, Case, Execution,
-    HookDeclaration, HookFilter, HookPoint, MachineReadable, OverrideDeclaration,
-    ProcedureDefinition, Source, Stage, UntranslatableEntry,
+    Action, ActionOperation, ActionValue, Article, ArticleBasedLaw, Case, CustomOperation,
+    Definition, Definitions, Execution, HookDeclaration, HookFilter, HookPoint, MachineReadable,
+    OverrideDeclaration, ProcedureDefinition, Source, Stage, TextSpan, UntranslatableEntry,
+    UriReference,
 };
+pub use audit::{AuditRecord, AuditSink};
+pub use clock::{Clock, FixedClock, SystemClock};
 pub use config::{
     MAX_ARRAY_SIZE, MAX_CROSS_LAW_DEPTH, MAX_LOADED_LAWS, MAX_OPERATION_DEPTH, MAX_PROPERTY_DEPTH,
     MAX_RESOLUTION_DEPTH, MAX_YAML_SIZE,
 };
 pub use context::RuleContext;
 pub use data_source::{DataSource, DataSourceMatch, DataSourceRegistry, DictDataSource};
-pub use engine::{ArticleEngine, ArticleResult, OutputProvenance};
+pub use engine::{
+    ArticleEngine, ArticleResult, CallCounts, CapturedInput, KeyCase, OutputChange,
+    OutputProvenance, PartialArticleResult, ResolutionSnapshot, Warning, WarningKind,
+};
 pub use error::{EngineError, ExternalError, Result};
-pub use operations::{evaluate_value, execute_operation, ValueResolver};
+pub use jsonl::JsonlWriter;
+pub use operations::{
+    evaluate_value, execute_operation, OperationHandler, OperationRegistry, ValueResolver,
+};
 pub use receipt::ExecutionReceipt;
-pub use resolver::RuleResolver;
-pub use service::{ExecutionOutcome, LawExecutionService, LawInfo, ServiceProvider, StageState};
-pub use trace::{PathNode, TraceBuilder};
+pub use resolver::{
+    BrokenBasis, MissingRef, MissingRefKind, ParameterSpec, ReloadReport, RuleResolver, Snapshot,
+    NAMESPACE_SEPARATOR,
+};
+pub use service::{
+    ExecutionOutcome, LawExecutionService, LawInfo, ServiceProvider, StageState, StartupReport,
+};
+pub use trace::{
+    FloatFormat, FormatterRegistry, PathNode, SourceSpan, TraceBuilder, TraceEvent, TraceSink,
+    ValueFormatter, CANONICAL_FLOAT_DIGITS, MONEY_TAG,
+};
 pub use types::{
-    Connectivity, LegalStatus, Operation, ParameterType, PathNodeType, RegulatoryLayer,
-    ResolveType, UntranslatableMode, Value,
+    AnniversaryPolicy, BoolCoercion, Connectivity, EnumValidation, EvaluationOptions,
+    IntegerDivision, LegalStatus, MissingCrossLawOutput, Operation, ParameterType, PathNodeType,
+    RegulatoryLayer, ResolveType, ShadowPrecedence, UntranslatableMode, Value,
 };
 pub use uri::{internal_reference, ReferenceType, RegelrechtUri, RegelrechtUriBuilder};
 
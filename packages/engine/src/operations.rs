@@ -5,20 +5,38 @@
 //!
 //! **Schema v0.5.0 operations:**
 //! - **Comparison:** EQUALS, GREATER_THAN, LESS_THAN, GREATER_THAN_OR_EQUAL, LESS_THAN_OR_EQUAL
-//! - **Arithmetic:** ADD, SUBTRACT, MULTIPLY, DIVIDE
-//! - **Aggregate:** MAX, MIN
+//! - **Arithmetic:** ADD, SUBTRACT, MULTIPLY, DIVIDE, INT_DIVIDE, COMPOUND_INDEX,
+//!   ROUND_TO_MULTIPLE, LINEAR_TAPER, CLAMP
+//! - **Aggregate:** MAX, MIN, WEIGHTED_AVERAGE
 //! - **Logical:** AND, OR, NOT
 //! - **Conditional:** IF (multi-case with cases/default)
-//! - **Collection:** IN, LIST
-//! - **Date:** AGE, DATE_ADD, DATE, DAY_OF_WEEK
+//! - **Collection:** IN, LIST, COUNT_WHERE, FLATTEN, DISTINCT, LABEL, SLICE, SORT,
+//!   CUMULATIVE_SUM, SUM_FIELD, IS_SUBSET, IS_SUPERSET, SET_EQUALS
+//! - **Date:** AGE, HAS_REACHED_AGE, AGE_IN_MONTHS, DATE_ADD, DATE, DAY_OF_WEEK, SUBTRACT_DATE, DATE_BEFORE, DATE_AFTER,
+//!   DATE_ON_OR_BEFORE, DATE_ON_OR_AFTER, PRO_RATA, CLAMP_DATE,
+//!   NTH_WEEKDAY, NEXT_BUSINESS_DAY, YEAR_FRACTION, DATE_IN_PERIODS, ISO_WEEK, QUARTER, CONVERT_DURATION,
+//!   PERIOD_OVERLAP_DAYS, MIN_DATE, MAX_DATE
+//! - **Cross-law:** URI_CALL, ANY_ENTITLED, DELEGATED_LOOKUP, APPLICABLE_VERSION
+//! - **Validation:** VALIDATE_BSN, NORMALIZE_POSTCODE, MATCHES
+//! - **Emptiness:** IS_EMPTY, NOT_EMPTY
+//! - **Type conversion:** CAST, PARSE_BOOL
+//! - **Formula:** CALL_FORMULA
 //!
 //! **Engine-only (not in schema, accepted for backward compatibility):**
 //! NOT_EQUALS, IS_NULL, NOT_NULL, NOT_IN
 
-use crate::article::{ActionOperation, ActionValue, Case};
+use crate::article::{ActionOperation, ActionValue, Case, UriReference};
+use crate::config;
 use crate::error::{EngineError, Result};
-use crate::types::{PathNodeType, Value};
+use crate::types::{
+    AnniversaryPolicy, BoolCoercion, EvaluationOptions, IntegerDivision, PathNodeType, Value,
+};
+use chrono::format::ParseErrorKind;
 use chrono::{Datelike, NaiveDate};
+use regex::{Regex, RegexBuilder};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
 
 /// Maximum nesting depth for operations to prevent stack overflow
 const MAX_OPERATION_DEPTH: usize = 100;
@@ -39,14 +57,23 @@ fn propagate_binary(a: &Value, b: &Value) -> Option<Value> {
     }
 }
 
-/// Maximum integer value that can be exactly represented in f64.
+/// Largest magnitude up to which every integer is exactly representable as
+/// f64 (2^53). Conversions to float never accept more than this, whatever
+/// [`EvaluationOptions::max_safe_integer`] is set to; only operations that
+/// stay in integer arithmetic honour a raised threshold.
+const F64_EXACT_INTEGER: i64 = 1 << 53;
+
+/// Default maximum integer value that can be exactly represented in f64.
 /// Beyond this, precision is lost when converting i64 to f64.
-/// This is 2^53 = 9007199254740992.
-const MAX_SAFE_INTEGER: i64 = 9_007_199_254_740_992;
+/// This is 2^53 = 9007199254740992; configurable via
+/// [`EvaluationOptions::max_safe_integer`].
+#[cfg(test)]
+const MAX_SAFE_INTEGER: i64 = EvaluationOptions::DEFAULT.max_safe_integer;
 
-/// Minimum integer value that can be exactly represented in f64.
+/// Default minimum integer value that can be exactly represented in f64.
 /// This is -2^53.
-const MIN_SAFE_INTEGER: i64 = -9_007_199_254_740_992;
+#[cfg(test)]
+const MIN_SAFE_INTEGER: i64 = -MAX_SAFE_INTEGER;
 
 /// Trait for resolving variable references ($var) during operation execution.
 ///
@@ -102,6 +129,190 @@ pub trait ValueResolver {
     fn has_trace(&self) -> bool {
         false
     }
+
+    /// Evaluation options for this execution. Returns the defaults by default.
+    fn options(&self) -> &EvaluationOptions {
+        &EvaluationOptions::DEFAULT
+    }
+
+    /// Count an executed operation. No-op by default.
+    fn record_operation(&self) {}
+
+    /// Count an AND, OR, ANY_ENTITLED or FIRST_RESOLVED that stopped before its
+    /// last operand. No-op by default.
+    fn record_short_circuit(&self) {}
+
+    /// Evaluate a `regelrecht://` URI with the given parameters (URI_CALL).
+    ///
+    /// Resolvers without access to a `ServiceProvider` cannot reach other laws
+    /// and return an error by default.
+    fn evaluate_uri(&self, uri: &str, _parameters: BTreeMap<String, Value>) -> Result<Value> {
+        Err(EngineError::InvalidOperation(format!(
+            "URI_CALL '{}' requires a service provider to resolve cross-law references",
+            uri
+        )))
+    }
+
+    /// Get the `valid_from` of the version of `law_id` that applies on the
+    /// calculation date (APPLICABLE_VERSION), or null if it has none.
+    ///
+    /// Like [`Self::evaluate_uri`] this needs a `ServiceProvider` and returns
+    /// an error by default.
+    fn applicable_version(&self, law_id: &str) -> Result<Value> {
+        Err(EngineError::InvalidOperation(format!(
+            "APPLICABLE_VERSION '{}' requires a service provider to look up law versions",
+            law_id
+        )))
+    }
+
+    /// Look up a named formula for CALL_FORMULA. Returns None by default.
+    fn formula(&self, _name: &str) -> Option<&ActionValue> {
+        None
+    }
+
+    /// Mark formula `name` as being evaluated, failing with
+    /// `CircularReference` if it already is. Succeeds by default.
+    fn enter_formula(&self, _name: &str) -> Result<()> {
+        Ok(())
+    }
+
+    /// Undo the matching [`Self::enter_formula`]. No-op by default.
+    fn leave_formula(&self) {}
+
+    /// Look up the handler of a custom operation. Returns None by default.
+    fn custom_operation(&self, _name: &str) -> Option<&OperationHandler> {
+        None
+    }
+
+    /// Compile a MATCHES pattern. Compiles on every call by default;
+    /// [`RuleContext`](crate::RuleContext) compiles each pattern once per
+    /// evaluation.
+    fn regex(&self, pattern: &str) -> Result<Regex> {
+        compile_regex(pattern)
+    }
+}
+
+/// Forwarding impl, so that a `&dyn ValueResolver` (as handed to an
+/// [`OperationHandler`]) can be passed to [`evaluate_value`].
+impl<T: ValueResolver + ?Sized> ValueResolver for &T {
+    fn resolve(&self, name: &str) -> Result<Value> {
+        (**self).resolve(name)
+    }
+
+    fn trace_push(&self, name: &str, node_type: PathNodeType) {
+        (**self).trace_push(name, node_type);
+    }
+
+    fn trace_pop(&self) {
+        (**self).trace_pop();
+    }
+
+    fn trace_set_result(&self, result: Value) {
+        (**self).trace_set_result(result);
+    }
+
+    fn trace_set_message(&self, msg: String) {
+        (**self).trace_set_message(msg);
+    }
+
+    fn trace_get_message(&self) -> Option<String> {
+        (**self).trace_get_message()
+    }
+
+    fn has_trace(&self) -> bool {
+        (**self).has_trace()
+    }
+
+    fn options(&self) -> &EvaluationOptions {
+        (**self).options()
+    }
+
+    fn record_operation(&self) {
+        (**self).record_operation();
+    }
+
+    fn record_short_circuit(&self) {
+        (**self).record_short_circuit();
+    }
+
+    fn evaluate_uri(&self, uri: &str, parameters: BTreeMap<String, Value>) -> Result<Value> {
+        (**self).evaluate_uri(uri, parameters)
+    }
+
+    fn applicable_version(&self, law_id: &str) -> Result<Value> {
+        (**self).applicable_version(law_id)
+    }
+
+    fn formula(&self, name: &str) -> Option<&ActionValue> {
+        (**self).formula(name)
+    }
+
+    fn enter_formula(&self, name: &str) -> Result<()> {
+        (**self).enter_formula(name)
+    }
+
+    fn leave_formula(&self) {
+        (**self).leave_formula();
+    }
+
+    fn custom_operation(&self, name: &str) -> Option<&OperationHandler> {
+        (**self).custom_operation(name)
+    }
+
+    fn regex(&self, pattern: &str) -> Result<Regex> {
+        (**self).regex(pattern)
+    }
+}
+
+/// Handler executing a custom operation.
+///
+/// Receives the operation (an [`ActionOperation::Custom`]), the resolver for
+/// evaluating its operands with [`evaluate_value`], and the current depth.
+pub type OperationHandler =
+    Arc<dyn Fn(&ActionOperation, &dyn ValueResolver, usize) -> Result<Value> + Send + Sync>;
+
+/// Handlers for custom operations, keyed by operation name.
+///
+/// Lets a deployment add a bespoke operation without forking the engine;
+/// register it on [`LawExecutionService`](crate::LawExecutionService) or
+/// pass a registry to [`ArticleEngine`](crate::ArticleEngine).
+#[derive(Clone, Default)]
+pub struct OperationRegistry {
+    handlers: HashMap<String, OperationHandler>,
+}
+
+impl OperationRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the handler for the operation `name`, replacing any earlier one.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        handler: impl Fn(&ActionOperation, &dyn ValueResolver, usize) -> Result<Value>
+            + Send
+            + Sync
+            + 'static,
+    ) {
+        self.handlers.insert(name.into(), Arc::new(handler));
+    }
+
+    /// Get the handler registered for `name`.
+    pub fn get(&self, name: &str) -> Option<&OperationHandler> {
+        self.handlers.get(name)
+    }
+}
+
+impl std::fmt::Debug for OperationRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names: Vec<&String> = self.handlers.keys().collect();
+        names.sort();
+        f.debug_struct("OperationRegistry")
+            .field("operations", &names)
+            .finish()
+    }
 }
 
 /// Evaluate an ActionValue to a concrete Value.
@@ -148,6 +359,7 @@ pub fn execute_operation<R: ValueResolver>(
         return Err(EngineError::MaxDepthExceeded(depth));
     }
 
+    resolver.record_operation();
     let op_name = op.operation_name();
     let tracing = resolver.has_trace();
     if tracing {
@@ -160,12 +372,21 @@ pub fn execute_operation<R: ValueResolver>(
         match &result {
             Ok(value) => {
                 resolver.trace_set_result(value.clone());
-                // For IF (cases/default), execute_if already set a message
-                // with case match info; incorporate it instead of overwriting.
+                // For IF (cases/default) and DELEGATED_OR_DEFAULT, the operation
+                // already set a message with the branch it took; incorporate it
+                // instead of overwriting.
                 let existing_msg = resolver.trace_get_message();
-                let msg = if matches!(op, ActionOperation::If { .. }) {
+                let msg = if matches!(
+                    op,
+                    ActionOperation::If { .. } | ActionOperation::DelegatedOrDefault { .. }
+                ) {
                     if let Some(case_info) = existing_msg {
-                        format!("IF({}) = {}", case_info, format_value_for_trace(value))
+                        format!(
+                            "{}({}) = {}",
+                            op_name,
+                            case_info,
+                            format_value_for_trace(value)
+                        )
                     } else {
                         format!(
                             "Compute {}(...) = {}",
@@ -230,27 +451,75 @@ fn execute_operation_internal<R: ValueResolver>(
             execute_equality(subject, value, resolver, depth, true)
         }
         ActionOperation::GreaterThan { subject, value } => {
-            execute_numeric_comparison(subject, value, resolver, depth, |a, b| a > b)
+            execute_numeric_comparison(subject, value, resolver, depth, |o| {
+                o == Some(Ordering::Greater)
+            })
         }
         ActionOperation::LessThan { subject, value } => {
-            execute_numeric_comparison(subject, value, resolver, depth, |a, b| a < b)
+            execute_numeric_comparison(subject, value, resolver, depth, |o| {
+                o == Some(Ordering::Less)
+            })
         }
         ActionOperation::GreaterThanOrEqual { subject, value } => {
-            execute_numeric_comparison(subject, value, resolver, depth, |a, b| a >= b)
+            execute_numeric_comparison(subject, value, resolver, depth, |o| {
+                matches!(o, Some(Ordering::Greater | Ordering::Equal))
+            })
         }
         ActionOperation::LessThanOrEqual { subject, value } => {
-            execute_numeric_comparison(subject, value, resolver, depth, |a, b| a <= b)
+            execute_numeric_comparison(subject, value, resolver, depth, |o| {
+                matches!(o, Some(Ordering::Less | Ordering::Equal))
+            })
         }
+        ActionOperation::ApproxEquals {
+            subject,
+            value,
+            tolerance,
+        } => execute_approx_equals(subject, value, tolerance.as_ref(), resolver, depth),
 
         // Arithmetic
-        ActionOperation::Add { values } => execute_add(values, resolver, depth),
+        ActionOperation::Add {
+            values,
+            allow_empty,
+        } => execute_add(values, *allow_empty, resolver, depth),
         ActionOperation::Subtract { values } => execute_subtract(values, resolver, depth),
-        ActionOperation::Multiply { values } => execute_multiply(values, resolver, depth),
+        ActionOperation::Multiply {
+            values,
+            allow_empty,
+        } => execute_multiply(values, *allow_empty, resolver, depth),
         ActionOperation::Divide { values } => execute_divide(values, resolver, depth),
+        ActionOperation::IntDivide { values } => execute_int_divide(values, resolver, depth),
+        ActionOperation::CompoundIndex { value, factors } => {
+            execute_compound_index(value, factors, resolver, depth)
+        }
+        ActionOperation::RoundToMultiple {
+            subject,
+            value,
+            rounding,
+        } => execute_round_to_multiple(subject, value, rounding.as_ref(), resolver, depth),
+        ActionOperation::LinearTaper {
+            amount,
+            income,
+            threshold,
+            rate,
+        } => execute_linear_taper([amount, income, threshold, rate], resolver, depth),
+        ActionOperation::Clamp { subject, min, max } => {
+            execute_clamp(subject, min, max, resolver, depth)
+        }
+        ActionOperation::ProgressiveBrackets { subject, brackets } => {
+            execute_progressive_brackets(subject, brackets, resolver, depth)
+        }
+        ActionOperation::Negate { subject } => execute_negate(subject, resolver, depth),
 
         // Aggregate
-        ActionOperation::Max { values } => execute_aggregate(values, resolver, depth, f64::max),
-        ActionOperation::Min { values } => execute_aggregate(values, resolver, depth, f64::min),
+        ActionOperation::Max { values } => {
+            execute_aggregate("MAX", values, resolver, depth, f64::max)
+        }
+        ActionOperation::Min { values } => {
+            execute_aggregate("MIN", values, resolver, depth, f64::min)
+        }
+        ActionOperation::WeightedAverage { values, weights } => {
+            execute_weighted_average(values, weights, resolver, depth)
+        }
 
         // Logical
         ActionOperation::And { conditions } => execute_and(conditions, resolver, depth),
@@ -265,6 +534,14 @@ fn execute_operation_internal<R: ValueResolver>(
         // Null checking operations
         ActionOperation::IsNull { subject } => execute_null_check(subject, resolver, depth, false),
         ActionOperation::NotNull { subject } => execute_null_check(subject, resolver, depth, true),
+        ActionOperation::IsEmpty {
+            subject,
+            ignore_whitespace,
+        } => execute_empty_check(subject, ignore_whitespace.as_ref(), resolver, depth, false),
+        ActionOperation::NotEmpty {
+            subject,
+            ignore_whitespace,
+        } => execute_empty_check(subject, ignore_whitespace.as_ref(), resolver, depth, true),
 
         // Collection operations
         ActionOperation::In {
@@ -292,12 +569,63 @@ fn execute_operation_internal<R: ValueResolver>(
             true,
         ),
         ActionOperation::List { items } => execute_list(items, resolver, depth),
+        ActionOperation::CountWhere { value, condition } => {
+            execute_count_where(value, condition, resolver, depth)
+        }
+        ActionOperation::Flatten { value } => execute_flatten(value, resolver, depth),
+        ActionOperation::Distinct { value } => execute_distinct(value, resolver, depth),
+        ActionOperation::Label {
+            subject,
+            table,
+            default,
+        } => execute_label(subject, table, default.as_ref(), resolver, depth),
+        ActionOperation::Slice {
+            value,
+            start,
+            length,
+        } => execute_slice(value, start, length.as_ref(), resolver, depth),
+        ActionOperation::Split {
+            subject,
+            value,
+            limit,
+        } => execute_split(subject, value, limit.as_ref(), resolver, depth),
+        ActionOperation::CumulativeSum { value } => execute_cumulative_sum(value, resolver, depth),
+        ActionOperation::SumField {
+            value,
+            field,
+            skip_missing,
+        } => execute_sum_field(value, field, skip_missing.as_ref(), resolver, depth),
+        ActionOperation::IsSubset { subject, value } => {
+            execute_subset(subject, value, resolver, depth, false)
+        }
+        ActionOperation::IsSuperset { subject, value } => {
+            execute_subset(subject, value, resolver, depth, true)
+        }
+        ActionOperation::SetEquals {
+            subject,
+            value,
+            count_duplicates,
+        } => execute_set_equals(subject, value, count_duplicates.as_ref(), resolver, depth),
+        ActionOperation::Sort {
+            value,
+            descending,
+            mode,
+        } => execute_sort(value, descending.as_ref(), mode.as_ref(), resolver, depth),
 
         // Date
         ActionOperation::Age {
             date_of_birth,
             reference_date,
         } => execute_age(date_of_birth, reference_date, resolver, depth),
+        ActionOperation::HasReachedAge {
+            date_of_birth,
+            age,
+            reference_date,
+        } => execute_has_reached_age(date_of_birth, age, reference_date.as_ref(), resolver, depth),
+        ActionOperation::AgeInMonths {
+            date_of_birth,
+            reference_date,
+        } => execute_age_in_months(date_of_birth, reference_date.as_ref(), resolver, depth),
         ActionOperation::DateAdd {
             date,
             years,
@@ -317,6 +645,124 @@ fn execute_operation_internal<R: ValueResolver>(
             execute_date_construct(year, month, day, resolver, depth)
         }
         ActionOperation::DayOfWeek { date } => execute_day_of_week(date, resolver, depth),
+        ActionOperation::SubtractDate {
+            values,
+            unit,
+            rounding,
+        } => execute_subtract_date(values, unit, rounding.as_ref(), resolver, depth),
+        ActionOperation::DateBefore { subject, value } => {
+            execute_date_comparison(subject, value, resolver, depth, |a, b| a < b)
+        }
+        ActionOperation::DateAfter { subject, value } => {
+            execute_date_comparison(subject, value, resolver, depth, |a, b| a > b)
+        }
+        ActionOperation::DateOnOrBefore { subject, value } => {
+            execute_date_comparison(subject, value, resolver, depth, |a, b| a <= b)
+        }
+        ActionOperation::DateOnOrAfter { subject, value } => {
+            execute_date_comparison(subject, value, resolver, depth, |a, b| a >= b)
+        }
+        ActionOperation::ProRata {
+            value,
+            start_date,
+            end_date,
+            period,
+        } => execute_pro_rata(value, start_date, end_date, period, resolver, depth),
+        ActionOperation::ClampDate { subject, min, max } => {
+            execute_clamp_date(subject, min, max, resolver, depth)
+        }
+        ActionOperation::NthWeekday {
+            date,
+            weekday,
+            ordinal,
+        } => execute_nth_weekday(date, weekday, ordinal, resolver, depth),
+        ActionOperation::NextBusinessDay { subject, holidays } => {
+            execute_next_business_day(subject, holidays.as_ref(), resolver, depth)
+        }
+        ActionOperation::YearFraction {
+            start,
+            end,
+            convention,
+        } => execute_year_fraction(start, end, convention.as_ref(), resolver, depth),
+        ActionOperation::IsoWeek { subject } => execute_iso_week(subject, resolver, depth),
+        ActionOperation::Quarter { subject } => execute_quarter(subject, resolver, depth),
+        ActionOperation::ConvertDuration { value, from, to } => {
+            execute_convert_duration(value, from, to, resolver, depth)
+        }
+        ActionOperation::PeriodLookup {
+            subject,
+            periods,
+            default,
+        } => execute_period_lookup(subject, periods, default.as_ref(), resolver, depth),
+        ActionOperation::DateInPeriods { subject, periods } => {
+            execute_date_in_periods(subject, periods, resolver, depth)
+        }
+        ActionOperation::PeriodOverlapDays {
+            a_from,
+            a_to,
+            b_from,
+            b_to,
+        } => execute_period_overlap_days([a_from, a_to, b_from, b_to], resolver, depth),
+        ActionOperation::MinDate { value } => execute_extreme_date(value, resolver, depth, false),
+        ActionOperation::MaxDate { value } => execute_extreme_date(value, resolver, depth, true),
+
+        // Cross-law
+        ActionOperation::UriCall { uri, parameters } => {
+            execute_uri_call(uri, parameters, resolver, depth)
+        }
+        ActionOperation::AnyEntitled { references } => {
+            execute_any_entitled(references, resolver, depth)
+        }
+        ActionOperation::FirstResolved {
+            references,
+            default,
+        } => execute_first_resolved(references, default.as_ref(), resolver, depth),
+        ActionOperation::DelegatedLookup { subject, select_on } => {
+            execute_delegated_lookup(subject, select_on, resolver, depth)
+        }
+        ActionOperation::DelegatedOrDefault { subject, default } => {
+            execute_delegated_or_default(subject, default, resolver, depth)
+        }
+        ActionOperation::ApplicableVersion { law_id } => resolver.applicable_version(law_id),
+
+        // Formula
+        ActionOperation::CallFormula { name } => execute_call_formula(name, resolver, depth),
+
+        // Custom
+        ActionOperation::Custom(custom) => {
+            let handler = resolver
+                .custom_operation(&custom.operation)
+                .ok_or_else(|| {
+                    EngineError::InvalidOperation(format!(
+                        "Unknown operation '{}': no custom handler registered",
+                        custom.operation
+                    ))
+                })?;
+            handler(op, resolver, depth)
+        }
+
+        // Validation
+        ActionOperation::ValidateBsn { subject } => execute_validate_bsn(subject, resolver, depth),
+        ActionOperation::NormalizePostcode { subject } => {
+            execute_normalize_postcode(subject, resolver, depth)
+        }
+        ActionOperation::Matches { subject, value } => {
+            execute_matches(subject, value, resolver, depth)
+        }
+
+        // Type conversion
+        ActionOperation::Cast { subject, value } => execute_cast(subject, value, resolver, depth),
+        ActionOperation::ParseBool {
+            subject,
+            true_values,
+            false_values,
+        } => execute_parse_bool(
+            subject,
+            true_values.as_ref(),
+            false_values.as_ref(),
+            resolver,
+            depth,
+        ),
     }
 }
 
@@ -340,7 +786,7 @@ fn execute_operation_internal<R: ValueResolver>(
 /// Integers beyond ±2^53 cannot be exactly represented as f64, so
 /// Int-Float comparisons involving such integers return `false` immediately
 /// to avoid silent precision loss.
-pub(crate) fn values_equal(a: &Value, b: &Value) -> bool {
+pub(crate) fn values_equal(a: &Value, b: &Value, max_safe_integer: i64) -> bool {
     match (a, b) {
         // Untranslatable: two untranslatables are equal, mixed is never equal
         (Value::Untranslatable { .. }, Value::Untranslatable { .. }) => true,
@@ -353,12 +799,14 @@ pub(crate) fn values_equal(a: &Value, b: &Value) -> bool {
                 f1 == f2
             }
         }
-        // Int-Float comparison: handle NaN and precision guard
-        (Value::Int(i), Value::Float(f)) => {
-            !f.is_nan() && *i >= MIN_SAFE_INTEGER && *i <= MAX_SAFE_INTEGER && (*i as f64) == *f
-        }
-        (Value::Float(f), Value::Int(i)) => {
-            !f.is_nan() && *i >= MIN_SAFE_INTEGER && *i <= MAX_SAFE_INTEGER && *f == (*i as f64)
+        // Int-Float comparison: handle NaN and precision guard. The float is
+        // converted to an integer rather than the other way round, so that
+        // integers above 2^53 compare exactly when the threshold is raised.
+        (Value::Int(i), Value::Float(f)) | (Value::Float(f), Value::Int(i)) => {
+            !f.is_nan()
+                && i.unsigned_abs() <= max_safe_integer.unsigned_abs()
+                && f.fract() == 0.0
+                && f64_to_i64_safe(*f).is_ok_and(|n| n == *i)
         }
         // Default: use structural equality
         _ => a == b,
@@ -384,13 +832,18 @@ fn execute_equality<R: ValueResolver>(
         return Ok(tainted);
     }
 
-    let equal = values_equal(&subject_val, &value_val);
+    let equal = values_equal(
+        &subject_val,
+        &value_val,
+        resolver.options().max_safe_integer,
+    );
     Ok(Value::Bool(if negate { !equal } else { equal }))
 }
 
 /// Execute a numeric comparison (>, <, >=, <=).
 ///
-/// Converts values to f64 for comparison to handle both Int and Float types.
+/// Two integers are compared exactly; otherwise both values are converted to
+/// f64. `compare` receives `None` when either value is NaN.
 fn execute_numeric_comparison<R: ValueResolver, F>(
     subject: &ActionValue,
     value: &ActionValue,
@@ -399,7 +852,7 @@ fn execute_numeric_comparison<R: ValueResolver, F>(
     compare: F,
 ) -> Result<Value>
 where
-    F: Fn(f64, f64) -> bool,
+    F: Fn(Option<Ordering>) -> bool,
 {
     let subject_val = evaluate_value(subject, resolver, depth)?;
     let value_val = evaluate_value(value, resolver, depth)?;
@@ -408,10 +861,56 @@ where
         return Ok(tainted);
     }
 
-    let subject_num = to_number(&subject_val)?;
-    let value_num = to_number(&value_val)?;
+    let limit = resolver.options().max_safe_integer;
+    if let (Value::Int(a), Value::Int(b)) = (&subject_val, &value_val) {
+        let a = checked_int(*a, limit)?;
+        let b = checked_int(*b, limit)?;
+        return Ok(Value::Bool(compare(Some(a.cmp(&b)))));
+    }
+
+    let subject_num = to_number(&subject_val, limit)?;
+    let value_num = to_number(&value_val, limit)?;
+
+    Ok(Value::Bool(compare(subject_num.partial_cmp(&value_num))))
+}
+
+/// Execute APPROX_EQUALS operation: whether `|subject - value| <= tolerance`.
+///
+/// Compares as floats, for reconciling a recomputed amount with a stated
+/// one. All operands must be numbers within the safe integer range; the
+/// tolerance defaults to 0 and must not be negative.
+fn execute_approx_equals<R: ValueResolver>(
+    subject: &ActionValue,
+    value: &ActionValue,
+    tolerance: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let mut operands = vec![
+        evaluate_value(subject, resolver, depth)?,
+        evaluate_value(value, resolver, depth)?,
+    ];
+    if let Some(tolerance) = tolerance {
+        operands.push(evaluate_value(tolerance, resolver, depth)?);
+    }
+    if let Some(tainted) = find_untranslatable(&operands) {
+        return Ok(tainted);
+    }
 
-    Ok(Value::Bool(compare(subject_num, value_num)))
+    let limit = resolver.options().max_safe_integer;
+    let numbers = operands
+        .iter()
+        .enumerate()
+        .map(|(index, val)| operand_to_number("APPROX_EQUALS", index, val, limit))
+        .collect::<Result<Vec<_>>>()?;
+    let tolerance = numbers.get(2).copied().unwrap_or(0.0);
+    if tolerance < 0.0 {
+        return Err(EngineError::InvalidOperation(format!(
+            "APPROX_EQUALS: tolerance must not be negative, got {}",
+            tolerance
+        )));
+    }
+    Ok(Value::Bool((numbers[0] - numbers[1]).abs() <= tolerance))
 }
 
 // =============================================================================
@@ -424,14 +923,21 @@ where
 /// - Numbers: sum all values
 /// - Arrays: concatenate all arrays
 /// - Strings: concatenate all strings
+///
+/// Empty `values` is an error unless `allow_empty` is set, in which case the
+/// additive identity `0` is returned.
 fn execute_add<R: ValueResolver>(
     values: &[ActionValue],
+    allow_empty: bool,
     resolver: &R,
     depth: usize,
 ) -> Result<Value> {
     let evaluated = evaluate_values(values, resolver, depth)?;
 
     if evaluated.is_empty() {
+        if allow_empty {
+            return Ok(Value::Int(0));
+        }
         return Err(EngineError::InvalidOperation(
             "ADD requires at least one value".to_string(),
         ));
@@ -476,12 +982,16 @@ fn execute_add<R: ValueResolver>(
             Ok(Value::String(result))
         }
         Value::Int(_) | Value::Float(_) => {
-            // Original numeric addition
+            let limit = resolver.options().max_safe_integer;
+            if let Some(ints) = all_ints(&evaluated) {
+                return checked_int_sum("ADD", ints, limit).map(Value::Int);
+            }
+
             let mut sum = 0.0;
             let mut has_float = false;
             for val in &evaluated {
                 match val {
-                    Value::Int(_) => sum += to_number(val)?,
+                    Value::Int(_) => sum += to_number(val, limit)?,
                     Value::Float(f) => {
                         sum += f;
                         has_float = true;
@@ -520,15 +1030,29 @@ fn execute_subtract<R: ValueResolver>(
         return Ok(tainted);
     }
 
+    let limit = resolver.options().max_safe_integer;
+    if let Some(ints) = all_ints(&evaluated) {
+        let Some((first, rest)) = ints.split_first() else {
+            unreachable!("values checked non-empty above")
+        };
+        let mut result = checked_int(*first, limit)?;
+        for i in rest {
+            result = result.checked_sub(checked_int(*i, limit)?).ok_or_else(|| {
+                EngineError::ArithmeticOverflow(format!("SUBTRACT overflow: {} - {}", result, i))
+            })?;
+        }
+        return Ok(Value::Int(result));
+    }
+
     // SAFETY: values guaranteed non-empty by check above
     let Some((first, rest)) = evaluated.split_first() else {
         unreachable!("values checked non-empty above")
     };
-    let mut result = to_number(first)?;
+    let mut result = operand_to_number("SUBTRACT", 0, first, limit)?;
     let mut has_float = matches!(first, Value::Float(_));
 
-    for val in rest {
-        result -= to_number(val)?;
+    for (i, val) in rest.iter().enumerate() {
+        result -= operand_to_number("SUBTRACT", i + 1, val, limit)?;
         if matches!(val, Value::Float(_)) {
             has_float = true;
         }
@@ -545,12 +1069,19 @@ fn execute_subtract<R: ValueResolver>(
 ///
 /// Note: Uses `to_number()` which validates that integers are within the
 /// safe range for f64 conversion (±2^53).
+///
+/// Empty `values` is an error unless `allow_empty` is set, in which case the
+/// multiplicative identity `1` is returned.
 fn execute_multiply<R: ValueResolver>(
     values: &[ActionValue],
+    allow_empty: bool,
     resolver: &R,
     depth: usize,
 ) -> Result<Value> {
     if values.is_empty() {
+        if allow_empty {
+            return Ok(Value::Int(1));
+        }
         return Err(EngineError::InvalidOperation(
             "MULTIPLY requires at least one value".to_string(),
         ));
@@ -562,12 +1093,23 @@ fn execute_multiply<R: ValueResolver>(
         return Ok(tainted);
     }
 
+    let limit = resolver.options().max_safe_integer;
+    if let Some(ints) = all_ints(&evaluated) {
+        let mut result: i64 = 1;
+        for i in ints {
+            result = result.checked_mul(checked_int(i, limit)?).ok_or_else(|| {
+                EngineError::ArithmeticOverflow(format!("MULTIPLY overflow: {} * {}", result, i))
+            })?;
+        }
+        return Ok(Value::Int(result));
+    }
+
     let mut result = 1.0;
     let mut has_float = false;
 
     for val in &evaluated {
         match val {
-            Value::Int(_) => result *= to_number(val)?,
+            Value::Int(_) => result *= to_number(val, limit)?,
             Value::Float(f) => {
                 result *= f;
                 has_float = true;
@@ -585,6 +1127,9 @@ fn execute_multiply<R: ValueResolver>(
 
 /// Execute DIVIDE operation: first value divided by all subsequent values.
 ///
+/// Returns a float, unless [`EvaluationOptions::integer_division`] selects a
+/// rounding mode and every operand is an integer.
+///
 /// Returns `Err(DivisionByZero)` for division by zero.
 /// Returns `Err(InvalidOperation)` for NaN or Infinity results.
 fn execute_divide<R: ValueResolver>(
@@ -604,14 +1149,28 @@ fn execute_divide<R: ValueResolver>(
         return Ok(tainted);
     }
 
+    let mode = resolver.options().integer_division;
+    if mode != IntegerDivision::Float {
+        let ints: Option<Vec<i64>> = evaluated
+            .iter()
+            .map(|v| match v {
+                Value::Int(i) => Some(*i),
+                _ => None,
+            })
+            .collect();
+        if let Some(ints) = ints {
+            return divide_integers(&ints, mode);
+        }
+    }
+
     // SAFETY: values guaranteed non-empty by check above
     let Some((first, rest)) = evaluated.split_first() else {
         unreachable!("values checked non-empty above")
     };
-    let mut result = to_number(first)?;
+    let mut result = to_number(first, resolver.options().max_safe_integer)?;
 
     for val in rest {
-        let divisor = to_number(val)?;
+        let divisor = to_number(val, resolver.options().max_safe_integer)?;
         if divisor == 0.0 {
             return Err(EngineError::DivisionByZero);
         }
@@ -630,27 +1189,61 @@ fn execute_divide<R: ValueResolver>(
         ));
     }
 
-    // Division always returns a float (like Python)
+    // Division returns a float (like Python) unless integer division is configured
     Ok(Value::Float(result))
 }
 
-// =============================================================================
-// Aggregate Operations
-// =============================================================================
+/// Divide integer operands exactly and round the quotient once with `mode`.
+///
+/// The divisors are multiplied into a single denominator first, so
+/// `DIVIDE(7, 2, 2)` rounds 7/4 rather than rounding 7/2 before dividing again.
+fn divide_integers(values: &[i64], mode: IntegerDivision) -> Result<Value> {
+    let overflow = || EngineError::ArithmeticOverflow("DIVIDE: integer overflow".to_string());
+    let mut operands = values.iter().map(|&v| i128::from(v));
+    let mut numerator = operands.next().unwrap_or(0);
+    let mut denominator: i128 = 1;
+    for divisor in operands {
+        if divisor == 0 {
+            return Err(EngineError::DivisionByZero);
+        }
+        denominator = denominator.checked_mul(divisor).ok_or_else(overflow)?;
+    }
+    if denominator < 0 {
+        numerator = numerator.checked_neg().ok_or_else(overflow)?;
+        denominator = denominator.checked_neg().ok_or_else(overflow)?;
+    }
 
-/// Execute aggregate operation (MAX, MIN).
-fn execute_aggregate<R: ValueResolver, F>(
+    let floor = numerator.div_euclid(denominator);
+    let remainder = numerator.rem_euclid(denominator);
+    let rounded = match mode {
+        IntegerDivision::Floor | IntegerDivision::Float => floor,
+        IntegerDivision::Ceil => floor + i128::from(remainder > 0),
+        IntegerDivision::HalfEven => match (2 * remainder).cmp(&denominator) {
+            std::cmp::Ordering::Less => floor,
+            std::cmp::Ordering::Greater => floor + 1,
+            std::cmp::Ordering::Equal => floor + floor.rem_euclid(2),
+        },
+    };
+    i64::try_from(rounded)
+        .map(Value::Int)
+        .map_err(|_| overflow())
+}
+
+/// Execute INT_DIVIDE operation: first value floor-divided by all subsequent values.
+///
+/// Rounds toward negative infinity (like Python's `//`), so `-7 // 2 == -4`.
+/// Integer operands are divided exactly; float operands are floored after
+/// dividing. Always returns an integer.
+///
+/// Returns `Err(DivisionByZero)` for division by zero.
+fn execute_int_divide<R: ValueResolver>(
     values: &[ActionValue],
     resolver: &R,
     depth: usize,
-    combine: F,
-) -> Result<Value>
-where
-    F: Fn(f64, f64) -> f64,
-{
+) -> Result<Value> {
     if values.is_empty() {
         return Err(EngineError::InvalidOperation(
-            "Aggregate operation requires at least one value".to_string(),
+            "INT_DIVIDE requires at least one value".to_string(),
         ));
     }
 
@@ -660,55 +1253,499 @@ where
         return Ok(tainted);
     }
 
-    let mut has_float = false;
-    let nums: Vec<f64> = evaluated
-        .iter()
-        .map(|v| {
-            if matches!(v, Value::Float(_)) {
-                has_float = true;
-            }
-            to_number(v)
-        })
-        .collect::<Result<Vec<_>>>()?;
-
-    // SAFETY: values guaranteed non-empty by check above
-    let Some(result) = nums.into_iter().reduce(combine) else {
+    let limit = resolver.options().max_safe_integer;
+    let Some((first, rest)) = evaluated.split_first() else {
         unreachable!("values checked non-empty above")
     };
+    let mut result = match first {
+        Value::Int(i) => *i,
+        other => f64_to_i64_safe(operand_to_number("INT_DIVIDE", 0, other, limit)?.floor())?,
+    };
 
-    Ok(if has_float {
-        Value::Float(result)
-    } else {
-        Value::Int(f64_to_i64_safe(result)?)
-    })
-}
+    for (index, val) in rest.iter().enumerate() {
+        result = match val {
+            Value::Int(0) => return Err(EngineError::DivisionByZero),
+            Value::Int(divisor) => {
+                let quotient = result.checked_div(*divisor).ok_or_else(|| {
+                    EngineError::ArithmeticOverflow(format!(
+                        "INT_DIVIDE overflow: {} // {}",
+                        result, divisor
+                    ))
+                })?;
+                if result % divisor != 0 && (result < 0) != (*divisor < 0) {
+                    quotient - 1
+                } else {
+                    quotient
+                }
+            }
+            other => {
+                let divisor = operand_to_number("INT_DIVIDE", index + 1, other, limit)?;
+                if divisor == 0.0 {
+                    return Err(EngineError::DivisionByZero);
+                }
+                f64_to_i64_safe((to_number(&Value::Int(result), limit)? / divisor).floor())?
+            }
+        };
+    }
 
-// =============================================================================
-// Logical Operations
-// =============================================================================
+    Ok(Value::Int(result))
+}
 
-/// Execute AND operation: short-circuit evaluation, returns false if any condition is false.
-fn execute_and<R: ValueResolver>(
-    conditions: &[ActionValue],
+/// Execute COMPOUND_INDEX operation: apply yearly indexation factors to a base.
+///
+/// Returns `value * factors[0] * factors[1] * ...` as a float, so an amount
+/// indexed by 2% and then 3.5% is `COMPOUND_INDEX(value, [1.02, 1.035])`. An
+/// empty factor list returns the base (as a float). Integer operands must lie
+/// within the safe integer range.
+fn execute_compound_index<R: ValueResolver>(
+    value: &ActionValue,
+    factors: &ActionValue,
     resolver: &R,
     depth: usize,
 ) -> Result<Value> {
-    let tracing = resolver.has_trace();
-    let mut results: Option<Vec<Value>> = if tracing { Some(Vec::new()) } else { None };
-    let mut taint: Option<Value> = None;
-    for condition in conditions {
-        let val = evaluate_value(condition, resolver, depth)?;
-        // Definitive false wins over taint (AND commutativity)
-        if !val.to_bool() && !val.is_untranslatable() {
-            return Ok(Value::Bool(false));
-        }
-        if val.is_untranslatable() && taint.is_none() {
-            taint = Some(val);
-            continue;
-        }
-        if let Some(ref mut r) = results {
-            r.push(val);
-        }
+    let base_val = evaluate_value(value, resolver, depth)?;
+    let factors_val = evaluate_value(factors, resolver, depth)?;
+    if let Some(tainted) = find_untranslatable(&[base_val.clone(), factors_val.clone()]) {
+        return Ok(tainted);
+    }
+    let factors = match factors_val {
+        Value::Array(items) => items,
+        other => return Err(type_error("array of factors", &other)),
+    };
+    if let Some(tainted) = find_untranslatable(&factors) {
+        return Ok(tainted);
+    }
+
+    let max_safe_integer = resolver.options().max_safe_integer;
+    let mut result = operand_to_number("COMPOUND_INDEX", 0, &base_val, max_safe_integer)?;
+    for (i, factor) in factors.iter().enumerate() {
+        result *= operand_to_number("COMPOUND_INDEX", i + 1, factor, max_safe_integer)?;
+    }
+    Ok(Value::Float(result))
+}
+
+/// Execute ROUND_TO_MULTIPLE operation: round `subject` to a multiple of `value`.
+///
+/// `rounding` is `nearest` (the default, ties away from zero; also spelled
+/// `half_away` for statutory rounding), `half_even` (ties to the even
+/// multiple, banker's rounding), `floor` or `ceil`. Integer operands give an
+/// exact integer; otherwise the result is a float, with representation error
+/// below 1e-9 discarded so that rounding 12.35 to 0.10 gives 12.4 rather than
+/// 12.3. The multiple must be positive.
+fn execute_round_to_multiple<R: ValueResolver>(
+    subject: &ActionValue,
+    value: &ActionValue,
+    rounding: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let multiple_val = evaluate_value(value, resolver, depth)?;
+    if let Some(tainted) = propagate_binary(&subject_val, &multiple_val) {
+        return Ok(tainted);
+    }
+    let rounding_val = rounding
+        .map(|r| evaluate_value(r, resolver, depth))
+        .transpose()?;
+    let rounding = match &rounding_val {
+        None => "nearest",
+        Some(r) => r.as_str().ok_or_else(|| type_error("rounding string", r))?,
+    };
+    if !matches!(
+        rounding,
+        "floor" | "ceil" | "nearest" | "half_away" | "half_even"
+    ) {
+        return Err(EngineError::InvalidOperation(format!(
+            "ROUND_TO_MULTIPLE: unknown rounding '{}', expected floor, ceil, nearest, half_away or half_even",
+            rounding
+        )));
+    }
+
+    let limit = resolver.options().max_safe_integer;
+    let not_positive = || {
+        EngineError::InvalidOperation(format!(
+            "ROUND_TO_MULTIPLE: multiple must be positive, got {}",
+            multiple_val
+        ))
+    };
+
+    if let (Value::Int(subject), Value::Int(multiple)) = (&subject_val, &multiple_val) {
+        let subject = checked_int(*subject, limit)?;
+        let multiple = checked_int(*multiple, limit)?;
+        if multiple <= 0 {
+            return Err(not_positive());
+        }
+        let overflow = || {
+            EngineError::ArithmeticOverflow(format!(
+                "ROUND_TO_MULTIPLE overflow: {} to a multiple of {}",
+                subject, multiple
+            ))
+        };
+        let remainder = subject.rem_euclid(multiple);
+        // Compare against the half-way point via `multiple - remainder`, since
+        // `2 * remainder` can overflow once the threshold is raised.
+        let rest = multiple - remainder;
+        let round_up = match rounding {
+            "floor" => false,
+            "ceil" => remainder != 0,
+            "half_even" => {
+                let odd = subject.div_euclid(multiple).rem_euclid(2) == 1;
+                remainder > rest || (remainder == rest && odd)
+            }
+            _ => remainder > rest || (remainder == rest && subject >= 0),
+        };
+        let floored = subject.checked_sub(remainder).ok_or_else(overflow)?;
+        return Ok(Value::Int(if round_up {
+            floored.checked_add(multiple).ok_or_else(overflow)?
+        } else {
+            floored
+        }));
+    }
+
+    let subject = operand_to_number("ROUND_TO_MULTIPLE", 0, &subject_val, limit)?;
+    let multiple = operand_to_number("ROUND_TO_MULTIPLE", 1, &multiple_val, limit)?;
+    if multiple <= 0.0 {
+        return Err(not_positive());
+    }
+
+    let snap = |x: f64| (x * 1e9).round() / 1e9;
+    let quotient = snap(subject / multiple);
+    let count = match rounding {
+        "floor" => quotient.floor(),
+        "ceil" => quotient.ceil(),
+        "half_even" => quotient.round_ties_even(),
+        _ => quotient.round(),
+    };
+    Ok(Value::Float(snap(count * multiple)))
+}
+
+/// Execute LINEAR_TAPER operation: an amount that tapers linearly to zero.
+///
+/// Operands are `[amount, income, threshold, rate]`; the result is
+/// `max(0, amount - max(0, income - threshold) * rate)`. All-integer operands
+/// give an exact integer, otherwise the result is a float. A negative rate is
+/// an error, since the amount would then grow with income.
+fn execute_linear_taper<R: ValueResolver>(
+    operands: [&ActionValue; 4],
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let values = operands
+        .iter()
+        .map(|operand| evaluate_value(operand, resolver, depth))
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(tainted) = find_untranslatable(&values) {
+        return Ok(tainted);
+    }
+
+    if let [Value::Int(amount), Value::Int(income), Value::Int(threshold), Value::Int(rate)] =
+        values[..]
+    {
+        if rate < 0 {
+            return Err(EngineError::InvalidOperation(format!(
+                "LINEAR_TAPER: rate must not be negative, got {}",
+                rate
+            )));
+        }
+        let overflow = || {
+            EngineError::ArithmeticOverflow(format!(
+                "LINEAR_TAPER overflow: {} - ({} - {}) * {}",
+                amount, income, threshold, rate
+            ))
+        };
+        let excess = income.checked_sub(threshold).ok_or_else(overflow)?.max(0);
+        let reduction = excess.checked_mul(rate).ok_or_else(overflow)?;
+        return Ok(Value::Int(
+            amount.checked_sub(reduction).ok_or_else(overflow)?.max(0),
+        ));
+    }
+
+    let limit = resolver.options().max_safe_integer;
+    let numbers = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| operand_to_number("LINEAR_TAPER", index, value, limit))
+        .collect::<Result<Vec<_>>>()?;
+    let [amount, income, threshold, rate] = numbers[..] else {
+        unreachable!("four operands evaluate to four numbers");
+    };
+    if rate < 0.0 {
+        return Err(EngineError::InvalidOperation(format!(
+            "LINEAR_TAPER: rate must not be negative, got {}",
+            rate
+        )));
+    }
+    let reduction = (income - threshold).max(0.0) * rate;
+    Ok(Value::Float((amount - reduction).max(0.0)))
+}
+
+/// Execute CLAMP operation: limit `subject` to the range `[min, max]`.
+///
+/// The bounds are ordinary operands, so a cap can come from one law and a
+/// floor from another. A null bound leaves that side open. All-integer
+/// operands give an integer, otherwise the result is a float. A floor above
+/// the cap is an error.
+fn execute_clamp<R: ValueResolver>(
+    subject: &ActionValue,
+    min: &ActionValue,
+    max: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let min_val = evaluate_value(min, resolver, depth)?;
+    let max_val = evaluate_value(max, resolver, depth)?;
+    if let Some(tainted) =
+        find_untranslatable(&[subject_val.clone(), min_val.clone(), max_val.clone()])
+    {
+        return Ok(tainted);
+    }
+
+    let limit = resolver.options().max_safe_integer;
+    let subject = operand_to_number("CLAMP", 0, &subject_val, limit)?;
+    let bound = |index: usize, value: &Value| match value {
+        Value::Null => Ok(None),
+        other => operand_to_number("CLAMP", index, other, limit).map(Some),
+    };
+    let lower = bound(1, &min_val)?;
+    let upper = bound(2, &max_val)?;
+    if let (Some(lower), Some(upper)) = (lower, upper) {
+        if lower > upper {
+            return Err(EngineError::InvalidOperation(format!(
+                "CLAMP: min {} is above max {}",
+                min_val, max_val
+            )));
+        }
+    }
+
+    let (result, source) = match (lower, upper) {
+        (Some(lower), _) if subject < lower => (lower, &min_val),
+        (_, Some(upper)) if subject > upper => (upper, &max_val),
+        _ => (subject, &subject_val),
+    };
+    let all_int = [&subject_val, &min_val, &max_val]
+        .iter()
+        .all(|v| matches!(v, Value::Int(_) | Value::Null));
+    Ok(match source {
+        Value::Int(i) if all_int => Value::Int(*i),
+        _ => Value::Float(result),
+    })
+}
+
+/// Execute PROGRESSIVE_BRACKETS operation: progressive tax over brackets.
+///
+/// `brackets` is a list of `{upper, rate}` objects with ascending `upper`
+/// bounds; each bracket starts where the previous one ends (the first at 0)
+/// and its rate applies only to the part of the income within it. Only the
+/// last bracket may leave `upper` null (unbounded); income above a bounded
+/// last bracket is untaxed. Always returns a float.
+fn execute_progressive_brackets<R: ValueResolver>(
+    subject: &ActionValue,
+    brackets: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let income_val = evaluate_value(subject, resolver, depth)?;
+    let brackets_val = evaluate_value(brackets, resolver, depth)?;
+    if let Some(tainted) = propagate_binary(&income_val, &brackets_val) {
+        return Ok(tainted);
+    }
+    let limit = resolver.options().max_safe_integer;
+    let income = operand_to_number("PROGRESSIVE_BRACKETS", 0, &income_val, limit)?;
+    let brackets = match brackets_val {
+        Value::Array(items) => items,
+        other => return Err(type_error("array of brackets", &other)),
+    };
+
+    let mut lower = 0.0;
+    let mut total = 0.0;
+    for (index, bracket) in brackets.iter().enumerate() {
+        let Value::Object(fields) = bracket else {
+            return Err(type_error("{upper, rate} bracket", bracket));
+        };
+        let field = |name: &str| fields.get(name).unwrap_or(&Value::Null);
+        let rate = to_number(field("rate"), limit)?;
+        let upper = match field("upper") {
+            Value::Null if index + 1 == brackets.len() => f64::INFINITY,
+            Value::Null => {
+                return Err(EngineError::InvalidOperation(format!(
+                    "PROGRESSIVE_BRACKETS: only the last bracket may omit 'upper' (bracket {})",
+                    index
+                )))
+            }
+            value => to_number(value, limit)?,
+        };
+        if upper < lower {
+            return Err(EngineError::InvalidOperation(format!(
+                "PROGRESSIVE_BRACKETS: bracket {} ends at {} below the previous bound {}",
+                index, upper, lower
+            )));
+        }
+        if income > lower {
+            total += (income.min(upper) - lower) * rate;
+        }
+        lower = upper;
+    }
+    Ok(Value::Float(total))
+}
+
+/// Execute NEGATE operation: flip the sign of an amount, e.g. for a refund.
+///
+/// Integers stay integers (so eurocent amounts stay exact) and floats stay
+/// floats. Negating `i64::MIN` overflows and is an error. Null passes
+/// through unchanged so missing data stays missing.
+fn execute_negate<R: ValueResolver>(
+    subject: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    match evaluate_value(subject, resolver, depth)? {
+        Value::Int(i) => i
+            .checked_neg()
+            .map(Value::Int)
+            .ok_or_else(|| EngineError::ArithmeticOverflow(format!("NEGATE overflow: -({})", i))),
+        Value::Float(f) => Ok(Value::Float(-f)),
+        value @ (Value::Null | Value::Untranslatable { .. }) => Ok(value),
+        other => Err(type_error("number", &other)),
+    }
+}
+
+// =============================================================================
+// Aggregate Operations
+// =============================================================================
+
+/// Execute aggregate operation (MAX, MIN).
+///
+/// Operands must be numbers; date strings are rejected rather than compared
+/// with numbers (MAX_DATE and MIN_DATE pick between dates).
+fn execute_aggregate<R: ValueResolver, F>(
+    op_name: &str,
+    values: &[ActionValue],
+    resolver: &R,
+    depth: usize,
+    combine: F,
+) -> Result<Value>
+where
+    F: Fn(f64, f64) -> f64,
+{
+    if values.is_empty() {
+        return Err(EngineError::InvalidOperation(
+            "Aggregate operation requires at least one value".to_string(),
+        ));
+    }
+
+    let evaluated = evaluate_values(values, resolver, depth)?;
+
+    if let Some(tainted) = find_untranslatable(&evaluated) {
+        return Ok(tainted);
+    }
+
+    let mut has_float = false;
+    let nums: Vec<f64> = evaluated
+        .iter()
+        .enumerate()
+        .map(|(index, v)| {
+            if matches!(v, Value::Float(_)) {
+                has_float = true;
+            }
+            operand_to_number(op_name, index, v, resolver.options().max_safe_integer)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // SAFETY: values guaranteed non-empty by check above
+    let Some(result) = nums.into_iter().reduce(combine) else {
+        unreachable!("values checked non-empty above")
+    };
+
+    Ok(if has_float {
+        Value::Float(result)
+    } else {
+        Value::Int(f64_to_i64_safe(result)?)
+    })
+}
+
+/// Execute WEIGHTED_AVERAGE operation: `sum(v_i * w_i) / sum(w_i)` over two
+/// parallel arrays.
+///
+/// Always returns a float. Returns `Err(InvalidOperation)` when the arrays
+/// differ in length and `Err(DivisionByZero)` when the weights sum to zero
+/// (including when both arrays are empty).
+fn execute_weighted_average<R: ValueResolver>(
+    values: &ActionValue,
+    weights: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let values = evaluate_value(values, resolver, depth)?;
+    let weights = evaluate_value(weights, resolver, depth)?;
+    if let Some(tainted) = propagate_binary(&values, &weights) {
+        return Ok(tainted);
+    }
+    let values = match values {
+        Value::Array(items) => items,
+        other => return Err(type_error("array", &other)),
+    };
+    let weights = match weights {
+        Value::Array(items) => items,
+        other => return Err(type_error("array", &other)),
+    };
+
+    if values.len() != weights.len() {
+        return Err(EngineError::InvalidOperation(format!(
+            "WEIGHTED_AVERAGE requires values and weights of equal length, got {} and {}",
+            values.len(),
+            weights.len()
+        )));
+    }
+    if let Some(tainted) = find_untranslatable(&values).or_else(|| find_untranslatable(&weights)) {
+        return Ok(tainted);
+    }
+
+    let limit = resolver.options().max_safe_integer;
+    let mut weighted_sum = 0.0;
+    let mut total_weight = 0.0;
+    for (index, (value, weight)) in values.iter().zip(&weights).enumerate() {
+        let weight = operand_to_number("WEIGHTED_AVERAGE weight", index, weight, limit)?;
+        weighted_sum += operand_to_number("WEIGHTED_AVERAGE value", index, value, limit)? * weight;
+        total_weight += weight;
+    }
+
+    if total_weight == 0.0 {
+        return Err(EngineError::DivisionByZero);
+    }
+
+    Ok(Value::Float(weighted_sum / total_weight))
+}
+
+// =============================================================================
+// Logical Operations
+// =============================================================================
+
+/// Execute AND operation: short-circuit evaluation, returns false if any condition is false.
+fn execute_and<R: ValueResolver>(
+    conditions: &[ActionValue],
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let tracing = resolver.has_trace();
+    let mut results: Option<Vec<Value>> = if tracing { Some(Vec::new()) } else { None };
+    let mut taint: Option<Value> = None;
+    for (index, condition) in conditions.iter().enumerate() {
+        let val = evaluate_value(condition, resolver, depth)?;
+        // Definitive false wins over taint (AND commutativity)
+        if !val.is_untranslatable() && !condition_to_bool(&val, resolver, "AND")? {
+            if index + 1 < conditions.len() {
+                resolver.record_short_circuit();
+            }
+            return Ok(Value::Bool(false));
+        }
+        if val.is_untranslatable() && taint.is_none() {
+            taint = Some(val);
+            continue;
+        }
+        if let Some(ref mut r) = results {
+            r.push(val);
+        }
     }
 
     // If any operand was tainted but none was definitively false, propagate
@@ -731,10 +1768,13 @@ fn execute_or<R: ValueResolver>(
     depth: usize,
 ) -> Result<Value> {
     let mut taint: Option<Value> = None;
-    for condition in conditions {
+    for (index, condition) in conditions.iter().enumerate() {
         let val = evaluate_value(condition, resolver, depth)?;
         // Definitive true wins over taint (OR commutativity)
-        if val.to_bool() {
+        if !val.is_untranslatable() && condition_to_bool(&val, resolver, "OR")? {
+            if index + 1 < conditions.len() {
+                resolver.record_short_circuit();
+            }
             return Ok(Value::Bool(true));
         }
         if val.is_untranslatable() && taint.is_none() {
@@ -758,7 +1798,24 @@ fn execute_not<R: ValueResolver>(value: &ActionValue, resolver: &R, depth: usize
     if val.is_untranslatable() {
         return Ok(val);
     }
-    Ok(Value::Bool(!val.to_bool()))
+    Ok(Value::Bool(!condition_to_bool(&val, resolver, "NOT")?))
+}
+
+/// Interpret a condition result as a boolean according to the resolver's [`BoolCoercion`].
+///
+/// In lenient mode this is [`Value::to_bool`]; in strict mode only `Bool` values are
+/// accepted, so that e.g. the string `"false"` cannot silently count as true.
+/// Callers handle `Untranslatable` values before calling this.
+fn condition_to_bool<R: ValueResolver>(val: &Value, resolver: &R, op_name: &str) -> Result<bool> {
+    match (val, resolver.options().bool_coercion) {
+        (Value::Bool(b), _) => Ok(*b),
+        (_, BoolCoercion::Lenient) => Ok(val.to_bool()),
+        (_, BoolCoercion::Strict) => Err(EngineError::InvalidOperation(format!(
+            "{op_name} requires a boolean condition, got {} ({})",
+            val.type_name(),
+            format_value_for_trace(val)
+        ))),
+    }
 }
 
 // =============================================================================
@@ -793,7 +1850,7 @@ fn execute_if<R: ValueResolver>(
             return Ok(condition_result);
         }
 
-        if condition_result.to_bool() {
+        if condition_to_bool(&condition_result, resolver, "IF")? {
             if tracing {
                 resolver.trace_push("THEN", PathNodeType::Operation);
             }
@@ -850,6 +1907,40 @@ fn execute_null_check<R: ValueResolver>(
     Ok(Value::Bool(if negate { !is_null } else { is_null }))
 }
 
+/// Execute IS_EMPTY / NOT_EMPTY operation.
+///
+/// A subject is empty when it is null, an empty string or an empty array; with
+/// `ignore_whitespace` a string of only whitespace counts as empty too. Other
+/// values (including `0` and `false`) are never empty. When `negate` is true,
+/// returns true if the subject is *not* empty (NOT_EMPTY).
+fn execute_empty_check<R: ValueResolver>(
+    subject: &ActionValue,
+    ignore_whitespace: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+    negate: bool,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    if subject_val.is_untranslatable() {
+        return Ok(subject_val);
+    }
+    let ignore_whitespace = match ignore_whitespace {
+        None => false,
+        Some(flag) => match evaluate_value(flag, resolver, depth)? {
+            Value::Bool(b) => b,
+            other => return Err(type_error("boolean", &other)),
+        },
+    };
+    let is_empty = match &subject_val {
+        Value::Null => true,
+        Value::String(s) if ignore_whitespace => s.trim().is_empty(),
+        Value::String(s) => s.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    };
+    Ok(Value::Bool(if negate { !is_empty } else { is_empty }))
+}
+
 // =============================================================================
 // Collection Operations
 // =============================================================================
@@ -891,7 +1982,7 @@ fn execute_membership<R: ValueResolver>(
 
     let found = check_values
         .iter()
-        .any(|val| values_equal(&subject_val, val));
+        .any(|val| values_equal(&subject_val, val, resolver.options().max_safe_integer));
     Ok(Value::Bool(if negate { !found } else { found }))
 }
 
@@ -909,1328 +2000,5282 @@ fn execute_list<R: ValueResolver>(
     Ok(Value::Array(values))
 }
 
-// =============================================================================
-// Date Operations
-// =============================================================================
-
-/// Execute AGE operation: calculate age in whole years from date_of_birth to reference_date.
+/// Resolver that binds `$element` to the current array item while a collection
+/// operation iterates, delegating every other variable to the outer resolver.
 ///
-/// # Arguments
-/// - `date_of_birth`: Birth date (ISO 8601 YYYY-MM-DD)
-/// - `reference_date`: Date to calculate age at (ISO 8601 YYYY-MM-DD)
-fn execute_age<R: ValueResolver>(
-    date_of_birth: &ActionValue,
-    reference_date: &ActionValue,
-    resolver: &R,
-    depth: usize,
-) -> Result<Value> {
-    let dob_val = evaluate_value(date_of_birth, resolver, depth)?;
-    let ref_val = evaluate_value(reference_date, resolver, depth)?;
+/// Dot notation (`$element.leeftijd`) reads fields of object elements. The outer
+/// resolver is held as a trait object so that nested collection operations reuse
+/// this type instead of stacking generic wrappers.
+struct ElementResolver<'a> {
+    outer: &'a dyn ValueResolver,
+    element: &'a Value,
+}
 
-    if let Some(tainted) = propagate_binary(&dob_val, &ref_val) {
-        return Ok(tainted);
+impl ValueResolver for ElementResolver<'_> {
+    fn resolve(&self, name: &str) -> Result<Value> {
+        if name == "element" {
+            return Ok(self.element.clone());
+        }
+        if let Some(path) = name.strip_prefix("element.") {
+            return crate::context::get_path_property(self.element, name, path);
+        }
+        self.outer.resolve(name)
     }
 
-    let dob_date = parse_date(&dob_val)?;
-    let ref_date_parsed = parse_date(&ref_val)?;
+    fn trace_push(&self, name: &str, node_type: PathNodeType) {
+        self.outer.trace_push(name, node_type);
+    }
 
-    let age = calculate_years_difference(ref_date_parsed, dob_date);
-    Ok(Value::Int(age))
-}
+    fn trace_pop(&self) {
+        self.outer.trace_pop();
+    }
 
-/// Execute DATE_ADD operation: add years, months, weeks, and/or days to a date.
-///
-/// Applied in order: years → months → weeks → days (coarsest to finest).
-///
-/// For months and years, uses standard calendar arithmetic: the result lands on
-/// the same day number in the target month, clamped to the last day of that month
-/// when the day doesn't exist. E.g., Jan 31 + 1 month = Feb 28 (or 29 in leap year).
-///
-/// This is not domain knowledge in the engine — it is pure calendar math. The Hoge
-/// Raad confirmed that Dutch legal termijnberekening follows standard calendar
-/// arithmetic (HR 1 September 2017, ECLI:NL:HR:2017:2225).
-fn execute_date_add<R: ValueResolver>(
-    date: &ActionValue,
-    years: Option<&ActionValue>,
-    months: Option<&ActionValue>,
-    weeks: Option<&ActionValue>,
-    days: Option<&ActionValue>,
-    resolver: &R,
-    depth: usize,
-) -> Result<Value> {
-    let date_val = evaluate_value(date, resolver, depth)?;
-    if date_val.is_untranslatable() {
-        return Ok(date_val);
+    fn trace_set_result(&self, result: Value) {
+        self.outer.trace_set_result(result);
     }
-    let mut result_date = parse_date(&date_val)?;
 
-    // Years: add to year component, clamp day to last day of target month
-    if let Some(years) = years {
-        let years_val = evaluate_value(years, resolver, depth)?;
-        let years_i64 = years_val.as_int().ok_or_else(|| {
-            EngineError::InvalidOperation("DATE_ADD 'years' must be a number".to_string())
-        })?;
-        let years_int = i32::try_from(years_i64).map_err(|_| {
-            EngineError::InvalidOperation(format!(
-                "DATE_ADD 'years' value {} exceeds supported range",
-                years_i64
-            ))
-        })?;
-        let target_year = result_date.year() + years_int;
-        let clamped_day = result_date
-            .day()
-            .min(days_in_month(target_year, result_date.month()));
-        result_date = NaiveDate::from_ymd_opt(target_year, result_date.month(), clamped_day)
-            .ok_or_else(|| {
-                EngineError::InvalidOperation(format!(
-                    "DATE_ADD: invalid date after adding {} years",
-                    years_int
-                ))
-            })?;
+    fn trace_set_message(&self, msg: String) {
+        self.outer.trace_set_message(msg);
     }
 
-    // Months: add to month component, clamp day to last day of target month
-    if let Some(months) = months {
-        let months_val = evaluate_value(months, resolver, depth)?;
-        let months_int = months_val.as_int().ok_or_else(|| {
-            EngineError::InvalidOperation("DATE_ADD 'months' must be a number".to_string())
-        })?;
-        result_date = add_months(result_date, months_int)?;
+    fn trace_get_message(&self) -> Option<String> {
+        self.outer.trace_get_message()
     }
 
-    // Weeks
-    if let Some(weeks) = weeks {
-        let weeks_val = evaluate_value(weeks, resolver, depth)?;
-        let weeks_int = weeks_val.as_int().ok_or_else(|| {
-            EngineError::InvalidOperation("DATE_ADD 'weeks' must be a number".to_string())
-        })?;
-        result_date = result_date
-            .checked_add_signed(chrono::Duration::weeks(weeks_int))
-            .ok_or_else(|| {
-                EngineError::InvalidOperation(
-                    "DATE_ADD: date out of range after adding weeks".to_string(),
-                )
-            })?;
+    fn has_trace(&self) -> bool {
+        self.outer.has_trace()
     }
 
-    // Days
-    if let Some(days) = days {
-        let days_val = evaluate_value(days, resolver, depth)?;
-        let days_int = days_val.as_int().ok_or_else(|| {
-            EngineError::InvalidOperation("DATE_ADD 'days' must be a number".to_string())
-        })?;
-        result_date = result_date
-            .checked_add_signed(chrono::Duration::days(days_int))
-            .ok_or_else(|| {
-                EngineError::InvalidOperation(
-                    "DATE_ADD: date out of range after adding days".to_string(),
-                )
-            })?;
+    fn options(&self) -> &EvaluationOptions {
+        self.outer.options()
     }
 
-    Ok(Value::String(result_date.format("%Y-%m-%d").to_string()))
-}
+    fn record_operation(&self) {
+        self.outer.record_operation();
+    }
 
-/// Add months to a date using standard calendar arithmetic.
-///
-/// Clamps the day to the last day of the target month when the original day
-/// doesn't exist in that month (e.g., Jan 31 + 1 month = Feb 28).
-fn add_months(date: NaiveDate, months: i64) -> Result<NaiveDate> {
-    let total_months = date.year() as i64 * 12 + (date.month() as i64 - 1) + months;
-    let target_year = i32::try_from(total_months.div_euclid(12)).map_err(|_| {
-        EngineError::InvalidOperation(format!(
-            "DATE_ADD: year out of range after adding {} months",
-            months
-        ))
-    })?;
-    let target_month = (total_months.rem_euclid(12) + 1) as u32;
-    let clamped_day = date.day().min(days_in_month(target_year, target_month));
+    fn record_short_circuit(&self) {
+        self.outer.record_short_circuit();
+    }
 
-    NaiveDate::from_ymd_opt(target_year, target_month, clamped_day).ok_or_else(|| {
-        EngineError::InvalidOperation(format!(
-            "DATE_ADD: invalid date after adding {} months",
-            months
-        ))
-    })
-}
+    fn evaluate_uri(&self, uri: &str, parameters: BTreeMap<String, Value>) -> Result<Value> {
+        self.outer.evaluate_uri(uri, parameters)
+    }
 
-/// Execute DATE operation: construct a date from year, month, day components.
-///
-/// # Arguments
-/// - `year`: Year component (integer)
-/// - `month`: Month component (integer, 1-12)
-/// - `day`: Day component (integer, 1-31)
-fn execute_date_construct<R: ValueResolver>(
-    year: &ActionValue,
-    month: &ActionValue,
-    day: &ActionValue,
-    resolver: &R,
-    depth: usize,
-) -> Result<Value> {
-    let year_val = evaluate_value(year, resolver, depth)?;
-    let month_val = evaluate_value(month, resolver, depth)?;
-    let day_val = evaluate_value(day, resolver, depth)?;
+    fn applicable_version(&self, law_id: &str) -> Result<Value> {
+        self.outer.applicable_version(law_id)
+    }
 
-    if let Some(tainted) =
-        find_untranslatable(&[year_val.clone(), month_val.clone(), day_val.clone()])
-    {
-        return Ok(tainted);
+    fn formula(&self, name: &str) -> Option<&ActionValue> {
+        self.outer.formula(name)
     }
 
-    let y_i64 = year_val
-        .as_int()
-        .ok_or_else(|| EngineError::InvalidOperation("DATE 'year' must be a number".to_string()))?;
-    let y = i32::try_from(y_i64).map_err(|_| {
-        EngineError::InvalidOperation(format!(
-            "DATE 'year' value {} exceeds supported range",
-            y_i64
-        ))
-    })?;
-    let m = month_val
-        .as_int()
-        .ok_or_else(|| EngineError::InvalidOperation("DATE 'month' must be a number".to_string()))?
-        as u32;
-    let d = day_val
-        .as_int()
-        .ok_or_else(|| EngineError::InvalidOperation("DATE 'day' must be a number".to_string()))?
-        as u32;
+    fn enter_formula(&self, name: &str) -> Result<()> {
+        self.outer.enter_formula(name)
+    }
 
-    let date = NaiveDate::from_ymd_opt(y, m, d).ok_or_else(|| {
-        EngineError::InvalidOperation(format!("DATE: invalid date {}-{}-{}", y, m, d))
-    })?;
+    fn leave_formula(&self) {
+        self.outer.leave_formula();
+    }
 
-    Ok(Value::String(date.format("%Y-%m-%d").to_string()))
+    fn custom_operation(&self, name: &str) -> Option<&OperationHandler> {
+        self.outer.custom_operation(name)
+    }
+
+    fn regex(&self, pattern: &str) -> Result<Regex> {
+        self.outer.regex(pattern)
+    }
 }
 
-/// Execute DAY_OF_WEEK operation: get the weekday number for a date.
+/// Execute COUNT_WHERE operation: count the array elements for which `condition` holds.
 ///
-/// Returns an integer where 0=Monday, 6=Sunday.
-///
-/// # Arguments
-/// - `date`: Date to get weekday for (ISO 8601 YYYY-MM-DD)
-fn execute_day_of_week<R: ValueResolver>(
-    date_value: &ActionValue,
+/// The condition is evaluated once per element with `$element` bound to that element.
+/// A null `value` counts as an empty array (0). Tainted conditions propagate.
+fn execute_count_where<R: ValueResolver>(
+    value: &ActionValue,
+    condition: &ActionValue,
     resolver: &R,
     depth: usize,
 ) -> Result<Value> {
-    let val = evaluate_value(date_value, resolver, depth)?;
-    if val.is_untranslatable() {
-        return Ok(val);
-    }
-    let parsed = parse_date(&val)?;
-    Ok(Value::Int(parsed.weekday().num_days_from_monday() as i64))
-}
+    let collection = evaluate_value(value, resolver, depth)?;
+    let items = match collection {
+        Value::Array(items) => items,
+        Value::Null => Vec::new(),
+        Value::Untranslatable { .. } => return Ok(collection),
+        other => return Err(type_error("array", &other)),
+    };
 
-/// Parse a date from a Value.
-///
-/// Expects the value to be a string in ISO 8601 format (YYYY-MM-DD).
-fn parse_date(value: &Value) -> Result<NaiveDate> {
-    match value {
-        Value::String(s) => NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|e| {
-            EngineError::InvalidOperation(format!(
-                "Failed to parse date '{}': {}. Expected format: YYYY-MM-DD",
-                s, e
-            ))
-        }),
-        // Handle referencedate objects with {iso, year, month, day}
-        Value::Object(obj) => {
-            if let Some(Value::String(iso)) = obj.get("iso") {
-                NaiveDate::parse_from_str(iso, "%Y-%m-%d").map_err(|e| {
-                    EngineError::InvalidOperation(format!(
-                        "Failed to parse date '{}': {}. Expected format: YYYY-MM-DD",
-                        iso, e
-                    ))
-                })
-            } else {
-                Err(EngineError::TypeMismatch {
-                    expected: "date string (YYYY-MM-DD) or object with 'iso' field".to_string(),
-                    actual: format!("{:?}", value),
-                })
-            }
+    let mut count: i64 = 0;
+    for item in &items {
+        let scoped = ElementResolver {
+            outer: resolver,
+            element: item,
+        };
+        let matched = evaluate_value(condition, &scoped, depth)?;
+        if matched.is_untranslatable() {
+            return Ok(matched);
+        }
+        if condition_to_bool(&matched, resolver, "COUNT_WHERE")? {
+            count += 1;
         }
-        _ => Err(EngineError::TypeMismatch {
-            expected: "date string (YYYY-MM-DD)".to_string(),
-            actual: format!("{:?}", value),
-        }),
     }
+
+    Ok(Value::Int(count))
 }
 
-/// Calculate the difference in complete months between two dates.
+/// Execute FLATTEN operation: splice nested arrays into the outer array (one level).
 ///
-/// Uses proper calendar arithmetic. A month is counted as complete when
-/// the same day-of-month (or end of month if day doesn't exist) is reached.
-/// For end-of-month edge cases (e.g., Jan 31 -> Feb 28), if `earlier.day()`
-/// exceeds the number of days in `later`'s month, it is capped to the last
-/// day of that month so the month is correctly counted as complete.
-#[allow(dead_code)] // Retained for potential future date-difference operations
-fn calculate_months_difference(date1: NaiveDate, date2: NaiveDate) -> i64 {
-    let (earlier, later, sign) = if date1 >= date2 {
-        (date2, date1, 1)
-    } else {
-        (date1, date2, -1)
+/// Non-array elements are kept as-is; arrays nested deeper than one level stay nested.
+/// A null `value` counts as an empty array.
+fn execute_flatten<R: ValueResolver>(
+    value: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let collection = evaluate_value(value, resolver, depth)?;
+    let items = match collection {
+        Value::Array(items) => items,
+        Value::Null => Vec::new(),
+        Value::Untranslatable { .. } => return Ok(collection),
+        other => return Err(type_error("array", &other)),
     };
 
-    let years_diff = later.year() - earlier.year();
-    let months_diff = later.month() as i32 - earlier.month() as i32;
-    let mut total_months = years_diff * 12 + months_diff;
-
-    // Cap earlier.day() to the max days in later's month so that
-    // Jan 31 → Feb 28 counts as 1 month (28 is the last day of Feb).
-    let max_day_in_later_month = days_in_month(later.year(), later.month());
-    let earlier_day_capped = earlier.day().min(max_day_in_later_month);
-
-    // Adjust if we haven't reached the (capped) day in the month
-    if later.day() < earlier_day_capped {
-        total_months -= 1;
+    let mut flattened = Vec::with_capacity(items.len());
+    for item in items {
+        match item {
+            Value::Array(inner) => flattened.extend(inner),
+            other => flattened.push(other),
+        }
     }
-
-    (total_months as i64) * sign
+    Ok(Value::Array(flattened))
 }
 
-/// Return the number of days in a given month.
-fn days_in_month(year: i32, month: u32) -> u32 {
-    match month {
-        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-        4 | 6 | 9 | 11 => 30,
-        2 => {
-            if NaiveDate::from_ymd_opt(year, 2, 29).is_some() {
-                29
-            } else {
-                28
-            }
+/// Execute DISTINCT operation: remove duplicates, keeping the first occurrence.
+///
+/// Elements are compared with `values_equal`, so `1` and `1.0` are duplicates.
+/// A null `value` counts as an empty array.
+fn execute_distinct<R: ValueResolver>(
+    value: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let collection = evaluate_value(value, resolver, depth)?;
+    let items = match collection {
+        Value::Array(items) => items,
+        Value::Null => Vec::new(),
+        Value::Untranslatable { .. } => return Ok(collection),
+        other => return Err(type_error("array", &other)),
+    };
+
+    let limit = resolver.options().max_safe_integer;
+    let mut distinct: Vec<Value> = Vec::with_capacity(items.len());
+    for item in items {
+        if !distinct.iter().any(|seen| values_equal(seen, &item, limit)) {
+            distinct.push(item);
         }
-        _ => unreachable!("Invalid month: {month}"),
     }
+    Ok(Value::Array(distinct))
 }
 
-/// Calculate the difference in complete years between two dates.
+/// Execute IS_SUBSET / IS_SUPERSET: whether every element of one array occurs in the other.
 ///
-/// Uses proper calendar arithmetic. A year is counted as complete when
-/// the anniversary date (or Feb 28 for leap year births on Feb 29) is reached.
-fn calculate_years_difference(date1: NaiveDate, date2: NaiveDate) -> i64 {
-    let (earlier, later, sign) = if date1 >= date2 {
-        (date2, date1, 1)
+/// IS_SUBSET checks `subject` against `value`; IS_SUPERSET swaps the roles.
+/// Elements are matched with `values_equal` and multiplicity is ignored, so
+/// `[1, 1]` is a subset of `[1]`. A null array counts as empty, and the empty
+/// set is a subset of every array.
+fn execute_subset<R: ValueResolver>(
+    subject: &ActionValue,
+    value: &ActionValue,
+    resolver: &R,
+    depth: usize,
+    superset: bool,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let value_val = evaluate_value(value, resolver, depth)?;
+    if let Some(tainted) = find_untranslatable(&[subject_val.clone(), value_val.clone()]) {
+        return Ok(tainted);
+    }
+    let as_items = |v: Value| match v {
+        Value::Array(items) => Ok(items),
+        Value::Null => Ok(Vec::new()),
+        other => Err(type_error("array", &other)),
+    };
+    let (part, whole) = if superset {
+        (as_items(value_val)?, as_items(subject_val)?)
     } else {
-        (date1, date2, -1)
+        (as_items(subject_val)?, as_items(value_val)?)
     };
 
-    let mut years = later.year() - earlier.year();
+    let limit = resolver.options().max_safe_integer;
+    Ok(Value::Bool(part.iter().all(|item| {
+        whole
+            .iter()
+            .any(|candidate| values_equal(item, candidate, limit))
+    })))
+}
 
-    // Check if we've reached the anniversary this year.
-    // For Feb 29 birthdays in non-leap years, the anniversary falls on Feb 28
-    // (per Dutch law: BW art. 1:2, Algemene Termijnenwet).
-    let anniversary_month = earlier.month();
-    let anniversary_day = {
-        let day = earlier.day();
-        if anniversary_month == 2 && day == 29 && days_in_month(later.year(), 2) < 29 {
-            28
-        } else {
-            day
-        }
-    };
+/// Execute SET_EQUALS operation: whether `subject` and `value` hold the same
+/// elements, regardless of order.
+///
+/// Elements are matched with `values_equal`. Multiplicity is ignored, like in
+/// IS_SUBSET, unless `count_duplicates` is true, in which case `[1, 1, 2]`
+/// no longer equals `[1, 2]`. A null array counts as empty.
+fn execute_set_equals<R: ValueResolver>(
+    subject: &ActionValue,
+    value: &ActionValue,
+    count_duplicates: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let value_val = evaluate_value(value, resolver, depth)?;
+    if let Some(tainted) = find_untranslatable(&[subject_val.clone(), value_val.clone()]) {
+        return Ok(tainted);
+    }
+    let count_duplicates = match count_duplicates {
+        None => false,
+        Some(flag) => match evaluate_value(flag, resolver, depth)? {
+            Value::Bool(b) => b,
+            other => return Err(type_error("boolean", &other)),
+        },
+    };
+    let as_items = |v: Value| match v {
+        Value::Array(items) => Ok(items),
+        Value::Null => Ok(Vec::new()),
+        other => Err(type_error("array", &other)),
+    };
+    let left = as_items(subject_val)?;
+    let right = as_items(value_val)?;
+
+    let limit = resolver.options().max_safe_integer;
+    if !count_duplicates {
+        let contained = |part: &[Value], whole: &[Value]| {
+            part.iter()
+                .all(|item| whole.iter().any(|other| values_equal(item, other, limit)))
+        };
+        return Ok(Value::Bool(
+            contained(&left, &right) && contained(&right, &left),
+        ));
+    }
 
-    if later.month() < anniversary_month
-        || (later.month() == anniversary_month && later.day() < anniversary_day)
-    {
-        years -= 1;
+    if left.len() != right.len() {
+        return Ok(Value::Bool(false));
+    }
+    let mut unmatched = vec![true; right.len()];
+    for item in &left {
+        let found = right
+            .iter()
+            .zip(unmatched.iter_mut())
+            .find(|(other, free)| **free && values_equal(item, other, limit));
+        match found {
+            Some((_, free)) => *free = false,
+            None => return Ok(Value::Bool(false)),
+        }
     }
+    Ok(Value::Bool(true))
+}
 
-    (years as i64) * sign
+/// Execute SPLIT operation: the parts of the string `subject` between
+/// occurrences of the delimiter `value`, e.g. `"1234 AB"` on `" "`.
+///
+/// With `limit` at most that many parts are returned, the last holding the
+/// rest of the string. The delimiter must not be empty and the limit must be
+/// a positive integer. A null subject splits into no parts.
+fn execute_split<R: ValueResolver>(
+    subject: &ActionValue,
+    value: &ActionValue,
+    limit: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let delimiter_val = evaluate_value(value, resolver, depth)?;
+    let limit_val = limit
+        .map(|l| evaluate_value(l, resolver, depth))
+        .transpose()?;
+    let mut operands = vec![subject_val.clone(), delimiter_val.clone()];
+    operands.extend(limit_val.clone());
+    if let Some(tainted) = find_untranslatable(&operands) {
+        return Ok(tainted);
+    }
+
+    let text = match &subject_val {
+        Value::String(s) => s,
+        Value::Null => return Ok(Value::Array(Vec::new())),
+        other => return Err(type_error("string", other)),
+    };
+    let delimiter = match &delimiter_val {
+        Value::String(d) if !d.is_empty() => d,
+        Value::String(_) => {
+            return Err(EngineError::InvalidOperation(
+                "SPLIT delimiter must not be empty".to_string(),
+            ))
+        }
+        other => return Err(type_error("delimiter string", other)),
+    };
+    let parts: Vec<Value> = match limit_val {
+        None => text.split(delimiter.as_str()).map(Value::from).collect(),
+        Some(l) => match l.as_int() {
+            Some(n) if n > 0 => text
+                .splitn(usize::try_from(n).unwrap_or(usize::MAX), delimiter.as_str())
+                .map(Value::from)
+                .collect(),
+            _ => {
+                return Err(EngineError::InvalidOperation(format!(
+                    "SPLIT 'limit' must be a positive integer, got {}",
+                    l
+                )))
+            }
+        },
+    };
+    Ok(Value::Array(parts))
+}
+
+/// Execute SLICE operation: the sub-array of `length` items starting at `start`.
+///
+/// A negative `start` counts from the end (`-1` is the last item). Indices are
+/// clamped to the array, so a range running past either end yields the items
+/// that exist (possibly none). Without `length` the slice runs to the end; a
+/// negative `length` is an error. A null array slices as empty.
+fn execute_slice<R: ValueResolver>(
+    value: &ActionValue,
+    start: &ActionValue,
+    length: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let collection = evaluate_value(value, resolver, depth)?;
+    let start_val = evaluate_value(start, resolver, depth)?;
+    let length_val = length
+        .map(|l| evaluate_value(l, resolver, depth))
+        .transpose()?;
+    let mut operands = vec![collection.clone(), start_val.clone()];
+    operands.extend(length_val.clone());
+    if let Some(tainted) = find_untranslatable(&operands) {
+        return Ok(tainted);
+    }
+
+    let items = match collection {
+        Value::Array(items) => items,
+        Value::Null => Vec::new(),
+        other => return Err(type_error("array", &other)),
+    };
+    let start = start_val.as_int().ok_or_else(|| {
+        EngineError::InvalidOperation(format!(
+            "SLICE 'start' must be an integer, got {}",
+            start_val
+        ))
+    })?;
+    let len = items.len() as i64;
+    let from = if start < 0 { len + start } else { start }.clamp(0, len);
+    let to = match length_val {
+        None => len,
+        Some(l) => match l.as_int() {
+            Some(n) if n >= 0 => from.saturating_add(n).min(len),
+            _ => {
+                return Err(EngineError::InvalidOperation(format!(
+                    "SLICE 'length' must be a non-negative integer, got {}",
+                    l
+                )))
+            }
+        },
+    };
+
+    Ok(Value::Array(
+        items
+            .into_iter()
+            .skip(from as usize)
+            .take((to - from) as usize)
+            .collect(),
+    ))
+}
+
+/// Execute SORT operation: a sorted copy of an array.
+///
+/// In `numeric` mode (the default) items are ordered as numbers, like the
+/// numeric comparison operators, so Int and Float mix freely; in `string`
+/// mode items must be strings and are ordered lexicographically. The sort is
+/// stable: items that compare equal keep their original order, also when
+/// `descending` is true. A null array sorts as empty.
+fn execute_sort<R: ValueResolver>(
+    value: &ActionValue,
+    descending: Option<&ActionValue>,
+    mode: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let collection = evaluate_value(value, resolver, depth)?;
+    if collection.is_untranslatable() {
+        return Ok(collection);
+    }
+    let descending = match descending {
+        None => false,
+        Some(d) => match evaluate_value(d, resolver, depth)? {
+            Value::Bool(b) => b,
+            other => return Err(type_error("boolean", &other)),
+        },
+    };
+    let mode_val = mode
+        .map(|m| evaluate_value(m, resolver, depth))
+        .transpose()?;
+    let mode = match &mode_val {
+        None => "numeric",
+        Some(m) => m.as_str().ok_or_else(|| type_error("mode string", m))?,
+    };
+
+    let items = match collection {
+        Value::Array(items) => items,
+        Value::Null => Vec::new(),
+        other => return Err(type_error("array", &other)),
+    };
+    if let Some(tainted) = find_untranslatable(&items) {
+        return Ok(tainted);
+    }
+
+    let sorted = match mode {
+        "numeric" => {
+            let max_safe_integer = resolver.options().max_safe_integer;
+            let mut keyed = items
+                .into_iter()
+                .map(|item| Ok((to_number(&item, max_safe_integer)?, item)))
+                .collect::<Result<Vec<_>>>()?;
+            keyed.sort_by(|(a, _), (b, _)| {
+                let ord = a.total_cmp(b);
+                if descending {
+                    ord.reverse()
+                } else {
+                    ord
+                }
+            });
+            keyed.into_iter().map(|(_, item)| item).collect::<Vec<_>>()
+        }
+        "string" => {
+            if let Some(bad) = items.iter().find(|item| item.as_str().is_none()) {
+                return Err(type_error("string", bad));
+            }
+            let mut items = items;
+            items.sort_by(|a, b| {
+                let ord = a.as_str().cmp(&b.as_str());
+                if descending {
+                    ord.reverse()
+                } else {
+                    ord
+                }
+            });
+            items
+        }
+        other => {
+            return Err(EngineError::InvalidOperation(format!(
+                "SORT: unknown mode '{}', expected numeric or string",
+                other
+            )))
+        }
+    };
+    Ok(Value::Array(sorted))
+}
+
+/// Execute CUMULATIVE_SUM operation: the running totals of a numeric array.
+///
+/// Item `i` of the result is the sum of items `0..=i`, typed like ADD over
+/// those items: an integer until the first float, a float from there on.
+/// Integers outside the safe range are rejected as in ADD. A null array
+/// yields an empty result.
+fn execute_cumulative_sum<R: ValueResolver>(
+    value: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let collection = evaluate_value(value, resolver, depth)?;
+    let items = match collection {
+        Value::Array(items) => items,
+        Value::Null => return Ok(Value::Array(Vec::new())),
+        Value::Untranslatable { .. } => return Ok(collection),
+        other => return Err(type_error("array", &other)),
+    };
+    if let Some(tainted) = find_untranslatable(&items) {
+        return Ok(tainted);
+    }
+
+    let max_safe_integer = resolver.options().max_safe_integer;
+    let mut total = Value::Int(0);
+    let mut totals = Vec::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+        total = add_to_total("CUMULATIVE_SUM", index, &total, item, max_safe_integer)?;
+        totals.push(total.clone());
+    }
+    Ok(Value::Array(totals))
+}
+
+/// Execute SUM_FIELD operation: the total of `field` across an array of objects.
+///
+/// The total is typed like ADD: an integer unless some value is a float, with
+/// integers outside the safe range rejected. An element without the field,
+/// or with a null value for it, is an error unless `skip_missing` is true. A
+/// null array totals 0.
+fn execute_sum_field<R: ValueResolver>(
+    value: &ActionValue,
+    field: &str,
+    skip_missing: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let collection = evaluate_value(value, resolver, depth)?;
+    let items = match collection {
+        Value::Array(items) => items,
+        Value::Null => return Ok(Value::Int(0)),
+        Value::Untranslatable { .. } => return Ok(collection),
+        other => return Err(type_error("array of objects", &other)),
+    };
+    let skip_missing = match skip_missing {
+        None => false,
+        Some(flag) => match evaluate_value(flag, resolver, depth)? {
+            Value::Bool(b) => b,
+            other => return Err(type_error("boolean", &other)),
+        },
+    };
+
+    let max_safe_integer = resolver.options().max_safe_integer;
+    let mut total = Value::Int(0);
+    for (index, item) in items.iter().enumerate() {
+        let obj = match item {
+            Value::Object(obj) => obj,
+            Value::Untranslatable { .. } => return Ok(item.clone()),
+            other => return Err(type_error("array of objects", other)),
+        };
+        let field_value = match obj.get(field) {
+            None | Some(Value::Null) if skip_missing => continue,
+            None | Some(Value::Null) => {
+                return Err(EngineError::InvalidOperation(format!(
+                    "SUM_FIELD: element {} has no '{}'",
+                    index, field
+                )))
+            }
+            Some(tainted @ Value::Untranslatable { .. }) => return Ok(tainted.clone()),
+            Some(v) => v,
+        };
+        total = add_to_total("SUM_FIELD", index, &total, field_value, max_safe_integer)?;
+    }
+    Ok(total)
+}
+
+/// Execute LABEL operation: map a coded value to its label via a lookup table.
+///
+/// `table` must evaluate to an object of code → label pairs (typically a
+/// definition in the article). Integer and boolean codes are matched by their
+/// string form, so a YAML key `1` matches code `1`. An unknown or null code
+/// returns `default` when given, otherwise null.
+fn execute_label<R: ValueResolver>(
+    subject: &ActionValue,
+    table: &ActionValue,
+    default: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let code = evaluate_value(subject, resolver, depth)?;
+    if code.is_untranslatable() {
+        return Ok(code);
+    }
+    let table_val = evaluate_value(table, resolver, depth)?;
+    let labels = table_val
+        .as_object()
+        .ok_or_else(|| type_error("object of code to label pairs", &table_val))?;
+
+    let key = match &code {
+        Value::String(s) => Some(s.clone()),
+        Value::Int(i) => Some(i.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Null => None,
+        other => return Err(type_error("string, integer or boolean code", other)),
+    };
+
+    match key.and_then(|k| labels.get(&k)) {
+        Some(label) => Ok(label.clone()),
+        None => match default {
+            Some(default) => evaluate_value(default, resolver, depth),
+            None => Ok(Value::Null),
+        },
+    }
 }
 
 // =============================================================================
-// Helper Functions
+// Date Operations
 // =============================================================================
 
-/// Safely convert f64 to i64, returning error on overflow/NaN/Infinity
-pub(crate) fn f64_to_i64_safe(f: f64) -> Result<i64> {
-    if f.is_nan() {
-        return Err(EngineError::ArithmeticOverflow("Result is NaN".to_string()));
-    }
-    if f.is_infinite() {
-        return Err(EngineError::ArithmeticOverflow(
-            "Result is infinite".to_string(),
-        ));
+/// Execute AGE operation: calculate age in whole years from date_of_birth to reference_date.
+///
+/// # Arguments
+/// - `date_of_birth`: Birth date (ISO 8601 YYYY-MM-DD)
+/// - `reference_date`: Date to calculate age at (ISO 8601 YYYY-MM-DD)
+fn execute_age<R: ValueResolver>(
+    date_of_birth: &ActionValue,
+    reference_date: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let dob_val = evaluate_value(date_of_birth, resolver, depth)?;
+    let ref_val = evaluate_value(reference_date, resolver, depth)?;
+
+    if let Some(tainted) = propagate_binary(&dob_val, &ref_val) {
+        return Ok(tainted);
     }
 
-    // i64::MIN as f64 is exact (-9223372036854775808.0).
-    // i64::MAX as f64 rounds UP to 9223372036854775808.0, which is i64::MAX + 1.
-    // Using strict < on the upper bound prevents accepting that rounded-up value,
-    // which would saturate to i64::MAX on `as i64` (semantically wrong).
-    const I64_MIN_F64: f64 = i64::MIN as f64;
-    const I64_MAX_F64: f64 = i64::MAX as f64;
+    let dob_date = parse_date(&dob_val)?;
+    let ref_date_parsed = parse_date(&ref_val)?;
 
-    if !(I64_MIN_F64..I64_MAX_F64).contains(&f) {
-        return Err(EngineError::ArithmeticOverflow(format!(
-            "Value {} exceeds i64 range",
-            f
-        )));
+    let policy = resolver.options().anniversary_policy;
+    let age = calculate_years_difference(ref_date_parsed, dob_date, policy);
+    Ok(Value::Int(age))
+}
+
+/// Execute HAS_REACHED_AGE operation: whether someone born on `date_of_birth`
+/// is at least `age` years old on `reference_date`.
+///
+/// The reference date defaults to `$referencedate`. Birthdays of people born
+/// on February 29 follow the configured [`AnniversaryPolicy`] in non-leap
+/// years, like AGE. The age must be a non-negative integer.
+fn execute_has_reached_age<R: ValueResolver>(
+    date_of_birth: &ActionValue,
+    age: &ActionValue,
+    reference_date: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let dob_val = evaluate_value(date_of_birth, resolver, depth)?;
+    let age_val = evaluate_value(age, resolver, depth)?;
+    let ref_val = match reference_date {
+        Some(reference_date) => evaluate_value(reference_date, resolver, depth)?,
+        None => resolver.resolve("referencedate")?,
+    };
+    if let Some(tainted) = find_untranslatable(&[dob_val.clone(), age_val.clone(), ref_val.clone()])
+    {
+        return Ok(tainted);
     }
 
-    Ok(f as i64)
+    let target = match age_val {
+        Value::Int(age) if age >= 0 => age,
+        other => return Err(type_error("non-negative integer age", &other)),
+    };
+    let policy = resolver.options().anniversary_policy;
+    let age = calculate_years_difference(parse_date(&ref_val)?, parse_date(&dob_val)?, policy);
+    Ok(Value::Bool(age >= target))
 }
 
-/// Evaluate a slice of ActionValues to concrete Values.
-fn evaluate_values<R: ValueResolver>(
-    values: &[ActionValue],
+/// Execute AGE_IN_MONTHS operation: age in complete months from
+/// `date_of_birth` to `reference_date` (default `$referencedate`).
+///
+/// Uses the same month arithmetic as SUBTRACT_DATE in months, so a month is
+/// complete once the day number of the birth date is reached again (clamped
+/// to the end of shorter months).
+fn execute_age_in_months<R: ValueResolver>(
+    date_of_birth: &ActionValue,
+    reference_date: Option<&ActionValue>,
     resolver: &R,
     depth: usize,
-) -> Result<Vec<Value>> {
-    values
-        .iter()
-        .map(|v| evaluate_value(v, resolver, depth))
-        .collect()
+) -> Result<Value> {
+    let dob_val = evaluate_value(date_of_birth, resolver, depth)?;
+    let ref_val = match reference_date {
+        Some(reference_date) => evaluate_value(reference_date, resolver, depth)?,
+        None => resolver.resolve("referencedate")?,
+    };
+    if let Some(tainted) = propagate_binary(&dob_val, &ref_val) {
+        return Ok(tainted);
+    }
+
+    let months = calculate_months_difference(parse_date(&ref_val)?, parse_date(&dob_val)?);
+    Ok(Value::Int(months))
 }
 
-/// Convert a Value to a number (f64).
-///
-/// # Precision
-///
-/// For integers larger than 2^53 or smaller than -2^53, precision is lost
-/// when converting to f64. This function returns an error for such values
-/// to prevent silent precision loss in financial/legal calculations.
-fn to_number(val: &Value) -> Result<f64> {
-    match val {
-        Value::Int(i) => {
-            // Check if integer is within safe range for f64
-            if *i > MAX_SAFE_INTEGER || *i < MIN_SAFE_INTEGER {
-                return Err(EngineError::ArithmeticOverflow(format!(
-                    "Integer {} exceeds safe range for floating-point conversion (±2^53)",
-                    i
-                )));
+/// Execute DATE_ADD operation: add years, months, weeks, and/or days to a date.
+///
+/// Applied in order: years → months → weeks → days (coarsest to finest).
+///
+/// For months and years, uses standard calendar arithmetic: the result lands on
+/// the same day number in the target month, clamped to the last day of that month
+/// when the day doesn't exist. E.g., Jan 31 + 1 month = Feb 28 (or 29 in leap year).
+///
+/// This is not domain knowledge in the engine — it is pure calendar math. The Hoge
+/// Raad confirmed that Dutch legal termijnberekening follows standard calendar
+/// arithmetic (HR 1 September 2017, ECLI:NL:HR:2017:2225).
+fn execute_date_add<R: ValueResolver>(
+    date: &ActionValue,
+    years: Option<&ActionValue>,
+    months: Option<&ActionValue>,
+    weeks: Option<&ActionValue>,
+    days: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let date_val = evaluate_value(date, resolver, depth)?;
+    if date_val.is_untranslatable() {
+        return Ok(date_val);
+    }
+    let mut result_date = parse_date(&date_val)?;
+
+    // Years: add to year component, clamp day to last day of target month
+    if let Some(years) = years {
+        let years_val = evaluate_value(years, resolver, depth)?;
+        let years_i64 = years_val.as_int().ok_or_else(|| {
+            EngineError::InvalidOperation("DATE_ADD 'years' must be a number".to_string())
+        })?;
+        let years_int = i32::try_from(years_i64).map_err(|_| {
+            EngineError::InvalidOperation(format!(
+                "DATE_ADD 'years' value {} exceeds supported range",
+                years_i64
+            ))
+        })?;
+        let target_year = result_date.year() + years_int;
+        let clamped_day = result_date
+            .day()
+            .min(days_in_month(target_year, result_date.month()));
+        result_date = NaiveDate::from_ymd_opt(target_year, result_date.month(), clamped_day)
+            .ok_or_else(|| {
+                EngineError::InvalidOperation(format!(
+                    "DATE_ADD: invalid date after adding {} years",
+                    years_int
+                ))
+            })?;
+    }
+
+    // Months: add to month component, clamp day to last day of target month
+    if let Some(months) = months {
+        let months_val = evaluate_value(months, resolver, depth)?;
+        let months_int = months_val.as_int().ok_or_else(|| {
+            EngineError::InvalidOperation("DATE_ADD 'months' must be a number".to_string())
+        })?;
+        result_date = add_months(result_date, months_int)?;
+    }
+
+    // Weeks
+    if let Some(weeks) = weeks {
+        let weeks_val = evaluate_value(weeks, resolver, depth)?;
+        let weeks_int = weeks_val.as_int().ok_or_else(|| {
+            EngineError::InvalidOperation("DATE_ADD 'weeks' must be a number".to_string())
+        })?;
+        result_date = result_date
+            .checked_add_signed(chrono::Duration::weeks(weeks_int))
+            .ok_or_else(|| {
+                EngineError::InvalidOperation(
+                    "DATE_ADD: date out of range after adding weeks".to_string(),
+                )
+            })?;
+    }
+
+    // Days
+    if let Some(days) = days {
+        let days_val = evaluate_value(days, resolver, depth)?;
+        let days_int = days_val.as_int().ok_or_else(|| {
+            EngineError::InvalidOperation("DATE_ADD 'days' must be a number".to_string())
+        })?;
+        result_date = result_date
+            .checked_add_signed(chrono::Duration::days(days_int))
+            .ok_or_else(|| {
+                EngineError::InvalidOperation(
+                    "DATE_ADD: date out of range after adding days".to_string(),
+                )
+            })?;
+    }
+
+    Ok(Value::String(result_date.format("%Y-%m-%d").to_string()))
+}
+
+/// Add months to a date using standard calendar arithmetic.
+///
+/// Clamps the day to the last day of the target month when the original day
+/// doesn't exist in that month (e.g., Jan 31 + 1 month = Feb 28).
+fn add_months(date: NaiveDate, months: i64) -> Result<NaiveDate> {
+    let total_months = date.year() as i64 * 12 + (date.month() as i64 - 1) + months;
+    let target_year = i32::try_from(total_months.div_euclid(12)).map_err(|_| {
+        EngineError::InvalidOperation(format!(
+            "DATE_ADD: year out of range after adding {} months",
+            months
+        ))
+    })?;
+    let target_month = (total_months.rem_euclid(12) + 1) as u32;
+    let clamped_day = date.day().min(days_in_month(target_year, target_month));
+
+    NaiveDate::from_ymd_opt(target_year, target_month, clamped_day).ok_or_else(|| {
+        EngineError::InvalidOperation(format!(
+            "DATE_ADD: invalid date after adding {} months",
+            months
+        ))
+    })
+}
+
+/// Execute DATE operation: construct a date from year, month, day components.
+///
+/// # Arguments
+/// - `year`: Year component (integer)
+/// - `month`: Month component (integer, 1-12)
+/// - `day`: Day component (integer, 1-31)
+fn execute_date_construct<R: ValueResolver>(
+    year: &ActionValue,
+    month: &ActionValue,
+    day: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let year_val = evaluate_value(year, resolver, depth)?;
+    let month_val = evaluate_value(month, resolver, depth)?;
+    let day_val = evaluate_value(day, resolver, depth)?;
+
+    if let Some(tainted) =
+        find_untranslatable(&[year_val.clone(), month_val.clone(), day_val.clone()])
+    {
+        return Ok(tainted);
+    }
+
+    let y_i64 = year_val
+        .as_int()
+        .ok_or_else(|| EngineError::InvalidOperation("DATE 'year' must be a number".to_string()))?;
+    let y = i32::try_from(y_i64).map_err(|_| {
+        EngineError::InvalidOperation(format!(
+            "DATE 'year' value {} exceeds supported range",
+            y_i64
+        ))
+    })?;
+    let m = month_val
+        .as_int()
+        .ok_or_else(|| EngineError::InvalidOperation("DATE 'month' must be a number".to_string()))?
+        as u32;
+    let d = day_val
+        .as_int()
+        .ok_or_else(|| EngineError::InvalidOperation("DATE 'day' must be a number".to_string()))?
+        as u32;
+
+    let date = NaiveDate::from_ymd_opt(y, m, d).ok_or_else(|| {
+        EngineError::InvalidOperation(format!("DATE: invalid date {}-{}-{}", y, m, d))
+    })?;
+
+    Ok(Value::String(date.format("%Y-%m-%d").to_string()))
+}
+
+/// Execute DAY_OF_WEEK operation: get the weekday number for a date.
+///
+/// Returns an integer where 0=Monday, 6=Sunday.
+///
+/// # Arguments
+/// - `date`: Date to get weekday for (ISO 8601 YYYY-MM-DD)
+fn execute_day_of_week<R: ValueResolver>(
+    date_value: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let val = evaluate_value(date_value, resolver, depth)?;
+    if val.is_untranslatable() {
+        return Ok(val);
+    }
+    let parsed = parse_date(&val)?;
+    Ok(Value::Int(parsed.weekday().num_days_from_monday() as i64))
+}
+
+/// Execute ISO_WEEK operation: the ISO 8601 week number (1-53) of a date.
+///
+/// Weeks start on Monday and week 1 holds the year's first Thursday, so dates
+/// around New Year can belong to a week of the neighbouring year: 2024-12-30
+/// is in week 1 (of 2025) and 2021-01-03 in week 53 (of 2020).
+fn execute_iso_week<R: ValueResolver>(
+    subject: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let val = evaluate_value(subject, resolver, depth)?;
+    if val.is_untranslatable() {
+        return Ok(val);
+    }
+    let parsed = parse_date(&val)?;
+    Ok(Value::Int(i64::from(parsed.iso_week().week())))
+}
+
+/// Length in days of each CONVERT_DURATION unit.
+///
+/// These are the conventional approximations used in statutory formulas
+/// (30-day months, 360-day years), not calendar lengths.
+const DURATION_UNIT_DAYS: &[(&str, i64)] =
+    &[("days", 1), ("weeks", 7), ("months", 30), ("years", 360)];
+
+/// Execute CONVERT_DURATION operation: express a duration in another unit.
+///
+/// Uses the fixed factors in [`DURATION_UNIT_DAYS`] (a month is 30 days, a
+/// year 360), for formulas that prescribe this approximation; use
+/// SUBTRACT_DATE or DATE_ADD for calendar arithmetic. An integer amount that
+/// converts exactly stays an integer, anything else becomes a float.
+fn execute_convert_duration<R: ValueResolver>(
+    value: &ActionValue,
+    from: &ActionValue,
+    to: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let amount = evaluate_value(value, resolver, depth)?;
+    let from_val = evaluate_value(from, resolver, depth)?;
+    let to_val = evaluate_value(to, resolver, depth)?;
+    if let Some(tainted) = find_untranslatable(&[amount.clone(), from_val.clone(), to_val.clone()])
+    {
+        return Ok(tainted);
+    }
+
+    let unit_days = |unit: &Value| {
+        let name = unit
+            .as_str()
+            .ok_or_else(|| type_error("unit string", unit))?;
+        DURATION_UNIT_DAYS
+            .iter()
+            .find(|(known, _)| *known == name)
+            .map(|(_, days)| *days)
+            .ok_or_else(|| {
+                EngineError::InvalidOperation(format!(
+                    "CONVERT_DURATION: unknown unit '{}', expected days, weeks, months or years",
+                    name
+                ))
+            })
+    };
+    let from_days = unit_days(&from_val)?;
+    let to_days = unit_days(&to_val)?;
+
+    if let Value::Int(n) = amount {
+        if let Some(days) = n.checked_mul(from_days) {
+            if days % to_days == 0 {
+                return Ok(Value::Int(days / to_days));
+            }
+        }
+    }
+    let number = to_number(&amount, resolver.options().max_safe_integer)?;
+    Ok(Value::Float(number * from_days as f64 / to_days as f64))
+}
+
+/// Execute QUARTER operation: the calendar quarter (1-4) of a date.
+fn execute_quarter<R: ValueResolver>(
+    subject: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let val = evaluate_value(subject, resolver, depth)?;
+    if val.is_untranslatable() {
+        return Ok(val);
+    }
+    let parsed = parse_date(&val)?;
+    Ok(Value::Int(i64::from(parsed.month0() / 3 + 1)))
+}
+
+/// Execute a date comparison (DATE_BEFORE, DATE_AFTER, DATE_ON_OR_BEFORE,
+/// DATE_ON_OR_AFTER): compare `subject` against `value` as calendar dates.
+fn execute_date_comparison<R: ValueResolver, F>(
+    subject: &ActionValue,
+    value: &ActionValue,
+    resolver: &R,
+    depth: usize,
+    compare: F,
+) -> Result<Value>
+where
+    F: Fn(NaiveDate, NaiveDate) -> bool,
+{
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let value_val = evaluate_value(value, resolver, depth)?;
+
+    if let Some(tainted) = propagate_binary(&subject_val, &value_val) {
+        return Ok(tainted);
+    }
+
+    let subject_date = parse_date(&subject_val)?;
+    let value_date = parse_date(&value_val)?;
+    Ok(Value::Bool(compare(subject_date, value_date)))
+}
+
+/// Execute SUBTRACT_DATE operation: the signed difference `values[0] - values[1]`.
+///
+/// Supported units:
+/// - `days`: total calendar days
+/// - `months` / `years`: complete months or years (same rules as AGE)
+/// - `ymd`: an object `{years, months, days}` decomposing the span so that
+///   `DATE_ADD(values[1], years, months, days)` lands on `values[0]`. When the
+///   first date is earlier, all three components are negative.
+///
+/// For `months` and `years`, the optional `rounding` decides how a started
+/// but incomplete period counts: `floor` (default) drops it, `ceil` counts it
+/// as a whole period, and `nearest` counts it once at least half of its days
+/// have passed. Rounding is applied to the magnitude, so the sign is kept.
+fn execute_subtract_date<R: ValueResolver>(
+    values: &[ActionValue],
+    unit: &ActionValue,
+    rounding: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    if values.len() != 2 {
+        return Err(EngineError::InvalidOperation(format!(
+            "SUBTRACT_DATE requires exactly 2 values, got {}",
+            values.len()
+        )));
+    }
+    let evaluated = evaluate_values(values, resolver, depth)?;
+    if let Some(tainted) = find_untranslatable(&evaluated) {
+        return Ok(tainted);
+    }
+    let unit_val = evaluate_value(unit, resolver, depth)?;
+    let unit_str = unit_val
+        .as_str()
+        .ok_or_else(|| type_error("unit string", &unit_val))?;
+
+    let rounding_val = rounding
+        .map(|r| evaluate_value(r, resolver, depth))
+        .transpose()?;
+    let rounding = match &rounding_val {
+        None => "floor",
+        Some(r) => r.as_str().ok_or_else(|| type_error("rounding string", r))?,
+    };
+    if !matches!(rounding, "floor" | "ceil" | "nearest") {
+        return Err(EngineError::InvalidOperation(format!(
+            "SUBTRACT_DATE: unknown rounding '{}', expected floor, ceil or nearest",
+            rounding
+        )));
+    }
+
+    let date1 = parse_date(&evaluated[0])?;
+    let date2 = parse_date(&evaluated[1])?;
+
+    match unit_str {
+        "days" => Ok(Value::Int((date1 - date2).num_days())),
+        "months" => {
+            let complete = calculate_months_difference(date1, date2);
+            round_date_difference(complete, date1, date2, 1, rounding).map(Value::Int)
+        }
+        "years" => {
+            let policy = resolver.options().anniversary_policy;
+            let complete = calculate_years_difference(date1, date2, policy);
+            round_date_difference(complete, date1, date2, 12, rounding).map(Value::Int)
+        }
+        "ymd" => {
+            let (earlier, later, sign) = if date1 >= date2 {
+                (date2, date1, 1)
+            } else {
+                (date1, date2, -1)
+            };
+            let total_months = calculate_months_difference(later, earlier);
+            let anchor = add_months(earlier, total_months)?;
+            let days = (later - anchor).num_days();
+
+            let mut obj = std::collections::BTreeMap::new();
+            obj.insert("years".to_string(), Value::Int(sign * (total_months / 12)));
+            obj.insert("months".to_string(), Value::Int(sign * (total_months % 12)));
+            obj.insert("days".to_string(), Value::Int(sign * days));
+            Ok(Value::Object(obj))
+        }
+        other => Err(EngineError::InvalidOperation(format!(
+            "SUBTRACT_DATE: unknown unit '{}', expected days, months, years or ymd",
+            other
+        ))),
+    }
+}
+
+/// Round a signed count of complete periods of `period_months` months between
+/// two dates according to SUBTRACT_DATE's `rounding`.
+fn round_date_difference(
+    complete: i64,
+    date1: NaiveDate,
+    date2: NaiveDate,
+    period_months: i64,
+    rounding: &str,
+) -> Result<i64> {
+    let (earlier, later, sign) = if date1 >= date2 {
+        (date2, date1, 1)
+    } else {
+        (date1, date2, -1)
+    };
+    let periods = complete.abs();
+    let anchor = add_months(earlier, periods * period_months)?;
+    if rounding == "floor" || anchor == later {
+        return Ok(complete);
+    }
+    let started = match rounding {
+        "ceil" => true,
+        _ => {
+            let next = add_months(earlier, (periods + 1) * period_months)?;
+            2 * (later - anchor).num_days() >= (next - anchor).num_days()
+        }
+    };
+    Ok(sign * (periods + i64::from(started)))
+}
+
+/// Execute PRO_RATA operation: the share of `value` covered by a span of days.
+///
+/// Returns `value * covered_days / total_days_in_period` as a float, where
+/// `covered_days` counts `start_date` through `end_date` inclusive and the
+/// period is the calendar `month` or `year` containing `start_date`. Both
+/// dates must fall within that period.
+fn execute_pro_rata<R: ValueResolver>(
+    value: &ActionValue,
+    start_date: &ActionValue,
+    end_date: &ActionValue,
+    period: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let amount_val = evaluate_value(value, resolver, depth)?;
+    let start_val = evaluate_value(start_date, resolver, depth)?;
+    let end_val = evaluate_value(end_date, resolver, depth)?;
+    if let Some(tainted) =
+        find_untranslatable(&[amount_val.clone(), start_val.clone(), end_val.clone()])
+    {
+        return Ok(tainted);
+    }
+    let period_val = evaluate_value(period, resolver, depth)?;
+    let period_str = period_val
+        .as_str()
+        .ok_or_else(|| type_error("period string", &period_val))?;
+
+    let amount = operand_to_number(
+        "PRO_RATA",
+        0,
+        &amount_val,
+        resolver.options().max_safe_integer,
+    )?;
+    let start = parse_date(&start_val)?;
+    let end = parse_date(&end_val)?;
+    if end < start {
+        return Err(EngineError::InvalidOperation(format!(
+            "PRO_RATA: end_date {} is before start_date {}",
+            end, start
+        )));
+    }
+
+    let (same_period, total_days) = match period_str {
+        "month" => (
+            end.year() == start.year() && end.month() == start.month(),
+            days_in_month(start.year(), start.month()),
+        ),
+        "year" => (
+            end.year() == start.year(),
+            (1..=12).map(|m| days_in_month(start.year(), m)).sum(),
+        ),
+        other => {
+            return Err(EngineError::InvalidOperation(format!(
+                "PRO_RATA: unknown period '{}', expected month or year",
+                other
+            )))
+        }
+    };
+    if !same_period {
+        return Err(EngineError::InvalidOperation(format!(
+            "PRO_RATA: start_date {} and end_date {} are not in the same {}",
+            start, end, period_str
+        )));
+    }
+
+    let covered_days = (end - start).num_days() + 1;
+    Ok(Value::Float(
+        amount * covered_days as f64 / f64::from(total_days),
+    ))
+}
+
+/// Execute CLAMP_DATE operation: the date in `[min, max]` nearest to `subject`.
+///
+/// Returns `subject` when it lies within the bounds, otherwise the bound it
+/// passed, as an ISO 8601 string. Bounds with `min` after `max` are an error.
+fn execute_clamp_date<R: ValueResolver>(
+    subject: &ActionValue,
+    min: &ActionValue,
+    max: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let min_val = evaluate_value(min, resolver, depth)?;
+    let max_val = evaluate_value(max, resolver, depth)?;
+    if let Some(tainted) =
+        find_untranslatable(&[subject_val.clone(), min_val.clone(), max_val.clone()])
+    {
+        return Ok(tainted);
+    }
+
+    let date = parse_date(&subject_val)?;
+    let lower = parse_date(&min_val)?;
+    let upper = parse_date(&max_val)?;
+    if lower > upper {
+        return Err(EngineError::InvalidOperation(format!(
+            "CLAMP_DATE: min {} is after max {}",
+            lower, upper
+        )));
+    }
+
+    Ok(Value::String(
+        date.clamp(lower, upper).format("%Y-%m-%d").to_string(),
+    ))
+}
+
+/// Execute NEXT_BUSINESS_DAY operation: `subject` itself when it is a
+/// business day, else the first business day after it (Algemene termijnenwet).
+///
+/// Saturdays, Sundays and the dates in `holidays` are not business days.
+/// Holidays are declared by the law, not taken from a built-in calendar;
+/// a null or absent list declares none. Returns an ISO 8601 string.
+fn execute_next_business_day<R: ValueResolver>(
+    subject: &ActionValue,
+    holidays: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let holidays_val = match holidays {
+        Some(holidays) => evaluate_value(holidays, resolver, depth)?,
+        None => Value::Null,
+    };
+    if let Some(tainted) = propagate_binary(&subject_val, &holidays_val) {
+        return Ok(tainted);
+    }
+
+    let holidays = match &holidays_val {
+        Value::Array(dates) => dates
+            .iter()
+            .map(parse_date)
+            .collect::<Result<HashSet<_>>>()?,
+        Value::Null => Default::default(),
+        other => return Err(type_error("array of holiday dates", other)),
+    };
+    let mut date = parse_date(&subject_val)?;
+    while date.weekday().number_from_monday() > 5 || holidays.contains(&date) {
+        date = date.succ_opt().ok_or_else(|| {
+            EngineError::InvalidOperation("NEXT_BUSINESS_DAY: date out of range".to_string())
+        })?;
+    }
+    Ok(Value::String(date.format("%Y-%m-%d").to_string()))
+}
+
+/// Execute YEAR_FRACTION operation: the span from `start` to `end` in years,
+/// as a float, under a day-count convention.
+///
+/// - `ACT/365` (default): actual days divided by 365
+/// - `ACT/360`: actual days divided by 360
+/// - `ACT/ACT`: the days in each calendar year divided by that year's
+///   length (365 or 366), summed (ISDA)
+///
+/// The fraction is negative when `end` is before `start`.
+fn execute_year_fraction<R: ValueResolver>(
+    start: &ActionValue,
+    end: &ActionValue,
+    convention: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let start_val = evaluate_value(start, resolver, depth)?;
+    let end_val = evaluate_value(end, resolver, depth)?;
+    if let Some(tainted) = propagate_binary(&start_val, &end_val) {
+        return Ok(tainted);
+    }
+    let convention = match convention {
+        Some(convention) => match evaluate_value(convention, resolver, depth)? {
+            Value::String(s) => s,
+            Value::Null => "ACT/365".to_string(),
+            other => return Err(type_error("day-count convention string", &other)),
+        },
+        None => "ACT/365".to_string(),
+    };
+
+    let start = parse_date(&start_val)?;
+    let end = parse_date(&end_val)?;
+    let (from, to, sign) = if end < start {
+        (end, start, -1.0)
+    } else {
+        (start, end, 1.0)
+    };
+    let days = (to - from).num_days() as f64;
+    let fraction = match convention.to_ascii_uppercase().as_str() {
+        "ACT/365" => days / 365.0,
+        "ACT/360" => days / 360.0,
+        "ACT/ACT" => {
+            let mut fraction = 0.0;
+            let mut cursor = from;
+            while cursor < to {
+                let year = cursor.year();
+                let next_year = NaiveDate::from_ymd_opt(year + 1, 1, 1).ok_or_else(|| {
+                    EngineError::InvalidOperation("YEAR_FRACTION: date out of range".to_string())
+                })?;
+                let year_end = next_year.min(to);
+                let year_days = if NaiveDate::from_ymd_opt(year, 2, 29).is_some() {
+                    366.0
+                } else {
+                    365.0
+                };
+                fraction += (year_end - cursor).num_days() as f64 / year_days;
+                cursor = year_end;
+            }
+            fraction
+        }
+        other => {
+            return Err(EngineError::InvalidOperation(format!(
+                "YEAR_FRACTION: unknown day-count convention '{}' (expected ACT/365, ACT/360 or ACT/ACT)",
+                other
+            )))
+        }
+    };
+    Ok(Value::Float(sign * fraction))
+}
+
+/// Execute NTH_WEEKDAY operation: the `ordinal`-th `weekday` of the month of `date`.
+///
+/// `weekday` is ISO numbered (1 = Monday .. 7 = Sunday); `ordinal` is 1-5, or -1
+/// for the last occurrence. Returns an ISO 8601 string. An ordinal the month
+/// does not have (e.g. a fifth Monday) is an error.
+fn execute_nth_weekday<R: ValueResolver>(
+    date: &ActionValue,
+    weekday: &ActionValue,
+    ordinal: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let date_val = evaluate_value(date, resolver, depth)?;
+    let weekday_val = evaluate_value(weekday, resolver, depth)?;
+    let ordinal_val = evaluate_value(ordinal, resolver, depth)?;
+    if let Some(tainted) =
+        find_untranslatable(&[date_val.clone(), weekday_val.clone(), ordinal_val.clone()])
+    {
+        return Ok(tainted);
+    }
+
+    let reference = parse_date(&date_val)?;
+    let weekday = match weekday_val.as_int() {
+        Some(n @ 1..=7) => n as u32,
+        _ => {
+            return Err(EngineError::InvalidOperation(format!(
+                "NTH_WEEKDAY 'weekday' must be 1 (Monday) to 7 (Sunday), got {}",
+                weekday_val
+            )))
+        }
+    };
+    let ordinal = match ordinal_val.as_int() {
+        Some(n @ (1..=5 | -1)) => n,
+        _ => {
+            return Err(EngineError::InvalidOperation(format!(
+                "NTH_WEEKDAY 'ordinal' must be 1 to 5 or -1 (last), got {}",
+                ordinal_val
+            )))
+        }
+    };
+
+    let (year, month) = (reference.year(), reference.month());
+    let month_days = days_in_month(year, month);
+    // ISO weekday (1-7) of the first and last day of the month
+    let first_weekday =
+        (reference.weekday().num_days_from_monday() + 35 - (reference.day() - 1)) % 7 + 1;
+    let last_weekday = (first_weekday - 1 + month_days - 1) % 7 + 1;
+    let day = if ordinal == -1 {
+        month_days - (last_weekday + 7 - weekday) % 7
+    } else {
+        let first = 1 + (weekday + 7 - first_weekday) % 7;
+        first + 7 * (ordinal as u32 - 1)
+    };
+    if day > month_days {
+        return Err(EngineError::InvalidOperation(format!(
+            "NTH_WEEKDAY: {}-{:02} has no occurrence {} of weekday {}",
+            year, month, ordinal, weekday
+        )));
+    }
+
+    NaiveDate::from_ymd_opt(year, month, day)
+        .map(|d| Value::String(d.format("%Y-%m-%d").to_string()))
+        .ok_or_else(|| {
+            EngineError::InvalidOperation(format!(
+                "NTH_WEEKDAY: invalid date {}-{:02}-{:02}",
+                year, month, day
+            ))
+        })
+}
+
+/// Execute DATE_IN_PERIODS operation: whether `subject` lies in any of `periods`.
+///
+/// Each period is an object with a `from` date and an optional `to` date,
+/// both inclusive; a null or absent `to` leaves the period open-ended. A
+/// period ending before it starts is an error. A null period list contains
+/// no dates.
+fn execute_date_in_periods<R: ValueResolver>(
+    subject: &ActionValue,
+    periods: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let periods_val = evaluate_value(periods, resolver, depth)?;
+    if let Some(tainted) = propagate_binary(&subject_val, &periods_val) {
+        return Ok(tainted);
+    }
+
+    let date = parse_date(&subject_val)?;
+    let periods = match periods_val {
+        Value::Array(periods) => periods,
+        Value::Null => return Ok(Value::Bool(false)),
+        other => return Err(type_error("array of periods", &other)),
+    };
+
+    for period in &periods {
+        if period_contains("DATE_IN_PERIODS", period, date)?.is_some() {
+            return Ok(Value::Bool(true));
+        }
+    }
+    Ok(Value::Bool(false))
+}
+
+/// Execute PERIOD_LOOKUP operation: the `value` of the first period in
+/// `periods` that contains the date `subject`, e.g. a yearly indexed amount.
+///
+/// Periods are `{from, to, value}` objects with the same bounds as
+/// DATE_IN_PERIODS. When no period contains the date, or the period list
+/// or the date is null, `default` is returned (null when absent).
+fn execute_period_lookup<R: ValueResolver>(
+    subject: &ActionValue,
+    periods: &ActionValue,
+    default: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let periods_val = evaluate_value(periods, resolver, depth)?;
+    if let Some(tainted) = propagate_binary(&subject_val, &periods_val) {
+        return Ok(tainted);
+    }
+
+    let found = match (&subject_val, &periods_val) {
+        (Value::Null, _) | (_, Value::Null) => None,
+        (_, Value::Array(periods)) => {
+            let date = parse_date(&subject_val)?;
+            let mut found = None;
+            for period in periods {
+                if let Some(fields) = period_contains("PERIOD_LOOKUP", period, date)? {
+                    found = Some(fields.get("value").cloned().unwrap_or(Value::Null));
+                    break;
+                }
+            }
+            found
+        }
+        (_, other) => return Err(type_error("array of periods", other)),
+    };
+    match (found, default) {
+        (Some(value), _) => Ok(value),
+        (None, Some(default)) => evaluate_value(default, resolver, depth),
+        (None, None) => Ok(Value::Null),
+    }
+}
+
+/// Check a `{from, to, ...}` period object, returning its fields when it
+/// contains `date`. Bounds are inclusive and a null or absent `to` leaves
+/// the period open-ended.
+fn period_contains<'v>(
+    op_name: &str,
+    period: &'v Value,
+    date: NaiveDate,
+) -> Result<Option<&'v BTreeMap<String, Value>>> {
+    let Value::Object(fields) = period else {
+        return Err(type_error("period object with 'from' and 'to'", period));
+    };
+    let from = parse_date(fields.get("from").unwrap_or(&Value::Null))?;
+    let to = match fields.get("to") {
+        None | Some(Value::Null) => None,
+        Some(to) => Some(parse_date(to)?),
+    };
+    if let Some(to) = to.filter(|to| *to < from) {
+        return Err(EngineError::InvalidOperation(format!(
+            "{}: period from {} ends before it starts ({})",
+            op_name, from, to
+        )));
+    }
+    Ok((date >= from && to.is_none_or(|to| date <= to)).then_some(fields))
+}
+
+/// Execute PERIOD_OVERLAP_DAYS operation: days shared by two periods.
+///
+/// Bounds are given as `[a_from, a_to, b_from, b_to]`, all inclusive, so two
+/// periods touching at a single day overlap by 1. A null bound leaves that
+/// side of its period open. Disjoint periods overlap by 0; an overlap that is
+/// itself unbounded, or a period ending before it starts, is an error.
+fn execute_period_overlap_days<R: ValueResolver>(
+    bounds: [&ActionValue; 4],
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let values = bounds
+        .iter()
+        .map(|bound| evaluate_value(bound, resolver, depth))
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(tainted) = find_untranslatable(&values) {
+        return Ok(tainted);
+    }
+
+    let dates = values
+        .iter()
+        .map(|value| match value {
+            Value::Null => Ok(None),
+            other => parse_date(other).map(Some),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let [a_from, a_to, b_from, b_to] = dates[..] else {
+        unreachable!("four bounds evaluate to four dates");
+    };
+
+    for (name, from, to) in [("a", a_from, a_to), ("b", b_from, b_to)] {
+        if let (Some(from), Some(to)) = (from, to) {
+            if to < from {
+                return Err(EngineError::InvalidOperation(format!(
+                    "PERIOD_OVERLAP_DAYS: period {} from {} ends before it starts ({})",
+                    name, from, to
+                )));
+            }
+        }
+    }
+
+    // `None` orders before any date, so `max` picks the later known start.
+    let start = a_from.max(b_from);
+    let end = match (a_to, b_to) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    };
+    let (Some(start), Some(end)) = (start, end) else {
+        return Err(EngineError::InvalidOperation(
+            "PERIOD_OVERLAP_DAYS: overlap of two open-ended periods is unbounded".to_string(),
+        ));
+    };
+
+    Ok(Value::Int(((end - start).num_days() + 1).max(0)))
+}
+
+/// Execute MIN_DATE / MAX_DATE: the earliest or latest date in an array.
+///
+/// Entries may be date strings or date objects (with an `iso` field); the
+/// result is an ISO date string. An empty array or an entry that is not a
+/// date is an error.
+fn execute_extreme_date<R: ValueResolver>(
+    value: &ActionValue,
+    resolver: &R,
+    depth: usize,
+    latest: bool,
+) -> Result<Value> {
+    let op_name = if latest { "MAX_DATE" } else { "MIN_DATE" };
+    let collection = evaluate_value(value, resolver, depth)?;
+    let items = match collection {
+        Value::Array(items) => items,
+        Value::Untranslatable { .. } => return Ok(collection),
+        other => return Err(type_error("array of dates", &other)),
+    };
+    if let Some(tainted) = find_untranslatable(&items) {
+        return Ok(tainted);
+    }
+
+    let dates = items.iter().map(parse_date).collect::<Result<Vec<_>>>()?;
+    let extreme = if latest {
+        dates.into_iter().max()
+    } else {
+        dates.into_iter().min()
+    };
+    let date = extreme.ok_or_else(|| {
+        EngineError::InvalidOperation(format!("{}: requires at least one date", op_name))
+    })?;
+    Ok(Value::String(date.format("%Y-%m-%d").to_string()))
+}
+
+/// Parse a date from a Value.
+///
+/// Expects the value to be a string in ISO 8601 format (YYYY-MM-DD).
+fn parse_date(value: &Value) -> Result<NaiveDate> {
+    match value {
+        Value::String(s) => parse_iso_date(s),
+        // Handle referencedate objects with {iso, year, month, day}
+        Value::Object(obj) => {
+            if let Some(Value::String(iso)) = obj.get("iso") {
+                parse_iso_date(iso)
+            } else {
+                Err(EngineError::TypeMismatch {
+                    expected: "date string (YYYY-MM-DD) or object with 'iso' field".to_string(),
+                    actual: format!("{:?}", value),
+                })
+            }
+        }
+        _ => Err(EngineError::TypeMismatch {
+            expected: "date string (YYYY-MM-DD)".to_string(),
+            actual: format!("{:?}", value),
+        }),
+    }
+}
+
+/// Parse a YYYY-MM-DD string, distinguishing impossible dates from format errors.
+///
+/// A well-formed string naming a day that does not exist (`2025-13-01`,
+/// `2025-02-30`) is reported as such; anything else that does not match the
+/// format (`15/01/2025`, `2025-01-01T00:00`) is reported as a format mismatch.
+fn parse_iso_date(s: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|e| match e.kind() {
+        ParseErrorKind::OutOfRange | ParseErrorKind::Impossible => EngineError::InvalidOperation(
+            format!("Date '{}' does not exist: month or day out of range", s),
+        ),
+        _ => EngineError::InvalidOperation(format!(
+            "Failed to parse date '{}': {}. Expected format: YYYY-MM-DD",
+            s, e
+        )),
+    })
+}
+
+/// Calculate the difference in complete months between two dates.
+///
+/// Uses proper calendar arithmetic. A month is counted as complete when
+/// the same day-of-month (or end of month if day doesn't exist) is reached.
+/// For end-of-month edge cases (e.g., Jan 31 -> Feb 28), if `earlier.day()`
+/// exceeds the number of days in `later`'s month, it is capped to the last
+/// day of that month so the month is correctly counted as complete.
+fn calculate_months_difference(date1: NaiveDate, date2: NaiveDate) -> i64 {
+    let (earlier, later, sign) = if date1 >= date2 {
+        (date2, date1, 1)
+    } else {
+        (date1, date2, -1)
+    };
+
+    let years_diff = later.year() - earlier.year();
+    let months_diff = later.month() as i32 - earlier.month() as i32;
+    let mut total_months = years_diff * 12 + months_diff;
+
+    // Cap earlier.day() to the max days in later's month so that
+    // Jan 31 → Feb 28 counts as 1 month (28 is the last day of Feb).
+    let max_day_in_later_month = days_in_month(later.year(), later.month());
+    let earlier_day_capped = earlier.day().min(max_day_in_later_month);
+
+    // Adjust if we haven't reached the (capped) day in the month
+    if later.day() < earlier_day_capped {
+        total_months -= 1;
+    }
+
+    (total_months as i64) * sign
+}
+
+/// Return the number of days in a given month.
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if NaiveDate::from_ymd_opt(year, 2, 29).is_some() {
+                29
+            } else {
+                28
+            }
+        }
+        _ => unreachable!("Invalid month: {month}"),
+    }
+}
+
+/// Calculate the difference in complete years between two dates.
+///
+/// Uses proper calendar arithmetic. A year is counted as complete when
+/// the anniversary date is reached; for leap year births on Feb 29 that is
+/// Feb 28 or Mar 1 in non-leap years, depending on `policy`.
+fn calculate_years_difference(
+    date1: NaiveDate,
+    date2: NaiveDate,
+    policy: AnniversaryPolicy,
+) -> i64 {
+    let (earlier, later, sign) = if date1 >= date2 {
+        (date2, date1, 1)
+    } else {
+        (date1, date2, -1)
+    };
+
+    let mut years = later.year() - earlier.year();
+
+    // Check if we've reached the anniversary this year.
+    // For Feb 29 birthdays in non-leap years, the anniversary falls on Feb 28
+    // by default (per Dutch law: BW art. 1:2, Algemene Termijnenwet).
+    let (anniversary_month, anniversary_day) =
+        if earlier.month() == 2 && earlier.day() == 29 && days_in_month(later.year(), 2) < 29 {
+            match policy {
+                AnniversaryPolicy::February28 => (2, 28),
+                AnniversaryPolicy::March1 => (3, 1),
+            }
+        } else {
+            (earlier.month(), earlier.day())
+        };
+
+    if later.month() < anniversary_month
+        || (later.month() == anniversary_month && later.day() < anniversary_day)
+    {
+        years -= 1;
+    }
+
+    (years as i64) * sign
+}
+
+// =============================================================================
+// Cross-law Operations
+// =============================================================================
+
+/// Execute URI_CALL operation: evaluate another law's output inline.
+///
+/// Parameters are evaluated in the current context and passed to the target
+/// article; the resolver hands the call to its `ServiceProvider`. Tainted
+/// parameters propagate without calling out.
+fn execute_uri_call<R: ValueResolver>(
+    uri: &str,
+    parameters: &BTreeMap<String, ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let mut evaluated = BTreeMap::new();
+    for (name, value) in parameters {
+        let val = evaluate_value(value, resolver, depth)?;
+        if val.is_untranslatable() {
+            return Ok(val);
+        }
+        evaluated.insert(name.clone(), val);
+    }
+
+    resolver.evaluate_uri(uri, evaluated)
+}
+
+/// Execute ANY_ENTITLED operation: true if any referenced output is truthy.
+///
+/// References are resolved in order via [`ValueResolver::evaluate_uri`], like
+/// URI_CALL, and evaluation stops at the first truthy output so that the
+/// remaining laws are never evaluated. Untranslatable outputs propagate as in
+/// OR: they only win when no reference is definitively true.
+fn execute_any_entitled<R: ValueResolver>(
+    references: &[UriReference],
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let mut taint: Option<Value> = None;
+    for (index, reference) in references.iter().enumerate() {
+        let val = execute_uri_call(&reference.uri, &reference.parameters, resolver, depth)?;
+        if !val.is_untranslatable() && condition_to_bool(&val, resolver, "ANY_ENTITLED")? {
+            if index + 1 < references.len() {
+                resolver.record_short_circuit();
+            }
+            return Ok(Value::Bool(true));
+        }
+        if val.is_untranslatable() && taint.is_none() {
+            taint = Some(val);
+        }
+    }
+
+    Ok(taint.unwrap_or(Value::Bool(false)))
+}
+
+/// Execute FIRST_RESOLVED operation: the first referenced output that is not
+/// null, e.g. income from one source, else from another.
+///
+/// References are resolved in order like URI_CALL, and evaluation stops at
+/// the first non-null output so that the remaining laws are never
+/// evaluated. An untranslatable output also stops evaluation and propagates.
+/// When every reference is null, `default` is returned (null when absent).
+fn execute_first_resolved<R: ValueResolver>(
+    references: &[UriReference],
+    default: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    for (index, reference) in references.iter().enumerate() {
+        let val = execute_uri_call(&reference.uri, &reference.parameters, resolver, depth)?;
+        if !val.is_null() {
+            if index + 1 < references.len() {
+                resolver.record_short_circuit();
+            }
+            return Ok(val);
+        }
+    }
+
+    match default {
+        Some(default) => evaluate_value(default, resolver, depth),
+        None => Ok(Value::Null),
+    }
+}
+
+/// Execute DELEGATED_LOOKUP operation: index a delegated keyed table.
+///
+/// `subject` is typically an open term filled by a lower regulation with an
+/// object such as `{GM0363: 1200, GM0344: 950}`; `select_on` picks the entry.
+/// A string key is used as-is and an integer key by its decimal form. A key
+/// that is absent from the table, or an unfilled (null) table, yields null.
+fn execute_delegated_lookup<R: ValueResolver>(
+    subject: &ActionValue,
+    select_on: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let table = evaluate_value(subject, resolver, depth)?;
+    let key = evaluate_value(select_on, resolver, depth)?;
+    if let Some(tainted) = find_untranslatable(&[table.clone(), key.clone()]) {
+        return Ok(tainted);
+    }
+    let key = match key {
+        Value::String(s) => s,
+        Value::Int(i) => i.to_string(),
+        other => return Err(type_error("string or integer key", &other)),
+    };
+    match table {
+        Value::Object(entries) => Ok(entries.get(&key).cloned().unwrap_or(Value::Null)),
+        Value::Null => Ok(Value::Null),
+        other => Err(type_error("object", &other)),
+    }
+}
+
+/// Execute DELEGATED_OR_DEFAULT operation: the value a lower regulation
+/// gave an optional open term, or the statutory `default` when none did.
+///
+/// An optional open term without implementation resolves to null, so a null
+/// `subject` selects `default`. The trace notes which of the two was used.
+fn execute_delegated_or_default<R: ValueResolver>(
+    subject: &ActionValue,
+    default: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let delegated = evaluate_value(subject, resolver, depth)?;
+    let (source, value) = match delegated {
+        Value::Null => ("default", evaluate_value(default, resolver, depth)?),
+        value => ("delegated", value),
+    };
+    if resolver.has_trace() {
+        resolver.trace_set_message(source.to_string());
+    }
+    Ok(value)
+}
+
+// =============================================================================
+// Validation Operations
+// =============================================================================
+
+/// Execute VALIDATE_BSN operation: check a BSN against the elfproef.
+///
+/// The subject may be a string of exactly 9 digits or a non-negative integer
+/// (padded to 9 digits, since integers lose leading zeros). A BSN is valid when
+/// `9*d1 + 8*d2 + ... + 2*d8 - d9` is a multiple of 11; the all-zero number is
+/// rejected. Anything else, including null, yields `false`.
+fn execute_validate_bsn<R: ValueResolver>(
+    subject: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let val = evaluate_value(subject, resolver, depth)?;
+    let digits = match &val {
+        Value::Untranslatable { .. } => return Ok(val),
+        Value::Null => return Ok(Value::Bool(false)),
+        Value::String(s) => s.clone(),
+        Value::Int(i) if *i >= 0 => format!("{:09}", i),
+        Value::Int(_) => return Ok(Value::Bool(false)),
+        other => {
+            return Err(EngineError::TypeMismatch {
+                expected: "string or int".to_string(),
+                actual: other.type_name().to_string(),
+            })
+        }
+    };
+    Ok(Value::Bool(is_valid_bsn(&digits)))
+}
+
+/// The elfproef on a string of 9 ASCII digits.
+fn is_valid_bsn(digits: &str) -> bool {
+    if digits.len() != 9 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    let (sum, nonzero) =
+        digits
+            .bytes()
+            .enumerate()
+            .fold((0i64, false), |(sum, nonzero), (i, b)| {
+                let digit = i64::from(b - b'0');
+                let weight = if i == 8 { -1 } else { 9 - i as i64 };
+                (sum + weight * digit, nonzero || digit != 0)
+            });
+    nonzero && sum % 11 == 0
+}
+
+/// Execute NORMALIZE_POSTCODE operation: canonicalize a Dutch postcode to `1234 AB`.
+///
+/// Accepts four digits (not starting with 0) and two letters, in either case,
+/// with or without whitespace in between. Null passes through; any other
+/// string is an `InvalidOperation` error and non-strings a `TypeMismatch`.
+fn execute_normalize_postcode<R: ValueResolver>(
+    subject: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let val = evaluate_value(subject, resolver, depth)?;
+    let raw = match &val {
+        Value::Untranslatable { .. } | Value::Null => return Ok(val),
+        Value::String(s) => s,
+        other => {
+            return Err(EngineError::TypeMismatch {
+                expected: "string".to_string(),
+                actual: other.type_name().to_string(),
+            })
+        }
+    };
+
+    let trimmed = raw.trim();
+    let digits = trimmed.get(..4).unwrap_or_default();
+    let letters = trimmed.get(4..).unwrap_or_default().trim_start();
+    let valid = digits.len() == 4
+        && digits.bytes().all(|b| b.is_ascii_digit())
+        && !digits.starts_with('0')
+        && letters.len() == 2
+        && letters.bytes().all(|b| b.is_ascii_alphabetic());
+    if !valid {
+        return Err(EngineError::InvalidOperation(format!(
+            "NORMALIZE_POSTCODE: '{}' is not a postcode of the form 1234 AB",
+            raw
+        )));
+    }
+
+    Ok(Value::String(format!(
+        "{} {}",
+        digits,
+        letters.to_ascii_uppercase()
+    )))
+}
+
+/// Execute MATCHES: whether the subject string matches the regex in `value`.
+///
+/// The pattern is unanchored, so use `^...$` to match the whole string. Null
+/// yields `false`, like VALIDATE_BSN. An invalid or oversized pattern is an
+/// error; see [`compile_regex`].
+fn execute_matches<R: ValueResolver>(
+    subject: &ActionValue,
+    value: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let pattern_val = evaluate_value(value, resolver, depth)?;
+    if let Some(tainted) = propagate_binary(&subject_val, &pattern_val) {
+        return Ok(tainted);
+    }
+    let pattern = match &pattern_val {
+        Value::String(s) => s,
+        other => return Err(type_error("regex pattern string", other)),
+    };
+    let regex = resolver.regex(pattern)?;
+    match &subject_val {
+        Value::Null => Ok(Value::Bool(false)),
+        Value::String(s) => Ok(Value::Bool(regex.is_match(s))),
+        other => Err(type_error("string", other)),
+    }
+}
+
+/// Compile a MATCHES pattern within the configured limits.
+///
+/// Rejects patterns longer than [`config::MAX_REGEX_PATTERN_LENGTH`] and
+/// patterns whose compiled form exceeds [`config::MAX_REGEX_COMPILED_SIZE`].
+/// Matching itself runs in linear time, so accepted patterns cannot cause
+/// catastrophic backtracking.
+pub(crate) fn compile_regex(pattern: &str) -> Result<Regex> {
+    if pattern.len() > config::MAX_REGEX_PATTERN_LENGTH {
+        return Err(EngineError::InvalidOperation(format!(
+            "MATCHES pattern is {} bytes, the limit is {}",
+            pattern.len(),
+            config::MAX_REGEX_PATTERN_LENGTH
+        )));
+    }
+    RegexBuilder::new(pattern)
+        .size_limit(config::MAX_REGEX_COMPILED_SIZE)
+        .build()
+        .map_err(|e| {
+            EngineError::InvalidOperation(format!("MATCHES: invalid pattern '{}': {}", pattern, e))
+        })
+}
+
+/// Execute CAST: coerce `subject` to the type named by `value`.
+///
+/// Supported targets are `int`, `float`, `string`, `bool` and `date`. Only
+/// lossless conversions succeed: `"42"` and `42.0` become `42`, but `"abc"`
+/// and `4.2` do not cast to `int`, nor integers beyond ±2^53 to `float`.
+/// Strings cast to `bool` when they read `true`/`false` (case-insensitive);
+/// `date` accepts ISO date strings and referencedate objects and yields a
+/// `YYYY-MM-DD` string. A subject that does not convert is a type mismatch.
+/// Null passes through unchanged so missing data stays missing.
+fn execute_cast<R: ValueResolver>(
+    subject: &ActionValue,
+    value: &ActionValue,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let target_val = evaluate_value(value, resolver, depth)?;
+
+    if let Some(tainted) = propagate_binary(&subject_val, &target_val) {
+        return Ok(tainted);
+    }
+    let target = match &target_val {
+        Value::String(s) => s.as_str(),
+        other => return Err(type_error("type name string", other)),
+    };
+    if subject_val.is_null() {
+        return Ok(Value::Null);
+    }
+
+    match target {
+        "int" => match &subject_val {
+            Value::Int(_) => Ok(subject_val),
+            Value::Float(f) if f.fract() == 0.0 => Ok(Value::Int(f64_to_i64_safe(*f)?)),
+            Value::String(s) => s
+                .trim()
+                .parse::<i64>()
+                .map(Value::Int)
+                .map_err(|_| type_error("integer", &subject_val)),
+            other => Err(type_error("integer", other)),
+        },
+        "float" => match &subject_val {
+            Value::Float(_) => Ok(subject_val),
+            Value::Int(i) if i.unsigned_abs() <= F64_EXACT_INTEGER.unsigned_abs() => {
+                Ok(Value::Float(*i as f64))
+            }
+            Value::String(s) => s
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|f| f.is_finite())
+                .map(Value::Float)
+                .ok_or_else(|| type_error("float", &subject_val)),
+            other => Err(type_error("float", other)),
+        },
+        "string" => match &subject_val {
+            Value::String(_) => Ok(subject_val),
+            Value::Int(_) | Value::Float(_) | Value::Bool(_) => {
+                Ok(Value::String(subject_val.to_string()))
+            }
+            other => Err(type_error("string", other)),
+        },
+        "bool" => match &subject_val {
+            Value::Bool(_) => Ok(subject_val),
+            Value::String(s) if s.trim().eq_ignore_ascii_case("true") => Ok(Value::Bool(true)),
+            Value::String(s) if s.trim().eq_ignore_ascii_case("false") => Ok(Value::Bool(false)),
+            other => Err(type_error("boolean", other)),
+        },
+        "date" => {
+            let date = parse_date(&subject_val).map_err(|_| type_error("date", &subject_val))?;
+            Ok(Value::String(date.format("%Y-%m-%d").to_string()))
+        }
+        other => Err(EngineError::InvalidOperation(format!(
+            "CAST target type must be one of int, float, string, bool, date; got '{}'",
+            other
+        ))),
+    }
+}
+
+/// Tokens PARSE_BOOL reads as true when the operation lists none.
+const DEFAULT_TRUE_TOKENS: &[&str] = &["ja", "waar", "true", "yes"];
+/// Tokens PARSE_BOOL reads as false when the operation lists none.
+const DEFAULT_FALSE_TOKENS: &[&str] = &["nee", "onwaar", "false", "no"];
+
+/// Execute PARSE_BOOL: read a yes/no string such as `"ja"` or `"nee"` as a
+/// boolean.
+///
+/// Tokens match case-insensitively after trimming. `true_values` and
+/// `false_values` (arrays of strings) replace the default Dutch and English
+/// tokens. Booleans and null pass through unchanged; an unrecognized token
+/// is an error rather than silently false.
+fn execute_parse_bool<R: ValueResolver>(
+    subject: &ActionValue,
+    true_values: Option<&ActionValue>,
+    false_values: Option<&ActionValue>,
+    resolver: &R,
+    depth: usize,
+) -> Result<Value> {
+    let subject_val = evaluate_value(subject, resolver, depth)?;
+    let token = match &subject_val {
+        Value::Untranslatable { .. } | Value::Bool(_) | Value::Null => return Ok(subject_val),
+        Value::String(s) => s.trim(),
+        other => return Err(type_error("yes/no string", other)),
+    };
+
+    let tokens = |values: Option<&ActionValue>, defaults: &[&str]| -> Result<Vec<String>> {
+        let Some(values) = values else {
+            return Ok(defaults.iter().map(|t| t.to_string()).collect());
+        };
+        match evaluate_value(values, resolver, depth)? {
+            Value::Array(items) => items
+                .iter()
+                .map(|item| match item {
+                    Value::String(s) => Ok(s.trim().to_string()),
+                    other => Err(type_error("string token", other)),
+                })
+                .collect(),
+            other => Err(type_error("array of tokens", &other)),
+        }
+    };
+    let matches = |candidates: &[String]| candidates.iter().any(|t| t.eq_ignore_ascii_case(token));
+
+    if matches(&tokens(true_values, DEFAULT_TRUE_TOKENS)?) {
+        Ok(Value::Bool(true))
+    } else if matches(&tokens(false_values, DEFAULT_FALSE_TOKENS)?) {
+        Ok(Value::Bool(false))
+    } else {
+        Err(EngineError::InvalidOperation(format!(
+            "PARSE_BOOL: '{}' is not a recognized yes/no value",
+            token
+        )))
+    }
+}
+
+// =============================================================================
+// Formula Operations
+// =============================================================================
+
+/// Execute CALL_FORMULA operation: evaluate a named formula.
+///
+/// The formula is evaluated in the caller's scope, so it sees the same
+/// parameters, definitions and outputs. A formula that (indirectly) calls
+/// itself fails with `CircularReference`.
+fn execute_call_formula<R: ValueResolver>(name: &str, resolver: &R, depth: usize) -> Result<Value> {
+    let formula = resolver
+        .formula(name)
+        .ok_or_else(|| EngineError::InvalidOperation(format!("Unknown formula '{}'", name)))?;
+    resolver.enter_formula(name)?;
+    let result = evaluate_value(formula, resolver, depth);
+    resolver.leave_formula();
+    result
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Safely convert f64 to i64, returning error on overflow/NaN/Infinity
+pub(crate) fn f64_to_i64_safe(f: f64) -> Result<i64> {
+    if f.is_nan() {
+        return Err(EngineError::ArithmeticOverflow("Result is NaN".to_string()));
+    }
+    if f.is_infinite() {
+        return Err(EngineError::ArithmeticOverflow(
+            "Result is infinite".to_string(),
+        ));
+    }
+
+    // i64::MIN as f64 is exact (-9223372036854775808.0).
+    // i64::MAX as f64 rounds UP to 9223372036854775808.0, which is i64::MAX + 1.
+    // Using strict < on the upper bound prevents accepting that rounded-up value,
+    // which would saturate to i64::MAX on `as i64` (semantically wrong).
+    const I64_MIN_F64: f64 = i64::MIN as f64;
+    const I64_MAX_F64: f64 = i64::MAX as f64;
+
+    if !(I64_MIN_F64..I64_MAX_F64).contains(&f) {
+        return Err(EngineError::ArithmeticOverflow(format!(
+            "Value {} exceeds i64 range",
+            f
+        )));
+    }
+
+    Ok(f as i64)
+}
+
+/// Evaluate a slice of ActionValues to concrete Values.
+fn evaluate_values<R: ValueResolver>(
+    values: &[ActionValue],
+    resolver: &R,
+    depth: usize,
+) -> Result<Vec<Value>> {
+    values
+        .iter()
+        .map(|v| evaluate_value(v, resolver, depth))
+        .collect()
+}
+
+/// Convert a Value to a number (f64).
+///
+/// # Precision
+///
+/// For integers larger than 2^53 or smaller than -2^53, precision is lost
+/// when converting to f64. This function returns an error for integers whose
+/// magnitude exceeds `max_safe_integer` to prevent silent precision loss in
+/// financial/legal calculations. A threshold configured above 2^53 via
+/// [`EvaluationOptions`] is capped at 2^53 here; only exact integer paths
+/// accept larger values.
+fn to_number(val: &Value, max_safe_integer: i64) -> Result<f64> {
+    match val {
+        Value::Int(i) => {
+            // Check if integer is within safe range for f64
+            let limit = max_safe_integer
+                .unsigned_abs()
+                .min(F64_EXACT_INTEGER as u64);
+            if i.unsigned_abs() > limit {
+                return Err(EngineError::ArithmeticOverflow(format!(
+                    "Integer {} exceeds safe range for floating-point conversion (±{})",
+                    i, limit
+                )));
+            }
+            Ok(*i as f64)
+        }
+        Value::Float(f) => Ok(*f),
+        // Untranslatable should be caught by the caller before reaching to_number,
+        // but handle it gracefully.
+        Value::Untranslatable { .. } => Err(type_error("number", val)),
+        _ => Err(type_error("number", val)),
+    }
+}
+
+/// Check that an integer operand lies within `max_safe_integer`.
+fn checked_int(i: i64, max_safe_integer: i64) -> Result<i64> {
+    if i.unsigned_abs() > max_safe_integer.unsigned_abs() {
+        return Err(EngineError::ArithmeticOverflow(format!(
+            "Integer {} exceeds safe range (±{})",
+            i, max_safe_integer
+        )));
+    }
+    Ok(i)
+}
+
+/// The integers in `values`, or `None` when any of them is not an integer.
+fn all_ints(values: &[Value]) -> Option<Vec<i64>> {
+    values
+        .iter()
+        .map(|v| match v {
+            Value::Int(i) => Some(*i),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Add `item` to `sum` in exact integer arithmetic, rejecting an item beyond
+/// `max_safe_integer` and a sum beyond the i64 range.
+fn checked_int_add(op_name: &str, sum: i64, item: i64, max_safe_integer: i64) -> Result<i64> {
+    sum.checked_add(checked_int(item, max_safe_integer)?)
+        .ok_or_else(|| {
+            EngineError::ArithmeticOverflow(format!("{} overflow: {} + {}", op_name, sum, item))
+        })
+}
+
+/// Sum integers exactly, as [`checked_int_add`] does for each of them.
+fn checked_int_sum(op_name: &str, ints: Vec<i64>, max_safe_integer: i64) -> Result<i64> {
+    ints.into_iter().try_fold(0, |sum, i| {
+        checked_int_add(op_name, sum, i, max_safe_integer)
+    })
+}
+
+/// Add `item` (operand `index` of `op_name`) to a running `total` the way ADD
+/// does: exactly while both are integers, as floats once either is a float.
+fn add_to_total(
+    op_name: &str,
+    index: usize,
+    total: &Value,
+    item: &Value,
+    max_safe_integer: i64,
+) -> Result<Value> {
+    match (total, item) {
+        (Value::Int(sum), Value::Int(i)) => {
+            checked_int_add(op_name, *sum, *i, max_safe_integer).map(Value::Int)
+        }
+        _ => Ok(Value::Float(
+            to_number(total, max_safe_integer)?
+                + operand_to_number(op_name, index, item, max_safe_integer)?,
+        )),
+    }
+}
+
+/// Convert an operand of an arithmetic operation to a number, naming the
+/// operation and (zero-based) operand position when it is not numeric.
+///
+/// The most common cause is a variable that resolved to a date string, so
+/// such operands get a hint pointing to the matching date operations.
+fn operand_to_number(
+    op_name: &str,
+    index: usize,
+    val: &Value,
+    max_safe_integer: i64,
+) -> Result<f64> {
+    match val {
+        Value::Int(_) | Value::Float(_) => to_number(val, max_safe_integer),
+        Value::String(s) if NaiveDate::parse_from_str(s, "%Y-%m-%d").is_ok() => {
+            let date_ops = match op_name {
+                "MAX" | "MIN" => "MAX_DATE or MIN_DATE",
+                _ => "SUBTRACT_DATE or DATE_ADD",
+            };
+            Err(EngineError::TypeMismatch {
+                expected: format!("number for {op_name} operand {index}"),
+                actual: format!("date string '{s}' (use {date_ops} for dates)"),
+            })
+        }
+        _ => Err(EngineError::TypeMismatch {
+            expected: format!("number for {op_name} operand {index}"),
+            actual: val.type_name().to_string(),
+        }),
+    }
+}
+
+/// Create a TypeMismatch error.
+fn type_error(expected: &str, actual: &Value) -> EngineError {
+    EngineError::TypeMismatch {
+        expected: expected.to_string(),
+        actual: actual.type_name().to_string(),
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    /// Simple resolver for testing that uses a HashMap
+    struct TestResolver {
+        vars: HashMap<String, Value>,
+        options: EvaluationOptions,
+    }
+
+    impl TestResolver {
+        fn new() -> Self {
+            Self {
+                vars: HashMap::new(),
+                options: EvaluationOptions::default(),
+            }
+        }
+
+        fn with_var(mut self, name: &str, value: impl Into<Value>) -> Self {
+            self.vars.insert(name.to_string(), value.into());
+            self
+        }
+
+        fn with_options(mut self, options: EvaluationOptions) -> Self {
+            self.options = options;
+            self
+        }
+    }
+
+    impl ValueResolver for TestResolver {
+        fn resolve(&self, name: &str) -> Result<Value> {
+            self.vars
+                .get(name)
+                .cloned()
+                .ok_or_else(|| EngineError::VariableNotFound(name.to_string()))
+        }
+
+        fn options(&self) -> &EvaluationOptions {
+            &self.options
+        }
+    }
+
+    /// Helper to create a literal ActionValue
+    fn lit(v: impl Into<Value>) -> ActionValue {
+        ActionValue::Literal(v.into())
+    }
+
+    /// Helper to create a variable reference
+    fn var(name: &str) -> ActionValue {
+        ActionValue::Literal(Value::String(format!("${}", name)))
+    }
+
+    // -------------------------------------------------------------------------
+    // Comparison Operations Tests
+    // -------------------------------------------------------------------------
+
+    mod comparison {
+        use super::*;
+
+        #[test]
+        fn test_equals_integers() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Equals {
+                subject: lit(42i64),
+                value: lit(42i64),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+
+        #[test]
+        fn test_equals_different_values() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Equals {
+                subject: lit(42i64),
+                value: lit(43i64),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(false));
+        }
+
+        fn approx_equals(subject: f64, value: f64, tolerance: Option<f64>) -> Value {
+            let op = ActionOperation::ApproxEquals {
+                subject: lit(subject),
+                value: lit(value),
+                tolerance: tolerance.map(lit),
+            };
+            execute_operation(&op, &TestResolver::new(), 0).unwrap()
+        }
+
+        #[test]
+        fn test_approx_equals_within_tolerance() {
+            assert_eq!(approx_equals(1000.0, 1000.4, Some(0.5)), Value::Bool(true));
+            assert_eq!(approx_equals(1000.5, 1000.0, Some(0.5)), Value::Bool(true));
+        }
+
+        #[test]
+        fn test_approx_equals_outside_tolerance() {
+            assert_eq!(approx_equals(1000.0, 1000.6, Some(0.5)), Value::Bool(false));
+        }
+
+        #[test]
+        fn test_approx_equals_zero_tolerance_is_exact() {
+            assert_eq!(approx_equals(21.12, 21.12, None), Value::Bool(true));
+            assert_eq!(approx_equals(21.12, 21.13, None), Value::Bool(false));
+            assert_eq!(approx_equals(7.0, 7.0, Some(0.0)), Value::Bool(true));
+        }
+
+        #[test]
+        fn test_greater_than() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::GreaterThan {
+                subject: lit(50i64),
+                value: lit(42i64),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+
+        #[test]
+        fn test_less_than() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::LessThan {
+                subject: lit(30i64),
+                value: lit(42i64),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+
+        #[test]
+        fn test_greater_than_or_equal() {
+            let resolver = TestResolver::new();
+
+            let op = ActionOperation::GreaterThanOrEqual {
+                subject: lit(42i64),
+                value: lit(42i64),
+            };
+            assert_eq!(
+                execute_operation(&op, &resolver, 0).unwrap(),
+                Value::Bool(true)
+            );
+
+            let op2 = ActionOperation::GreaterThanOrEqual {
+                subject: lit(50i64),
+                value: lit(42i64),
+            };
+            assert_eq!(
+                execute_operation(&op2, &resolver, 0).unwrap(),
+                Value::Bool(true)
+            );
+        }
+
+        #[test]
+        fn test_less_than_or_equal() {
+            let resolver = TestResolver::new();
+
+            let op = ActionOperation::LessThanOrEqual {
+                subject: lit(42i64),
+                value: lit(42i64),
+            };
+            assert_eq!(
+                execute_operation(&op, &resolver, 0).unwrap(),
+                Value::Bool(true)
+            );
+
+            let op2 = ActionOperation::LessThanOrEqual {
+                subject: lit(30i64),
+                value: lit(42i64),
+            };
+            assert_eq!(
+                execute_operation(&op2, &resolver, 0).unwrap(),
+                Value::Bool(true)
+            );
+        }
+
+        #[test]
+        fn test_comparison_with_variables() {
+            let resolver = TestResolver::new()
+                .with_var("age", 25i64)
+                .with_var("min_age", 18i64);
+
+            let op = ActionOperation::GreaterThanOrEqual {
+                subject: var("age"),
+                value: var("min_age"),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+
+        #[test]
+        fn test_comparison_mixed_int_float() {
+            let resolver = TestResolver::new();
+
+            let op = ActionOperation::Equals {
+                subject: lit(42i64),
+                value: lit(42.0f64),
+            };
+            assert_eq!(
+                execute_operation(&op, &resolver, 0).unwrap(),
+                Value::Bool(true)
+            );
+
+            let op2 = ActionOperation::Equals {
+                subject: lit(42.0f64),
+                value: lit(42i64),
+            };
+            assert_eq!(
+                execute_operation(&op2, &resolver, 0).unwrap(),
+                Value::Bool(true)
+            );
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Arithmetic Operations Tests
+    // -------------------------------------------------------------------------
+
+    mod arithmetic {
+        use super::*;
+
+        fn compound_index(base: Value, factors: Vec<Value>) -> Result<Value> {
+            let op = ActionOperation::CompoundIndex {
+                value: lit(base),
+                factors: ActionValue::Literal(Value::Array(factors)),
+            };
+            execute_operation(&op, &TestResolver::new(), 0)
+        }
+
+        #[test]
+        fn test_compound_index_multiplies_factors() {
+            let result = compound_index(
+                Value::Int(1000),
+                vec![Value::Float(1.02), Value::Float(1.035)],
+            )
+            .unwrap();
+            let Value::Float(f) = result else {
+                panic!("expected float, got {:?}", result);
+            };
+            assert!((f - 1055.7).abs() < 1e-9, "got {}", f);
+        }
+
+        fn round_to_multiple(subject: Value, multiple: Value, rounding: &str) -> Result<Value> {
+            let op = ActionOperation::RoundToMultiple {
+                subject: lit(subject),
+                value: lit(multiple),
+                rounding: Some(lit(rounding)),
+            };
+            execute_operation(&op, &TestResolver::new(), 0)
+        }
+
+        #[test]
+        fn test_round_to_multiple_of_five() {
+            let round = |subject: i64, rounding: &str| {
+                round_to_multiple(Value::Int(subject), Value::Int(5), rounding).unwrap()
+            };
+            assert_eq!(round(12, "nearest"), Value::Int(10));
+            assert_eq!(round(12, "floor"), Value::Int(10));
+            assert_eq!(round(12, "ceil"), Value::Int(15));
+            assert_eq!(round(13, "nearest"), Value::Int(15));
+            assert_eq!(round(15, "ceil"), Value::Int(15));
+            // Ties round away from zero
+            assert_eq!(
+                round_to_multiple(Value::Int(-15), Value::Int(10), "nearest").unwrap(),
+                Value::Int(-20)
+            );
+            assert_eq!(
+                round_to_multiple(Value::Int(15), Value::Int(10), "nearest").unwrap(),
+                Value::Int(20)
+            );
+        }
+
+        #[test]
+        fn test_round_to_multiple_of_ten_cents() {
+            let round = |subject: f64, rounding: &str| {
+                round_to_multiple(Value::Float(subject), Value::Float(0.10), rounding).unwrap()
+            };
+            assert_eq!(round(12.34, "nearest"), Value::Float(12.3));
+            assert_eq!(round(12.35, "nearest"), Value::Float(12.4));
+            assert_eq!(round(12.31, "ceil"), Value::Float(12.4));
+            assert_eq!(round(12.39, "floor"), Value::Float(12.3));
+            assert_eq!(round(0.3, "floor"), Value::Float(0.3));
+        }
+
+        #[test]
+        fn test_round_to_multiple_half_away_versus_half_even() {
+            let round = |subject: f64, rounding: &str| {
+                round_to_multiple(Value::Float(subject), Value::Int(1), rounding).unwrap()
+            };
+            assert_eq!(round(2.5, "half_away"), Value::Float(3.0));
+            assert_eq!(round(-2.5, "half_away"), Value::Float(-3.0));
+            assert_eq!(round(2.5, "half_even"), Value::Float(2.0));
+            assert_eq!(round(-2.5, "half_even"), Value::Float(-2.0));
+            assert_eq!(round(3.5, "half_even"), Value::Float(4.0));
+            assert_eq!(round(2.4, "half_away"), Value::Float(2.0));
+
+            // Integer operands: ties at 25 and -25 when rounding to 10
+            let round_int = |subject: i64, rounding: &str| {
+                round_to_multiple(Value::Int(subject), Value::Int(10), rounding).unwrap()
+            };
+            assert_eq!(round_int(25, "half_away"), Value::Int(30));
+            assert_eq!(round_int(-25, "half_away"), Value::Int(-30));
+            assert_eq!(round_int(25, "half_even"), Value::Int(20));
+            assert_eq!(round_int(-25, "half_even"), Value::Int(-20));
+            assert_eq!(round_int(35, "half_even"), Value::Int(40));
+        }
+
+        #[test]
+        fn test_round_to_multiple_rejects_invalid_multiple_and_mode() {
+            assert!(matches!(
+                round_to_multiple(Value::Int(12), Value::Int(0), "nearest"),
+                Err(EngineError::InvalidOperation(_))
+            ));
+            assert!(matches!(
+                round_to_multiple(Value::Int(12), Value::Int(5), "banker"),
+                Err(EngineError::InvalidOperation(_))
+            ));
+        }
+
+        fn linear_taper(amount: Value, income: Value, threshold: Value, rate: Value) -> Value {
+            let op = ActionOperation::LinearTaper {
+                amount: lit(amount),
+                income: lit(income),
+                threshold: lit(threshold),
+                rate: lit(rate),
+            };
+            execute_operation(&op, &TestResolver::new(), 0).unwrap()
+        }
+
+        #[test]
+        fn test_linear_taper_below_threshold_keeps_full_amount() {
+            let result = linear_taper(
+                Value::Int(2_000),
+                Value::Int(25_000),
+                Value::Int(30_000),
+                Value::Float(0.1),
+            );
+            assert_eq!(result, Value::Float(2_000.0));
+            let result = linear_taper(
+                Value::Int(2_000),
+                Value::Int(30_000),
+                Value::Int(30_000),
+                Value::Int(1),
+            );
+            assert_eq!(result, Value::Int(2_000));
+        }
+
+        #[test]
+        fn test_linear_taper_partially_tapered() {
+            let result = linear_taper(
+                Value::Int(2_000),
+                Value::Int(35_000),
+                Value::Int(30_000),
+                Value::Float(0.1),
+            );
+            assert_eq!(result, Value::Float(1_500.0));
+            let result = linear_taper(
+                Value::Int(2_000),
+                Value::Int(30_500),
+                Value::Int(30_000),
+                Value::Int(2),
+            );
+            assert_eq!(result, Value::Int(1_000));
+        }
+
+        #[test]
+        fn test_linear_taper_floors_at_zero() {
+            let result = linear_taper(
+                Value::Int(2_000),
+                Value::Int(60_000),
+                Value::Int(30_000),
+                Value::Float(0.1),
+            );
+            assert_eq!(result, Value::Float(0.0));
+            let result = linear_taper(
+                Value::Int(2_000),
+                Value::Int(40_000),
+                Value::Int(30_000),
+                Value::Int(1),
+            );
+            assert_eq!(result, Value::Int(0));
+
+            let op = ActionOperation::LinearTaper {
+                amount: lit(2_000i64),
+                income: lit(40_000i64),
+                threshold: lit(30_000i64),
+                rate: lit(-0.1),
+            };
+            assert!(matches!(
+                execute_operation(&op, &TestResolver::new(), 0),
+                Err(EngineError::InvalidOperation(_))
+            ));
+        }
+
+        fn clamp(subject: Value, min: Value, max: Value) -> Result<Value> {
+            let op = ActionOperation::Clamp {
+                subject: lit(subject),
+                min: lit(min),
+                max: lit(max),
+            };
+            execute_operation(&op, &TestResolver::new(), 0)
+        }
+
+        #[test]
+        fn test_clamp_between_floor_and_cap() {
+            let range = |subject: i64| clamp(Value::Int(subject), Value::Int(20), Value::Int(100));
+            assert_eq!(range(5).unwrap(), Value::Int(20));
+            assert_eq!(range(50).unwrap(), Value::Int(50));
+            assert_eq!(range(250).unwrap(), Value::Int(100));
+            assert_eq!(
+                clamp(Value::Float(12.5), Value::Int(20), Value::Int(100)).unwrap(),
+                Value::Float(20.0)
+            );
+            // A null bound leaves that side open
+            assert_eq!(
+                clamp(Value::Int(250), Value::Int(20), Value::Null).unwrap(),
+                Value::Int(250)
+            );
+            assert!(matches!(
+                clamp(Value::Int(50), Value::Int(100), Value::Int(20)),
+                Err(EngineError::InvalidOperation(_))
+            ));
+        }
+
+        fn progressive_brackets(income: i64) -> Value {
+            let bracket = |upper: Value, rate: f64| {
+                Value::Object(BTreeMap::from([
+                    ("upper".to_string(), upper),
+                    ("rate".to_string(), Value::Float(rate)),
+                ]))
+            };
+            let op = ActionOperation::ProgressiveBrackets {
+                subject: lit(income),
+                brackets: ActionValue::Literal(Value::Array(vec![
+                    bracket(Value::Int(40_000), 0.25),
+                    bracket(Value::Null, 0.5),
+                ])),
+            };
+            execute_operation(&op, &TestResolver::new(), 0).unwrap()
+        }
+
+        #[test]
+        fn test_progressive_brackets_two_bracket_schedule() {
+            // Below the top bracket: only the first rate applies
+            assert_eq!(progressive_brackets(20_000), Value::Float(5_000.0));
+            // At the boundary
+            assert_eq!(progressive_brackets(40_000), Value::Float(10_000.0));
+            // Into the top bracket: 40_000 * 0.25 + 20_000 * 0.5
+            assert_eq!(progressive_brackets(60_000), Value::Float(20_000.0));
+            assert_eq!(progressive_brackets(0), Value::Float(0.0));
+        }
+
+        #[test]
+        fn test_progressive_brackets_rejects_malformed_schedule() {
+            let bracket = |upper: Value| {
+                Value::Object(BTreeMap::from([
+                    ("upper".to_string(), upper),
+                    ("rate".to_string(), Value::Float(0.1)),
+                ]))
+            };
+            for brackets in [
+                vec![bracket(Value::Null), bracket(Value::Int(10))],
+                vec![bracket(Value::Int(20)), bracket(Value::Int(10))],
+            ] {
+                let op = ActionOperation::ProgressiveBrackets {
+                    subject: lit(15i64),
+                    brackets: ActionValue::Literal(Value::Array(brackets)),
+                };
+                assert!(matches!(
+                    execute_operation(&op, &TestResolver::new(), 0),
+                    Err(EngineError::InvalidOperation(_))
+                ));
+            }
+        }
+
+        fn negate(subject: Value) -> Result<Value> {
+            let op = ActionOperation::Negate {
+                subject: lit(subject),
+            };
+            execute_operation(&op, &TestResolver::new(), 0)
+        }
+
+        #[test]
+        fn test_negate_preserves_type() {
+            assert_eq!(negate(Value::Int(2_112)).unwrap(), Value::Int(-2_112));
+            assert_eq!(negate(Value::Int(-5)).unwrap(), Value::Int(5));
+            assert_eq!(negate(Value::Float(21.12)).unwrap(), Value::Float(-21.12));
+            assert_eq!(negate(Value::Null).unwrap(), Value::Null);
+        }
+
+        #[test]
+        fn test_negate_min_int_overflows() {
+            assert!(matches!(
+                negate(Value::Int(i64::MIN)),
+                Err(EngineError::ArithmeticOverflow(_))
+            ));
+            assert_eq!(negate(Value::Int(i64::MAX)).unwrap(), Value::Int(-i64::MAX));
+        }
+
+        #[test]
+        fn test_compound_index_without_factors_returns_base() {
+            assert_eq!(
+                compound_index(Value::Int(1000), vec![]).unwrap(),
+                Value::Float(1000.0)
+            );
+            assert!(matches!(
+                compound_index(Value::Int(i64::MAX), vec![]),
+                Err(EngineError::ArithmeticOverflow(_))
+            ));
+        }
+
+        #[test]
+        fn test_empty_add_and_multiply_error_by_default() {
+            let resolver = TestResolver::new();
+            let ops = [
+                ActionOperation::Add {
+                    values: vec![],
+                    allow_empty: false,
+                },
+                ActionOperation::Multiply {
+                    values: vec![],
+                    allow_empty: false,
+                },
+            ];
+            for op in &ops {
+                let result = execute_operation(op, &resolver, 0);
+                assert!(matches!(result, Err(EngineError::InvalidOperation(_))));
+            }
+        }
+
+        #[test]
+        fn test_empty_add_allowed_returns_zero() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Add {
+                values: vec![],
+                allow_empty: true,
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(0));
+        }
+
+        #[test]
+        fn test_empty_multiply_allowed_returns_one() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Multiply {
+                values: vec![],
+                allow_empty: true,
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(1));
+        }
+
+        #[test]
+        fn test_add_integers() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Add {
+                values: vec![lit(10i64), lit(20i64), lit(30i64)],
+                allow_empty: false,
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(60));
+        }
+
+        #[test]
+        fn test_add_with_floats() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Add {
+                values: vec![lit(10i64), lit(20.5f64), lit(30i64)],
+                allow_empty: false,
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Float(60.5));
+        }
+
+        #[test]
+        fn test_subtract() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Subtract {
+                values: vec![lit(100i64), lit(30i64), lit(20i64)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(50));
+        }
+
+        #[test]
+        fn test_multiply() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Multiply {
+                values: vec![lit(2i64), lit(3i64), lit(4i64)],
+                allow_empty: false,
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(24));
+        }
+
+        #[test]
+        fn test_divide() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Divide {
+                values: vec![lit(100i64), lit(2i64)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Float(50.0));
+        }
+
+        fn divide_with(mode: IntegerDivision, a: i64, b: i64) -> Value {
+            let resolver = TestResolver::new().with_options(EvaluationOptions {
+                integer_division: mode,
+                ..EvaluationOptions::DEFAULT
+            });
+            let op = ActionOperation::Divide {
+                values: vec![lit(a), lit(b)],
+            };
+            execute_operation(&op, &resolver, 0).unwrap()
+        }
+
+        #[test]
+        fn test_divide_integers_floor_and_ceil() {
+            assert_eq!(divide_with(IntegerDivision::Floor, 7, 2), Value::Int(3));
+            assert_eq!(divide_with(IntegerDivision::Floor, -7, 2), Value::Int(-4));
+            assert_eq!(divide_with(IntegerDivision::Ceil, 7, 2), Value::Int(4));
+            assert_eq!(divide_with(IntegerDivision::Ceil, -7, 2), Value::Int(-3));
+            assert_eq!(divide_with(IntegerDivision::Ceil, 6, -3), Value::Int(-2));
+            // Default keeps the float result
+            assert_eq!(divide_with(IntegerDivision::Float, 7, 2), Value::Float(3.5));
+        }
+
+        #[test]
+        fn test_divide_integers_half_even() {
+            assert_eq!(divide_with(IntegerDivision::HalfEven, 5, 2), Value::Int(2));
+            assert_eq!(divide_with(IntegerDivision::HalfEven, 7, 2), Value::Int(4));
+            assert_eq!(
+                divide_with(IntegerDivision::HalfEven, -5, 2),
+                Value::Int(-2)
+            );
+            assert_eq!(divide_with(IntegerDivision::HalfEven, 8, 3), Value::Int(3));
+        }
+
+        #[test]
+        fn test_divide_integer_mode_keeps_float_operands() {
+            let resolver = TestResolver::new().with_options(EvaluationOptions {
+                integer_division: IntegerDivision::Floor,
+                ..EvaluationOptions::DEFAULT
+            });
+            let op = ActionOperation::Divide {
+                values: vec![lit(7.0), lit(2i64)],
+            };
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Float(3.5));
+        }
+
+        #[test]
+        fn test_divide_by_zero() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Divide {
+                values: vec![lit(100i64), lit(0i64)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::DivisionByZero)));
+        }
+
+        #[test]
+        fn test_int_divide_floors() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::IntDivide {
+                values: vec![lit(7i64), lit(2i64)],
+            };
+            assert_eq!(execute_operation(&op, &resolver, 0).unwrap(), Value::Int(3));
+
+            let op = ActionOperation::IntDivide {
+                values: vec![lit(-7i64), lit(2i64)],
+            };
+            assert_eq!(
+                execute_operation(&op, &resolver, 0).unwrap(),
+                Value::Int(-4)
+            );
+
+            let op = ActionOperation::IntDivide {
+                values: vec![lit(7.5f64), lit(2i64)],
+            };
+            assert_eq!(execute_operation(&op, &resolver, 0).unwrap(), Value::Int(3));
+        }
+
+        #[test]
+        fn test_int_divide_by_zero() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::IntDivide {
+                values: vec![lit(7i64), lit(0i64)],
+            };
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::DivisionByZero)));
+
+            let op = ActionOperation::IntDivide {
+                values: vec![lit(7i64), lit(0.0f64)],
+            };
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::DivisionByZero)));
+        }
+
+        #[test]
+        fn test_arithmetic_with_variables() {
+            let resolver = TestResolver::new()
+                .with_var("base", 1000i64)
+                .with_var("rate", 0.05f64);
+
+            let op = ActionOperation::Multiply {
+                values: vec![var("base"), var("rate")],
+                allow_empty: false,
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Float(50.0));
+        }
+
+        #[test]
+        fn test_add_arrays() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Add {
+                values: vec![
+                    lit(Value::Array(vec![Value::Int(1), Value::Int(2)])),
+                    lit(Value::Array(vec![Value::Int(3), Value::Int(4)])),
+                ],
+                allow_empty: false,
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(
+                result,
+                Value::Array(vec![
+                    Value::Int(1),
+                    Value::Int(2),
+                    Value::Int(3),
+                    Value::Int(4)
+                ])
+            );
+        }
+
+        #[test]
+        fn test_add_strings() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Add {
+                values: vec![lit("hello"), lit(" "), lit("world")],
+                allow_empty: false,
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::String("hello world".to_string()));
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Aggregate Operations Tests
+    // -------------------------------------------------------------------------
+
+    mod aggregate {
+        use super::*;
+
+        #[test]
+        fn test_max() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Max {
+                values: vec![lit(10i64), lit(50i64), lit(30i64)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(50));
+        }
+
+        #[test]
+        fn test_min() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Min {
+                values: vec![lit(10i64), lit(50i64), lit(30i64)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(10));
+        }
+
+        #[test]
+        fn test_max_with_floats() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Max {
+                values: vec![lit(10.5f64), lit(50.3f64), lit(30.7f64)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Float(50.3));
+        }
+
+        #[test]
+        fn test_max_with_zero() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Max {
+                values: vec![lit(0i64), lit(-10i64)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(0));
+        }
+
+        #[test]
+        fn test_max_rejects_date_string() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Max {
+                values: vec![lit(10i64), lit("2025-01-01")],
+            };
+
+            let err = execute_operation(&op, &resolver, 0).unwrap_err();
+            match err {
+                EngineError::TypeMismatch { expected, actual } => {
+                    assert_eq!(expected, "number for MAX operand 1");
+                    assert!(actual.contains("MAX_DATE or MIN_DATE"), "{actual}");
+                }
+                other => panic!("expected TypeMismatch, got {other:?}"),
+            }
+        }
+
+        fn list(items: Vec<Value>) -> ActionValue {
+            ActionValue::Literal(Value::Array(items))
+        }
+
+        #[test]
+        fn test_weighted_average() {
+            // Two months at 1000 and one month at 2500: (2000 + 2500) / 3 = 1500
+            let resolver = TestResolver::new().with_var(
+                "inkomens",
+                Value::Array(vec![Value::Int(1000), Value::Int(2500)]),
+            );
+            let op = ActionOperation::WeightedAverage {
+                values: var("inkomens"),
+                weights: list(vec![Value::Int(2), Value::Int(1)]),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Float(1500.0));
+        }
+
+        #[test]
+        fn test_weighted_average_length_mismatch() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::WeightedAverage {
+                values: list(vec![Value::Int(10), Value::Int(20)]),
+                weights: list(vec![Value::Int(1)]),
+            };
+
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::InvalidOperation(_))));
+        }
+
+        #[test]
+        fn test_weighted_average_zero_total_weight() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::WeightedAverage {
+                values: list(vec![Value::Int(10), Value::Int(20)]),
+                weights: list(vec![Value::Int(0), Value::Float(0.0)]),
+            };
+
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::DivisionByZero)));
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Logical Operations Tests
+    // -------------------------------------------------------------------------
+
+    mod logical {
+        use super::*;
+
+        #[test]
+        fn test_and_all_true() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::And {
+                conditions: vec![lit(true), lit(true), lit(true)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+
+        #[test]
+        fn test_and_one_false() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::And {
+                conditions: vec![lit(true), lit(false), lit(true)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(false));
+        }
+
+        #[test]
+        fn test_or_one_true() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Or {
+                conditions: vec![lit(false), lit(true), lit(false)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+
+        #[test]
+        fn test_or_all_false() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Or {
+                conditions: vec![lit(false), lit(false), lit(false)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(false));
+        }
+
+        #[test]
+        fn test_and_with_nested_comparison() {
+            let resolver = TestResolver::new()
+                .with_var("age", 25i64)
+                .with_var("has_insurance", true);
+
+            let age_check_op = ActionOperation::GreaterThanOrEqual {
+                subject: var("age"),
+                value: lit(18i64),
+            };
+            let age_check = ActionValue::Operation(Box::new(age_check_op));
+
+            let op = ActionOperation::And {
+                conditions: vec![age_check, var("has_insurance")],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+
+        #[test]
+        fn test_not_negates_true() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Not { value: lit(true) };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(false));
+        }
+
+        #[test]
+        fn test_not_negates_false() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Not { value: lit(false) };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+
+        #[test]
+        fn test_not_wrapping_equals() {
+            let resolver = TestResolver::new();
+
+            // NOT(EQUALS(42, 42)) should be false
+            let eq_op = ActionOperation::Equals {
+                subject: lit(42i64),
+                value: lit(42i64),
+            };
+
+            let op = ActionOperation::Not {
+                value: ActionValue::Operation(Box::new(eq_op)),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(false));
+
+            // NOT(EQUALS(42, 43)) should be true
+            let eq_op2 = ActionOperation::Equals {
+                subject: lit(42i64),
+                value: lit(43i64),
+            };
+
+            let op2 = ActionOperation::Not {
+                value: ActionValue::Operation(Box::new(eq_op2)),
+            };
+
+            let result2 = execute_operation(&op2, &resolver, 0).unwrap();
+            assert_eq!(result2, Value::Bool(true));
+        }
+
+        fn non_bool_conditions() -> Vec<Value> {
+            vec![
+                Value::String("false".to_string()),
+                Value::Int(0),
+                Value::Null,
+                Value::Array(vec![]),
+            ]
+        }
+
+        #[test]
+        fn test_lenient_bool_coercion() {
+            let resolver = TestResolver::new();
+            // "false" is a non-empty string and therefore truthy
+            let expected = [true, false, false, false];
+
+            for (value, expected) in non_bool_conditions().into_iter().zip(expected) {
+                let op = ActionOperation::Not {
+                    value: ActionValue::Literal(value.clone()),
+                };
+                let result = execute_operation(&op, &resolver, 0).unwrap();
+                assert_eq!(result, Value::Bool(!expected), "NOT {:?}", value);
+
+                let op = ActionOperation::And {
+                    conditions: vec![lit(true), ActionValue::Literal(value.clone())],
+                };
+                let result = execute_operation(&op, &resolver, 0).unwrap();
+                assert_eq!(result, Value::Bool(expected), "AND {:?}", value);
+            }
+        }
+
+        #[test]
+        fn test_strict_bool_coercion_rejects_non_bool() {
+            let resolver = TestResolver::new().with_options(EvaluationOptions {
+                bool_coercion: BoolCoercion::Strict,
+                ..EvaluationOptions::DEFAULT
+            });
+
+            for value in non_bool_conditions() {
+                let ops = [
+                    ActionOperation::Not {
+                        value: ActionValue::Literal(value.clone()),
+                    },
+                    ActionOperation::And {
+                        conditions: vec![lit(true), ActionValue::Literal(value.clone())],
+                    },
+                    ActionOperation::Or {
+                        conditions: vec![lit(false), ActionValue::Literal(value.clone())],
+                    },
+                    ActionOperation::If {
+                        cases: vec![Case {
+                            when: ActionValue::Literal(value.clone()),
+                            then: lit(1i64),
+                        }],
+                        default: Some(lit(0i64)),
+                    },
+                ];
+                for op in &ops {
+                    let result = execute_operation(op, &resolver, 0);
+                    assert!(
+                        matches!(result, Err(EngineError::InvalidOperation(_))),
+                        "{} {:?} should be rejected, got {:?}",
+                        op.operation_name(),
+                        value,
+                        result
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_strict_bool_coercion_accepts_bool() {
+            let resolver = TestResolver::new().with_options(EvaluationOptions {
+                bool_coercion: BoolCoercion::Strict,
+                ..EvaluationOptions::DEFAULT
+            });
+            let op = ActionOperation::And {
+                conditions: vec![
+                    lit(true),
+                    ActionValue::Operation(Box::new(ActionOperation::Not { value: lit(false) })),
+                ],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Conditional Operations Tests (IF with cases/default)
+    // -------------------------------------------------------------------------
+
+    mod conditional {
+        use super::*;
+
+        #[test]
+        fn test_if_first_match() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::If {
+                cases: vec![
+                    Case {
+                        when: lit(true),
+                        then: lit(100i64),
+                    },
+                    Case {
+                        when: lit(true),
+                        then: lit(200i64),
+                    },
+                ],
+                default: Some(lit(0i64)),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(100));
+        }
+
+        #[test]
+        fn test_if_second_match() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::If {
+                cases: vec![
+                    Case {
+                        when: lit(false),
+                        then: lit(100i64),
+                    },
+                    Case {
+                        when: lit(true),
+                        then: lit(200i64),
+                    },
+                ],
+                default: Some(lit(0i64)),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(200));
+        }
+
+        #[test]
+        fn test_if_default() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::If {
+                cases: vec![
+                    Case {
+                        when: lit(false),
+                        then: lit(100i64),
+                    },
+                    Case {
+                        when: lit(false),
+                        then: lit(200i64),
+                    },
+                ],
+                default: Some(lit(0i64)),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(0));
+        }
+
+        #[test]
+        fn test_if_no_default_returns_null() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::If {
+                cases: vec![Case {
+                    when: lit(false),
+                    then: lit(100i64),
+                }],
+                default: None,
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Null);
+        }
+
+        #[test]
+        fn test_if_with_nested_conditions() {
+            let resolver = TestResolver::new().with_var("status", "active");
+
+            let pending_check = ActionOperation::Equals {
+                subject: var("status"),
+                value: lit("pending"),
+            };
+
+            let active_check = ActionOperation::Equals {
+                subject: var("status"),
+                value: lit("active"),
+            };
+
+            let op = ActionOperation::If {
+                cases: vec![
+                    Case {
+                        when: ActionValue::Operation(Box::new(pending_check)),
+                        then: lit(10i64),
+                    },
+                    Case {
+                        when: ActionValue::Operation(Box::new(active_check)),
+                        then: lit(20i64),
+                    },
+                ],
+                default: Some(lit(0i64)),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(20));
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Nested Operations Tests
+    // -------------------------------------------------------------------------
+
+    mod nested {
+        use super::*;
+
+        #[test]
+        fn test_nested_arithmetic_in_max() {
+            // MAX(0, 100 - 50) = MAX(0, 50) = 50
+            let resolver = TestResolver::new();
+
+            let sub_op = ActionOperation::Subtract {
+                values: vec![lit(100i64), lit(50i64)],
+            };
+            let subtract_val = ActionValue::Operation(Box::new(sub_op));
+
+            let op = ActionOperation::Max {
+                values: vec![lit(0i64), subtract_val],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(50));
+        }
+
+        #[test]
+        fn test_deeply_nested_operations() {
+            // IF (10 > 5) THEN (2 * 3) ELSE (1 + 1)
+            // Expressed as IF with cases: [case(10>5 -> 2*3)], default: 1+1
+            let resolver = TestResolver::new();
+
+            let gt_op = ActionOperation::GreaterThan {
+                subject: lit(10i64),
+                value: lit(5i64),
+            };
+            let condition = ActionValue::Operation(Box::new(gt_op));
+
+            let mul_op = ActionOperation::Multiply {
+                values: vec![lit(2i64), lit(3i64)],
+                allow_empty: false,
+            };
+            let then_branch = ActionValue::Operation(Box::new(mul_op));
+
+            let add_op = ActionOperation::Add {
+                values: vec![lit(1i64), lit(1i64)],
+                allow_empty: false,
+            };
+            let else_branch = ActionValue::Operation(Box::new(add_op));
+
+            let op = ActionOperation::If {
+                cases: vec![Case {
+                    when: condition,
+                    then: then_branch,
+                }],
+                default: Some(else_branch),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(6));
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Error Handling Tests
+    // -------------------------------------------------------------------------
+
+    mod errors {
+        use super::*;
+
+        #[test]
+        fn test_type_mismatch_in_arithmetic() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Add {
+                values: vec![lit(10i64), lit("not a number")],
+                allow_empty: false,
+            };
+
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::TypeMismatch { .. })));
+        }
+
+        #[test]
+        fn test_uri_call_without_service_provider() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::UriCall {
+                uri: "regelrecht://base_law/base_value".to_string(),
+                parameters: BTreeMap::new(),
+            };
+
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::InvalidOperation(_))));
+        }
+
+        #[test]
+        fn test_subtract_date_string_operand() {
+            let resolver = TestResolver::new().with_var("ingangsdatum", "2025-01-01");
+            let op = ActionOperation::Subtract {
+                values: vec![lit(100i64), var("ingangsdatum")],
+            };
+
+            let err = execute_operation(&op, &resolver, 0).unwrap_err();
+            assert!(matches!(err, EngineError::TypeMismatch { .. }));
+            let msg = err.to_string();
+            assert!(msg.contains("SUBTRACT operand 1"), "{}", msg);
+            assert!(msg.contains("'2025-01-01'"), "{}", msg);
+        }
+
+        #[test]
+        fn test_variable_not_found() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Equals {
+                subject: var("nonexistent"),
+                value: lit(42i64),
+            };
+
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::VariableNotFound(_))));
+        }
+
+        #[test]
+        fn test_overflow_detection() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Multiply {
+                values: vec![lit(i64::MAX), lit(2i64)],
+                allow_empty: false,
+            };
+
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::ArithmeticOverflow(_))));
+        }
+
+        #[test]
+        fn test_max_depth_exceeded() {
+            let resolver = TestResolver::new();
+
+            let mut nested: ActionValue = lit(42i64);
+            for _ in 0..=MAX_OPERATION_DEPTH + 1 {
+                let if_op = ActionOperation::If {
+                    cases: vec![Case {
+                        when: lit(true),
+                        then: nested,
+                    }],
+                    default: None,
+                };
+                nested = ActionValue::Operation(Box::new(if_op));
+            }
+
+            if let ActionValue::Operation(op) = nested {
+                let result = execute_operation(&op, &resolver, 0);
+                assert!(
+                    matches!(result, Err(EngineError::MaxDepthExceeded(_))),
+                    "Expected MaxDepthExceeded error"
+                );
+            }
+        }
+
+        #[test]
+        fn test_nan_detection_in_f64_to_i64() {
+            let result = f64_to_i64_safe(f64::NAN);
+            assert!(matches!(result, Err(EngineError::ArithmeticOverflow(_))));
+            if let Err(EngineError::ArithmeticOverflow(msg)) = result {
+                assert!(msg.contains("NaN"));
+            }
+        }
+
+        #[test]
+        fn test_infinity_detection_in_f64_to_i64() {
+            let result_pos = f64_to_i64_safe(f64::INFINITY);
+            assert!(matches!(
+                result_pos,
+                Err(EngineError::ArithmeticOverflow(_))
+            ));
+
+            let result_neg = f64_to_i64_safe(f64::NEG_INFINITY);
+            assert!(matches!(
+                result_neg,
+                Err(EngineError::ArithmeticOverflow(_))
+            ));
+        }
+
+        #[test]
+        fn test_i64_range_overflow_detection() {
+            let result_high = f64_to_i64_safe(1e20);
+            assert!(matches!(
+                result_high,
+                Err(EngineError::ArithmeticOverflow(_))
+            ));
+
+            let result_low = f64_to_i64_safe(-1e20);
+            assert!(matches!(
+                result_low,
+                Err(EngineError::ArithmeticOverflow(_))
+            ));
+        }
+
+        #[test]
+        fn test_valid_f64_to_i64_conversion() {
+            assert_eq!(f64_to_i64_safe(42.0).unwrap(), 42);
+            assert_eq!(f64_to_i64_safe(-42.0).unwrap(), -42);
+            assert_eq!(f64_to_i64_safe(0.0).unwrap(), 0);
+            assert_eq!(f64_to_i64_safe(0.9).unwrap(), 0);
+            assert_eq!(f64_to_i64_safe(-0.9).unwrap(), 0);
+        }
+
+        #[test]
+        fn test_f64_to_i64_rejects_i64_max_as_f64() {
+            let result = f64_to_i64_safe(i64::MAX as f64);
+            assert!(
+                matches!(result, Err(EngineError::ArithmeticOverflow(_))),
+                "Expected ArithmeticOverflow for i64::MAX as f64, got: {:?}",
+                result
+            );
+        }
+
+        #[test]
+        fn test_nan_equality() {
+            let nan1 = Value::Float(f64::NAN);
+            let nan2 = Value::Float(f64::NAN);
+            assert!(
+                values_equal(&nan1, &nan2, MAX_SAFE_INTEGER),
+                "Two NaN values should be considered equal"
+            );
+
+            assert!(!values_equal(
+                &Value::Float(f64::NAN),
+                &Value::Int(42),
+                MAX_SAFE_INTEGER
+            ));
+            assert!(!values_equal(
+                &Value::Int(42),
+                &Value::Float(f64::NAN),
+                MAX_SAFE_INTEGER
+            ));
+            assert!(!values_equal(
+                &Value::Float(f64::NAN),
+                &Value::Float(42.0),
+                MAX_SAFE_INTEGER
+            ));
+        }
+
+        #[test]
+        fn test_large_integer_precision_error() {
+            let large_int = Value::Int(MAX_SAFE_INTEGER + 1);
+            assert!(matches!(
+                to_number(&large_int, MAX_SAFE_INTEGER),
+                Err(EngineError::ArithmeticOverflow(_))
+            ));
+
+            let small_int = Value::Int(MIN_SAFE_INTEGER - 1);
+            assert!(matches!(
+                to_number(&small_int, MAX_SAFE_INTEGER),
+                Err(EngineError::ArithmeticOverflow(_))
+            ));
+
+            let safe_int = Value::Int(MAX_SAFE_INTEGER);
+            assert_eq!(
+                to_number(&safe_int, MAX_SAFE_INTEGER).unwrap(),
+                MAX_SAFE_INTEGER as f64
+            );
+
+            let safe_neg = Value::Int(MIN_SAFE_INTEGER);
+            assert_eq!(
+                to_number(&safe_neg, MAX_SAFE_INTEGER).unwrap(),
+                MIN_SAFE_INTEGER as f64
+            );
+        }
+
+        #[test]
+        fn test_arithmetic_with_large_integer() {
+            let large_value: i64 = 9_007_199_254_740_993; // MAX_SAFE_INTEGER + 1
+
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Add {
+                values: vec![lit(large_value), lit(1i64)],
+                allow_empty: false,
+            };
+
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(
+                matches!(result, Err(EngineError::ArithmeticOverflow(_))),
+                "Large integer in arithmetic should cause overflow error"
+            );
+        }
+
+        #[test]
+        fn test_raised_precision_threshold_accepts_larger_integers() {
+            let op = ActionOperation::Add {
+                values: vec![lit(MAX_SAFE_INTEGER + 2), lit(2i64)],
+                allow_empty: false,
+            };
+
+            let default_result = execute_operation(&op, &TestResolver::new(), 0);
+            assert!(matches!(
+                default_result,
+                Err(EngineError::ArithmeticOverflow(_))
+            ));
+
+            let resolver = TestResolver::new().with_options(EvaluationOptions {
+                max_safe_integer: MAX_SAFE_INTEGER * 2,
+                ..EvaluationOptions::DEFAULT
+            });
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(MAX_SAFE_INTEGER + 4));
+        }
+
+        #[test]
+        fn test_raised_precision_threshold_adds_odd_integers_exactly() {
+            let resolver = TestResolver::new().with_options(EvaluationOptions {
+                max_safe_integer: MAX_SAFE_INTEGER * 2,
+                ..EvaluationOptions::DEFAULT
+            });
+
+            let add = ActionOperation::Add {
+                values: vec![lit(MAX_SAFE_INTEGER + 2), lit(1i64)],
+                allow_empty: false,
+            };
+            assert_eq!(
+                execute_operation(&add, &resolver, 0).unwrap(),
+                Value::Int(MAX_SAFE_INTEGER + 3)
+            );
+
+            let cumulative = ActionOperation::CumulativeSum {
+                value: lit(Value::Array(vec![
+                    Value::Int(MAX_SAFE_INTEGER + 2),
+                    Value::Int(1),
+                ])),
+            };
+            assert_eq!(
+                execute_operation(&cumulative, &resolver, 0).unwrap(),
+                Value::Array(vec![
+                    Value::Int(MAX_SAFE_INTEGER + 2),
+                    Value::Int(MAX_SAFE_INTEGER + 3)
+                ])
+            );
+
+            // Mixing in a float still goes through f64, which stays capped at 2^53.
+            let mixed = ActionOperation::Add {
+                values: vec![lit(MAX_SAFE_INTEGER + 2), lit(1.0)],
+                allow_empty: false,
+            };
+            assert!(matches!(
+                execute_operation(&mixed, &resolver, 0),
+                Err(EngineError::ArithmeticOverflow(_))
+            ));
+        }
+
+        #[test]
+        fn test_round_to_multiple_overflow_is_an_error() {
+            let resolver = TestResolver::new().with_options(EvaluationOptions {
+                max_safe_integer: i64::MAX,
+                ..EvaluationOptions::DEFAULT
+            });
+            let op = ActionOperation::RoundToMultiple {
+                subject: lit(i64::MAX - 1),
+                value: lit(i64::MAX / 2 + 1),
+                rounding: Some(lit("ceil")),
+            };
+            assert!(matches!(
+                execute_operation(&op, &resolver, 0),
+                Err(EngineError::ArithmeticOverflow(_))
+            ));
+        }
+
+        #[test]
+        fn test_raised_precision_threshold_in_equality() {
+            let op = ActionOperation::Equals {
+                subject: lit(MAX_SAFE_INTEGER + 2),
+                value: lit((MAX_SAFE_INTEGER + 2) as f64),
+            };
+
+            let default_result = execute_operation(&op, &TestResolver::new(), 0).unwrap();
+            assert_eq!(default_result, Value::Bool(false));
+
+            let resolver = TestResolver::new().with_options(EvaluationOptions {
+                max_safe_integer: MAX_SAFE_INTEGER * 2,
+                ..EvaluationOptions::DEFAULT
+            });
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Collection Operations Tests
+    // -------------------------------------------------------------------------
+
+    mod collection {
+        use super::*;
+
+        #[test]
+        fn test_in_found() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::In {
+                subject: lit(42i64),
+                value: None,
+                values: Some(vec![lit(10i64), lit(20i64), lit(42i64), lit(50i64)]),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+
+        #[test]
+        fn test_in_not_found() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::In {
+                subject: lit(99i64),
+                value: None,
+                values: Some(vec![lit(10i64), lit(20i64), lit(42i64), lit(50i64)]),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(false));
+        }
+
+        #[test]
+        fn test_in_with_strings() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::In {
+                subject: lit("apple"),
+                value: None,
+                values: Some(vec![lit("banana"), lit("apple"), lit("orange")]),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+
+        #[test]
+        fn test_in_with_mixed_int_float() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::In {
+                subject: lit(42i64),
+                value: None,
+                values: Some(vec![lit(10i64), lit(42.0f64), lit(50i64)]),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+
+        #[test]
+        fn test_in_with_variables() {
+            let resolver = TestResolver::new().with_var("status", "active");
+
+            let op = ActionOperation::In {
+                subject: var("status"),
+                value: None,
+                values: Some(vec![lit("active"), lit("pending"), lit("inactive")]),
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Bool(true));
+        }
+
+        #[test]
+        fn test_in_missing_values() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::In {
+                subject: lit(42i64),
+                value: None,
+                values: None,
+            };
+
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::InvalidOperation(_))));
+        }
+
+        #[test]
+        fn test_list_construct() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::List {
+                items: vec![lit(1i64), lit(2i64), lit(3i64)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(
+                result,
+                Value::Array(vec![Value::Int(1), Value::Int(2), Value::Int(3)])
+            );
+        }
+
+        #[test]
+        fn test_list_with_mixed_types() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::List {
+                items: vec![lit(1i64), lit("two"), lit(true)],
+            };
+
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(
+                result,
+                Value::Array(vec![
+                    Value::Int(1),
+                    Value::String("two".to_string()),
+                    Value::Bool(true),
+                ])
+            );
+        }
+
+        fn count_adults() -> ActionOperation {
+            ActionOperation::CountWhere {
+                value: var("leden"),
+                condition: ActionValue::Operation(Box::new(ActionOperation::GreaterThanOrEqual {
+                    subject: var("element.leeftijd"),
+                    value: lit(18i64),
+                })),
             }
-            Ok(*i as f64)
         }
-        Value::Float(f) => Ok(*f),
-        // Untranslatable should be caught by the caller before reaching to_number,
-        // but handle it gracefully.
-        Value::Untranslatable { .. } => Err(type_error("number", val)),
-        _ => Err(type_error("number", val)),
-    }
-}
 
-/// Create a TypeMismatch error.
-fn type_error(expected: &str, actual: &Value) -> EngineError {
-    EngineError::TypeMismatch {
-        expected: expected.to_string(),
-        actual: actual.type_name().to_string(),
-    }
-}
+        fn member(age: i64) -> Value {
+            let mut obj = BTreeMap::new();
+            obj.insert("leeftijd".to_string(), Value::Int(age));
+            Value::Object(obj)
+        }
 
-// =============================================================================
-// Tests
-// =============================================================================
+        #[test]
+        fn test_count_where_mixed_ages() {
+            let resolver = TestResolver::new().with_var(
+                "leden",
+                Value::Array(vec![member(42), member(17), member(18), member(3)]),
+            );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::collections::{BTreeMap, HashMap};
+            let result = execute_operation(&count_adults(), &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(2));
+        }
 
-    /// Simple resolver for testing that uses a HashMap
-    struct TestResolver {
-        vars: HashMap<String, Value>,
-    }
+        #[test]
+        fn test_count_where_empty_array() {
+            let resolver = TestResolver::new().with_var("leden", Value::Array(vec![]));
 
-    impl TestResolver {
-        fn new() -> Self {
-            Self {
-                vars: HashMap::new(),
-            }
+            let result = execute_operation(&count_adults(), &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(0));
         }
 
-        fn with_var(mut self, name: &str, value: impl Into<Value>) -> Self {
-            self.vars.insert(name.to_string(), value.into());
-            self
-        }
-    }
+        #[test]
+        fn test_count_where_scalar_elements_and_outer_vars() {
+            let resolver = TestResolver::new()
+                .with_var(
+                    "leeftijden",
+                    Value::Array(vec![Value::Int(12), Value::Int(30), Value::Int(65)]),
+                )
+                .with_var("grens", 18i64);
+            let op = ActionOperation::CountWhere {
+                value: var("leeftijden"),
+                condition: ActionValue::Operation(Box::new(ActionOperation::LessThan {
+                    subject: var("element"),
+                    value: var("grens"),
+                })),
+            };
 
-    impl ValueResolver for TestResolver {
-        fn resolve(&self, name: &str) -> Result<Value> {
-            self.vars
-                .get(name)
-                .cloned()
-                .ok_or_else(|| EngineError::VariableNotFound(name.to_string()))
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(1));
         }
-    }
-
-    /// Helper to create a literal ActionValue
-    fn lit(v: impl Into<Value>) -> ActionValue {
-        ActionValue::Literal(v.into())
-    }
 
-    /// Helper to create a variable reference
-    fn var(name: &str) -> ActionValue {
-        ActionValue::Literal(Value::String(format!("${}", name)))
-    }
+        #[test]
+        fn test_count_where_non_array() {
+            let resolver = TestResolver::new().with_var("leden", 5i64);
 
-    // -------------------------------------------------------------------------
-    // Comparison Operations Tests
-    // -------------------------------------------------------------------------
+            let result = execute_operation(&count_adults(), &resolver, 0);
+            assert!(matches!(result, Err(EngineError::TypeMismatch { .. })));
+        }
 
-    mod comparison {
-        use super::*;
+        fn array(items: Vec<Value>) -> ActionValue {
+            ActionValue::Literal(Value::Array(items))
+        }
 
         #[test]
-        fn test_equals_integers() {
+        fn test_flatten_one_level() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Equals {
-                subject: lit(42i64),
-                value: lit(42i64),
+            let op = ActionOperation::Flatten {
+                value: array(vec![
+                    Value::Array(vec![Value::Int(1), Value::Int(2)]),
+                    Value::Array(vec![Value::Int(3)]),
+                ]),
             };
 
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(true));
+            assert_eq!(
+                result,
+                Value::Array(vec![Value::Int(1), Value::Int(2), Value::Int(3)])
+            );
         }
 
         #[test]
-        fn test_equals_different_values() {
+        fn test_flatten_keeps_scalars_and_deeper_nesting() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Equals {
-                subject: lit(42i64),
-                value: lit(43i64),
+            let deep = Value::Array(vec![Value::Int(3)]);
+            let op = ActionOperation::Flatten {
+                value: array(vec![
+                    Value::Int(1),
+                    Value::Array(vec![Value::Int(2), deep.clone()]),
+                ]),
             };
 
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(false));
+            assert_eq!(
+                result,
+                Value::Array(vec![Value::Int(1), Value::Int(2), deep])
+            );
         }
 
         #[test]
-        fn test_greater_than() {
+        fn test_distinct_numeric_coercion() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::GreaterThan {
-                subject: lit(50i64),
-                value: lit(42i64),
+            let op = ActionOperation::Distinct {
+                value: array(vec![Value::Int(1), Value::Float(1.0), Value::Int(2)]),
             };
 
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(true));
+            assert_eq!(result, Value::Array(vec![Value::Int(1), Value::Int(2)]));
         }
 
         #[test]
-        fn test_less_than() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::LessThan {
-                subject: lit(30i64),
-                value: lit(42i64),
+        fn test_distinct_null_and_non_array() {
+            let resolver = TestResolver::new().with_var("leden", 5i64);
+            let op = ActionOperation::Distinct {
+                value: ActionValue::Literal(Value::Null),
             };
-
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(true));
+            assert_eq!(result, Value::Array(vec![]));
+
+            let op = ActionOperation::Distinct {
+                value: var("leden"),
+            };
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::TypeMismatch { .. })));
         }
 
         #[test]
-        fn test_greater_than_or_equal() {
+        fn test_is_subset() {
             let resolver = TestResolver::new();
-
-            let op = ActionOperation::GreaterThanOrEqual {
-                subject: lit(42i64),
-                value: lit(42i64),
+            let required = || array(vec![Value::String("id".into()), Value::Int(1)]);
+            let op = ActionOperation::IsSubset {
+                subject: required(),
+                value: array(vec![
+                    Value::Float(1.0),
+                    Value::String("loonstrook".into()),
+                    Value::String("id".into()),
+                ]),
             };
             assert_eq!(
                 execute_operation(&op, &resolver, 0).unwrap(),
                 Value::Bool(true)
             );
 
-            let op2 = ActionOperation::GreaterThanOrEqual {
-                subject: lit(50i64),
-                value: lit(42i64),
+            let op = ActionOperation::IsSubset {
+                subject: required(),
+                value: array(vec![Value::String("id".into())]),
             };
             assert_eq!(
-                execute_operation(&op2, &resolver, 0).unwrap(),
-                Value::Bool(true)
+                execute_operation(&op, &resolver, 0).unwrap(),
+                Value::Bool(false)
             );
         }
 
         #[test]
-        fn test_less_than_or_equal() {
+        fn test_is_superset() {
             let resolver = TestResolver::new();
-
-            let op = ActionOperation::LessThanOrEqual {
-                subject: lit(42i64),
-                value: lit(42i64),
+            let op = ActionOperation::IsSuperset {
+                subject: array(vec![Value::Int(1), Value::Int(2), Value::Int(3)]),
+                value: array(vec![Value::Int(3), Value::Int(1), Value::Int(1)]),
             };
             assert_eq!(
                 execute_operation(&op, &resolver, 0).unwrap(),
                 Value::Bool(true)
             );
 
-            let op2 = ActionOperation::LessThanOrEqual {
-                subject: lit(30i64),
-                value: lit(42i64),
+            let op = ActionOperation::IsSuperset {
+                subject: array(vec![Value::Int(1)]),
+                value: array(vec![Value::Int(1), Value::Int(2)]),
             };
             assert_eq!(
-                execute_operation(&op2, &resolver, 0).unwrap(),
-                Value::Bool(true)
+                execute_operation(&op, &resolver, 0).unwrap(),
+                Value::Bool(false)
             );
         }
 
         #[test]
-        fn test_comparison_with_variables() {
-            let resolver = TestResolver::new()
-                .with_var("age", 25i64)
-                .with_var("min_age", 18i64);
+        fn test_subset_with_empty_sets() {
+            let resolver = TestResolver::new().with_var("leden", 5i64);
+            let empty = || array(vec![]);
+            let cases = [
+                (empty(), array(vec![Value::Int(1)]), true),
+                (empty(), empty(), true),
+                (ActionValue::Literal(Value::Null), empty(), true),
+                (array(vec![Value::Int(1)]), empty(), false),
+            ];
+            for (subject, value, expected) in cases {
+                let op = ActionOperation::IsSubset { subject, value };
+                assert_eq!(
+                    execute_operation(&op, &resolver, 0).unwrap(),
+                    Value::Bool(expected)
+                );
+            }
 
-            let op = ActionOperation::GreaterThanOrEqual {
-                subject: var("age"),
-                value: var("min_age"),
+            let op = ActionOperation::IsSuperset {
+                subject: array(vec![Value::Int(1)]),
+                value: empty(),
+            };
+            assert_eq!(
+                execute_operation(&op, &resolver, 0).unwrap(),
+                Value::Bool(true)
+            );
+
+            let op = ActionOperation::IsSubset {
+                subject: var("leden"),
+                value: empty(),
             };
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::TypeMismatch { .. })));
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(true));
+        fn set_equals(left: Vec<Value>, right: Vec<Value>, count_duplicates: bool) -> Value {
+            let op = ActionOperation::SetEquals {
+                subject: array(left),
+                value: array(right),
+                count_duplicates: Some(lit(Value::Bool(count_duplicates))),
+            };
+            execute_operation(&op, &TestResolver::new(), 0).unwrap()
         }
 
         #[test]
-        fn test_comparison_mixed_int_float() {
-            let resolver = TestResolver::new();
+        fn test_set_equals_ignores_order() {
+            let codes = || vec![Value::from("id"), Value::from("loonstrook"), Value::Int(1)];
+            let reordered = vec![
+                Value::Float(1.0),
+                Value::from("id"),
+                Value::from("loonstrook"),
+            ];
+            assert_eq!(
+                set_equals(codes(), reordered.clone(), false),
+                Value::Bool(true)
+            );
+            assert_eq!(set_equals(codes(), reordered, true), Value::Bool(true));
+        }
 
-            let op = ActionOperation::Equals {
-                subject: lit(42i64),
-                value: lit(42.0f64),
-            };
+        #[test]
+        fn test_set_equals_duplicates_and_unequal_arrays() {
+            let with_duplicate = vec![Value::Int(1), Value::Int(1), Value::Int(2)];
+            let distinct = vec![Value::Int(2), Value::Int(1)];
             assert_eq!(
-                execute_operation(&op, &resolver, 0).unwrap(),
+                set_equals(with_duplicate.clone(), distinct.clone(), false),
                 Value::Bool(true)
             );
+            assert_eq!(
+                set_equals(with_duplicate, distinct, true),
+                Value::Bool(false)
+            );
+            // Same length, different multiplicities
+            assert_eq!(
+                set_equals(
+                    vec![Value::Int(1), Value::Int(1), Value::Int(2)],
+                    vec![Value::Int(1), Value::Int(2), Value::Int(2)],
+                    true
+                ),
+                Value::Bool(false)
+            );
 
-            let op2 = ActionOperation::Equals {
-                subject: lit(42.0f64),
-                value: lit(42i64),
+            let unequal = vec![Value::Int(1), Value::Int(3)];
+            assert_eq!(
+                set_equals(vec![Value::Int(1), Value::Int(2)], unequal.clone(), false),
+                Value::Bool(false)
+            );
+            assert_eq!(
+                set_equals(vec![Value::Int(1), Value::Int(2)], unequal, true),
+                Value::Bool(false)
+            );
+        }
+
+        #[test]
+        fn test_cumulative_sum_of_integers() {
+            let op = ActionOperation::CumulativeSum {
+                value: array(vec![Value::Int(1), Value::Int(2), Value::Int(3)]),
             };
+            let result = execute_operation(&op, &TestResolver::new(), 0).unwrap();
             assert_eq!(
-                execute_operation(&op2, &resolver, 0).unwrap(),
-                Value::Bool(true)
+                result,
+                Value::Array(vec![Value::Int(1), Value::Int(3), Value::Int(6)])
             );
         }
-    }
 
-    // -------------------------------------------------------------------------
-    // Arithmetic Operations Tests
-    // -------------------------------------------------------------------------
+        #[test]
+        fn test_cumulative_sum_promotes_from_first_float() {
+            let op = ActionOperation::CumulativeSum {
+                value: array(vec![Value::Int(1), Value::Float(2.5), Value::Int(3)]),
+            };
+            let result = execute_operation(&op, &TestResolver::new(), 0).unwrap();
+            assert_eq!(
+                result,
+                Value::Array(vec![Value::Int(1), Value::Float(3.5), Value::Float(6.5)])
+            );
 
-    mod arithmetic {
-        use super::*;
+            let op = ActionOperation::CumulativeSum {
+                value: array(vec![Value::Int(1), Value::Int(MAX_SAFE_INTEGER + 1)]),
+            };
+            let result = execute_operation(&op, &TestResolver::new(), 0);
+            assert!(matches!(result, Err(EngineError::ArithmeticOverflow(_))));
+        }
+
+        fn earner(income: Option<Value>) -> Value {
+            let mut obj = BTreeMap::new();
+            obj.insert("bsn".to_string(), Value::from("999993653"));
+            if let Some(income) = income {
+                obj.insert("income".to_string(), income);
+            }
+            Value::Object(obj)
+        }
+
+        fn sum_field(members: Vec<Value>, skip_missing: Option<bool>) -> Result<Value> {
+            let op = ActionOperation::SumField {
+                value: array(members),
+                field: "income".to_string(),
+                skip_missing: skip_missing.map(|b| lit(Value::Bool(b))),
+            };
+            execute_operation(&op, &TestResolver::new(), 0)
+        }
 
         #[test]
-        fn test_add_integers() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Add {
-                values: vec![lit(10i64), lit(20i64), lit(30i64)],
+        fn test_sum_field_totals_member_incomes() {
+            let members = vec![
+                earner(Some(Value::Int(20000))),
+                earner(Some(Value::Int(15000))),
+                earner(Some(Value::Int(5000))),
+            ];
+            assert_eq!(sum_field(members, None).unwrap(), Value::Int(40000));
+
+            let members = vec![
+                earner(Some(Value::Int(20000))),
+                earner(Some(Value::Float(0.5))),
+                earner(Some(Value::Int(5000))),
+            ];
+            assert_eq!(sum_field(members, None).unwrap(), Value::Float(25000.5));
+        }
+
+        #[test]
+        fn test_sum_field_missing_field() {
+            let members = vec![
+                earner(Some(Value::Int(20000))),
+                earner(None),
+                earner(Some(Value::Null)),
+            ];
+            assert!(matches!(
+                sum_field(members.clone(), None),
+                Err(EngineError::InvalidOperation(_))
+            ));
+            assert_eq!(sum_field(members, Some(true)).unwrap(), Value::Int(20000));
+        }
+
+        fn split(subject: &str, delimiter: &str, limit: Option<i64>) -> Value {
+            let op = ActionOperation::Split {
+                subject: lit(subject),
+                value: lit(delimiter),
+                limit: limit.map(lit),
             };
+            execute_operation(&op, &TestResolver::new(), 0).unwrap()
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(60));
+        fn strings(parts: &[&str]) -> Value {
+            Value::Array(parts.iter().map(|&p| Value::from(p)).collect())
+        }
+
+        #[test]
+        fn test_split_postcode_on_space() {
+            assert_eq!(split("1234 AB", " ", None), strings(&["1234", "AB"]));
+        }
+
+        #[test]
+        fn test_split_code_on_hyphen_with_limit() {
+            assert_eq!(
+                split("GM-0363-A-1", "-", None),
+                strings(&["GM", "0363", "A", "1"])
+            );
+            assert_eq!(
+                split("GM-0363-A-1", "-", Some(2)),
+                strings(&["GM", "0363-A-1"])
+            );
+            assert_eq!(
+                split("GM-0363-A-1", "-", Some(1)),
+                strings(&["GM-0363-A-1"])
+            );
+        }
+
+        fn slice(start: i64, length: Option<i64>) -> Result<Value> {
+            let op = ActionOperation::Slice {
+                value: array((1..=5).map(Value::Int).collect()),
+                start: lit(start),
+                length: length.map(lit),
+            };
+            execute_operation(&op, &TestResolver::new(), 0)
+        }
+
+        #[test]
+        fn test_slice_mid_range() {
+            assert_eq!(
+                slice(1, Some(3)).unwrap(),
+                Value::Array(vec![Value::Int(2), Value::Int(3), Value::Int(4)])
+            );
+        }
+
+        #[test]
+        fn test_slice_over_length_is_clamped() {
+            assert_eq!(
+                slice(3, Some(10)).unwrap(),
+                Value::Array(vec![Value::Int(4), Value::Int(5)])
+            );
+            assert_eq!(slice(7, None).unwrap(), Value::Array(vec![]));
         }
 
         #[test]
-        fn test_add_with_floats() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Add {
-                values: vec![lit(10i64), lit(20.5f64), lit(30i64)],
-            };
+        fn test_slice_negative_start_counts_from_end() {
+            assert_eq!(
+                slice(-2, None).unwrap(),
+                Value::Array(vec![Value::Int(4), Value::Int(5)])
+            );
+            assert_eq!(
+                slice(-9, Some(1)).unwrap(),
+                Value::Array(vec![Value::Int(1)])
+            );
+            assert!(matches!(
+                slice(0, Some(-1)),
+                Err(EngineError::InvalidOperation(_))
+            ));
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Float(60.5));
+        fn sort(items: Vec<Value>, descending: Option<bool>, mode: Option<&str>) -> Result<Value> {
+            let op = ActionOperation::Sort {
+                value: array(items),
+                descending: descending.map(lit),
+                mode: mode.map(lit),
+            };
+            execute_operation(&op, &TestResolver::new(), 0)
         }
 
         #[test]
-        fn test_subtract() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Subtract {
-                values: vec![lit(100i64), lit(30i64), lit(20i64)],
-            };
+        fn test_sort_numeric_ascending() {
+            let items = vec![Value::Int(3), Value::Float(1.5), Value::Int(2)];
+            assert_eq!(
+                sort(items, None, None).unwrap(),
+                Value::Array(vec![Value::Float(1.5), Value::Int(2), Value::Int(3)])
+            );
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(50));
+        #[test]
+        fn test_sort_descending() {
+            let items = vec![Value::Int(1), Value::Int(3), Value::Int(2)];
+            assert_eq!(
+                sort(items, Some(true), Some("numeric")).unwrap(),
+                Value::Array(vec![Value::Int(3), Value::Int(2), Value::Int(1)])
+            );
         }
 
         #[test]
-        fn test_multiply() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Multiply {
-                values: vec![lit(2i64), lit(3i64), lit(4i64)],
-            };
+        fn test_sort_strings() {
+            let items = vec![
+                Value::from("peer"),
+                Value::from("appel"),
+                Value::from("kers"),
+            ];
+            assert_eq!(
+                sort(items, None, Some("string")).unwrap(),
+                Value::Array(vec![
+                    Value::from("appel"),
+                    Value::from("kers"),
+                    Value::from("peer")
+                ])
+            );
+            let mixed = vec![Value::from("appel"), Value::Int(1)];
+            assert!(matches!(
+                sort(mixed, None, Some("string")),
+                Err(EngineError::TypeMismatch { .. })
+            ));
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(24));
+        fn woonsituatie_labels() -> Value {
+            let mut table = BTreeMap::new();
+            table.insert("1".to_string(), Value::from("alleenstaand"));
+            table.insert("2".to_string(), Value::from("samenwonend"));
+            Value::Object(table)
         }
 
         #[test]
-        fn test_divide() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Divide {
-                values: vec![lit(100i64), lit(2i64)],
+        fn test_label_known_code() {
+            let resolver = TestResolver::new()
+                .with_var("WOONSITUATIE_LABELS", woonsituatie_labels())
+                .with_var("woonsituatie", 2i64);
+            let op = ActionOperation::Label {
+                subject: var("woonsituatie"),
+                table: var("WOONSITUATIE_LABELS"),
+                default: Some(lit("onbekend")),
             };
-
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Float(50.0));
+            assert_eq!(result, Value::String("samenwonend".to_string()));
         }
 
         #[test]
-        fn test_divide_by_zero() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Divide {
-                values: vec![lit(100i64), lit(0i64)],
+        fn test_label_unknown_code_uses_default() {
+            let resolver =
+                TestResolver::new().with_var("WOONSITUATIE_LABELS", woonsituatie_labels());
+            let op = ActionOperation::Label {
+                subject: lit(9i64),
+                table: var("WOONSITUATIE_LABELS"),
+                default: Some(lit("onbekend")),
             };
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::String("onbekend".to_string()));
 
-            let result = execute_operation(&op, &resolver, 0);
-            assert!(matches!(result, Err(EngineError::DivisionByZero)));
+            let op = ActionOperation::Label {
+                subject: lit(9i64),
+                table: var("WOONSITUATIE_LABELS"),
+                default: None,
+            };
+            assert_eq!(execute_operation(&op, &resolver, 0).unwrap(), Value::Null);
         }
+    }
 
-        #[test]
-        fn test_arithmetic_with_variables() {
-            let resolver = TestResolver::new()
-                .with_var("base", 1000i64)
-                .with_var("rate", 0.05f64);
+    // -------------------------------------------------------------------------
+    // Validation Operations Tests
+    // -------------------------------------------------------------------------
 
-            let op = ActionOperation::Multiply {
-                values: vec![var("base"), var("rate")],
-            };
+    mod empty_check {
+        use super::*;
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Float(50.0));
+        fn is_empty(subject: Value, ignore_whitespace: bool) -> Value {
+            let op = ActionOperation::IsEmpty {
+                subject: lit(subject),
+                ignore_whitespace: Some(lit(ignore_whitespace)),
+            };
+            execute_operation(&op, &TestResolver::new(), 0).unwrap()
         }
 
         #[test]
-        fn test_add_arrays() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Add {
-                values: vec![
-                    lit(Value::Array(vec![Value::Int(1), Value::Int(2)])),
-                    lit(Value::Array(vec![Value::Int(3), Value::Int(4)])),
-                ],
-            };
+        fn test_is_empty_cases() {
+            assert_eq!(is_empty(Value::Null, false), Value::Bool(true));
+            assert_eq!(
+                is_empty(Value::String(String::new()), false),
+                Value::Bool(true)
+            );
+            assert_eq!(is_empty(Value::Array(vec![]), false), Value::Bool(true));
+            assert_eq!(
+                is_empty(Value::String("  ".to_string()), true),
+                Value::Bool(true)
+            );
+            // Whitespace only counts as empty when asked for
+            assert_eq!(
+                is_empty(Value::String("  ".to_string()), false),
+                Value::Bool(false)
+            );
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
+        #[test]
+        fn test_is_empty_non_empty_values() {
             assert_eq!(
-                result,
-                Value::Array(vec![
-                    Value::Int(1),
-                    Value::Int(2),
-                    Value::Int(3),
-                    Value::Int(4)
-                ])
+                is_empty(Value::String("a".to_string()), true),
+                Value::Bool(false)
             );
+            assert_eq!(
+                is_empty(Value::Array(vec![Value::Null]), false),
+                Value::Bool(false)
+            );
+            assert_eq!(is_empty(Value::Int(0), false), Value::Bool(false));
         }
 
         #[test]
-        fn test_add_strings() {
+        fn test_not_empty() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Add {
-                values: vec![lit("hello"), lit(" "), lit("world")],
+            let not_empty = |subject: Value| {
+                let op = ActionOperation::NotEmpty {
+                    subject: lit(subject),
+                    ignore_whitespace: None,
+                };
+                execute_operation(&op, &resolver, 0).unwrap()
             };
-
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("hello world".to_string()));
+            assert_eq!(not_empty(Value::String("a".to_string())), Value::Bool(true));
+            assert_eq!(not_empty(Value::Array(vec![])), Value::Bool(false));
+            assert_eq!(not_empty(Value::Null), Value::Bool(false));
         }
     }
 
-    // -------------------------------------------------------------------------
-    // Aggregate Operations Tests
-    // -------------------------------------------------------------------------
-
-    mod aggregate {
+    mod validation {
         use super::*;
 
-        #[test]
-        fn test_max() {
+        fn validate(subject: Value) -> Result<Value> {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Max {
-                values: vec![lit(10i64), lit(50i64), lit(30i64)],
+            let op = ActionOperation::ValidateBsn {
+                subject: ActionValue::Literal(subject),
             };
+            execute_operation(&op, &resolver, 0)
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(50));
+        #[test]
+        fn test_validate_bsn_valid() {
+            assert_eq!(
+                validate(Value::String("999993653".to_string())).unwrap(),
+                Value::Bool(true)
+            );
+            assert_eq!(validate(Value::Int(999993653)).unwrap(), Value::Bool(true));
         }
 
         #[test]
-        fn test_min() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Min {
-                values: vec![lit(10i64), lit(50i64), lit(30i64)],
-            };
+        fn test_validate_bsn_int_keeps_leading_zero() {
+            // 012345672: 0*9 + 1*8 + 2*7 + 3*6 + 4*5 + 5*4 + 6*3 + 7*2 - 2 = 110
+            assert_eq!(validate(Value::Int(12345672)).unwrap(), Value::Bool(true));
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(10));
+        #[test]
+        fn test_validate_bsn_invalid_checksum() {
+            assert_eq!(
+                validate(Value::String("999993654".to_string())).unwrap(),
+                Value::Bool(false)
+            );
+            assert_eq!(
+                validate(Value::String("000000000".to_string())).unwrap(),
+                Value::Bool(false)
+            );
         }
 
         #[test]
-        fn test_max_with_floats() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Max {
-                values: vec![lit(10.5f64), lit(50.3f64), lit(30.7f64)],
+        fn test_validate_bsn_wrong_length() {
+            assert_eq!(
+                validate(Value::String("99999365".to_string())).unwrap(),
+                Value::Bool(false)
+            );
+            assert_eq!(
+                validate(Value::String("9999936530".to_string())).unwrap(),
+                Value::Bool(false)
+            );
+            assert_eq!(
+                validate(Value::Int(9999936530)).unwrap(),
+                Value::Bool(false)
+            );
+        }
+
+        #[test]
+        fn test_validate_bsn_non_digits_and_null() {
+            assert_eq!(
+                validate(Value::String("99999365a".to_string())).unwrap(),
+                Value::Bool(false)
+            );
+            assert_eq!(validate(Value::Null).unwrap(), Value::Bool(false));
+            assert!(validate(Value::Float(999993653.0)).is_err());
+        }
+
+        fn normalize_postcode(subject: &str) -> Result<Value> {
+            let op = ActionOperation::NormalizePostcode {
+                subject: lit(subject),
             };
+            execute_operation(&op, &TestResolver::new(), 0)
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Float(50.3));
+        #[test]
+        fn test_normalize_postcode_variants() {
+            for input in ["1234 AB", "1234AB", "1234ab"] {
+                assert_eq!(
+                    normalize_postcode(input).unwrap(),
+                    Value::String("1234 AB".to_string()),
+                    "input {:?}",
+                    input
+                );
+            }
         }
 
         #[test]
-        fn test_max_with_zero() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Max {
-                values: vec![lit(0i64), lit(-10i64)],
+        fn test_normalize_postcode_invalid() {
+            for input in ["0123 AB", "123 AB", "1234 A1", "1234 ABC", "123é AB"] {
+                assert!(
+                    matches!(
+                        normalize_postcode(input),
+                        Err(EngineError::InvalidOperation(_))
+                    ),
+                    "input {:?}",
+                    input
+                );
+            }
+        }
+
+        fn matches(subject: &str, pattern: &str) -> Result<Value> {
+            let op = ActionOperation::Matches {
+                subject: lit(subject),
+                value: lit(pattern),
             };
+            execute_operation(&op, &TestResolver::new(), 0)
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(0));
+        #[test]
+        fn test_matches_postcode_pattern() {
+            let postcode = r"^[1-9][0-9]{3} ?[A-Z]{2}$";
+            assert_eq!(matches("1234 AB", postcode).unwrap(), Value::Bool(true));
+            assert_eq!(matches("0123 AB", postcode).unwrap(), Value::Bool(false));
+        }
+
+        #[test]
+        fn test_matches_invalid_or_oversized_pattern() {
+            assert!(matches!(
+                matches("1234 AB", "[0-9"),
+                Err(EngineError::InvalidOperation(_))
+            ));
+            let long = "a".repeat(config::MAX_REGEX_PATTERN_LENGTH + 1);
+            assert!(matches!(
+                matches("a", &long),
+                Err(EngineError::InvalidOperation(_))
+            ));
+            assert!(matches!(
+                matches("a", "(a{1000}){1000}"),
+                Err(EngineError::InvalidOperation(_))
+            ));
         }
     }
 
     // -------------------------------------------------------------------------
-    // Logical Operations Tests
+    // Type Conversion Tests
     // -------------------------------------------------------------------------
 
-    mod logical {
+    mod type_conversion {
         use super::*;
 
-        #[test]
-        fn test_and_all_true() {
+        fn cast(subject: Value, target: &str) -> Result<Value> {
             let resolver = TestResolver::new();
-            let op = ActionOperation::And {
-                conditions: vec![lit(true), lit(true), lit(true)],
+            let op = ActionOperation::Cast {
+                subject: ActionValue::Literal(subject),
+                value: lit(target),
             };
-
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(true));
+            execute_operation(&op, &resolver, 0)
         }
 
         #[test]
-        fn test_and_one_false() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::And {
-                conditions: vec![lit(true), lit(false), lit(true)],
-            };
-
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(false));
+        fn test_cast_string_to_int() {
+            assert_eq!(
+                cast(Value::String("42".to_string()), "int").unwrap(),
+                Value::Int(42)
+            );
         }
 
         #[test]
-        fn test_or_one_true() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Or {
-                conditions: vec![lit(false), lit(true), lit(false)],
-            };
-
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(true));
+        fn test_cast_int_to_float() {
+            assert_eq!(cast(Value::Int(42), "float").unwrap(), Value::Float(42.0));
         }
 
         #[test]
-        fn test_or_all_false() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Or {
-                conditions: vec![lit(false), lit(false), lit(false)],
-            };
-
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(false));
+        fn test_cast_int_beyond_f64_precision_to_float() {
+            let exact = 1_i64 << 53;
+            assert_eq!(
+                cast(Value::Int(-exact), "float").unwrap(),
+                Value::Float(-(exact as f64))
+            );
+            assert!(matches!(
+                cast(Value::Int(exact + 1), "float"),
+                Err(EngineError::TypeMismatch { .. })
+            ));
         }
 
         #[test]
-        fn test_and_with_nested_comparison() {
-            let resolver = TestResolver::new()
-                .with_var("age", 25i64)
-                .with_var("has_insurance", true);
-
-            let age_check_op = ActionOperation::GreaterThanOrEqual {
-                subject: var("age"),
-                value: lit(18i64),
-            };
-            let age_check = ActionValue::Operation(Box::new(age_check_op));
-
-            let op = ActionOperation::And {
-                conditions: vec![age_check, var("has_insurance")],
-            };
+        fn test_cast_invalid_date() {
+            for subject in [
+                Value::String("2025-02-30".to_string()),
+                Value::String("31-01-2025".to_string()),
+                Value::Int(20250131),
+            ] {
+                assert!(matches!(
+                    cast(subject, "date"),
+                    Err(EngineError::TypeMismatch { .. })
+                ));
+            }
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(true));
+        #[test]
+        fn test_cast_invalid_string_to_int() {
+            let err = cast(Value::String("abc".to_string()), "int").unwrap_err();
+            assert!(matches!(err, EngineError::TypeMismatch { .. }));
+            assert!(cast(Value::Float(4.2), "int").is_err());
         }
 
         #[test]
-        fn test_not_negates_true() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Not { value: lit(true) };
+        fn test_cast_bool_date_and_unknown_target() {
+            assert_eq!(
+                cast(Value::String("True".to_string()), "bool").unwrap(),
+                Value::Bool(true)
+            );
+            assert_eq!(
+                cast(Value::String("2025-01-31".to_string()), "date").unwrap(),
+                Value::String("2025-01-31".to_string())
+            );
+            assert_eq!(cast(Value::Null, "int").unwrap(), Value::Null);
+            assert!(matches!(
+                cast(Value::Int(1), "decimal"),
+                Err(EngineError::InvalidOperation(_))
+            ));
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(false));
+        fn parse_bool(subject: &str) -> Result<Value> {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::ParseBool {
+                subject: lit(subject),
+                true_values: None,
+                false_values: None,
+            };
+            execute_operation(&op, &resolver, 0)
         }
 
         #[test]
-        fn test_not_negates_false() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Not { value: lit(false) };
+        fn test_parse_bool_default_tokens() {
+            assert_eq!(parse_bool("ja").unwrap(), Value::Bool(true));
+            assert_eq!(parse_bool(" Nee ").unwrap(), Value::Bool(false));
+            assert_eq!(parse_bool("true").unwrap(), Value::Bool(true));
+            assert_eq!(parse_bool("FALSE").unwrap(), Value::Bool(false));
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(true));
+        #[test]
+        fn test_parse_bool_unrecognized_token() {
+            let err = parse_bool("misschien").unwrap_err();
+            assert!(matches!(err, EngineError::InvalidOperation(_)));
+            assert!(err.to_string().contains("misschien"));
         }
 
         #[test]
-        fn test_not_wrapping_equals() {
+        fn test_parse_bool_custom_tokens() {
             let resolver = TestResolver::new();
-
-            // NOT(EQUALS(42, 42)) should be false
-            let eq_op = ActionOperation::Equals {
-                subject: lit(42i64),
-                value: lit(42i64),
-            };
-
-            let op = ActionOperation::Not {
-                value: ActionValue::Operation(Box::new(eq_op)),
-            };
-
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(false));
-
-            // NOT(EQUALS(42, 43)) should be true
-            let eq_op2 = ActionOperation::Equals {
-                subject: lit(42i64),
-                value: lit(43i64),
+            let tokens = |items: &[&str]| {
+                ActionValue::Literal(Value::Array(
+                    items.iter().map(|t| Value::String(t.to_string())).collect(),
+                ))
             };
-
-            let op2 = ActionOperation::Not {
-                value: ActionValue::Operation(Box::new(eq_op2)),
+            let op = |subject: &str| ActionOperation::ParseBool {
+                subject: lit(subject),
+                true_values: Some(tokens(&["J"])),
+                false_values: Some(tokens(&["N"])),
             };
 
-            let result2 = execute_operation(&op2, &resolver, 0).unwrap();
-            assert_eq!(result2, Value::Bool(true));
+            assert_eq!(
+                execute_operation(&op("j"), &resolver, 0).unwrap(),
+                Value::Bool(true)
+            );
+            assert_eq!(
+                execute_operation(&op("N"), &resolver, 0).unwrap(),
+                Value::Bool(false)
+            );
+            assert!(execute_operation(&op("ja"), &resolver, 0).is_err());
         }
     }
 
     // -------------------------------------------------------------------------
-    // Conditional Operations Tests (IF with cases/default)
+    // Date Operations Tests
     // -------------------------------------------------------------------------
 
-    mod conditional {
+    mod date_operations {
         use super::*;
 
         #[test]
-        fn test_if_first_match() {
+        fn test_age_exact_birthday() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::If {
-                cases: vec![
-                    Case {
-                        when: lit(true),
-                        then: lit(100i64),
-                    },
-                    Case {
-                        when: lit(true),
-                        then: lit(200i64),
-                    },
-                ],
-                default: Some(lit(0i64)),
+            let op = ActionOperation::Age {
+                date_of_birth: lit("1990-03-15"),
+                reference_date: lit("2025-03-15"),
             };
 
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(100));
+            assert_eq!(result, Value::Int(35));
         }
 
         #[test]
-        fn test_if_second_match() {
+        fn test_age_before_birthday() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::If {
-                cases: vec![
-                    Case {
-                        when: lit(false),
-                        then: lit(100i64),
-                    },
-                    Case {
-                        when: lit(true),
-                        then: lit(200i64),
-                    },
-                ],
-                default: Some(lit(0i64)),
+            let op = ActionOperation::Age {
+                date_of_birth: lit("1990-03-15"),
+                reference_date: lit("2025-03-14"),
             };
 
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(200));
+            assert_eq!(result, Value::Int(34));
         }
 
-        #[test]
-        fn test_if_default() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::If {
-                cases: vec![
-                    Case {
-                        when: lit(false),
-                        then: lit(100i64),
-                    },
-                    Case {
-                        when: lit(false),
-                        then: lit(200i64),
-                    },
-                ],
-                default: Some(lit(0i64)),
+        fn has_reached_age(dob: &str, age: i64, on: &str, policy: AnniversaryPolicy) -> Value {
+            let resolver = TestResolver::new().with_options(EvaluationOptions {
+                anniversary_policy: policy,
+                ..EvaluationOptions::DEFAULT
+            });
+            let op = ActionOperation::HasReachedAge {
+                date_of_birth: lit(dob),
+                age: lit(age),
+                reference_date: Some(lit(on)),
             };
-
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(0));
+            execute_operation(&op, &resolver, 0).unwrap()
         }
 
         #[test]
-        fn test_if_no_default_returns_null() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::If {
-                cases: vec![Case {
-                    when: lit(false),
-                    then: lit(100i64),
-                }],
-                default: None,
-            };
-
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Null);
+        fn test_has_reached_age_on_birthday_and_day_before() {
+            let policy = AnniversaryPolicy::default();
+            assert_eq!(
+                has_reached_age("1958-07-10", 67, "2025-07-10", policy),
+                Value::Bool(true)
+            );
+            assert_eq!(
+                has_reached_age("1958-07-10", 67, "2025-07-09", policy),
+                Value::Bool(false)
+            );
         }
 
         #[test]
-        fn test_if_with_nested_conditions() {
-            let resolver = TestResolver::new().with_var("status", "active");
+        fn test_has_reached_age_feb29_policy() {
+            let feb28 = AnniversaryPolicy::February28;
+            let mar1 = AnniversaryPolicy::March1;
+            assert_eq!(
+                has_reached_age("2008-02-29", 18, "2026-02-28", feb28),
+                Value::Bool(true)
+            );
+            assert_eq!(
+                has_reached_age("2008-02-29", 18, "2026-02-27", feb28),
+                Value::Bool(false)
+            );
+            assert_eq!(
+                has_reached_age("2008-02-29", 18, "2026-02-28", mar1),
+                Value::Bool(false)
+            );
+            assert_eq!(
+                has_reached_age("2008-02-29", 18, "2026-03-01", mar1),
+                Value::Bool(true)
+            );
+            // In leap years the birthday itself exists under either policy
+            assert_eq!(
+                has_reached_age("2008-02-29", 16, "2024-02-29", mar1),
+                Value::Bool(true)
+            );
+        }
 
-            let pending_check = ActionOperation::Equals {
-                subject: var("status"),
-                value: lit("pending"),
+        #[test]
+        fn test_has_reached_age_defaults_to_referencedate() {
+            let resolver = TestResolver::new().with_var("referencedate", "2025-07-10");
+            let op = ActionOperation::HasReachedAge {
+                date_of_birth: lit("1958-07-10"),
+                age: lit(67),
+                reference_date: None,
             };
+            assert_eq!(
+                execute_operation(&op, &resolver, 0).unwrap(),
+                Value::Bool(true)
+            );
+        }
 
-            let active_check = ActionOperation::Equals {
-                subject: var("status"),
-                value: lit("active"),
+        fn age_in_months(dob: &str, on: &str) -> Value {
+            let op = ActionOperation::AgeInMonths {
+                date_of_birth: lit(dob),
+                reference_date: Some(lit(on)),
             };
+            execute_operation(&op, &TestResolver::new(), 0).unwrap()
+        }
 
-            let op = ActionOperation::If {
-                cases: vec![
-                    Case {
-                        when: ActionValue::Operation(Box::new(pending_check)),
-                        then: lit(10i64),
-                    },
-                    Case {
-                        when: ActionValue::Operation(Box::new(active_check)),
-                        then: lit(20i64),
-                    },
-                ],
-                default: Some(lit(0i64)),
+        #[test]
+        fn test_age_in_months_of_toddler() {
+            assert_eq!(age_in_months("2023-07-15", "2025-01-15"), Value::Int(18));
+            assert_eq!(age_in_months("2023-07-15", "2025-02-01"), Value::Int(18));
+
+            let resolver = TestResolver::new().with_var("referencedate", "2025-01-15");
+            let op = ActionOperation::AgeInMonths {
+                date_of_birth: lit("2023-07-15"),
+                reference_date: None,
             };
-
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(20));
+            assert_eq!(
+                execute_operation(&op, &resolver, 0).unwrap(),
+                Value::Int(18)
+            );
         }
-    }
-
-    // -------------------------------------------------------------------------
-    // Nested Operations Tests
-    // -------------------------------------------------------------------------
 
-    mod nested {
-        use super::*;
+        #[test]
+        fn test_age_in_months_day_before_month_completes() {
+            assert_eq!(age_in_months("2023-07-15", "2025-02-14"), Value::Int(18));
+            assert_eq!(age_in_months("2023-07-15", "2025-02-15"), Value::Int(19));
+        }
 
         #[test]
-        fn test_nested_arithmetic_in_max() {
-            // MAX(0, 100 - 50) = MAX(0, 50) = 50
+        fn test_age_feb29_birthday_on_non_leap_year() {
+            // Per Dutch law (BW art. 1:2): Feb 28 counts as birthday
             let resolver = TestResolver::new();
-
-            let sub_op = ActionOperation::Subtract {
-                values: vec![lit(100i64), lit(50i64)],
+            let op = ActionOperation::Age {
+                date_of_birth: lit("2000-02-29"),
+                reference_date: lit("2001-02-28"),
             };
-            let subtract_val = ActionValue::Operation(Box::new(sub_op));
 
-            let op = ActionOperation::Max {
-                values: vec![lit(0i64), subtract_val],
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(1));
+        }
+
+        #[test]
+        fn test_age_feb29_birthday_before_feb28() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Age {
+                date_of_birth: lit("2000-02-29"),
+                reference_date: lit("2001-02-27"),
             };
 
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(50));
+            assert_eq!(result, Value::Int(0));
         }
 
         #[test]
-        fn test_deeply_nested_operations() {
-            // IF (10 > 5) THEN (2 * 3) ELSE (1 + 1)
-            // Expressed as IF with cases: [case(10>5 -> 2*3)], default: 1+1
+        fn test_age_feb29_birthday_on_leap_year() {
             let resolver = TestResolver::new();
-
-            let gt_op = ActionOperation::GreaterThan {
-                subject: lit(10i64),
-                value: lit(5i64),
+            let op = ActionOperation::Age {
+                date_of_birth: lit("2000-02-29"),
+                reference_date: lit("2004-02-29"),
             };
-            let condition = ActionValue::Operation(Box::new(gt_op));
 
-            let mul_op = ActionOperation::Multiply {
-                values: vec![lit(2i64), lit(3i64)],
-            };
-            let then_branch = ActionValue::Operation(Box::new(mul_op));
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(4));
+        }
 
-            let add_op = ActionOperation::Add {
-                values: vec![lit(1i64), lit(1i64)],
-            };
-            let else_branch = ActionValue::Operation(Box::new(add_op));
+        #[test]
+        fn test_age_with_variables() {
+            let resolver = TestResolver::new()
+                .with_var("birth_date", "1990-03-15")
+                .with_var("ref_date", "2025-03-15");
 
-            let op = ActionOperation::If {
-                cases: vec![Case {
-                    when: condition,
-                    then: then_branch,
-                }],
-                default: Some(else_branch),
+            let op = ActionOperation::Age {
+                date_of_birth: var("birth_date"),
+                reference_date: var("ref_date"),
             };
 
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(6));
+            assert_eq!(result, Value::Int(35));
         }
-    }
-
-    // -------------------------------------------------------------------------
-    // Error Handling Tests
-    // -------------------------------------------------------------------------
-
-    mod errors {
-        use super::*;
 
         #[test]
-        fn test_type_mismatch_in_arithmetic() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Add {
-                values: vec![lit(10i64), lit("not a number")],
+        fn test_age_with_object_date() {
+            let mut date_obj = BTreeMap::new();
+            date_obj.insert("iso".to_string(), Value::String("2025-01-01".to_string()));
+            date_obj.insert("year".to_string(), Value::Int(2025));
+            date_obj.insert("month".to_string(), Value::Int(1));
+            date_obj.insert("day".to_string(), Value::Int(1));
+
+            let resolver = TestResolver::new()
+                .with_var("referencedate", Value::Object(date_obj))
+                .with_var("geboortedatum", Value::String("2005-01-01".to_string()));
+
+            let op = ActionOperation::Age {
+                date_of_birth: var("geboortedatum"),
+                reference_date: var("referencedate"),
             };
 
-            let result = execute_operation(&op, &resolver, 0);
-            assert!(matches!(result, Err(EngineError::TypeMismatch { .. })));
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::Int(20));
         }
 
         #[test]
-        fn test_variable_not_found() {
+        fn test_date_add_days() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Equals {
-                subject: var("nonexistent"),
-                value: lit(42i64),
+            let op = ActionOperation::DateAdd {
+                date: lit("2025-01-10"),
+                years: None,
+                months: None,
+                days: Some(lit(5i64)),
+                weeks: None,
             };
 
-            let result = execute_operation(&op, &resolver, 0);
-            assert!(matches!(result, Err(EngineError::VariableNotFound(_))));
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::String("2025-01-15".to_string()));
         }
 
         #[test]
-        fn test_overflow_detection() {
+        fn test_date_add_weeks() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Multiply {
-                values: vec![lit(i64::MAX), lit(2i64)],
+            let op = ActionOperation::DateAdd {
+                date: lit("2025-01-01"),
+                years: None,
+                months: None,
+                days: None,
+                weeks: Some(lit(2i64)),
             };
 
-            let result = execute_operation(&op, &resolver, 0);
-            assert!(matches!(result, Err(EngineError::ArithmeticOverflow(_))));
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::String("2025-01-15".to_string()));
         }
 
         #[test]
-        fn test_max_depth_exceeded() {
+        fn test_date_add_days_and_weeks() {
             let resolver = TestResolver::new();
+            let op = ActionOperation::DateAdd {
+                date: lit("2025-01-01"),
+                years: None,
+                months: None,
+                days: Some(lit(3i64)),
+                weeks: Some(lit(1i64)),
+            };
 
-            let mut nested: ActionValue = lit(42i64);
-            for _ in 0..=MAX_OPERATION_DEPTH + 1 {
-                let if_op = ActionOperation::If {
-                    cases: vec![Case {
-                        when: lit(true),
-                        then: nested,
-                    }],
-                    default: None,
-                };
-                nested = ActionValue::Operation(Box::new(if_op));
-            }
-
-            if let ActionValue::Operation(op) = nested {
-                let result = execute_operation(&op, &resolver, 0);
-                assert!(
-                    matches!(result, Err(EngineError::MaxDepthExceeded(_))),
-                    "Expected MaxDepthExceeded error"
-                );
-            }
-        }
-
-        #[test]
-        fn test_nan_detection_in_f64_to_i64() {
-            let result = f64_to_i64_safe(f64::NAN);
-            assert!(matches!(result, Err(EngineError::ArithmeticOverflow(_))));
-            if let Err(EngineError::ArithmeticOverflow(msg)) = result {
-                assert!(msg.contains("NaN"));
-            }
+            // 1 week + 3 days = 10 days from Jan 1 = Jan 11
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::String("2025-01-11".to_string()));
         }
 
         #[test]
-        fn test_infinity_detection_in_f64_to_i64() {
-            let result_pos = f64_to_i64_safe(f64::INFINITY);
-            assert!(matches!(
-                result_pos,
-                Err(EngineError::ArithmeticOverflow(_))
-            ));
+        fn test_date_add_negative_days() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::DateAdd {
+                date: lit("2025-01-15"),
+                years: None,
+                months: None,
+                days: Some(lit(-5i64)),
+                weeks: None,
+            };
 
-            let result_neg = f64_to_i64_safe(f64::NEG_INFINITY);
-            assert!(matches!(
-                result_neg,
-                Err(EngineError::ArithmeticOverflow(_))
-            ));
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::String("2025-01-10".to_string()));
         }
 
         #[test]
-        fn test_i64_range_overflow_detection() {
-            let result_high = f64_to_i64_safe(1e20);
-            assert!(matches!(
-                result_high,
-                Err(EngineError::ArithmeticOverflow(_))
-            ));
-
-            let result_low = f64_to_i64_safe(-1e20);
-            assert!(matches!(
-                result_low,
-                Err(EngineError::ArithmeticOverflow(_))
-            ));
+        fn test_date_add_months() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::DateAdd {
+                date: lit("2025-03-15"),
+                years: None,
+                months: Some(lit(2i64)),
+                weeks: None,
+                days: None,
+            };
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::String("2025-05-15".to_string()));
         }
 
         #[test]
-        fn test_valid_f64_to_i64_conversion() {
-            assert_eq!(f64_to_i64_safe(42.0).unwrap(), 42);
-            assert_eq!(f64_to_i64_safe(-42.0).unwrap(), -42);
-            assert_eq!(f64_to_i64_safe(0.0).unwrap(), 0);
-            assert_eq!(f64_to_i64_safe(0.9).unwrap(), 0);
-            assert_eq!(f64_to_i64_safe(-0.9).unwrap(), 0);
+        fn test_date_add_months_end_of_month_clamping() {
+            // Jan 31 + 1 month = Feb 28 (not March 1)
+            let resolver = TestResolver::new();
+            let op = ActionOperation::DateAdd {
+                date: lit("2025-01-31"),
+                years: None,
+                months: Some(lit(1i64)),
+                weeks: None,
+                days: None,
+            };
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::String("2025-02-28".to_string()));
         }
 
         #[test]
-        fn test_f64_to_i64_rejects_i64_max_as_f64() {
-            let result = f64_to_i64_safe(i64::MAX as f64);
-            assert!(
-                matches!(result, Err(EngineError::ArithmeticOverflow(_))),
-                "Expected ArithmeticOverflow for i64::MAX as f64, got: {:?}",
-                result
-            );
+        fn test_date_add_months_end_of_month_leap_year() {
+            // Jan 31 + 1 month in leap year = Feb 29
+            let resolver = TestResolver::new();
+            let op = ActionOperation::DateAdd {
+                date: lit("2024-01-31"),
+                years: None,
+                months: Some(lit(1i64)),
+                weeks: None,
+                days: None,
+            };
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::String("2024-02-29".to_string()));
         }
 
         #[test]
-        fn test_nan_equality() {
-            let nan1 = Value::Float(f64::NAN);
-            let nan2 = Value::Float(f64::NAN);
-            assert!(
-                values_equal(&nan1, &nan2),
-                "Two NaN values should be considered equal"
-            );
-
-            assert!(!values_equal(&Value::Float(f64::NAN), &Value::Int(42)));
-            assert!(!values_equal(&Value::Int(42), &Value::Float(f64::NAN)));
-            assert!(!values_equal(&Value::Float(f64::NAN), &Value::Float(42.0)));
+        fn test_date_add_months_negative() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::DateAdd {
+                date: lit("2025-03-31"),
+                years: None,
+                months: Some(lit(-1i64)),
+                weeks: None,
+                days: None,
+            };
+            // Mar 31 - 1 month = Feb 28
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::String("2025-02-28".to_string()));
         }
 
         #[test]
-        fn test_large_integer_precision_error() {
-            let large_int = Value::Int(MAX_SAFE_INTEGER + 1);
-            assert!(matches!(
-                to_number(&large_int),
-                Err(EngineError::ArithmeticOverflow(_))
-            ));
-
-            let small_int = Value::Int(MIN_SAFE_INTEGER - 1);
-            assert!(matches!(
-                to_number(&small_int),
-                Err(EngineError::ArithmeticOverflow(_))
-            ));
-
-            let safe_int = Value::Int(MAX_SAFE_INTEGER);
-            assert_eq!(to_number(&safe_int).unwrap(), MAX_SAFE_INTEGER as f64);
-
-            let safe_neg = Value::Int(MIN_SAFE_INTEGER);
-            assert_eq!(to_number(&safe_neg).unwrap(), MIN_SAFE_INTEGER as f64);
+        fn test_date_add_months_six_months() {
+            // "binnen zes maanden" from Aug 31
+            let resolver = TestResolver::new();
+            let op = ActionOperation::DateAdd {
+                date: lit("2025-08-31"),
+                years: None,
+                months: Some(lit(6i64)),
+                weeks: None,
+                days: None,
+            };
+            // Aug 31 + 6 months = Feb 28
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::String("2026-02-28".to_string()));
         }
 
         #[test]
-        fn test_arithmetic_with_large_integer() {
-            let large_value: i64 = 9_007_199_254_740_993; // MAX_SAFE_INTEGER + 1
-
+        fn test_date_add_years() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Add {
-                values: vec![lit(large_value), lit(1i64)],
+            let op = ActionOperation::DateAdd {
+                date: lit("2025-06-15"),
+                years: Some(lit(2i64)),
+                months: None,
+                weeks: None,
+                days: None,
             };
-
-            let result = execute_operation(&op, &resolver, 0);
-            assert!(
-                matches!(result, Err(EngineError::ArithmeticOverflow(_))),
-                "Large integer in arithmetic should cause overflow error"
-            );
+            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(result, Value::String("2027-06-15".to_string()));
         }
-    }
-
-    // -------------------------------------------------------------------------
-    // Collection Operations Tests
-    // -------------------------------------------------------------------------
-
-    mod collection {
-        use super::*;
 
         #[test]
-        fn test_in_found() {
+        fn test_date_add_years_leap_day() {
+            // Feb 29 + 1 year = Feb 28 (non-leap year)
             let resolver = TestResolver::new();
-            let op = ActionOperation::In {
-                subject: lit(42i64),
-                value: None,
-                values: Some(vec![lit(10i64), lit(20i64), lit(42i64), lit(50i64)]),
+            let op = ActionOperation::DateAdd {
+                date: lit("2024-02-29"),
+                years: Some(lit(1i64)),
+                months: None,
+                weeks: None,
+                days: None,
             };
-
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(true));
+            assert_eq!(result, Value::String("2025-02-28".to_string()));
         }
 
         #[test]
-        fn test_in_not_found() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::In {
-                subject: lit(99i64),
-                value: None,
-                values: Some(vec![lit(10i64), lit(20i64), lit(42i64), lit(50i64)]),
+        fn test_date_add_years_leap_day_to_leap_year() {
+            // Feb 29 + 4 years = Feb 29 (another leap year)
+            let resolver = TestResolver::new();
+            let op = ActionOperation::DateAdd {
+                date: lit("2024-02-29"),
+                years: Some(lit(4i64)),
+                months: None,
+                weeks: None,
+                days: None,
             };
-
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(false));
+            assert_eq!(result, Value::String("2028-02-29".to_string()));
         }
 
         #[test]
-        fn test_in_with_strings() {
+        fn test_date_add_combined_years_months_weeks_days() {
+            // 2025-01-15 + 1 year + 2 months + 1 week + 3 days
             let resolver = TestResolver::new();
-            let op = ActionOperation::In {
-                subject: lit("apple"),
-                value: None,
-                values: Some(vec![lit("banana"), lit("apple"), lit("orange")]),
+            let op = ActionOperation::DateAdd {
+                date: lit("2025-01-15"),
+                years: Some(lit(1i64)),
+                months: Some(lit(2i64)),
+                weeks: Some(lit(1i64)),
+                days: Some(lit(3i64)),
             };
-
+            // +1y = 2026-01-15, +2m = 2026-03-15, +1w = 2026-03-22, +3d = 2026-03-25
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(true));
+            assert_eq!(result, Value::String("2026-03-25".to_string()));
         }
 
         #[test]
-        fn test_in_with_mixed_int_float() {
+        fn test_date_construct() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::In {
-                subject: lit(42i64),
-                value: None,
-                values: Some(vec![lit(10i64), lit(42.0f64), lit(50i64)]),
+            let op = ActionOperation::Date {
+                year: lit(2025i64),
+                month: lit(3i64),
+                day: lit(15i64),
             };
 
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(true));
+            assert_eq!(result, Value::String("2025-03-15".to_string()));
         }
 
         #[test]
-        fn test_in_with_variables() {
-            let resolver = TestResolver::new().with_var("status", "active");
-
-            let op = ActionOperation::In {
-                subject: var("status"),
-                value: None,
-                values: Some(vec![lit("active"), lit("pending"), lit("inactive")]),
+        fn test_date_construct_leap_year() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::Date {
+                year: lit(2024i64),
+                month: lit(2i64),
+                day: lit(29i64),
             };
 
             let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Bool(true));
+            assert_eq!(result, Value::String("2024-02-29".to_string()));
         }
 
         #[test]
-        fn test_in_missing_values() {
+        fn test_date_construct_invalid() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::In {
-                subject: lit(42i64),
-                value: None,
-                values: None,
+            let op = ActionOperation::Date {
+                year: lit(2025i64),
+                month: lit(2i64),
+                day: lit(30i64), // Feb 30 doesn't exist
             };
 
             let result = execute_operation(&op, &resolver, 0);
@@ -2238,395 +7283,667 @@ mod tests {
         }
 
         #[test]
-        fn test_list_construct() {
+        fn test_day_of_week() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::List {
-                items: vec![lit(1i64), lit(2i64), lit(3i64)],
+
+            // 2025-01-06 is a Monday (weekday 0)
+            let op = ActionOperation::DayOfWeek {
+                date: lit("2025-01-06"),
             };
+            assert_eq!(execute_operation(&op, &resolver, 0).unwrap(), Value::Int(0));
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
+            // 2025-01-12 is a Sunday (weekday 6)
+            let op2 = ActionOperation::DayOfWeek {
+                date: lit("2025-01-12"),
+            };
             assert_eq!(
-                result,
-                Value::Array(vec![Value::Int(1), Value::Int(2), Value::Int(3)])
+                execute_operation(&op2, &resolver, 0).unwrap(),
+                Value::Int(6)
             );
         }
 
+        fn subtract_date(later: &str, earlier: &str, unit: &str) -> ActionOperation {
+            ActionOperation::SubtractDate {
+                values: vec![lit(later), lit(earlier)],
+                unit: lit(unit),
+                rounding: None,
+            }
+        }
+
+        fn subtract_date_rounded(
+            later: &str,
+            earlier: &str,
+            unit: &str,
+            rounding: &str,
+        ) -> ActionOperation {
+            ActionOperation::SubtractDate {
+                values: vec![lit(later), lit(earlier)],
+                unit: lit(unit),
+                rounding: Some(lit(rounding)),
+            }
+        }
+
         #[test]
-        fn test_list_with_mixed_types() {
+        fn test_subtract_date_months_rounding() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::List {
-                items: vec![lit(1i64), lit("two"), lit(true)],
+            let months = |later: &str, earlier: &str, rounding: &str| {
+                execute_operation(
+                    &subtract_date_rounded(later, earlier, "months", rounding),
+                    &resolver,
+                    0,
+                )
+                .unwrap()
             };
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
+            assert_eq!(months("2025-02-14", "2025-01-15", "floor"), Value::Int(0));
+            assert_eq!(months("2025-02-14", "2025-01-15", "ceil"), Value::Int(1));
+            assert_eq!(months("2025-02-14", "2025-01-15", "nearest"), Value::Int(1));
+            assert_eq!(months("2025-01-20", "2025-01-15", "nearest"), Value::Int(0));
+            // Exact months are not rounded up, and the sign is kept
+            assert_eq!(months("2025-03-15", "2025-01-15", "ceil"), Value::Int(2));
+            assert_eq!(months("2025-01-15", "2025-02-14", "ceil"), Value::Int(-1));
+            // Default stays floor
             assert_eq!(
-                result,
-                Value::Array(vec![
-                    Value::Int(1),
-                    Value::String("two".to_string()),
-                    Value::Bool(true),
-                ])
+                execute_operation(
+                    &subtract_date("2025-02-14", "2025-01-15", "months"),
+                    &resolver,
+                    0
+                )
+                .unwrap(),
+                Value::Int(0)
             );
         }
-    }
 
-    // -------------------------------------------------------------------------
-    // Date Operations Tests
-    // -------------------------------------------------------------------------
+        #[test]
+        fn test_subtract_date_years_rounding_and_unknown_mode() {
+            let resolver = TestResolver::new();
+            let op = subtract_date_rounded("2025-01-16", "2024-01-15", "years", "ceil");
+            assert_eq!(execute_operation(&op, &resolver, 0).unwrap(), Value::Int(2));
 
-    mod date_operations {
-        use super::*;
+            let op = subtract_date_rounded("2025-02-14", "2025-01-15", "months", "up");
+            assert!(matches!(
+                execute_operation(&op, &resolver, 0),
+                Err(EngineError::InvalidOperation(_))
+            ));
+        }
+
+        fn ymd(years: i64, months: i64, days: i64) -> Value {
+            let mut obj = BTreeMap::new();
+            obj.insert("years".to_string(), Value::Int(years));
+            obj.insert("months".to_string(), Value::Int(months));
+            obj.insert("days".to_string(), Value::Int(days));
+            Value::Object(obj)
+        }
 
         #[test]
-        fn test_age_exact_birthday() {
+        fn test_subtract_date_ymd() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Age {
-                date_of_birth: lit("1990-03-15"),
-                reference_date: lit("2025-03-15"),
-            };
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(35));
+            // 2020-01-31 + 3 years = 2023-01-31, + 1 month = 2023-02-28 (clamped), + 1 day
+            let op = subtract_date("2023-03-01", "2020-01-31", "ymd");
+            assert_eq!(execute_operation(&op, &resolver, 0).unwrap(), ymd(3, 1, 1));
+
+            let op = subtract_date("2025-05-20", "1990-03-15", "ymd");
+            assert_eq!(execute_operation(&op, &resolver, 0).unwrap(), ymd(35, 2, 5));
         }
 
         #[test]
-        fn test_age_before_birthday() {
+        fn test_subtract_date_ymd_negative() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Age {
-                date_of_birth: lit("1990-03-15"),
-                reference_date: lit("2025-03-14"),
-            };
+            let op = subtract_date("2020-01-31", "2023-03-01", "ymd");
+            assert_eq!(
+                execute_operation(&op, &resolver, 0).unwrap(),
+                ymd(-3, -1, -1)
+            );
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(34));
+        #[test]
+        fn test_subtract_date_scalar_units() {
+            let resolver = TestResolver::new();
+            let cases = [("days", 1125), ("months", 37), ("years", 3)];
+            for (unit, expected) in cases {
+                let op = subtract_date("2023-03-01", "2020-01-31", unit);
+                assert_eq!(
+                    execute_operation(&op, &resolver, 0).unwrap(),
+                    Value::Int(expected),
+                    "unit {}",
+                    unit
+                );
+            }
         }
 
         #[test]
-        fn test_age_feb29_birthday_on_non_leap_year() {
-            // Per Dutch law (BW art. 1:2): Feb 28 counts as birthday
+        fn test_subtract_date_unknown_unit() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Age {
-                date_of_birth: lit("2000-02-29"),
-                reference_date: lit("2001-02-28"),
-            };
+            let op = subtract_date("2023-03-01", "2020-01-31", "fortnights");
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::InvalidOperation(_))));
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(1));
+        fn pro_rata(amount: f64, start: &str, end: &str, period: &str) -> ActionOperation {
+            ActionOperation::ProRata {
+                value: lit(amount),
+                start_date: lit(start),
+                end_date: lit(end),
+                period: lit(period),
+            }
         }
 
         #[test]
-        fn test_age_feb29_birthday_before_feb28() {
+        fn test_pro_rata_full_period_is_full_amount() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Age {
-                date_of_birth: lit("2000-02-29"),
-                reference_date: lit("2001-02-27"),
-            };
-
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(0));
+            let month = pro_rata(120.0, "2024-02-01", "2024-02-29", "month");
+            assert_eq!(
+                execute_operation(&month, &resolver, 0).unwrap(),
+                Value::Float(120.0)
+            );
+            let year = pro_rata(1200.0, "2025-01-01", "2025-12-31", "year");
+            assert_eq!(
+                execute_operation(&year, &resolver, 0).unwrap(),
+                Value::Float(1200.0)
+            );
         }
 
         #[test]
-        fn test_age_feb29_birthday_on_leap_year() {
+        fn test_pro_rata_half_month() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Age {
-                date_of_birth: lit("2000-02-29"),
-                reference_date: lit("2004-02-29"),
-            };
+            let op = pro_rata(150.0, "2025-04-16", "2025-04-30", "month");
+            assert_eq!(
+                execute_operation(&op, &resolver, 0).unwrap(),
+                Value::Float(75.0)
+            );
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(4));
+        fn clamp_date(subject: &str) -> Result<Value> {
+            let op = ActionOperation::ClampDate {
+                subject: lit(subject),
+                min: lit("2025-01-01"),
+                max: lit("2025-12-31"),
+            };
+            execute_operation(&op, &TestResolver::new(), 0)
         }
 
         #[test]
-        fn test_age_with_variables() {
-            let resolver = TestResolver::new()
-                .with_var("birth_date", "1990-03-15")
-                .with_var("ref_date", "2025-03-15");
+        fn test_clamp_date_to_range() {
+            assert_eq!(
+                clamp_date("2024-06-15").unwrap(),
+                Value::String("2025-01-01".to_string())
+            );
+            assert_eq!(
+                clamp_date("2026-02-01").unwrap(),
+                Value::String("2025-12-31".to_string())
+            );
+            assert_eq!(
+                clamp_date("2025-07-04").unwrap(),
+                Value::String("2025-07-04".to_string())
+            );
+        }
 
-            let op = ActionOperation::Age {
-                date_of_birth: var("birth_date"),
-                reference_date: var("ref_date"),
+        #[test]
+        fn test_clamp_date_inverted_bounds_is_error() {
+            let op = ActionOperation::ClampDate {
+                subject: lit("2025-07-04"),
+                min: lit("2025-12-31"),
+                max: lit("2025-01-01"),
             };
+            let result = execute_operation(&op, &TestResolver::new(), 0);
+            assert!(matches!(result, Err(EngineError::InvalidOperation(_))));
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(35));
+        fn next_business_day(subject: &str, holidays: &[&str]) -> Value {
+            let holidays = holidays.iter().map(|&d| Value::from(d)).collect();
+            let op = ActionOperation::NextBusinessDay {
+                subject: lit(subject),
+                holidays: Some(lit(Value::Array(holidays))),
+            };
+            execute_operation(&op, &TestResolver::new(), 0).unwrap()
         }
 
         #[test]
-        fn test_age_with_object_date() {
-            let mut date_obj = BTreeMap::new();
-            date_obj.insert("iso".to_string(), Value::String("2025-01-01".to_string()));
-            date_obj.insert("year".to_string(), Value::Int(2025));
-            date_obj.insert("month".to_string(), Value::Int(1));
-            date_obj.insert("day".to_string(), Value::Int(1));
+        fn test_next_business_day_rolls_weekend_to_monday() {
+            // 2025-10-18 is a Saturday
+            assert_eq!(
+                next_business_day("2025-10-18", &[]),
+                Value::from("2025-10-20")
+            );
+            assert_eq!(
+                next_business_day("2025-10-19", &[]),
+                Value::from("2025-10-20")
+            );
+            assert_eq!(
+                next_business_day("2025-10-17", &[]),
+                Value::from("2025-10-17")
+            );
+        }
 
-            let resolver = TestResolver::new()
-                .with_var("referencedate", Value::Object(date_obj))
-                .with_var("geboortedatum", Value::String("2005-01-01".to_string()));
+        #[test]
+        fn test_next_business_day_skips_declared_holidays() {
+            // Christmas 2025 falls on Thursday and Friday, then a weekend
+            let christmas = ["2025-12-25", "2025-12-26"];
+            assert_eq!(
+                next_business_day("2025-12-25", &christmas),
+                Value::from("2025-12-29")
+            );
+            assert_eq!(
+                next_business_day("2025-12-24", &christmas),
+                Value::from("2025-12-24")
+            );
+        }
 
-            let op = ActionOperation::Age {
-                date_of_birth: var("geboortedatum"),
-                reference_date: var("referencedate"),
+        fn year_fraction(start: &str, end: &str, convention: Option<&str>) -> f64 {
+            let op = ActionOperation::YearFraction {
+                start: lit(start),
+                end: lit(end),
+                convention: convention.map(lit),
             };
+            match execute_operation(&op, &TestResolver::new(), 0).unwrap() {
+                Value::Float(f) => f,
+                other => panic!("expected float, got {:?}", other),
+            }
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::Int(20));
+        #[test]
+        fn test_year_fraction_half_year_act_365() {
+            // 181 days from January 1 to July 1, 2025; the convention defaults to ACT/365
+            let fraction = year_fraction("2025-01-01", "2025-07-01", None);
+            assert!((fraction - 181.0 / 365.0).abs() < 1e-12, "{}", fraction);
+            let explicit = year_fraction("2025-01-01", "2025-07-01", Some("ACT/365"));
+            assert_eq!(fraction, explicit);
+            let reversed = year_fraction("2025-07-01", "2025-01-01", None);
+            assert_eq!(reversed, -fraction);
         }
 
         #[test]
-        fn test_date_add_days() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2025-01-10"),
-                years: None,
-                months: None,
-                days: Some(lit(5i64)),
-                weeks: None,
+        fn test_year_fraction_act_act_across_leap_year() {
+            // 184 days of 2023 (365) and 182 days of 2024 (366)
+            let fraction = year_fraction("2023-07-01", "2024-07-01", Some("ACT/ACT"));
+            let expected = 184.0 / 365.0 + 182.0 / 366.0;
+            assert!((fraction - expected).abs() < 1e-12, "{}", fraction);
+
+            let op = ActionOperation::YearFraction {
+                start: lit("2023-07-01"),
+                end: lit("2024-07-01"),
+                convention: Some(lit("30/360")),
             };
+            let result = execute_operation(&op, &TestResolver::new(), 0);
+            assert!(matches!(result, Err(EngineError::InvalidOperation(_))));
+        }
+
+        fn nth_weekday(date: &str, weekday: i64, ordinal: i64) -> Result<Value> {
+            let op = ActionOperation::NthWeekday {
+                date: lit(date),
+                weekday: lit(weekday),
+                ordinal: lit(ordinal),
+            };
+            execute_operation(&op, &TestResolver::new(), 0)
+        }
+
+        #[test]
+        fn test_nth_weekday_first_monday() {
+            assert_eq!(
+                nth_weekday("2025-09-20", 1, 1).unwrap(),
+                Value::String("2025-09-01".to_string())
+            );
+            assert_eq!(
+                nth_weekday("2025-10-20", 1, 1).unwrap(),
+                Value::String("2025-10-06".to_string())
+            );
+        }
+
+        #[test]
+        fn test_nth_weekday_last_friday() {
+            assert_eq!(
+                nth_weekday("2025-10-01", 5, -1).unwrap(),
+                Value::String("2025-10-31".to_string())
+            );
+            assert_eq!(
+                nth_weekday("2024-02-10", 5, -1).unwrap(),
+                Value::String("2024-02-23".to_string())
+            );
+        }
+
+        #[test]
+        fn test_nth_weekday_nonexistent_fifth_is_error() {
+            // February 2025 has only four Mondays
+            let result = nth_weekday("2025-02-01", 1, 5);
+            assert!(matches!(result, Err(EngineError::InvalidOperation(_))));
+            assert_eq!(
+                nth_weekday("2025-06-01", 1, 5).unwrap(),
+                Value::String("2025-06-30".to_string())
+            );
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2025-01-15".to_string()));
+        fn date_number(op: fn(ActionValue) -> ActionOperation, date: &str) -> Value {
+            execute_operation(&op(lit(date)), &TestResolver::new(), 0).unwrap()
         }
 
         #[test]
-        fn test_date_add_weeks() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2025-01-01"),
-                years: None,
-                months: None,
-                days: None,
-                weeks: Some(lit(2i64)),
-            };
+        fn test_iso_week_across_year_boundary() {
+            let iso_week = |subject| ActionOperation::IsoWeek { subject };
+            // Monday 2024-12-30 starts ISO week 1 of 2025
+            assert_eq!(date_number(iso_week, "2024-12-30"), Value::Int(1));
+            // Sunday 2021-01-03 still belongs to week 53 of 2020
+            assert_eq!(date_number(iso_week, "2021-01-03"), Value::Int(53));
+            assert_eq!(date_number(iso_week, "2025-06-15"), Value::Int(24));
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2025-01-15".to_string()));
+        fn convert_duration(value: impl Into<Value>, from: &str, to: &str) -> Result<Value> {
+            let op = ActionOperation::ConvertDuration {
+                value: lit(value),
+                from: lit(from),
+                to: lit(to),
+            };
+            execute_operation(&op, &TestResolver::new(), 0)
         }
 
         #[test]
-        fn test_date_add_days_and_weeks() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2025-01-01"),
-                years: None,
-                months: None,
-                days: Some(lit(3i64)),
-                weeks: Some(lit(1i64)),
-            };
+        fn test_convert_duration_uses_declared_factors() {
+            assert_eq!(
+                convert_duration(90, "days", "months").unwrap(),
+                Value::Int(3)
+            );
+            assert_eq!(
+                convert_duration(720, "days", "years").unwrap(),
+                Value::Int(2)
+            );
+            assert_eq!(
+                convert_duration(2, "years", "months").unwrap(),
+                Value::Int(24)
+            );
+            assert_eq!(
+                convert_duration(45, "days", "months").unwrap(),
+                Value::Float(1.5)
+            );
+        }
 
-            // 1 week + 3 days = 10 days from Jan 1 = Jan 11
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2025-01-11".to_string()));
+        #[test]
+        fn test_convert_duration_unknown_unit_is_error() {
+            let result = convert_duration(1, "days", "fortnights");
+            assert!(matches!(result, Err(EngineError::InvalidOperation(_))));
         }
 
         #[test]
-        fn test_date_add_negative_days() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2025-01-15"),
-                years: None,
-                months: None,
-                days: Some(lit(-5i64)),
-                weeks: None,
-            };
+        fn test_quarter() {
+            let quarter = |subject| ActionOperation::Quarter { subject };
+            assert_eq!(date_number(quarter, "2025-10-01"), Value::Int(4));
+            assert_eq!(date_number(quarter, "2025-12-31"), Value::Int(4));
+            assert_eq!(date_number(quarter, "2025-03-31"), Value::Int(1));
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2025-01-10".to_string()));
+        fn date_in_periods(subject: &str) -> Value {
+            let period = |from: &str, to: Option<&str>| {
+                let mut bounds = BTreeMap::new();
+                bounds.insert("from".to_string(), Value::from(from));
+                bounds.insert("to".to_string(), to.map_or(Value::Null, Value::from));
+                Value::Object(bounds)
+            };
+            let op = ActionOperation::DateInPeriods {
+                subject: lit(subject),
+                periods: lit(Value::Array(vec![
+                    period("2024-01-01", Some("2024-03-31")),
+                    period("2024-07-01", Some("2024-09-30")),
+                    period("2025-01-01", None),
+                ])),
+            };
+            execute_operation(&op, &TestResolver::new(), 0).unwrap()
         }
 
         #[test]
-        fn test_date_add_months() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2025-03-15"),
-                years: None,
-                months: Some(lit(2i64)),
-                weeks: None,
-                days: None,
-            };
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2025-05-15".to_string()));
+        fn test_date_in_periods_inside_a_period() {
+            assert_eq!(date_in_periods("2024-01-01"), Value::Bool(true));
+            assert_eq!(date_in_periods("2024-09-30"), Value::Bool(true));
         }
 
         #[test]
-        fn test_date_add_months_end_of_month_clamping() {
-            // Jan 31 + 1 month = Feb 28 (not March 1)
-            let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2025-01-31"),
-                years: None,
-                months: Some(lit(1i64)),
-                weeks: None,
-                days: None,
-            };
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2025-02-28".to_string()));
+        fn test_date_in_periods_between_periods_is_false() {
+            assert_eq!(date_in_periods("2024-04-01"), Value::Bool(false));
+            assert_eq!(date_in_periods("2023-12-31"), Value::Bool(false));
         }
 
         #[test]
-        fn test_date_add_months_end_of_month_leap_year() {
-            // Jan 31 + 1 month in leap year = Feb 29
-            let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2024-01-31"),
-                years: None,
-                months: Some(lit(1i64)),
-                weeks: None,
-                days: None,
+        fn test_date_in_periods_open_ended() {
+            assert_eq!(date_in_periods("2031-06-15"), Value::Bool(true));
+        }
+
+        fn standaardpremie_on(subject: &str) -> Value {
+            let period = |from: &str, to: Option<&str>, value: i64| {
+                let mut fields = BTreeMap::new();
+                fields.insert("from".to_string(), Value::from(from));
+                fields.insert("to".to_string(), to.map_or(Value::Null, Value::from));
+                fields.insert("value".to_string(), Value::Int(value));
+                Value::Object(fields)
             };
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2024-02-29".to_string()));
+            let op = ActionOperation::PeriodLookup {
+                subject: lit(subject),
+                periods: lit(Value::Array(vec![
+                    period("2024-01-01", Some("2024-12-31"), 195_800),
+                    period("2025-01-01", None, 208_800),
+                ])),
+                default: Some(lit(0)),
+            };
+            execute_operation(&op, &TestResolver::new(), 0).unwrap()
         }
 
         #[test]
-        fn test_date_add_months_negative() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2025-03-31"),
-                years: None,
-                months: Some(lit(-1i64)),
-                weeks: None,
-                days: None,
-            };
-            // Mar 31 - 1 month = Feb 28
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2025-02-28".to_string()));
+        fn test_period_lookup_selects_period_value() {
+            assert_eq!(standaardpremie_on("2024-06-30"), Value::Int(195_800));
+            assert_eq!(standaardpremie_on("2024-12-31"), Value::Int(195_800));
+            assert_eq!(standaardpremie_on("2025-01-01"), Value::Int(208_800));
+            assert_eq!(standaardpremie_on("2031-03-15"), Value::Int(208_800));
         }
 
         #[test]
-        fn test_date_add_months_six_months() {
-            // "binnen zes maanden" from Aug 31
-            let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2025-08-31"),
-                years: None,
-                months: Some(lit(6i64)),
-                weeks: None,
-                days: None,
+        fn test_period_lookup_default_outside_all_periods() {
+            assert_eq!(standaardpremie_on("2023-12-31"), Value::Int(0));
+        }
+
+        fn extreme_date(dates: Vec<Value>, latest: bool) -> Result<Value> {
+            let value = lit(Value::Array(dates));
+            let op = if latest {
+                ActionOperation::MaxDate { value }
+            } else {
+                ActionOperation::MinDate { value }
             };
-            // Aug 31 + 6 months = Feb 28
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2026-02-28".to_string()));
+            execute_operation(&op, &TestResolver::new(), 0)
         }
 
         #[test]
-        fn test_date_add_years() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2025-06-15"),
-                years: Some(lit(2i64)),
-                months: None,
-                weeks: None,
-                days: None,
-            };
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2027-06-15".to_string()));
+        fn test_min_and_max_date_of_three_dates() {
+            let mut reference = BTreeMap::new();
+            reference.insert("iso".to_string(), Value::from("2023-11-30"));
+            let dates = vec![
+                Value::from("2024-03-15"),
+                Value::Object(reference),
+                Value::from("2024-01-02"),
+            ];
+            assert_eq!(
+                extreme_date(dates.clone(), false).unwrap(),
+                Value::from("2023-11-30")
+            );
+            assert_eq!(
+                extreme_date(dates, true).unwrap(),
+                Value::from("2024-03-15")
+            );
         }
 
         #[test]
-        fn test_date_add_years_leap_day() {
-            // Feb 29 + 1 year = Feb 28 (non-leap year)
+        fn test_min_and_max_date_reject_empty_and_invalid_entries() {
+            assert!(matches!(
+                extreme_date(vec![], false),
+                Err(EngineError::InvalidOperation(_))
+            ));
+            assert!(matches!(
+                extreme_date(vec![], true),
+                Err(EngineError::InvalidOperation(_))
+            ));
+            let invalid = vec![Value::from("2024-03-15"), Value::from("15-03-2024")];
+            assert!(extreme_date(invalid, true).is_err());
+            let invalid = vec![Value::from("2024-03-15"), Value::Int(20240315)];
+            assert!(matches!(
+                extreme_date(invalid, false),
+                Err(EngineError::TypeMismatch { .. })
+            ));
+        }
+
+        fn period_overlap_days(bounds: [Option<&str>; 4]) -> Result<Value> {
             let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2024-02-29"),
-                years: Some(lit(1i64)),
-                months: None,
-                weeks: None,
-                days: None,
+            let [a_from, a_to, b_from, b_to] =
+                bounds.map(|bound| lit(bound.map_or(Value::Null, Value::from)));
+            let op = ActionOperation::PeriodOverlapDays {
+                a_from,
+                a_to,
+                b_from,
+                b_to,
             };
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2025-02-28".to_string()));
+            execute_operation(&op, &resolver, 0)
         }
 
         #[test]
-        fn test_date_add_years_leap_day_to_leap_year() {
-            // Feb 29 + 4 years = Feb 29 (another leap year)
-            let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2024-02-29"),
-                years: Some(lit(4i64)),
-                months: None,
-                weeks: None,
-                days: None,
-            };
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2028-02-29".to_string()));
+        fn test_period_overlap_days_full_and_partial_overlap() {
+            let full = [
+                Some("2024-01-01"),
+                Some("2024-12-31"),
+                Some("2024-03-01"),
+                Some("2024-03-31"),
+            ];
+            assert_eq!(period_overlap_days(full).unwrap(), Value::Int(31));
+
+            let partial = [
+                Some("2024-01-01"),
+                Some("2024-01-31"),
+                Some("2024-01-20"),
+                Some("2024-02-10"),
+            ];
+            assert_eq!(period_overlap_days(partial).unwrap(), Value::Int(12));
         }
 
         #[test]
-        fn test_date_add_combined_years_months_weeks_days() {
-            // 2025-01-15 + 1 year + 2 months + 1 week + 3 days
-            let resolver = TestResolver::new();
-            let op = ActionOperation::DateAdd {
-                date: lit("2025-01-15"),
-                years: Some(lit(1i64)),
-                months: Some(lit(2i64)),
-                weeks: Some(lit(1i64)),
-                days: Some(lit(3i64)),
-            };
-            // +1y = 2026-01-15, +2m = 2026-03-15, +1w = 2026-03-22, +3d = 2026-03-25
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2026-03-25".to_string()));
+        fn test_period_overlap_days_disjoint_and_touching() {
+            let disjoint = [
+                Some("2024-01-01"),
+                Some("2024-01-31"),
+                Some("2024-03-01"),
+                Some("2024-03-31"),
+            ];
+            assert_eq!(period_overlap_days(disjoint).unwrap(), Value::Int(0));
+
+            let adjacent = [
+                Some("2024-01-01"),
+                Some("2024-01-31"),
+                Some("2024-02-01"),
+                Some("2024-02-29"),
+            ];
+            assert_eq!(period_overlap_days(adjacent).unwrap(), Value::Int(0));
+
+            let touching = [
+                Some("2024-01-01"),
+                Some("2024-01-31"),
+                Some("2024-01-31"),
+                Some("2024-02-29"),
+            ];
+            assert_eq!(period_overlap_days(touching).unwrap(), Value::Int(1));
         }
 
         #[test]
-        fn test_date_construct() {
-            let resolver = TestResolver::new();
-            let op = ActionOperation::Date {
-                year: lit(2025i64),
-                month: lit(3i64),
-                day: lit(15i64),
-            };
+        fn test_period_overlap_days_open_ended_bounds() {
+            let open_end = [
+                Some("2024-01-01"),
+                None,
+                Some("2024-12-01"),
+                Some("2024-12-31"),
+            ];
+            assert_eq!(period_overlap_days(open_end).unwrap(), Value::Int(31));
+
+            let open_start = [None, Some("2024-01-10"), Some("2024-01-01"), None];
+            assert_eq!(period_overlap_days(open_start).unwrap(), Value::Int(10));
+
+            let unbounded = [Some("2024-01-01"), None, Some("2024-06-01"), None];
+            assert!(matches!(
+                period_overlap_days(unbounded),
+                Err(EngineError::InvalidOperation(_))
+            ));
+        }
 
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2025-03-15".to_string()));
+        #[test]
+        fn test_period_overlap_days_inverted_period_is_error() {
+            let inverted = [
+                Some("2024-02-01"),
+                Some("2024-01-01"),
+                Some("2024-01-01"),
+                Some("2024-12-31"),
+            ];
+            assert!(matches!(
+                period_overlap_days(inverted),
+                Err(EngineError::InvalidOperation(_))
+            ));
         }
 
         #[test]
-        fn test_date_construct_leap_year() {
+        fn test_pro_rata_span_outside_period_is_error() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Date {
-                year: lit(2024i64),
-                month: lit(2i64),
-                day: lit(29i64),
-            };
-
-            let result = execute_operation(&op, &resolver, 0).unwrap();
-            assert_eq!(result, Value::String("2024-02-29".to_string()));
+            let op = pro_rata(150.0, "2025-04-16", "2025-05-15", "month");
+            let result = execute_operation(&op, &resolver, 0);
+            assert!(matches!(result, Err(EngineError::InvalidOperation(_))));
         }
 
         #[test]
-        fn test_date_construct_invalid() {
+        fn test_date_strict_comparisons() {
             let resolver = TestResolver::new();
-            let op = ActionOperation::Date {
-                year: lit(2025i64),
-                month: lit(2i64),
-                day: lit(30i64), // Feb 30 doesn't exist
+            let before = |a: &str, b: &str| ActionOperation::DateBefore {
+                subject: lit(a),
+                value: lit(b),
             };
+            let after = |a: &str, b: &str| ActionOperation::DateAfter {
+                subject: lit(a),
+                value: lit(b),
+            };
+            let eval = |op: ActionOperation| execute_operation(&op, &resolver, 0).unwrap();
 
-            let result = execute_operation(&op, &resolver, 0);
-            assert!(matches!(result, Err(EngineError::InvalidOperation(_))));
+            assert_eq!(eval(before("2024-12-31", "2025-01-01")), Value::Bool(true));
+            assert_eq!(eval(before("2025-01-01", "2025-01-01")), Value::Bool(false));
+            assert_eq!(eval(after("2025-01-02", "2025-01-01")), Value::Bool(true));
+            assert_eq!(eval(after("2025-01-01", "2025-01-01")), Value::Bool(false));
         }
 
         #[test]
-        fn test_day_of_week() {
+        fn test_date_inclusive_comparisons() {
             let resolver = TestResolver::new();
-
-            // 2025-01-06 is a Monday (weekday 0)
-            let op = ActionOperation::DayOfWeek {
-                date: lit("2025-01-06"),
+            let on_or_before = |a: &str, b: &str| ActionOperation::DateOnOrBefore {
+                subject: lit(a),
+                value: lit(b),
             };
-            assert_eq!(execute_operation(&op, &resolver, 0).unwrap(), Value::Int(0));
-
-            // 2025-01-12 is a Sunday (weekday 6)
-            let op2 = ActionOperation::DayOfWeek {
-                date: lit("2025-01-12"),
+            let on_or_after = |a: &str, b: &str| ActionOperation::DateOnOrAfter {
+                subject: lit(a),
+                value: lit(b),
             };
+            let eval = |op: ActionOperation| execute_operation(&op, &resolver, 0).unwrap();
+
             assert_eq!(
-                execute_operation(&op2, &resolver, 0).unwrap(),
-                Value::Int(6)
+                eval(on_or_before("2025-01-01", "2025-01-01")),
+                Value::Bool(true)
+            );
+            assert_eq!(
+                eval(on_or_before("2025-01-02", "2025-01-01")),
+                Value::Bool(false)
+            );
+            assert_eq!(
+                eval(on_or_after("2025-01-01", "2025-01-01")),
+                Value::Bool(true)
             );
+            assert_eq!(
+                eval(on_or_after("2024-12-31", "2025-01-01")),
+                Value::Bool(false)
+            );
+        }
+
+        #[test]
+        fn test_date_comparison_invalid_date() {
+            let resolver = TestResolver::new();
+            let op = ActionOperation::DateBefore {
+                subject: lit("01-01-2025"),
+                value: lit("2025-01-01"),
+            };
+            assert!(execute_operation(&op, &resolver, 0).is_err());
         }
     }
 
@@ -2653,6 +7970,25 @@ mod tests {
         assert!(matches!(result, Err(EngineError::TypeMismatch { .. })));
     }
 
+    #[test]
+    fn test_parse_date_out_of_range_vs_bad_format() {
+        for impossible in ["2025-13-01", "2025-02-30", "2025-00-10"] {
+            let err = parse_date(&Value::String(impossible.to_string())).unwrap_err();
+            assert!(
+                err.to_string().contains("does not exist"),
+                "{impossible}: {err}"
+            );
+        }
+
+        for malformed in ["15/01/2025", "2025-01", "2025-01-01T00:00", "", "januari"] {
+            let err = parse_date(&Value::String(malformed.to_string())).unwrap_err();
+            assert!(
+                err.to_string().contains("Expected format: YYYY-MM-DD"),
+                "{malformed}: {err}"
+            );
+        }
+    }
+
     // -------------------------------------------------------------------------
     // values_equal Tests
     // -------------------------------------------------------------------------
@@ -2664,25 +8000,45 @@ mod tests {
 
         assert!(!values_equal(
             &Value::Int(large_int),
-            &Value::Float(large_int as f64)
+            &Value::Float(large_int as f64),
+            MAX_SAFE_INTEGER
         ));
         assert!(!values_equal(
             &Value::Float(large_neg as f64),
-            &Value::Int(large_neg)
+            &Value::Int(large_neg),
+            MAX_SAFE_INTEGER
         ));
 
-        assert!(values_equal(&Value::Int(42), &Value::Float(42.0)));
-        assert!(values_equal(&Value::Float(42.0), &Value::Int(42)));
+        assert!(values_equal(
+            &Value::Int(42),
+            &Value::Float(42.0),
+            MAX_SAFE_INTEGER
+        ));
+        assert!(values_equal(
+            &Value::Float(42.0),
+            &Value::Int(42),
+            MAX_SAFE_INTEGER
+        ));
         assert!(values_equal(
             &Value::Int(MAX_SAFE_INTEGER),
-            &Value::Float(MAX_SAFE_INTEGER as f64)
+            &Value::Float(MAX_SAFE_INTEGER as f64),
+            MAX_SAFE_INTEGER
         ));
 
         assert!(values_equal(
             &Value::Float(f64::NAN),
-            &Value::Float(f64::NAN)
+            &Value::Float(f64::NAN),
+            MAX_SAFE_INTEGER
+        ));
+        assert!(!values_equal(
+            &Value::Int(0),
+            &Value::Float(f64::NAN),
+            MAX_SAFE_INTEGER
+        ));
+        assert!(!values_equal(
+            &Value::Float(f64::NAN),
+            &Value::Int(0),
+            MAX_SAFE_INTEGER
         ));
-        assert!(!values_equal(&Value::Int(0), &Value::Float(f64::NAN)));
-        assert!(!values_equal(&Value::Float(f64::NAN), &Value::Int(0)));
     }
 }
@@ -5,6 +5,8 @@
 //! - Output index for fast article lookup by output name
 //! - Implements index for IoC open term resolution
 //! - Version selection based on reference_date
+//! - Optional namespaces (`nl:zorgtoeslagwet`) to keep IDs from different
+//!   jurisdictions apart
 //!
 //! # Multi-version Support
 //!
@@ -19,13 +21,18 @@
 //! The resolver enforces a maximum number of loaded laws (see [`crate::config::MAX_LOADED_LAWS`])
 //! to prevent memory exhaustion attacks.
 
-use crate::article::{Article, ArticleBasedLaw, HookFilter, HookPoint, ProcedureDefinition};
+use crate::article::{Article, ArticleBasedLaw, HookFilter, HookPoint, Input, ProcedureDefinition};
 use crate::config;
 use crate::error::{EngineError, Result};
 use crate::priority::{self, Candidate};
-use crate::types::Value;
+use crate::types::{ParameterType, RegulatoryLayer, Value};
 use chrono::NaiveDate;
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+/// Separator between a namespace and a bare law ID, as in `nl:zorgtoeslagwet`.
+pub const NAMESPACE_SEPARATOR: char = ':';
 
 /// A reference to a law article, used in implements and overrides indexes.
 #[derive(Debug, Clone, PartialEq)]
@@ -35,12 +42,119 @@ pub(crate) struct LawArticleRef {
 }
 
 /// A hook index entry linking a hook declaration to the law and article that defined it.
+#[derive(Clone)]
 pub(crate) struct HookEntry {
     pub(crate) law_id: String,
     pub(crate) article_number: String,
     filter: HookFilter,
 }
 
+/// A `legal_basis` entry that does not resolve to a loaded law and article.
+///
+/// Reported by [`RuleResolver::check_legal_basis_integrity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokenBasis {
+    /// Regulation declaring the legal basis
+    pub law_id: String,
+    /// `valid_from` of the declaring version
+    pub valid_from: Option<String>,
+    /// Cited law
+    pub target_law: String,
+    /// Cited article
+    pub target_article: String,
+    /// `true` when the cited law isn't loaded at all, `false` when only the
+    /// article is missing from it
+    pub law_missing: bool,
+}
+
+/// Why a referenced output cannot be resolved. See [`MissingRef`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingRefKind {
+    /// The referenced law is not loaded at all
+    LawNotLoaded,
+    /// The law is loaded, but no version applies on the date (or the pinned
+    /// `version` is not loaded)
+    NoApplicableVersion,
+    /// The applicable version has no article producing the output
+    OutputNotFound,
+}
+
+/// A reference that cannot be resolved on a date.
+///
+/// Reported by [`RuleResolver::missing_references`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingRef {
+    /// Referenced law
+    pub law_id: String,
+    /// Referenced output
+    pub output: String,
+    /// Law and article number making the reference; `None` for the output
+    /// the walk started from
+    pub referenced_by: Option<(String, String)>,
+    pub kind: MissingRefKind,
+}
+
+/// A parameter declared by one or more articles of a law.
+///
+/// Returned by [`RuleResolver::law_parameters`], e.g. to generate an input form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParameterSpec {
+    /// Parameter name
+    pub name: String,
+    /// Declared type (identical across all declaring articles)
+    pub param_type: ParameterType,
+    /// `true` when any declaring article requires the parameter
+    pub required: bool,
+    /// First description found among the declarations
+    pub description: Option<String>,
+    /// Numbers of the articles declaring the parameter, in article order
+    pub articles: Vec<String>,
+}
+
+/// The loaded laws of a [`RuleResolver`] at one point in time.
+///
+/// Taken with [`RuleResolver::snapshot`] and put back with
+/// [`RuleResolver::restore`]. Law data is shared with the resolver rather
+/// than copied, so a snapshot only costs a copy of the indexes.
+#[derive(Clone)]
+pub struct Snapshot {
+    resolver: RuleResolver,
+}
+
+impl Snapshot {
+    /// Number of unique law IDs in the snapshot.
+    pub fn law_count(&self) -> usize {
+        self.resolver.law_count()
+    }
+}
+
+impl std::fmt::Debug for Snapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("laws", &self.resolver.list_laws())
+            .finish()
+    }
+}
+
+/// Differences between the loaded laws before and after
+/// [`RuleResolver::reload_directory`], by law ID (sorted).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReloadReport {
+    /// Laws that were not loaded before
+    pub added: Vec<String>,
+    /// Laws whose set of versions or content changed
+    pub updated: Vec<String>,
+    /// Laws that are no longer present
+    pub removed: Vec<String>,
+}
+
+impl ReloadReport {
+    /// `true` when the reload changed nothing.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+}
+
 /// Resolves cross-law references and provides law registry functionality.
 ///
 /// The resolver maintains several indexes for efficient lookups:
@@ -75,10 +189,11 @@ pub(crate) struct HookEntry {
 /// // Find article by output
 /// let article = resolver.get_article_by_output("zorgtoeslagwet", "standaardpremie", None);
 /// ```
+#[derive(Clone)]
 pub struct RuleResolver {
     /// Registry of loaded laws by ID, supporting multiple versions per law ID.
     /// Each law ID maps to a list of versions, sorted by valid_from date (newest first).
-    law_versions: HashMap<String, Vec<ArticleBasedLaw>>,
+    law_versions: HashMap<String, Vec<Arc<ArticleBasedLaw>>>,
     /// Index: "law_id\0output_name" -> article_number
     /// Note: This index uses the most recent version of each law.
     /// Uses a flat string key (null-separated) to avoid two allocations per lookup.
@@ -96,6 +211,12 @@ pub struct RuleResolver {
     procedure_index: HashMap<(String, String), (ProcedureDefinition, String)>,
     /// Maps legal_character -> default procedure_id for that character.
     procedure_defaults: HashMap<String, String>,
+    /// Alias index: declared alias -> canonical law ID (from each law's `aliases`).
+    alias_index: HashMap<String, String>,
+    /// Names of the custom operations loaded laws may use.
+    custom_operations: BTreeSet<String>,
+    /// Reject YAML whose operations carry fields irrelevant to their type
+    strict_operation_fields: bool,
 }
 
 impl Default for RuleResolver {
@@ -115,27 +236,121 @@ impl RuleResolver {
             overrides_index: HashMap::new(),
             procedure_index: HashMap::new(),
             procedure_defaults: HashMap::new(),
+            alias_index: HashMap::new(),
+            custom_operations: BTreeSet::new(),
+            strict_operation_fields: false,
+        }
+    }
+
+    /// Allow loaded laws to use the custom operation `name`.
+    ///
+    /// Laws are checked when they are loaded, so register their custom
+    /// operations first; any other operation name that is not built in (such
+    /// as a misspelled `MULTIPLLY`) fails the load.
+    pub fn register_custom_operation(&mut self, name: impl Into<String>) {
+        self.custom_operations.insert(name.into());
+    }
+
+    /// Reject laws whose operations carry fields irrelevant to their type.
+    ///
+    /// By default such fields (e.g. `cases` on an `EQUALS`) are silently
+    /// ignored. In strict mode every YAML loader (strings, directories and
+    /// archives) fails on a law that contains one, with a `LoadError` listing
+    /// them; see [`ArticleBasedLaw::find_ignored_operation_fields`]. Laws
+    /// passed in as structs have no raw fields left to check.
+    pub fn set_strict_operation_fields(&mut self, strict: bool) {
+        self.strict_operation_fields = strict;
+    }
+
+    /// Parse a law from YAML, applying the strict operation-field check when
+    /// enabled (see [`set_strict_operation_fields`](Self::set_strict_operation_fields)).
+    ///
+    /// # Errors
+    /// Returns any error of [`ArticleBasedLaw::from_yaml_str`], or `LoadError`
+    /// for ignored operation fields in strict mode.
+    pub fn parse_law(&self, yaml: &str) -> Result<ArticleBasedLaw> {
+        if self.strict_operation_fields {
+            let ignored = ArticleBasedLaw::find_ignored_operation_fields(yaml)?;
+            if !ignored.is_empty() {
+                return Err(EngineError::LoadError(format!(
+                    "Operation fields not used by their operation type: {}",
+                    ignored.join(", ")
+                )));
+            }
         }
+        ArticleBasedLaw::from_yaml_str(yaml)
     }
 
     /// Load a law into the resolver.
     ///
     /// If a law with the same ID and valid_from already exists, it will be replaced.
-    /// Otherwise, the new version is added to the version list.
+    /// Otherwise, the new version is added to the version list. This is the reload
+    /// path used by editors; use [`add_law`](Self::add_law) when a duplicate
+    /// `(id, valid_from)` pair indicates a corpus error.
     ///
     /// # Arguments
     /// * `law` - The law to load
     ///
     /// # Returns
-    /// `Ok(())` on success, `Err` if the maximum number of laws would be exceeded.
+    /// `Ok(())` on success, `Err` if the maximum number of laws would be exceeded
+    /// or another law already declares one of its aliases
+    /// ([`EngineError::DuplicateLawAlias`]).
     ///
     /// # Security
     ///
     /// Enforces [`config::MAX_LOADED_LAWS`] to prevent memory exhaustion.
     pub fn load_law(&mut self, law: ArticleBasedLaw) -> Result<()> {
+        self.insert_law(law, true)
+    }
+
+    /// Add a new law version to the resolver, rejecting duplicates.
+    ///
+    /// Unlike [`load_law`](Self::load_law), a law whose `(id, valid_from)` pair is
+    /// already loaded is not replaced. Distinct `valid_from` dates for the same ID
+    /// are added as separate versions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EngineError::DuplicateLawVersion`] if the version is already loaded,
+    /// [`EngineError::DuplicateLawAlias`] if another law already declares one of
+    /// its aliases, or `LoadError` if the maximum number of laws would be exceeded.
+    pub fn add_law(&mut self, law: ArticleBasedLaw) -> Result<()> {
+        self.insert_law(law, false)
+    }
+
+    /// Shared implementation of [`load_law`](Self::load_law) and [`add_law`](Self::add_law).
+    fn insert_law(&mut self, law: ArticleBasedLaw, replace: bool) -> Result<()> {
         let law_id = law.id.clone();
         let valid_from = law.valid_from.clone();
 
+        law.validate_source_spans()?;
+        let unknown: Vec<String> = law
+            .custom_operation_names()
+            .into_iter()
+            .filter(|name| !self.custom_operations.contains(name))
+            .collect();
+        if !unknown.is_empty() {
+            return Err(EngineError::LoadError(format!(
+                "Unknown operation(s) in law '{}': {}",
+                law_id,
+                unknown.join(", ")
+            )));
+        }
+
+        // An alias names a single law; another law claiming it is a corpus error
+        for alias in law.aliases.iter().flatten() {
+            if let Some(existing) = self.alias_index.get(alias) {
+                if *existing != law_id {
+                    tracing::warn!(law_id = %law_id, alias = %alias, existing = %existing, "Duplicate law alias");
+                    return Err(EngineError::DuplicateLawAlias {
+                        alias: alias.clone(),
+                        law_id,
+                        existing_law_id: existing.clone(),
+                    });
+                }
+            }
+        }
+
         // Count total laws across all versions
         let total_laws: usize = self.law_versions.values().map(|v| v.len()).sum();
 
@@ -145,6 +360,11 @@ impl RuleResolver {
             .get(&law_id)
             .is_some_and(|versions| versions.iter().any(|v| v.valid_from == valid_from));
 
+        if is_replacement && !replace {
+            tracing::warn!(law_id = %law_id, valid_from = ?valid_from, "Duplicate law version");
+            return Err(EngineError::DuplicateLawVersion { law_id, valid_from });
+        }
+
         // Enforce law count limit (applies to all new versions, not just new law IDs)
         if !is_replacement && total_laws >= config::MAX_LOADED_LAWS {
             tracing::warn!(
@@ -166,10 +386,10 @@ impl RuleResolver {
         let existing_idx = versions.iter().position(|v| v.valid_from == valid_from);
         if let Some(idx) = existing_idx {
             tracing::debug!(law_id = %law_id, valid_from = ?valid_from, "Replacing existing version");
-            versions[idx] = law;
+            versions[idx] = Arc::new(law);
         } else {
             tracing::debug!(law_id = %law_id, valid_from = ?valid_from, "Adding new version");
-            versions.push(law);
+            versions.push(Arc::new(law));
         }
 
         // Sort versions by valid_from date (newest first)
@@ -186,6 +406,90 @@ impl RuleResolver {
         Ok(())
     }
 
+    /// Load a law under a namespace, so that its ID becomes `namespace:id`.
+    ///
+    /// Lets laws from different jurisdictions share a bare `$id`. Cross-law
+    /// references from a namespaced law resolve through
+    /// [`referenced_law_id`](Self::referenced_law_id).
+    ///
+    /// # Errors
+    ///
+    /// Returns `LoadError` for an empty namespace or one containing
+    /// [`NAMESPACE_SEPARATOR`], or any error of [`load_law`](Self::load_law).
+    pub fn load_law_in_namespace(
+        &mut self,
+        mut law: ArticleBasedLaw,
+        namespace: &str,
+    ) -> Result<()> {
+        if namespace.is_empty() || namespace.contains(NAMESPACE_SEPARATOR) {
+            return Err(EngineError::LoadError(format!(
+                "Invalid law namespace '{}'",
+                namespace
+            )));
+        }
+        law.id = format!("{}{}{}", namespace, NAMESPACE_SEPARATOR, law.id);
+        self.load_law(law)
+    }
+
+    /// Resolve a law reference made from within `from_law_id`.
+    ///
+    /// A qualified reference (`eu:verordening`) is used as is. A bare reference
+    /// from a namespaced law prefers the law with that ID in the same namespace
+    /// and otherwise falls back to the bare ID, so national laws stay reachable
+    /// from municipal ones.
+    pub fn qualify_law_id<'a>(&self, law_id: &'a str, from_law_id: &str) -> Cow<'a, str> {
+        if !law_id.contains(NAMESPACE_SEPARATOR) {
+            if let Some((namespace, _)) = from_law_id.split_once(NAMESPACE_SEPARATOR) {
+                let qualified = format!("{}{}{}", namespace, NAMESPACE_SEPARATOR, law_id);
+                if self.law_versions.contains_key(&qualified) {
+                    return Cow::Owned(qualified);
+                }
+            }
+        }
+        Cow::Borrowed(law_id)
+    }
+
+    /// The canonical ID of the law a reference made from within `from_law_id`
+    /// points to: the reference qualified by
+    /// [`qualify_law_id`](Self::qualify_law_id), then resolved through
+    /// [`canonical_law_id`](Self::canonical_law_id).
+    ///
+    /// Every cross-law reference (input sources, `implements`, `overrides`,
+    /// `legal_basis`, URI calls and the operations built on them) is followed
+    /// through this lookup, so namespaced laws reach their siblings the same
+    /// way everywhere.
+    pub fn referenced_law_id(&self, law_id: &str, from_law_id: &str) -> String {
+        let qualified = self.qualify_law_id(law_id, from_law_id);
+        self.canonical_law_id(&qualified).to_string()
+    }
+
+    /// Entries of the implements or overrides index that refer to `law_id`.
+    ///
+    /// Declarations are indexed under the law ID as written, so one made from
+    /// a namespaced law may be indexed under the bare ID of its sibling. Each
+    /// entry is kept only when its reference, followed from the declaring law
+    /// through [`referenced_law_id`](Self::referenced_law_id), names `law_id`.
+    fn index_entries<'i>(
+        &self,
+        index: &'i HashMap<(String, String, String), Vec<LawArticleRef>>,
+        law_id: &str,
+        article: &str,
+        name: &str,
+    ) -> Vec<&'i LawArticleRef> {
+        let bare = law_id.split_once(NAMESPACE_SEPARATOR).map(|(_, bare)| bare);
+        std::iter::once(law_id)
+            .chain(bare)
+            .flat_map(|written| {
+                let key = (written.to_string(), article.to_string(), name.to_string());
+                index
+                    .get(&key)
+                    .into_iter()
+                    .flatten()
+                    .filter(move |entry| self.referenced_law_id(written, &entry.law_id) == law_id)
+            })
+            .collect()
+    }
+
     /// Load a law from YAML string.
     ///
     /// # Arguments
@@ -200,20 +504,87 @@ impl RuleResolver {
     /// - YAML parsing fails
     /// - Maximum number of laws would be exceeded
     pub fn load_from_yaml(&mut self, yaml: &str) -> Result<String> {
-        let law = ArticleBasedLaw::from_yaml_str(yaml)?;
+        let law = self.parse_law(yaml)?;
         let law_id = law.id.clone();
         self.load_law(law)?;
         Ok(law_id)
     }
 
+    /// Load all versions of a single law from a multi-document YAML string.
+    ///
+    /// Documents are separated by `---` lines; each document is parsed as a
+    /// separate version (keyed by its `valid_from`) and loaded like
+    /// [`load_from_yaml`](Self::load_from_yaml). Nothing is loaded unless every
+    /// document parses. The string as a whole is subject to
+    /// [`config::MAX_YAML_SIZE`].
+    ///
+    /// # Returns
+    /// The law ID on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - A document fails to parse, or the string contains no documents
+    /// - The documents declare different law IDs
+    /// - Two documents share the same `valid_from`
+    /// - Maximum number of laws would be exceeded
+    pub fn load_multidoc_yaml(&mut self, yaml: &str) -> Result<String> {
+        let laws = split_yaml_documents(yaml)?
+            .iter()
+            .map(|document| self.parse_law(document))
+            .collect::<Result<Vec<_>>>()?;
+
+        let Some(law_id) = laws.first().map(|law| law.id.clone()) else {
+            return Err(EngineError::LoadError(
+                "Multi-document YAML contains no documents".to_string(),
+            ));
+        };
+
+        let mut seen_valid_from = HashSet::new();
+        for law in &laws {
+            if law.id != law_id {
+                return Err(EngineError::LoadError(format!(
+                    "Multi-document YAML mixes laws '{}' and '{}'",
+                    law_id, law.id
+                )));
+            }
+            if !seen_valid_from.insert(law.valid_from.clone()) {
+                return Err(EngineError::DuplicateLawVersion {
+                    law_id,
+                    valid_from: law.valid_from.clone(),
+                });
+            }
+        }
+
+        for law in laws {
+            self.load_law(law)?;
+        }
+        Ok(law_id)
+    }
+
     /// Get a law by ID (returns the most recent version).
     ///
     /// This is a convenience method that returns the most recent version.
     /// For version-aware lookups, use [`Self::get_law_for_date`].
     pub fn get_law(&self, law_id: &str) -> Option<&ArticleBasedLaw> {
         self.law_versions
-            .get(law_id)
+            .get(self.canonical_law_id(law_id))
             .and_then(|versions| versions.first())
+            .map(|law| &**law)
+    }
+
+    /// Resolve a law ID or declared alias to the canonical law ID.
+    ///
+    /// IDs of loaded laws always take precedence over aliases; unknown IDs are
+    /// returned unchanged.
+    pub fn canonical_law_id<'a>(&'a self, law_id: &'a str) -> &'a str {
+        if self.law_versions.contains_key(law_id) {
+            return law_id;
+        }
+        self.alias_index
+            .get(law_id)
+            .map(String::as_str)
+            .unwrap_or(law_id)
     }
 
     /// Get a law by ID for a specific reference date.
@@ -234,14 +605,51 @@ impl RuleResolver {
         law_id: &str,
         reference_date: Option<NaiveDate>,
     ) -> Option<&ArticleBasedLaw> {
-        let versions = self.law_versions.get(law_id)?;
+        let versions = self.law_versions.get(self.canonical_law_id(law_id))?;
 
         match reference_date {
-            None => versions.first(), // Return most recent
+            None => versions.first().map(|law| &**law), // Return most recent
             Some(ref_date) => self.select_version_for_date(versions, ref_date),
         }
     }
 
+    /// Get a law for a reference date, distinguishing why none was found.
+    ///
+    /// Same selection as [`Self::get_law_for_date`], but returns
+    /// [`EngineError::LawNotFound`] when the law is not loaded at all and
+    /// [`EngineError::NoApplicableVersion`] when it is loaded but no version is
+    /// valid on `reference_date`.
+    pub fn require_law_for_date(
+        &self,
+        law_id: &str,
+        reference_date: Option<NaiveDate>,
+    ) -> Result<&ArticleBasedLaw> {
+        match (
+            self.get_law_for_date(law_id, reference_date),
+            reference_date,
+        ) {
+            (Some(law), _) => Ok(law),
+            (None, Some(date)) if self.has_law(self.canonical_law_id(law_id)) => {
+                Err(EngineError::NoApplicableVersion {
+                    law_id: law_id.to_string(),
+                    date: date.format("%Y-%m-%d").to_string(),
+                })
+            }
+            (None, _) => Err(EngineError::LawNotFound(law_id.to_string())),
+        }
+    }
+
+    /// Get the version of a law with exactly the given `valid_from` date.
+    ///
+    /// Used for version-pinned references, which bypass date-based selection.
+    pub fn get_law_version(&self, law_id: &str, valid_from: &str) -> Option<&ArticleBasedLaw> {
+        self.law_versions
+            .get(self.canonical_law_id(law_id))?
+            .iter()
+            .find(|v| v.valid_from.as_deref() == Some(valid_from))
+            .map(|law| &**law)
+    }
+
     /// Select the appropriate version for a reference date.
     ///
     /// # Selection Logic
@@ -250,16 +658,19 @@ impl RuleResolver {
     /// 3. If no version has `valid_from`, return the most recent overall
     fn select_version_for_date<'a>(
         &self,
-        versions: &'a [ArticleBasedLaw],
+        versions: &'a [Arc<ArticleBasedLaw>],
         reference_date: NaiveDate,
     ) -> Option<&'a ArticleBasedLaw> {
         // Return the first valid version (already sorted newest first)
-        versions.iter().find(|v| {
-            v.valid_from
-                .as_ref()
-                .and_then(|s| parse_date(s).ok())
-                .is_none_or(|valid_from| valid_from <= reference_date)
-        })
+        versions
+            .iter()
+            .find(|v| {
+                v.valid_from
+                    .as_ref()
+                    .and_then(|s| parse_date(s).ok())
+                    .is_none_or(|valid_from| valid_from <= reference_date)
+            })
+            .map(|law| &**law)
     }
 
     /// Get an article by law ID and output name.
@@ -343,15 +754,11 @@ impl RuleResolver {
         reference_date: Option<NaiveDate>,
         scope: &HashMap<String, Value>,
     ) -> Result<Vec<(&ArticleBasedLaw, &Article)>> {
-        let key = (
-            law_id.to_string(),
-            article.to_string(),
-            open_term_id.to_string(),
-        );
-        let candidate_entries = match self.implements_index.get(&key) {
-            Some(entries) => entries,
-            None => return Ok(Vec::new()),
-        };
+        let candidate_entries =
+            self.index_entries(&self.implements_index, law_id, article, open_term_id);
+        if candidate_entries.is_empty() {
+            return Ok(Vec::new());
+        }
 
         tracing::debug!(
             law_id = %law_id,
@@ -421,155 +828,879 @@ impl RuleResolver {
         Ok(resolved)
     }
 
-    /// Get the number of entries in the implements index.
-    #[cfg(test)]
-    pub fn implements_count(&self) -> usize {
-        self.implements_index.values().map(|v| v.len()).sum()
-    }
-
-    /// Get the number of entries in the output index.
+    /// List the regulations delegated under an article of a law.
     ///
-    /// This counts the total number of (law_id, output_name) pairs across all laws.
-    pub fn output_count(&self) -> usize {
-        self.output_index.len()
-    }
-
-    /// List all (law_id, output_name) pairs from the output index.
-    pub fn list_all_outputs(&self) -> Vec<(&str, &str)> {
-        let mut outputs: Vec<(&str, &str)> = self
-            .output_index
+    /// A regulation counts as delegated when its `legal_basis` cites
+    /// `law_id`/`article`, or when one of its articles `implements` an open
+    /// term declared on that article. For each law ID the version valid on
+    /// `reference_date` is checked (the most recent version when `None`).
+    ///
+    /// Returns the matching versions sorted by law ID.
+    pub fn list_delegated_regulations(
+        &self,
+        law_id: &str,
+        article: &str,
+        reference_date: Option<NaiveDate>,
+    ) -> Vec<&ArticleBasedLaw> {
+        let mut ids: Vec<&String> = self
+            .law_versions
             .keys()
-            .filter_map(|key| key.split_once('\0'))
+            .filter(|id| id.as_str() != law_id)
             .collect();
-        outputs.sort();
-        outputs
+        ids.sort();
+
+        ids.into_iter()
+            .filter_map(|id| self.get_law_for_date(id, reference_date))
+            .filter(|law| {
+                let cites = law.legal_basis.iter().flatten().any(|basis| {
+                    self.referenced_law_id(&basis.law_id, &law.id) == law_id
+                        && basis.article == article
+                });
+                let implements = law
+                    .articles
+                    .iter()
+                    .filter_map(|a| a.get_implements())
+                    .flatten()
+                    .any(|decl| {
+                        self.referenced_law_id(&decl.law, &law.id) == law_id
+                            && decl.article == article
+                    });
+                cites || implements
+            })
+            .collect()
     }
 
-    /// Load all YAML law files from a directory (recursively).
-    ///
-    /// Scans the given directory for `.yaml` files and loads each one.
-    /// Files that fail to parse are logged as warnings and skipped.
-    ///
-    /// # Arguments
-    /// * `dir` - Path to the directory to scan
+    /// Compute every `(law_id, output)` that transitively reads the given output.
     ///
-    /// # Returns
-    /// Number of successfully loaded law files.
+    /// The reverse dependency set is derived statically from the laws valid on
+    /// `reference_date` (the most recent versions when `None`):
+    /// - an input whose `source` names the output (cross-law, or same-law via
+    ///   `source.output`) makes every output of the reading article a dependent;
+    /// - an output that `implements` an open term makes every output of the
+    ///   article declaring that open term a dependent.
     ///
-    /// # Errors
-    /// Returns error if the directory cannot be read.
-    #[cfg(not(target_arch = "wasm32"))]
-    pub fn load_from_directory(&mut self, dir: &std::path::Path) -> Result<usize> {
-        let mut count = 0;
-        self.load_from_directory_recursive(dir, &mut count)?;
-        Ok(count)
-    }
+    /// References made only through `URI_CALL` operations are not detected.
+    /// The result is sorted and excludes the queried output itself.
+    pub fn dependents_of(
+        &self,
+        law_id: &str,
+        output: &str,
+        reference_date: Option<NaiveDate>,
+    ) -> Vec<(String, String)> {
+        let laws: Vec<&ArticleBasedLaw> = self
+            .law_versions
+            .keys()
+            .filter_map(|id| self.get_law_for_date(id, reference_date))
+            .collect();
 
-    #[cfg(not(target_arch = "wasm32"))]
-    fn load_from_directory_recursive(
-        &mut self,
-        dir: &std::path::Path,
-        count: &mut usize,
-    ) -> Result<()> {
-        use std::fs;
+        let article_outputs = |article: &Article| -> Vec<String> {
+            article
+                .get_execution_spec()
+                .and_then(|exec| exec.output.as_ref())
+                .map(|outputs| outputs.iter().map(|o| o.name.clone()).collect())
+                .unwrap_or_default()
+        };
 
-        let entries = fs::read_dir(dir).map_err(|e| {
-            EngineError::LoadError(format!(
-                "Failed to read directory '{}': {}",
-                dir.display(),
-                e
-            ))
-        })?;
+        // Reverse edges: (law, output) read -> (law, output) produced by the reader
+        let mut readers: HashMap<(String, String), Vec<(String, String)>> = HashMap::new();
+        for law in &laws {
+            for article in &law.articles {
+                let produced = article_outputs(article);
+                if produced.is_empty() {
+                    continue;
+                }
 
-        for entry in entries {
-            let entry = entry.map_err(|e| {
-                EngineError::LoadError(format!("Failed to read directory entry: {}", e))
-            })?;
-            let path = entry.path();
-
-            if path.is_dir() {
-                self.load_from_directory_recursive(&path, count)?;
-            } else if path.extension().and_then(|e| e.to_str()) == Some("yaml") {
-                match ArticleBasedLaw::from_yaml_file(&path) {
-                    Ok(law) => match self.load_law(law) {
-                        Ok(()) => {
-                            *count += 1;
+                let mut reads: Vec<(String, String)> = article
+                    .get_inputs()
+                    .iter()
+                    .filter_map(|input| {
+                        let source = input.source.as_ref()?;
+                        if source.regulation.is_none() && source.output.is_none() {
+                            return None;
                         }
-                        Err(e) => {
-                            tracing::warn!(
-                                path = %path.display(),
-                                error = %e,
-                                "Failed to register law from file"
-                            );
-                        }
-                    },
-                    Err(e) => {
-                        tracing::warn!(
-                            path = %path.display(),
-                            error = %e,
-                            "Failed to parse YAML law file"
+                        let regulation = match source.regulation.as_deref() {
+                            Some(regulation) => self.referenced_law_id(regulation, &law.id),
+                            None => law.id.clone(),
+                        };
+                        let read = source.output.as_deref().unwrap_or(&input.name);
+                        Some((regulation, read.to_string()))
+                    })
+                    .collect();
+
+                for decl in article.get_implements().into_iter().flatten() {
+                    let target_id = self.referenced_law_id(&decl.law, &law.id);
+                    let Some(target) = laws.iter().find(|l| l.id == target_id) else {
+                        continue;
+                    };
+                    let Some(target_article) =
+                        target.articles.iter().find(|a| a.number == decl.article)
+                    else {
+                        continue;
+                    };
+                    readers
+                        .entry((law.id.clone(), decl.open_term.clone()))
+                        .or_default()
+                        .extend(
+                            article_outputs(target_article)
+                                .into_iter()
+                                .map(|o| (target.id.clone(), o)),
                         );
-                    }
+                }
+
+                reads.sort();
+                reads.dedup();
+                for read in reads {
+                    let entry = readers.entry(read).or_default();
+                    entry.extend(produced.iter().map(|o| (law.id.clone(), o.clone())));
                 }
             }
         }
 
-        Ok(())
-    }
+        let start = (law_id.to_string(), output.to_string());
+        let mut dependents: BTreeSet<(String, String)> = BTreeSet::new();
+        let mut queue: VecDeque<(String, String)> = VecDeque::from([start.clone()]);
+        while let Some(current) = queue.pop_front() {
+            for dependent in readers.get(&current).into_iter().flatten() {
+                if *dependent != start && dependents.insert(dependent.clone()) {
+                    queue.push_back(dependent.clone());
+                }
+            }
+        }
 
-    /// List all loaded law IDs (unique, not including versions).
-    pub fn list_laws(&self) -> Vec<&str> {
-        let mut ids: Vec<&str> = self.law_versions.keys().map(|s| s.as_str()).collect();
-        ids.sort();
-        ids
+        dependents.into_iter().collect()
     }
 
-    /// Get the number of unique law IDs (not counting versions).
-    pub fn law_count(&self) -> usize {
-        self.law_versions.len()
-    }
+    /// Find the references an output transitively depends on that cannot be
+    /// resolved on `reference_date` (the most recent versions when `None`).
+    ///
+    /// Walks input sources statically from `law_id`/`output`: cross-law
+    /// references (honouring a pinned `version`) and same-law references via
+    /// `source.output`. Open terms are not followed, since an unimplemented
+    /// open term falls back to its default, and neither are references made
+    /// only through `URI_CALL` operations. An empty result means every
+    /// reference found has an applicable version producing the output.
+    pub fn missing_references(
+        &self,
+        law_id: &str,
+        output: &str,
+        reference_date: Option<NaiveDate>,
+    ) -> Vec<MissingRef> {
+        struct Pending {
+            law_id: String,
+            output: String,
+            /// Pinned `valid_from`, if the reference names a version
+            version: Option<String>,
+            referenced_by: Option<(String, String)>,
+        }
 
-    /// Get the total number of loaded law versions.
-    pub fn version_count(&self) -> usize {
-        self.law_versions.values().map(|v| v.len()).sum()
-    }
+        let mut missing = Vec::new();
+        let mut visited: HashSet<(String, String, Option<String>)> = HashSet::new();
+        let mut queue = VecDeque::from([Pending {
+            law_id: law_id.to_string(),
+            output: output.to_string(),
+            version: None,
+            referenced_by: None,
+        }]);
+
+        while let Some(Pending {
+            law_id,
+            output,
+            version,
+            referenced_by,
+        }) = queue.pop_front()
+        {
+            if !visited.insert((law_id.clone(), output.clone(), version.clone())) {
+                continue;
+            }
+            let report = |kind| MissingRef {
+                law_id: law_id.clone(),
+                output: output.clone(),
+                referenced_by: referenced_by.clone(),
+                kind,
+            };
 
-    /// Get the number of versions for a specific law.
-    pub fn version_count_for_law(&self, law_id: &str) -> usize {
-        self.law_versions.get(law_id).map(|v| v.len()).unwrap_or(0)
-    }
+            let law = match &version {
+                Some(valid_from) => self.get_law_version(&law_id, valid_from),
+                None => self.get_law_for_date(&law_id, reference_date),
+            };
+            let Some(law) = law else {
+                missing.push(report(if self.has_law(self.canonical_law_id(&law_id)) {
+                    MissingRefKind::NoApplicableVersion
+                } else {
+                    MissingRefKind::LawNotLoaded
+                }));
+                continue;
+            };
+            let Some(article) = law.find_article_by_output(&output) else {
+                missing.push(report(MissingRefKind::OutputNotFound));
+                continue;
+            };
 
-    /// Check if a law is loaded (any version).
-    pub fn has_law(&self, law_id: &str) -> bool {
-        self.law_versions.contains_key(law_id)
-    }
+            for input in article.get_inputs() {
+                let Some(source) = input.source.as_ref() else {
+                    continue;
+                };
+                if source.regulation.is_none() && source.output.is_none() {
+                    continue;
+                }
+                let target = match source.regulation.as_deref() {
+                    Some(regulation) => self.referenced_law_id(regulation, &law.id),
+                    None => law.id.clone(),
+                };
+                queue.push_back(Pending {
+                    law_id: target,
+                    output: source.output.clone().unwrap_or_else(|| input.name.clone()),
+                    version: source.version.clone(),
+                    referenced_by: Some((law.id.clone(), article.number.clone())),
+                });
+            }
+        }
 
-    /// Iterate over all loaded law versions (all IDs, all versions).
-    pub fn all_law_versions(&self) -> impl Iterator<Item = &ArticleBasedLaw> {
-        self.law_versions.values().flat_map(|v| v.iter())
+        missing
     }
 
-    /// Unload all versions of a law from the resolver.
+    /// Find circular references between outputs across all loaded laws.
     ///
-    /// Removes all versions of the law and all its indexes.
-    ///
-    /// # Returns
-    /// `true` if the law was removed, `false` if it didn't exist.
-    pub fn unload_law(&mut self, law_id: &str) -> bool {
-        if self.law_versions.remove(law_id).is_some() {
-            self.remove_indexes_for_law(law_id);
-            true
-        } else {
-            false
+    /// Builds the static dependency graph of the laws valid on
+    /// `reference_date` (the most recent versions when `None`): an output
+    /// depends on the outputs its article's inputs `source` (cross-law or
+    /// same-law), and on the implementations of its article's open terms.
+    /// Every group of outputs that depend on each other is reported once, as
+    /// a cycle starting at its smallest `(law_id, output)`, each element
+    /// depending on the next and the last on the first. References made only
+    /// through `URI_CALL` operations are not followed. The result is sorted.
+    pub fn find_cycles(&self, reference_date: Option<NaiveDate>) -> Vec<Vec<(String, String)>> {
+        #[derive(Default)]
+        struct Graph {
+            nodes: Vec<(String, String)>,
+            index: HashMap<(String, String), usize>,
+            edges: Vec<Vec<usize>>,
+        }
+        impl Graph {
+            fn node(&mut self, key: (String, String)) -> usize {
+                if let Some(&i) = self.index.get(&key) {
+                    return i;
+                }
+                self.nodes.push(key.clone());
+                self.edges.push(Vec::new());
+                self.index.insert(key, self.nodes.len() - 1);
+                self.nodes.len() - 1
+            }
+            fn edge(&mut self, from: (String, String), to: (String, String)) {
+                let (from, to) = (self.node(from), self.node(to));
+                self.edges[from].push(to);
+            }
         }
-    }
 
-    /// Unload a specific version of a law.
-    ///
-    /// # Arguments
-    /// * `law_id` - The law identifier
-    /// * `valid_from` - The valid_from date of the version to remove
-    ///
+        let laws: Vec<&ArticleBasedLaw> = self
+            .law_versions
+            .keys()
+            .filter_map(|id| self.get_law_for_date(id, reference_date))
+            .collect();
+        let article_outputs = |article: &Article| -> Vec<String> {
+            article
+                .get_execution_spec()
+                .and_then(|exec| exec.output.as_ref())
+                .map(|outputs| outputs.iter().map(|o| o.name.clone()).collect())
+                .unwrap_or_default()
+        };
+
+        let mut graph = Graph::default();
+        for law in &laws {
+            for article in &law.articles {
+                let produced = article_outputs(article);
+                for input in article.get_inputs() {
+                    let Some(source) = input.source.as_ref() else {
+                        continue;
+                    };
+                    if source.regulation.is_none() && source.output.is_none() {
+                        continue;
+                    }
+                    let target = match source.regulation.as_deref() {
+                        Some(regulation) => self.referenced_law_id(regulation, &law.id),
+                        None => law.id.clone(),
+                    };
+                    let read = source.output.clone().unwrap_or_else(|| input.name.clone());
+                    for output in &produced {
+                        graph.edge(
+                            (law.id.clone(), output.clone()),
+                            (target.clone(), read.clone()),
+                        );
+                    }
+                }
+
+                // The outputs of an article declaring an open term depend on
+                // the implementing output.
+                for decl in article.get_implements().into_iter().flatten() {
+                    let target_id = self.referenced_law_id(&decl.law, &law.id);
+                    let Some(target) = laws.iter().find(|l| l.id == target_id) else {
+                        continue;
+                    };
+                    let Some(target_article) = target.find_article_by_number(&decl.article) else {
+                        continue;
+                    };
+                    for output in article_outputs(target_article) {
+                        graph.edge(
+                            (target.id.clone(), output),
+                            (law.id.clone(), decl.open_term.clone()),
+                        );
+                    }
+                }
+            }
+        }
+        for edges in &mut graph.edges {
+            edges.sort_unstable();
+            edges.dedup();
+        }
+
+        // Tarjan's strongly connected components, iteratively
+        let n = graph.nodes.len();
+        let mut index = vec![usize::MAX; n];
+        let mut low = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+        for root in 0..n {
+            if index[root] != usize::MAX {
+                continue;
+            }
+            index[root] = next_index;
+            low[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            let mut work = vec![(root, 0)];
+            while let Some(&(v, pos)) = work.last() {
+                if let Some(&w) = graph.edges[v].get(pos) {
+                    if let Some(top) = work.last_mut() {
+                        top.1 += 1;
+                    }
+                    if index[w] == usize::MAX {
+                        index[w] = next_index;
+                        low[w] = next_index;
+                        next_index += 1;
+                        stack.push(w);
+                        on_stack[w] = true;
+                        work.push((w, 0));
+                    } else if on_stack[w] {
+                        low[v] = low[v].min(index[w]);
+                    }
+                    continue;
+                }
+                work.pop();
+                if let Some(&(parent, _)) = work.last() {
+                    low[parent] = low[parent].min(low[v]);
+                }
+                if low[v] == index[v] {
+                    let mut component = Vec::new();
+                    while let Some(w) = stack.pop() {
+                        on_stack[w] = false;
+                        component.push(w);
+                        if w == v {
+                            break;
+                        }
+                    }
+                    if component.len() > 1 || graph.edges[v].contains(&v) {
+                        components.push(component);
+                    }
+                }
+            }
+        }
+
+        // Walk each component from its smallest node back to itself
+        let mut cycles: Vec<Vec<(String, String)>> = components
+            .into_iter()
+            .filter_map(|component| {
+                let members: HashSet<usize> = component.iter().copied().collect();
+                let start = *component.iter().min_by_key(|&&i| &graph.nodes[i])?;
+                let mut parent: HashMap<usize, usize> = HashMap::new();
+                let mut queue = VecDeque::from([start]);
+                while let Some(v) = queue.pop_front() {
+                    for &w in &graph.edges[v] {
+                        if w == start {
+                            let mut path = vec![v];
+                            // Ends at `start`, which has no parent
+                            while let Some(&p) = parent.get(path.last()?) {
+                                path.push(p);
+                            }
+                            path.reverse();
+                            return Some(path.iter().map(|&i| graph.nodes[i].clone()).collect());
+                        }
+                        if members.contains(&w) && !parent.contains_key(&w) {
+                            parent.insert(w, v);
+                            queue.push_back(w);
+                        }
+                    }
+                }
+                None
+            })
+            .collect();
+        cycles.sort();
+        cycles
+    }
+
+    /// Compute the dates on which a law's results may change through versioning.
+    ///
+    /// Collects the `valid_from` of every loaded version of `law_id` and of the
+    /// laws it depends on, transitively: laws named by an input `source`,
+    /// regulations implementing its open terms, and laws overriding its
+    /// outputs. Between two consecutive boundaries the same set of versions is
+    /// selected, so a result computed for one calculation date holds for the
+    /// whole window. References made only through `URI_CALL` operations are
+    /// not followed. Returned sorted and without duplicates.
+    pub fn calculation_date_boundaries(&self, law_id: &str) -> Vec<NaiveDate> {
+        let mut boundaries: BTreeSet<NaiveDate> = BTreeSet::new();
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut queue: VecDeque<String> =
+            VecDeque::from([self.canonical_law_id(law_id).to_string()]);
+
+        while let Some(id) = queue.pop_front() {
+            if !visited.insert(id.clone()) {
+                continue;
+            }
+            let Some(versions) = self.law_versions.get(&id) else {
+                continue;
+            };
+
+            for law in versions {
+                if let Some(date) = law.valid_from.as_deref().and_then(|s| parse_date(s).ok()) {
+                    boundaries.insert(date);
+                }
+                for article in &law.articles {
+                    for input in article.get_inputs() {
+                        if let Some(regulation) =
+                            input.source.as_ref().and_then(|s| s.regulation.as_deref())
+                        {
+                            queue.push_back(self.referenced_law_id(regulation, &law.id));
+                        }
+                    }
+                }
+            }
+
+            let implementors = self
+                .implements_index
+                .iter()
+                .chain(self.overrides_index.iter())
+                .flat_map(|((target, _, _), refs)| {
+                    refs.iter()
+                        .filter(|r| self.referenced_law_id(target, &r.law_id) == id)
+                        .map(|r| r.law_id.clone())
+                })
+                .collect::<Vec<_>>();
+            queue.extend(implementors);
+        }
+
+        boundaries.into_iter().collect()
+    }
+
+    /// List outputs that nothing consumes: candidates for review as dead logic.
+    ///
+    /// Considers the laws valid on `reference_date` (the most recent versions
+    /// when `None`). An output counts as consumed when it is:
+    /// - read by an input `source` of any loaded law (cross-law or same-law);
+    /// - an open term some article `implements`;
+    /// - referenced as `$name`/`#name` in the actions of another article of the same law.
+    ///
+    /// Outputs of entry-point articles are never reported: articles that declare
+    /// `produces`, carry the `output` classification, or fire as a hook or override.
+    /// References made only through `URI_CALL` operations are not detected.
+    /// The result is sorted by law ID, then output name.
+    pub fn unreachable_outputs(&self, reference_date: Option<NaiveDate>) -> Vec<(String, String)> {
+        let laws: Vec<&ArticleBasedLaw> = self
+            .law_versions
+            .keys()
+            .filter_map(|id| self.get_law_for_date(id, reference_date))
+            .collect();
+
+        let mut consumed: HashSet<(String, String)> = HashSet::new();
+        let mut candidates: BTreeSet<(String, String)> = BTreeSet::new();
+        for law in &laws {
+            let references: Vec<(&str, HashSet<String>)> = law
+                .articles
+                .iter()
+                .map(|a| (a.number.as_str(), action_references(a)))
+                .collect();
+            for article in &law.articles {
+                for input in article.get_inputs() {
+                    let Some(source) = input.source.as_ref() else {
+                        continue;
+                    };
+                    if source.regulation.is_none() && source.output.is_none() {
+                        continue;
+                    }
+                    let regulation = match source.regulation.as_deref() {
+                        Some(regulation) => self.referenced_law_id(regulation, &law.id),
+                        None => law.id.clone(),
+                    };
+                    let read = source.output.as_deref().unwrap_or(&input.name);
+                    consumed.insert((regulation, read.to_string()));
+                }
+                for decl in article.get_implements().into_iter().flatten() {
+                    consumed.insert((law.id.clone(), decl.open_term.clone()));
+                }
+
+                let Some(outputs) = article
+                    .get_execution_spec()
+                    .and_then(|exec| exec.output.as_ref())
+                else {
+                    continue;
+                };
+                let is_entry_point = article.machine_readable.as_ref().is_some_and(|mr| {
+                    mr.execution.as_ref().is_some_and(|e| e.produces.is_some())
+                        || mr.hooks.as_ref().is_some_and(|h| !h.is_empty())
+                        || mr.overrides.as_ref().is_some_and(|o| !o.is_empty())
+                }) || article.has_classification("output");
+                if is_entry_point {
+                    continue;
+                }
+
+                // Same-law references from the actions of other articles
+                let referenced_elsewhere = |name: &str| {
+                    references
+                        .iter()
+                        .any(|(number, names)| *number != article.number && names.contains(name))
+                };
+                for output in outputs {
+                    if !referenced_elsewhere(&output.name) {
+                        candidates.insert((law.id.clone(), output.name.clone()));
+                    }
+                }
+            }
+        }
+
+        candidates
+            .into_iter()
+            .filter(|key| !consumed.contains(key))
+            .collect()
+    }
+
+    /// Find all articles tagged with a legal concept classification.
+    ///
+    /// Searches the version of each law valid on `reference_date` (the most
+    /// recent version when `None`). Results are ordered by law ID, then by
+    /// article order within the law.
+    pub fn find_articles_by_classification(
+        &self,
+        classification: &str,
+        reference_date: Option<NaiveDate>,
+    ) -> Vec<(&ArticleBasedLaw, &Article)> {
+        let mut ids: Vec<&String> = self.law_versions.keys().collect();
+        ids.sort();
+
+        ids.into_iter()
+            .filter_map(|id| self.get_law_for_date(id, reference_date))
+            .flat_map(|law| {
+                law.articles
+                    .iter()
+                    .filter(move |article| article.has_classification(classification))
+                    .map(move |article| (law, article))
+            })
+            .collect()
+    }
+
+    /// Get the number of entries in the implements index.
+    #[cfg(test)]
+    pub fn implements_count(&self) -> usize {
+        self.implements_index.values().map(|v| v.len()).sum()
+    }
+
+    /// Get the number of entries in the output index.
+    ///
+    /// This counts the total number of (law_id, output_name) pairs across all laws.
+    pub fn output_count(&self) -> usize {
+        self.output_index.len()
+    }
+
+    /// List all (law_id, output_name) pairs from the output index.
+    pub fn list_all_outputs(&self) -> Vec<(&str, &str)> {
+        let mut outputs: Vec<(&str, &str)> = self
+            .output_index
+            .keys()
+            .filter_map(|key| key.split_once('\0'))
+            .collect();
+        outputs.sort();
+        outputs
+    }
+
+    /// Load all YAML law files from a directory (recursively).
+    ///
+    /// Scans the given directory for `.yaml` files and loads each one, in
+    /// path order. Files that fail to parse are logged as warnings and skipped.
+    ///
+    /// Every file is parsed before any law is registered, so the load is
+    /// all-or-nothing: two files declaring the same `$id` and `valid_from`, or
+    /// one that repeats an already loaded version, abort it with
+    /// [`EngineError::DuplicateLawVersion`] and leave the loaded laws untouched.
+    ///
+    /// # Arguments
+    /// * `dir` - Path to the directory to scan
+    ///
+    /// # Returns
+    /// Number of successfully loaded law files.
+    ///
+    /// # Errors
+    /// Returns error if the directory cannot be read or contains a duplicate law version.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_from_directory(&mut self, dir: &std::path::Path) -> Result<usize> {
+        let mut paths = Vec::new();
+        collect_yaml_files(dir, &mut paths)?;
+        paths.sort();
+
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let parsed =
+                    ArticleBasedLaw::read_yaml_file(&path).and_then(|yaml| self.parse_law(&yaml));
+                (path.display().to_string(), parsed)
+            })
+            .collect();
+        self.register_loaded_files(files)
+    }
+
+    /// Replace the loaded laws with those in a directory and report the changes.
+    ///
+    /// The directory is loaded as by [`load_from_directory`](Self::load_from_directory)
+    /// into a fresh resolver, which then replaces this one, so laws loaded
+    /// from elsewhere (e.g. YAML strings) are dropped and reported as removed.
+    /// A law counts as updated when any of its versions was added, removed or
+    /// changed.
+    ///
+    /// # Errors
+    /// Returns the errors of `load_from_directory`; the loaded laws are left
+    /// untouched in that case.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn reload_directory(&mut self, dir: &std::path::Path) -> Result<ReloadReport> {
+        let mut fresh = RuleResolver {
+            custom_operations: self.custom_operations.clone(),
+            strict_operation_fields: self.strict_operation_fields,
+            ..RuleResolver::new()
+        };
+        fresh.load_from_directory(dir)?;
+
+        let mut report = ReloadReport::default();
+        for (law_id, versions) in &fresh.law_versions {
+            match self.law_versions.get(law_id) {
+                None => report.added.push(law_id.clone()),
+                Some(old) if old != versions => report.updated.push(law_id.clone()),
+                Some(_) => {}
+            }
+        }
+        report.removed = self
+            .law_versions
+            .keys()
+            .filter(|law_id| !fresh.law_versions.contains_key(*law_id))
+            .cloned()
+            .collect();
+        report.added.sort();
+        report.updated.sort();
+        report.removed.sort();
+
+        *self = fresh;
+        Ok(report)
+    }
+
+    /// Capture the loaded laws, e.g. before a what-if reload.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            resolver: self.clone(),
+        }
+    }
+
+    /// Replace the loaded laws with those captured in `snapshot`, discarding
+    /// every load and unload since.
+    pub fn restore(&mut self, snapshot: Snapshot) {
+        let custom_operations = std::mem::take(&mut self.custom_operations);
+        let strict_operation_fields = self.strict_operation_fields;
+        *self = snapshot.resolver;
+        self.custom_operations = custom_operations;
+        self.strict_operation_fields = strict_operation_fields;
+    }
+
+    /// Register the laws parsed from corpus files, as part of a bulk load.
+    ///
+    /// Parse and registration failures are logged and skipped. Duplicate law
+    /// versions, among the files or with the loaded laws, are checked before
+    /// anything is registered and abort the load.
+    #[cfg(any(not(target_arch = "wasm32"), feature = "archive"))]
+    fn register_loaded_files(
+        &mut self,
+        files: Vec<(String, Result<ArticleBasedLaw>)>,
+    ) -> Result<usize> {
+        let mut laws = Vec::with_capacity(files.len());
+        for (path, parsed) in files {
+            match parsed {
+                Ok(law) => laws.push((path, law)),
+                Err(e) => {
+                    tracing::warn!(path = %path, error = %e, "Failed to parse YAML law file");
+                }
+            }
+        }
+
+        let mut seen = HashSet::new();
+        for (path, law) in &laws {
+            let loaded = self
+                .law_versions
+                .get(&law.id)
+                .is_some_and(|versions| versions.iter().any(|v| v.valid_from == law.valid_from));
+            if loaded || !seen.insert((&law.id, &law.valid_from)) {
+                let e = EngineError::DuplicateLawVersion {
+                    law_id: law.id.clone(),
+                    valid_from: law.valid_from.clone(),
+                };
+                tracing::warn!(path = %path, error = %e, "Duplicate law version in corpus");
+                return Err(e);
+            }
+        }
+
+        let mut count = 0;
+        for (path, law) in laws {
+            match self.add_law(law) {
+                Ok(()) => count += 1,
+                Err(e) => {
+                    tracing::warn!(path = %path, error = %e, "Failed to register law from file");
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    /// Load all YAML law files from an in-memory zip or tar archive.
+    ///
+    /// Archives use the same layout as the corpus directory
+    /// (`.../<law_id>/<valid_from>.yaml`). A law file without a `valid_from`
+    /// field takes it from its file name when that is a `YYYY-MM-DD` date.
+    /// As with [`load_from_directory`](Self::load_from_directory), entries are
+    /// loaded in path order, entries that fail to parse are logged and skipped,
+    /// and a duplicate law version aborts the load before anything is loaded.
+    ///
+    /// The format is detected from the leading bytes: zip archives (with stored
+    /// or deflated entries) start with `PK`, anything else is read as an
+    /// uncompressed tar.
+    ///
+    /// # Returns
+    /// Number of successfully loaded law files.
+    ///
+    /// # Errors
+    /// Returns [`EngineError::LoadError`] if the archive cannot be read, or
+    /// [`EngineError::DuplicateLawVersion`] for a duplicate law version.
+    #[cfg(feature = "archive")]
+    pub fn load_from_archive(&mut self, bytes: &[u8]) -> Result<usize> {
+        let entries = if bytes.starts_with(b"PK") {
+            read_zip_entries(bytes)?
+        } else {
+            read_tar_entries(bytes)?
+        };
+
+        let mut files: Vec<_> = entries
+            .into_iter()
+            .map(|(path, content)| {
+                let parsed = content.and_then(|yaml| {
+                    let mut law = self.parse_law(&yaml)?;
+                    if law.valid_from.is_none() {
+                        law.valid_from = valid_from_from_path(&path);
+                    }
+                    Ok(law)
+                });
+                (path, parsed)
+            })
+            .collect();
+        files.sort_by(|(a, _), (b, _)| a.cmp(b));
+        self.register_loaded_files(files)
+    }
+
+    /// List all loaded law IDs (unique, not including versions).
+    pub fn list_laws(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.law_versions.keys().map(|s| s.as_str()).collect();
+        ids.sort();
+        ids
+    }
+
+    /// Get the number of unique law IDs (not counting versions).
+    pub fn law_count(&self) -> usize {
+        self.law_versions.len()
+    }
+
+    /// Count the loaded laws per regulatory layer (latest version of each law).
+    pub fn layer_histogram(&self) -> HashMap<RegulatoryLayer, usize> {
+        let mut histogram = HashMap::new();
+        for versions in self.law_versions.values() {
+            if let Some(latest) = versions.first() {
+                *histogram.entry(latest.regulatory_layer).or_insert(0) += 1;
+            }
+        }
+        histogram
+    }
+
+    /// Compute a SHA-256 hash of every loaded law version, for cache
+    /// invalidation and audit.
+    ///
+    /// Combines the [`ArticleBasedLaw::content_hash`] of each version in
+    /// sorted order, so the hash does not depend on the order laws were
+    /// loaded in but changes when any version is added, removed or edited.
+    pub fn corpus_hash(&self) -> Result<String> {
+        use sha2::Digest;
+        let mut hashes = self
+            .all_law_versions()
+            .map(ArticleBasedLaw::content_hash)
+            .collect::<Result<Vec<_>>>()?;
+        hashes.sort_unstable();
+        let hash = sha2::Sha256::digest(hashes.join("\n"));
+        Ok(format!("sha256:{}", hex::encode(hash)))
+    }
+
+    /// Get the total number of loaded law versions.
+    pub fn version_count(&self) -> usize {
+        self.law_versions.values().map(|v| v.len()).sum()
+    }
+
+    /// Get the number of versions for a specific law.
+    pub fn version_count_for_law(&self, law_id: &str) -> usize {
+        self.law_versions.get(law_id).map(|v| v.len()).unwrap_or(0)
+    }
+
+    /// Check if a law is loaded (any version).
+    pub fn has_law(&self, law_id: &str) -> bool {
+        self.law_versions.contains_key(law_id)
+    }
+
+    /// Iterate over all loaded law versions (all IDs, all versions).
+    pub fn all_law_versions(&self) -> impl Iterator<Item = &ArticleBasedLaw> {
+        self.law_versions.values().flatten().map(|law| &**law)
+    }
+
+    /// Unload a law from the resolver: every loaded version, not just the latest.
+    ///
+    /// Equivalent to [`unload_all_versions`](Self::unload_all_versions); use
+    /// [`unload_law_version`](Self::unload_law_version) to remove a single
+    /// version.
+    ///
+    /// # Returns
+    /// `true` if the law was removed, `false` if it didn't exist.
+    pub fn unload_law(&mut self, law_id: &str) -> bool {
+        self.unload_all_versions(law_id) > 0
+    }
+
+    /// Unload all versions of a law and all its indexes.
+    ///
+    /// # Returns
+    /// The number of versions removed (0 if the law wasn't loaded).
+    pub fn unload_all_versions(&mut self, law_id: &str) -> usize {
+        match self.law_versions.remove(law_id) {
+            Some(versions) => {
+                self.remove_indexes_for_law(law_id);
+                versions.len()
+            }
+            None => 0,
+        }
+    }
+
+    /// Unload a specific version of a law.
+    ///
+    /// # Arguments
+    /// * `law_id` - The law identifier
+    /// * `valid_from` - The valid_from date of the version to remove
+    ///
     /// # Returns
     /// `true` if the version was removed, `false` if it didn't exist.
     pub fn unload_law_version(&mut self, law_id: &str, valid_from: Option<&str>) -> bool {
@@ -626,9 +1757,19 @@ impl RuleResolver {
         self.procedure_defaults
             .retain(|lc, proc_id| proc_index.contains_key(&(lc.clone(), proc_id.clone())));
 
+        // Remove old alias entries for this law
+        self.alias_index.retain(|_, canonical| canonical != law_id);
+
         // Add new index entries from the most recent version
         // Access law_versions directly to avoid borrowing self through get_law()
         if let Some(versions) = self.law_versions.get(law_id) {
+            // Alias index (all versions, so older versions stay reachable)
+            for alias in versions.iter().flat_map(|v| v.aliases.iter().flatten()) {
+                if alias != law_id {
+                    self.alias_index.insert(alias.clone(), law_id.to_string());
+                }
+            }
+
             if let Some(law) = versions.first() {
                 // Procedure index (top-level)
                 if let Some(procedures) = &law.procedure {
@@ -745,6 +1886,9 @@ impl RuleResolver {
         let proc_index = &self.procedure_index;
         self.procedure_defaults
             .retain(|lc, proc_id| proc_index.contains_key(&(lc.clone(), proc_id.clone())));
+
+        // Remove alias entries for this law
+        self.alias_index.retain(|_, canonical| canonical != law_id);
     }
 
     /// Find hooks that match a given lifecycle event.
@@ -793,16 +1937,8 @@ impl RuleResolver {
         target_law: &str,
         target_article: &str,
         output: &str,
-    ) -> &[LawArticleRef] {
-        let key = (
-            target_law.to_string(),
-            target_article.to_string(),
-            output.to_string(),
-        );
-        self.overrides_index
-            .get(&key)
-            .map(|v| v.as_slice())
-            .unwrap_or(&[])
+    ) -> Vec<&LawArticleRef> {
+        self.index_entries(&self.overrides_index, target_law, target_article, output)
     }
 
     /// Find a procedure definition for a given legal character and optional procedure ID.
@@ -862,21 +1998,360 @@ impl RuleResolver {
         }
         errors
     }
-}
-
-/// Parse a date string in ISO 8601 format (YYYY-MM-DD).
-fn parse_date(s: &str) -> Result<NaiveDate> {
-    NaiveDate::parse_from_str(s, "%Y-%m-%d")
-        .map_err(|e| EngineError::InvalidOperation(format!("Failed to parse date '{}': {}", s, e)))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    fn make_test_law() -> &'static str {
-        r#"
-$id: test_law
+    /// List every parameter declared by any article of a law, deduplicated.
+    ///
+    /// Uses the version valid on `reference_date` (the most recent one for
+    /// `None`). Parameters are returned in order of first declaration.
+    ///
+    /// # Errors
+    /// Returns [`EngineError::ResolutionError`] when two articles declare the
+    /// same parameter with different types, and the errors of
+    /// [`Self::require_law_for_date`] when no version applies.
+    pub fn law_parameters(
+        &self,
+        law_id: &str,
+        reference_date: Option<NaiveDate>,
+    ) -> Result<Vec<ParameterSpec>> {
+        let law = self.require_law_for_date(law_id, reference_date)?;
+
+        let mut specs: Vec<ParameterSpec> = Vec::new();
+        for article in &law.articles {
+            let declared = article
+                .get_execution_spec()
+                .and_then(|exec| exec.parameters.as_ref());
+            for param in declared.into_iter().flatten() {
+                let required = param.required.unwrap_or(false);
+                match specs.iter_mut().find(|spec| spec.name == param.name) {
+                    Some(spec) if spec.param_type != param.param_type => {
+                        return Err(EngineError::ResolutionError(format!(
+                            "Parameter '{}' of law '{}' is declared as {:?} in article {} but as {:?} in article {}",
+                            param.name,
+                            law.id,
+                            spec.param_type,
+                            spec.articles[0],
+                            param.param_type,
+                            article.number
+                        )));
+                    }
+                    Some(spec) => {
+                        spec.required |= required;
+                        if spec.description.is_none() {
+                            spec.description = param.description.clone();
+                        }
+                        spec.articles.push(article.number.clone());
+                    }
+                    None => specs.push(ParameterSpec {
+                        name: param.name.clone(),
+                        param_type: param.param_type,
+                        required,
+                        description: param.description.clone(),
+                        articles: vec![article.number.clone()],
+                    }),
+                }
+            }
+        }
+        Ok(specs)
+    }
+
+    /// Check that every `legal_basis` of every loaded law version resolves.
+    ///
+    /// A basis resolves when the cited law is loaded (by ID or alias) and any
+    /// of its versions has the cited article. Broken entries are returned
+    /// sorted by declaring law and version.
+    pub fn check_legal_basis_integrity(&self) -> Vec<BrokenBasis> {
+        let mut broken: Vec<BrokenBasis> = self
+            .all_law_versions()
+            .flat_map(|law| {
+                law.legal_basis.iter().flatten().filter_map(move |basis| {
+                    let target_versions = self
+                        .law_versions
+                        .get(&self.referenced_law_id(&basis.law_id, &law.id));
+                    let has_article = target_versions.is_some_and(|versions| {
+                        versions
+                            .iter()
+                            .any(|v| v.find_article_by_number(&basis.article).is_some())
+                    });
+                    (!has_article).then(|| BrokenBasis {
+                        law_id: law.id.clone(),
+                        valid_from: law.valid_from.clone(),
+                        target_law: basis.law_id.clone(),
+                        target_article: basis.article.clone(),
+                        law_missing: target_versions.is_none(),
+                    })
+                })
+            })
+            .collect();
+        broken.sort_by(|a, b| {
+            (&a.law_id, &a.valid_from, &a.target_law, &a.target_article).cmp(&(
+                &b.law_id,
+                &b.valid_from,
+                &b.target_law,
+                &b.target_article,
+            ))
+        });
+        broken
+    }
+
+    /// Validate the most recent version of a loaded law for authoring mistakes.
+    ///
+    /// Reports articles with actions but no declared outputs (their results
+    /// are silently dropped), actions that write an output their article does
+    /// not declare, and inputs sourced from a law that isn't loaded or from an
+    /// output the source law (this law, for same-law sources) doesn't produce.
+    ///
+    /// With `fail_fast` validation stops at the first issue, so at most one is
+    /// returned (for quick CI gating); otherwise all issues are collected.
+    pub fn validate_law(&self, law_id: &str, fail_fast: bool) -> Vec<String> {
+        let Some(law) = self.get_law(law_id) else {
+            return vec![format!("Law '{law_id}' is not loaded")];
+        };
+
+        let mut issues = Vec::new();
+        for article in &law.articles {
+            let Some(exec) = article.get_execution_spec() else {
+                continue;
+            };
+            let has_actions = exec.actions.as_ref().is_some_and(|a| !a.is_empty());
+            let has_outputs = exec.output.as_ref().is_some_and(|o| !o.is_empty());
+            // Every action would also count as undeclared; report the article once.
+            let no_outputs = (has_actions && !has_outputs).then(|| {
+                format!(
+                    "{}:{} has actions but declares no outputs",
+                    law.id, article.number
+                )
+            });
+            let undeclared_outputs = exec
+                .actions
+                .iter()
+                .flatten()
+                .filter_map(|action| action.output.as_deref())
+                .filter(|output| has_outputs && !article.has_output(output))
+                .map(|output| {
+                    format!(
+                        "{}:{} action writes undeclared output '{output}'",
+                        law.id, article.number
+                    )
+                });
+            let broken_sources = exec
+                .input
+                .iter()
+                .flatten()
+                .filter_map(|input| self.input_source_issue(law, article, input));
+
+            for issue in no_outputs
+                .into_iter()
+                .chain(undeclared_outputs)
+                .chain(broken_sources)
+            {
+                issues.push(issue);
+                if fail_fast {
+                    return issues;
+                }
+            }
+        }
+        issues
+    }
+
+    /// Describe why an input's source can't be resolved, if it can't.
+    fn input_source_issue(
+        &self,
+        law: &ArticleBasedLaw,
+        article: &Article,
+        input: &Input,
+    ) -> Option<String> {
+        let source = input.source.as_ref()?;
+        // Without an output the input comes from the data source registry.
+        let output = source.output.as_deref()?;
+        let source_law = match source.regulation.as_deref() {
+            Some(regulation) => match self.get_law(&self.referenced_law_id(regulation, &law.id)) {
+                Some(source_law) => source_law,
+                None => {
+                    return Some(format!(
+                        "{}:{} input '{}' sources non-existent law '{regulation}'",
+                        law.id, article.number, input.name
+                    ))
+                }
+            },
+            None => law,
+        };
+        if source_law.find_article_by_output(output).is_some() {
+            return None;
+        }
+        Some(format!(
+            "{}:{} input '{}' sources non-existent output '{output}' from '{}'",
+            law.id, article.number, input.name, source_law.id
+        ))
+    }
+}
+
+/// Split a multi-document YAML string into its documents, each serialized
+/// back to YAML on its own.
+///
+/// Empty documents (e.g. before a leading `---`) are skipped.
+fn split_yaml_documents(yaml: &str) -> Result<Vec<String>> {
+    use serde::Deserialize;
+
+    if yaml.len() > config::MAX_YAML_SIZE {
+        return Err(EngineError::LoadError(format!(
+            "YAML content exceeds maximum size limit ({} bytes)",
+            config::MAX_YAML_SIZE
+        )));
+    }
+
+    let mut documents = Vec::new();
+    for document in serde_yaml_ng::Deserializer::from_str(yaml) {
+        let value = serde_yaml_ng::Value::deserialize(document)?;
+        if !value.is_null() {
+            documents.push(serde_yaml_ng::to_string(&value)?);
+        }
+    }
+    Ok(documents)
+}
+
+/// Collect the paths of the `.yaml` files under `dir`, recursively.
+#[cfg(not(target_arch = "wasm32"))]
+fn collect_yaml_files(dir: &std::path::Path, paths: &mut Vec<std::path::PathBuf>) -> Result<()> {
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        EngineError::LoadError(format!(
+            "Failed to read directory '{}': {}",
+            dir.display(),
+            e
+        ))
+    })?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| {
+            EngineError::LoadError(format!("Failed to read directory entry: {}", e))
+        })?;
+        let path = entry.path();
+
+        if path.is_dir() {
+            collect_yaml_files(&path, paths)?;
+        } else if path.extension().and_then(|e| e.to_str()) == Some("yaml") {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse a date string in ISO 8601 format (YYYY-MM-DD).
+fn parse_date(s: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|e| EngineError::InvalidOperation(format!("Failed to parse date '{}': {}", s, e)))
+}
+
+/// A `.yaml` archive entry: its path and its contents (or why they could not be read).
+#[cfg(feature = "archive")]
+type ArchiveEntry = (String, Result<String>);
+
+/// Read an archive entry as UTF-8, refusing entries over [`config::MAX_YAML_SIZE`].
+#[cfg(feature = "archive")]
+fn read_archive_entry(reader: impl std::io::Read) -> Result<String> {
+    use std::io::Read;
+
+    let mut content = String::new();
+    reader
+        .take(config::MAX_YAML_SIZE as u64 + 1)
+        .read_to_string(&mut content)
+        .map_err(|_| EngineError::LoadError("Failed to read archive entry".to_string()))?;
+    if content.len() > config::MAX_YAML_SIZE {
+        return Err(EngineError::LoadError(format!(
+            "File exceeds maximum size limit ({} bytes)",
+            config::MAX_YAML_SIZE
+        )));
+    }
+    Ok(content)
+}
+
+#[cfg(feature = "archive")]
+fn is_yaml_path(path: &str) -> bool {
+    std::path::Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        == Some("yaml")
+}
+
+/// Collect the `.yaml` entries of a zip archive.
+#[cfg(feature = "archive")]
+fn read_zip_entries(bytes: &[u8]) -> Result<Vec<ArchiveEntry>> {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes))
+        .map_err(|e| EngineError::LoadError(format!("Failed to read zip archive: {}", e)))?;
+
+    let mut entries = Vec::new();
+    for i in 0..archive.len() {
+        let file = archive
+            .by_index(i)
+            .map_err(|e| EngineError::LoadError(format!("Failed to read zip entry: {}", e)))?;
+        let path = file.name().to_string();
+        if file.is_file() && is_yaml_path(&path) {
+            entries.push((path, read_archive_entry(file)));
+        }
+    }
+    Ok(entries)
+}
+
+/// Collect the `.yaml` entries of an uncompressed tar archive.
+#[cfg(feature = "archive")]
+fn read_tar_entries(bytes: &[u8]) -> Result<Vec<ArchiveEntry>> {
+    let tar_error =
+        |e: std::io::Error| EngineError::LoadError(format!("Failed to read tar archive: {}", e));
+
+    let mut archive = tar::Archive::new(bytes);
+    let mut entries = Vec::new();
+    for entry in archive.entries().map_err(tar_error)? {
+        let entry = entry.map_err(tar_error)?;
+        let path = entry.path().map_err(tar_error)?.display().to_string();
+        if entry.header().entry_type().is_file() && is_yaml_path(&path) {
+            entries.push((path, read_archive_entry(entry)));
+        }
+    }
+    Ok(entries)
+}
+
+/// Derive `valid_from` from a corpus file name such as `wet_x/2025-01-01.yaml`.
+#[cfg(feature = "archive")]
+fn valid_from_from_path(path: &str) -> Option<String> {
+    let stem = std::path::Path::new(path).file_stem()?.to_str()?;
+    parse_date(stem).ok().map(|_| stem.to_string())
+}
+
+/// Names referenced as `$name` or `#name` (up to the first `.`) in an article's actions.
+fn action_references(article: &Article) -> HashSet<String> {
+    fn collect(value: &serde_json::Value, names: &mut HashSet<String>) {
+        match value {
+            serde_json::Value::String(s) => {
+                if let Some(name) = s.strip_prefix('$').or_else(|| s.strip_prefix('#')) {
+                    let name = name.split('.').next().unwrap_or(name);
+                    names.insert(name.to_string());
+                }
+            }
+            serde_json::Value::Array(items) => items.iter().for_each(|v| collect(v, names)),
+            serde_json::Value::Object(map) => map.values().for_each(|v| collect(v, names)),
+            _ => {}
+        }
+    }
+
+    let mut names = HashSet::new();
+    if let Some(actions) = article
+        .get_execution_spec()
+        .and_then(|exec| exec.actions.as_ref())
+    {
+        if let Ok(value) = serde_json::to_value(actions) {
+            collect(&value, &mut names);
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_test_law() -> &'static str {
+        r#"
+$id: test_law
 regulatory_layer: WET
 publication_date: '2025-01-01'
 articles:
@@ -1138,6 +2613,179 @@ articles:
         assert!(law.is_none());
     }
 
+    #[test]
+    fn test_load_multidoc_yaml_loads_every_version() {
+        let mut resolver = RuleResolver::new();
+        let yaml = format!(
+            "---\n{}\n--- # 2025 version\n{}",
+            make_test_law_with_valid_from("2024-01-01", 100),
+            make_test_law_with_valid_from("2025-01-01", 200)
+        );
+
+        let law_id = resolver.load_multidoc_yaml(&yaml).unwrap();
+
+        assert_eq!(law_id, "test_law");
+        assert_eq!(resolver.version_count_for_law("test_law"), 2);
+        let date_2024 = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let date_2025 = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        assert_eq!(
+            resolver
+                .get_law_for_date("test_law", Some(date_2024))
+                .unwrap()
+                .valid_from
+                .as_deref(),
+            Some("2024-01-01")
+        );
+        assert_eq!(
+            resolver
+                .get_law_for_date("test_law", Some(date_2025))
+                .unwrap()
+                .valid_from
+                .as_deref(),
+            Some("2025-01-01")
+        );
+    }
+
+    #[test]
+    fn test_load_multidoc_yaml_rejects_duplicate_version() {
+        let mut resolver = RuleResolver::new();
+        let version = make_test_law_with_valid_from("2024-01-01", 100);
+        let yaml = format!("{}\n---\n{}", version, version);
+
+        let result = resolver.load_multidoc_yaml(&yaml);
+
+        assert!(matches!(
+            result,
+            Err(EngineError::DuplicateLawVersion { .. })
+        ));
+        assert_eq!(resolver.law_count(), 0);
+    }
+
+    #[cfg(feature = "archive")]
+    #[test]
+    fn test_load_from_archive_zip() {
+        use std::io::Write;
+        use zip::write::SimpleFileOptions;
+
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let options =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        let files = [
+            (
+                "regulation/nl/wet/test_law/2024-01-01.yaml",
+                make_test_law_with_valid_from("2024-01-01", 100),
+            ),
+            (
+                "regulation/nl/wet/other_law/2023-05-01.yaml",
+                "$id: other_law\nregulatory_layer: WET\npublication_date: '2023-01-01'\narticles: []\n"
+                    .to_string(),
+            ),
+            ("regulation/README.md", "not a law".to_string()),
+        ];
+        for (name, content) in &files {
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        let bytes = writer.finish().unwrap().into_inner();
+
+        let mut resolver = RuleResolver::new();
+        assert_eq!(resolver.load_from_archive(&bytes).unwrap(), 2);
+        assert_eq!(resolver.list_laws(), vec!["other_law", "test_law"]);
+        assert_eq!(
+            resolver.get_law("other_law").unwrap().valid_from.as_deref(),
+            Some("2023-05-01")
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_source_span_fails_to_load() {
+        let mut resolver = RuleResolver::new();
+        let result = resolver.load_from_yaml(
+            r#"
+$id: span_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Kort.
+    machine_readable:
+      execution:
+        output:
+          - name: waarde
+            type: number
+            source_span:
+              start: 2
+              end: 40
+        actions:
+          - output: waarde
+            value: 1
+"#,
+        );
+
+        assert!(
+            matches!(result, Err(EngineError::LoadError(ref msg)) if msg.contains("source_span 2..40")),
+            "{:?}",
+            result
+        );
+        assert_eq!(resolver.law_count(), 0);
+    }
+
+    #[test]
+    fn test_resolver_resolves_law_aliases() {
+        let mut resolver = RuleResolver::new();
+        resolver
+            .load_from_yaml(
+                r#"
+$id: zorgtoeslagwet
+aliases:
+  - wet_op_de_zorgtoeslag
+regulatory_layer: WET
+publication_date: '2025-01-01'
+valid_from: '2025-01-01'
+articles: []
+"#,
+            )
+            .unwrap();
+
+        let date = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        for id in ["zorgtoeslagwet", "wet_op_de_zorgtoeslag"] {
+            assert_eq!(resolver.get_law(id).unwrap().id, "zorgtoeslagwet");
+            assert_eq!(
+                resolver.get_law_for_date(id, Some(date)).unwrap().id,
+                "zorgtoeslagwet"
+            );
+        }
+        assert_eq!(
+            resolver.canonical_law_id("wet_op_de_zorgtoeslag"),
+            "zorgtoeslagwet"
+        );
+
+        // Another law cannot claim the same alias
+        let result = resolver.load_from_yaml(
+            r#"
+$id: andere_wet
+aliases:
+  - wet_op_de_zorgtoeslag
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles: []
+"#,
+        );
+        assert!(matches!(
+            result,
+            Err(EngineError::DuplicateLawAlias { ref alias, ref existing_law_id, .. })
+                if alias == "wet_op_de_zorgtoeslag" && existing_law_id == "zorgtoeslagwet"
+        ));
+        assert!(!resolver.has_law("andere_wet"));
+        assert_eq!(
+            resolver.canonical_law_id("wet_op_de_zorgtoeslag"),
+            "zorgtoeslagwet"
+        );
+
+        assert!(resolver.unload_law("zorgtoeslagwet"));
+        assert!(resolver.get_law("wet_op_de_zorgtoeslag").is_none());
+    }
+
     #[test]
     fn test_resolver_version_replacement() {
         let mut resolver = RuleResolver::new();
@@ -1161,6 +2809,279 @@ articles:
         assert_eq!(resolver.version_count(), 2);
     }
 
+    #[test]
+    fn test_add_law_rejects_duplicate_version() {
+        let mut resolver = RuleResolver::new();
+        let first =
+            ArticleBasedLaw::from_yaml_str(&make_test_law_with_valid_from("2024-01-01", 100))
+                .unwrap();
+        let duplicate =
+            ArticleBasedLaw::from_yaml_str(&make_test_law_with_valid_from("2024-01-01", 200))
+                .unwrap();
+        let later =
+            ArticleBasedLaw::from_yaml_str(&make_test_law_with_valid_from("2025-01-01", 300))
+                .unwrap();
+
+        resolver.add_law(first).unwrap();
+        let result = resolver.add_law(duplicate);
+        assert!(matches!(
+            result,
+            Err(EngineError::DuplicateLawVersion { ref law_id, ref valid_from })
+                if law_id == "test_law" && valid_from.as_deref() == Some("2024-01-01")
+        ));
+        assert_eq!(resolver.version_count(), 1);
+
+        resolver.add_law(later).unwrap();
+        assert_eq!(resolver.version_count(), 2);
+    }
+
+    #[test]
+    fn test_load_from_directory_duplicate_version() {
+        let dir = std::env::temp_dir().join(format!(
+            "regelrecht_duplicate_versions_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("a")).unwrap();
+        std::fs::create_dir_all(dir.join("b")).unwrap();
+
+        // Distinct valid_from dates load as two versions
+        std::fs::write(
+            dir.join("a/2024-01-01.yaml"),
+            make_test_law_with_valid_from("2024-01-01", 100),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("b/2025-01-01.yaml"),
+            make_test_law_with_valid_from("2025-01-01", 200),
+        )
+        .unwrap();
+        let mut resolver = RuleResolver::new();
+        assert_eq!(resolver.load_from_directory(&dir).unwrap(), 2);
+        assert_eq!(resolver.version_count(), 2);
+
+        // A second file with the same id and valid_from is rejected
+        std::fs::write(
+            dir.join("b/2024-01-01.yaml"),
+            make_test_law_with_valid_from("2024-01-01", 300),
+        )
+        .unwrap();
+        let mut resolver = RuleResolver::new();
+        let result = resolver.load_from_directory(&dir);
+        assert!(matches!(
+            result,
+            Err(EngineError::DuplicateLawVersion { .. })
+        ));
+        // Nothing is loaded, not even the files before the duplicate
+        assert_eq!(resolver.version_count(), 0);
+
+        // A file repeating an already loaded version is rejected as well
+        std::fs::remove_file(dir.join("b/2024-01-01.yaml")).unwrap();
+        let mut resolver = RuleResolver::new();
+        resolver
+            .add_law(
+                ArticleBasedLaw::from_yaml_str(&make_test_law_with_valid_from("2025-01-01", 200))
+                    .unwrap(),
+            )
+            .unwrap();
+        let result = resolver.load_from_directory(&dir);
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(matches!(
+            result,
+            Err(EngineError::DuplicateLawVersion { .. })
+        ));
+        assert_eq!(resolver.version_count(), 1);
+    }
+
+    #[test]
+    fn test_find_cycles_reports_cross_law_cycle() {
+        let law = |id: &str, output: &str, other: &str, other_output: &str| {
+            format!(
+                r#"
+$id: {id}
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: References {other}
+    machine_readable:
+      execution:
+        input:
+          - name: from_other
+            type: number
+            source:
+              regulation: {other}
+              output: {other_output}
+        output:
+          - name: {output}
+            type: number
+        actions:
+          - output: {output}
+            value: $from_other
+"#
+            )
+        };
+        let mut resolver = RuleResolver::new();
+        resolver
+            .load_from_yaml(&law("law_a", "output_a", "law_b", "output_b"))
+            .unwrap();
+        resolver
+            .load_from_yaml(&law("law_b", "output_b", "law_a", "output_a"))
+            .unwrap();
+        resolver.load_from_yaml(make_test_law()).unwrap();
+
+        assert_eq!(
+            resolver.find_cycles(None),
+            vec![vec![
+                ("law_a".to_string(), "output_a".to_string()),
+                ("law_b".to_string(), "output_b".to_string()),
+            ]]
+        );
+
+        resolver.unload_law("law_b");
+        assert!(resolver.find_cycles(None).is_empty());
+    }
+
+    #[test]
+    fn test_corpus_hash() {
+        let law = |id: &str, value: i32| {
+            make_test_law_with_valid_from("2025-01-01", value)
+                .replace("$id: test_law", &format!("$id: {id}"))
+        };
+        let mut forward = RuleResolver::new();
+        forward.load_from_yaml(&law("wet_a", 1)).unwrap();
+        forward.load_from_yaml(&law("wet_b", 2)).unwrap();
+        let mut reversed = RuleResolver::new();
+        reversed.load_from_yaml(&law("wet_b", 2)).unwrap();
+        reversed.load_from_yaml(&law("wet_a", 1)).unwrap();
+
+        let hash = forward.corpus_hash().unwrap();
+        assert!(hash.starts_with("sha256:"));
+        assert_eq!(hash, reversed.corpus_hash().unwrap());
+
+        reversed.unload_law("wet_a");
+        reversed.load_from_yaml(&law("wet_a", 3)).unwrap();
+        assert_ne!(hash, reversed.corpus_hash().unwrap());
+    }
+
+    #[test]
+    fn test_layer_histogram() {
+        let mut resolver = RuleResolver::new();
+        let law = |id: &str, layer: &str| {
+            make_test_law()
+                .replace("$id: test_law", &format!("$id: {id}"))
+                .replace(
+                    "regulatory_layer: WET",
+                    &format!("regulatory_layer: {layer}"),
+                )
+        };
+        resolver.load_from_yaml(&law("wet_a", "WET")).unwrap();
+        resolver.load_from_yaml(&law("wet_b", "WET")).unwrap();
+        resolver
+            .load_from_yaml(&law("regeling", "MINISTERIELE_REGELING"))
+            .unwrap();
+        resolver
+            .load_from_yaml(&law("verordening", "GEMEENTELIJKE_VERORDENING"))
+            .unwrap();
+        // A newer version moves wet_b to another layer
+        resolver
+            .load_from_yaml(
+                &make_test_law_with_valid_from("2026-01-01", 1)
+                    .replace("$id: test_law", "$id: wet_b")
+                    .replace("regulatory_layer: WET", "regulatory_layer: AMVB"),
+            )
+            .unwrap();
+
+        let histogram = resolver.layer_histogram();
+        assert_eq!(histogram.get(&RegulatoryLayer::Wet), Some(&1));
+        assert_eq!(histogram.get(&RegulatoryLayer::Amvb), Some(&1));
+        assert_eq!(
+            histogram.get(&RegulatoryLayer::MinisterieleRegeling),
+            Some(&1)
+        );
+        assert_eq!(
+            histogram.get(&RegulatoryLayer::GemeentelijkeVerordening),
+            Some(&1)
+        );
+        assert_eq!(histogram.values().sum::<usize>(), 4);
+    }
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let evaluate = |resolver: &RuleResolver| {
+            let law = resolver.get_law("test_law").unwrap();
+            crate::ArticleEngine::new(&law.articles[0], law)
+                .evaluate(std::collections::BTreeMap::new(), "2025-06-01")
+                .unwrap()
+                .outputs
+                .get("test_output")
+                .cloned()
+        };
+        let mut resolver = RuleResolver::new();
+        resolver
+            .load_from_yaml(&make_test_law_with_valid_from("2025-01-01", 100))
+            .unwrap();
+        let snapshot = resolver.snapshot();
+
+        resolver
+            .load_from_yaml(&make_test_law_with_valid_from("2025-01-01", 200))
+            .unwrap();
+        resolver
+            .load_from_yaml(&make_test_law().replace("$id: test_law", "$id: what_if_law"))
+            .unwrap();
+        assert_eq!(evaluate(&resolver), Some(Value::Int(200)));
+
+        assert_eq!(snapshot.law_count(), 1);
+        resolver.restore(snapshot);
+        assert_eq!(evaluate(&resolver), Some(Value::Int(100)));
+        assert!(!resolver.has_law("what_if_law"));
+    }
+
+    #[test]
+    fn test_reload_directory_reports_changes() {
+        let dir = std::env::temp_dir().join(format!(
+            "regelrecht_reload_directory_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let other_law = make_test_law_with_valid_from("2025-01-01", 1)
+            .replace("$id: test_law", "$id: other_law");
+        std::fs::write(
+            dir.join("test_law.yaml"),
+            make_test_law_with_valid_from("2025-01-01", 100),
+        )
+        .unwrap();
+        std::fs::write(dir.join("other_law.yaml"), &other_law).unwrap();
+
+        let mut resolver = RuleResolver::new();
+        resolver.load_from_directory(&dir).unwrap();
+        resolver
+            .load_from_yaml(&other_law.replace("$id: other_law", "$id: yaml_only_law"))
+            .unwrap();
+
+        // Modify one fixture, leave the other as is
+        std::fs::write(
+            dir.join("test_law.yaml"),
+            make_test_law_with_valid_from("2025-01-01", 200),
+        )
+        .unwrap();
+        let report = resolver.reload_directory(&dir);
+        let _ = std::fs::remove_dir_all(&dir);
+        let report = report.unwrap();
+
+        assert_eq!(
+            report,
+            ReloadReport {
+                added: vec![],
+                updated: vec!["test_law".to_string()],
+                removed: vec!["yaml_only_law".to_string()],
+            }
+        );
+        assert!(resolver.has_law("other_law"));
+        assert!(!resolver.has_law("yaml_only_law"));
+    }
+
     #[test]
     fn test_resolver_unload_version() {
         let mut resolver = RuleResolver::new();
@@ -1185,6 +3106,24 @@ articles:
         assert!(!resolver.has_law("test_law"));
     }
 
+    #[test]
+    fn test_resolver_unload_all_versions() {
+        let mut resolver = RuleResolver::new();
+        resolver
+            .load_from_yaml(&make_test_law_with_valid_from("2024-01-01", 100))
+            .unwrap();
+        resolver
+            .load_from_yaml(&make_test_law_with_valid_from("2025-01-01", 200))
+            .unwrap();
+
+        assert_eq!(resolver.unload_all_versions("test_law"), 2);
+        assert_eq!(resolver.version_count(), 0);
+        assert!(!resolver.has_law("test_law"));
+        assert!(resolver.get_law("test_law").is_none());
+        assert_eq!(resolver.output_count(), 0);
+        assert_eq!(resolver.unload_all_versions("test_law"), 0);
+    }
+
     #[test]
     fn test_resolver_article_by_output_with_date() {
         let mut resolver = RuleResolver::new();
@@ -1395,6 +3334,438 @@ articles:
         assert_eq!(results[0].1.number, "1");
     }
 
+    fn make_verordening(id: &str, gemeente_code: &str, article: &str) -> String {
+        format!(
+            r#"
+$id: {id}
+regulatory_layer: GEMEENTELIJKE_VERORDENING
+publication_date: '2015-01-01'
+valid_from: '2015-01-01'
+gemeente_code: {gemeente_code}
+legal_basis:
+  - law_id: participatiewet
+    article: '{article}'
+articles:
+  - number: '1'
+    text: Verordening
+"#
+        )
+    }
+
+    fn load_law_with_parameters(second_bsn_type: &str) -> RuleResolver {
+        let mut resolver = RuleResolver::new();
+        resolver
+            .load_from_yaml(&format!(
+                r#"
+$id: form_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Declares bsn and peildatum
+    machine_readable:
+      execution:
+        parameters:
+          - name: bsn
+            type: string
+            description: Burgerservicenummer
+          - name: peildatum
+            type: date
+            required: true
+  - number: '2'
+    text: Declares bsn again
+    machine_readable:
+      execution:
+        parameters:
+          - name: bsn
+            type: {second_bsn_type}
+            required: true
+"#
+            ))
+            .unwrap();
+        resolver
+    }
+
+    #[test]
+    fn test_law_parameters_deduplicates_declarations() {
+        let resolver = load_law_with_parameters("string");
+        let params = resolver.law_parameters("form_law", None).unwrap();
+
+        assert_eq!(
+            params,
+            vec![
+                ParameterSpec {
+                    name: "bsn".to_string(),
+                    param_type: ParameterType::String,
+                    required: true,
+                    description: Some("Burgerservicenummer".to_string()),
+                    articles: vec!["1".to_string(), "2".to_string()],
+                },
+                ParameterSpec {
+                    name: "peildatum".to_string(),
+                    param_type: ParameterType::Date,
+                    required: true,
+                    description: None,
+                    articles: vec!["1".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_law_parameters_reports_type_conflict() {
+        let resolver = load_law_with_parameters("number");
+        let err = resolver.law_parameters("form_law", None).unwrap_err();
+
+        assert!(matches!(err, EngineError::ResolutionError(_)));
+        assert!(err.to_string().contains("'bsn'"), "{}", err);
+    }
+
+    #[test]
+    fn test_check_legal_basis_integrity() {
+        let mut resolver = RuleResolver::new();
+        resolver
+            .load_from_yaml(
+                r#"
+$id: participatiewet
+regulatory_layer: WET
+publication_date: '2015-01-01'
+articles:
+  - number: '8'
+    text: Verordeningen
+"#,
+            )
+            .unwrap();
+        resolver
+            .load_from_yaml(&make_verordening("verordening_diemen", "GM0384", "8"))
+            .unwrap();
+        resolver
+            .load_from_yaml(&make_verordening("verordening_utrecht", "GM0344", "99"))
+            .unwrap();
+
+        assert_eq!(
+            resolver.check_legal_basis_integrity(),
+            vec![BrokenBasis {
+                law_id: "verordening_utrecht".to_string(),
+                valid_from: Some("2015-01-01".to_string()),
+                target_law: "participatiewet".to_string(),
+                target_article: "99".to_string(),
+                law_missing: false,
+            }]
+        );
+
+        resolver.unload_law("participatiewet");
+        let broken = resolver.check_legal_basis_integrity();
+        assert_eq!(broken.len(), 2);
+        assert!(broken.iter().all(|b| b.law_missing));
+    }
+
+    #[test]
+    fn test_list_delegated_regulations() {
+        let mut resolver = RuleResolver::new();
+        resolver
+            .load_from_yaml(&make_verordening("verordening_diemen", "GM0384", "8"))
+            .unwrap();
+        resolver
+            .load_from_yaml(&make_verordening("verordening_amsterdam", "GM0363", "8"))
+            .unwrap();
+        resolver
+            .load_from_yaml(&make_verordening("verordening_utrecht", "GM0344", "9"))
+            .unwrap();
+
+        let ids: Vec<&str> = resolver
+            .list_delegated_regulations("participatiewet", "8", None)
+            .iter()
+            .map(|law| law.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["verordening_amsterdam", "verordening_diemen"]);
+
+        // Not yet in force on the reference date
+        let before = NaiveDate::from_ymd_opt(2014, 6, 1);
+        assert!(resolver
+            .list_delegated_regulations("participatiewet", "8", before)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_list_delegated_regulations_via_implements() {
+        let mut resolver = RuleResolver::new();
+        resolver.load_from_yaml(make_law_with_open_term()).unwrap();
+        resolver
+            .load_from_yaml(make_implementing_regulation())
+            .unwrap();
+
+        let delegated = resolver.list_delegated_regulations("zorgtoeslagwet", "4", None);
+        assert_eq!(delegated.len(), 1);
+        assert_eq!(delegated[0].id, "regeling_standaardpremie");
+        assert!(resolver
+            .list_delegated_regulations("zorgtoeslagwet", "5", None)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_find_articles_by_classification() {
+        let mut resolver = RuleResolver::new();
+        resolver
+            .load_from_yaml(
+                r#"
+$id: tagged_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: In deze wet wordt verstaan onder verzekerde...
+    machine_readable:
+      classifications: [definition]
+  - number: '2'
+    text: De verzekerde heeft aanspraak op een toeslag.
+    machine_readable:
+      classifications: [logic, output]
+  - number: '3'
+    text: Deze wet treedt in werking op 1 januari 2025.
+"#,
+            )
+            .unwrap();
+
+        let found = resolver.find_articles_by_classification("definition", None);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0.id, "tagged_law");
+        assert_eq!(found[0].1.number, "1");
+
+        let found = resolver.find_articles_by_classification("output", None);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1.number, "2");
+
+        assert!(resolver
+            .find_articles_by_classification("input", None)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_calculation_date_boundaries_follow_dependencies() {
+        let base = |valid_from: &str, value: i64| {
+            format!(
+                r#"
+$id: versioned_base
+regulatory_layer: WET
+publication_date: '{valid_from}'
+valid_from: '{valid_from}'
+articles:
+  - number: '1'
+    text: Base value
+    machine_readable:
+      execution:
+        output:
+          - name: base_value
+            type: number
+        actions:
+          - output: base_value
+            value: {value}
+"#
+            )
+        };
+        let mut resolver = RuleResolver::new();
+        resolver.load_from_yaml(&base("2024-01-01", 100)).unwrap();
+        resolver.load_from_yaml(&base("2025-01-01", 200)).unwrap();
+        resolver
+            .load_from_yaml(
+                r#"
+$id: cross_law_consumer
+regulatory_layer: WET
+publication_date: '2024-01-01'
+articles:
+  - number: '1'
+    text: Uses versioned base
+    machine_readable:
+      execution:
+        input:
+          - name: external_base
+            type: number
+            source:
+              regulation: versioned_base
+              output: base_value
+        output:
+          - name: result
+            type: number
+        actions:
+          - output: result
+            value: $external_base
+"#,
+            )
+            .unwrap();
+
+        let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        assert_eq!(
+            resolver.calculation_date_boundaries("cross_law_consumer"),
+            vec![date("2024-01-01"), date("2025-01-01")]
+        );
+        assert!(resolver
+            .calculation_date_boundaries("unknown_law")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_unreachable_outputs() {
+        let mut resolver = RuleResolver::new();
+        resolver
+            .load_from_yaml(
+                r#"
+$id: chain_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Intermediate value
+    machine_readable:
+      execution:
+        output:
+          - name: tussenwaarde
+            type: number
+        actions:
+          - output: tussenwaarde
+            value: 10
+  - number: '2'
+    text: Decision built on the intermediate value
+    machine_readable:
+      classifications: [output]
+      execution:
+        input:
+          - name: tussenwaarde
+            type: number
+            source:
+              output: tussenwaarde
+        output:
+          - name: besluit
+            type: number
+        actions:
+          - output: besluit
+            operation: MULTIPLY
+            values:
+              - $tussenwaarde
+              - 2
+  - number: '3'
+    text: Nobody reads this
+    machine_readable:
+      execution:
+        output:
+          - name: vergeten_waarde
+            type: number
+        actions:
+          - output: vergeten_waarde
+            value: 1
+"#,
+            )
+            .unwrap();
+
+        assert_eq!(
+            resolver.unreachable_outputs(None),
+            vec![("chain_law".to_string(), "vergeten_waarde".to_string())]
+        );
+    }
+
+    fn load_law_with_two_issues() -> RuleResolver {
+        let mut resolver = RuleResolver::new();
+        resolver
+            .load_from_yaml(
+                r#"
+$id: sloppy_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Writes an output it never declares
+    machine_readable:
+      execution:
+        output:
+          - name: uitkomst
+            type: number
+        actions:
+          - output: uitkomst
+            value: 1
+          - output: uitkomst_typo
+            value: 2
+  - number: '2'
+    text: Reads from a law that isn't loaded
+    machine_readable:
+      execution:
+        input:
+          - name: inkomen
+            type: amount
+            source:
+              regulation: ontbrekende_wet
+              output: inkomen
+        output:
+          - name: besluit
+            type: amount
+        actions:
+          - output: besluit
+            value: $inkomen
+"#,
+            )
+            .unwrap();
+        resolver
+    }
+
+    #[test]
+    fn test_validate_law_collects_all_issues() {
+        let resolver = load_law_with_two_issues();
+        assert_eq!(
+            resolver.validate_law("sloppy_law", false),
+            vec![
+                "sloppy_law:1 action writes undeclared output 'uitkomst_typo'".to_string(),
+                "sloppy_law:2 input 'inkomen' sources non-existent law 'ontbrekende_wet'"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_law_fail_fast_stops_at_first_issue() {
+        let resolver = load_law_with_two_issues();
+        assert_eq!(
+            resolver.validate_law("sloppy_law", true),
+            vec!["sloppy_law:1 action writes undeclared output 'uitkomst_typo'".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_validate_law_flags_article_without_outputs() {
+        let mut resolver = RuleResolver::new();
+        resolver
+            .load_from_yaml(
+                r#"
+$id: outputless_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Computes a value nobody can read
+    machine_readable:
+      execution:
+        actions:
+          - output: verloren_waarde
+            value: 1
+  - number: '2'
+    text: Declares an empty output list
+    machine_readable:
+      execution:
+        output: []
+        actions:
+          - output: ook_verloren
+            value: 2
+"#,
+            )
+            .unwrap();
+
+        assert_eq!(
+            resolver.validate_law("outputless_law", false),
+            vec![
+                "outputless_law:1 has actions but declares no outputs".to_string(),
+                "outputless_law:2 has actions but declares no outputs".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn test_implements_index_no_match() {
         let mut resolver = RuleResolver::new();
@@ -25,27 +25,39 @@
 //! )?;
 //! ```
 
-use crate::article::{Article, ArticleBasedLaw, Execution, HookPoint, MachineReadable};
+use crate::article::{
+    ActionOperation, Article, ArticleBasedLaw, CompetentAuthority, Execution, HookPoint,
+    MachineReadable,
+};
+use crate::audit::{AuditRecord, AuditSink};
+use crate::clock::{Clock, SystemClock};
 use crate::config;
-use crate::context::RuleContext;
+use crate::context::{reference_date_of, RuleContext};
 use crate::data_source::{DataSource, DataSourceRegistry, DictDataSource};
-use crate::engine::{ArticleEngine, ArticleResult, OutputProvenance};
+use crate::engine::{
+    ArticleEngine, ArticleResult, CallCounts, CapturedInput, OutputProvenance, ResolutionSnapshot,
+    Warning, WarningKind,
+};
 use crate::error::{EngineError, Result};
-use crate::operations::ValueResolver;
+use crate::operations::{OperationRegistry, ValueResolver};
 use crate::priority;
-use crate::resolver::RuleResolver;
-use crate::trace::TraceBuilder;
+use crate::resolver::{BrokenBasis, MissingRef, RuleResolver, NAMESPACE_SEPARATOR};
+use crate::trace::{TraceBuilder, REDACTED};
 use crate::types::{
-    Connectivity, LegalStatus, PathNodeType, RegulatoryLayer, ResolveType, UntranslatableMode,
-    Value,
+    AnniversaryPolicy, BoolCoercion, Connectivity, EnumValidation, EvaluationOptions,
+    IntegerDivision, LegalStatus, MissingCrossLawOutput, PathNodeType, RegulatoryLayer,
+    ResolveType, ShadowPrecedence, UntranslatableMode, Value,
 };
 use crate::uri::RegelrechtUri;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::cell::RefCell;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 // =============================================================================
 // Resolution Context
@@ -64,16 +76,28 @@ struct CacheEntry {
     output_name: String,
     outputs: BTreeMap<String, Value>,
     output_provenance: BTreeMap<String, OutputProvenance>,
+    indicative_outputs: BTreeSet<String>,
+    parameters: BTreeMap<String, Value>,
+}
+
+/// A top-level evaluation being audited: when it started, its dates, and
+/// its parameters with redacted values already replaced.
+struct PendingAudit {
+    started: Instant,
+    calculation_date: String,
+    version_date: Option<String>,
     parameters: BTreeMap<String, Value>,
 }
 
 /// Uses a scoped push/pop pattern for the visited set to avoid
 /// cloning the HashSet on every cross-law descent.
 struct ResolutionContext<'a> {
-    /// Date for calculations (YYYY-MM-DD)
-    calculation_date: &'a str,
-    /// Cached parsed date for version selection (parsed once at construction)
-    reference_date: Option<NaiveDate>,
+    /// Date values are computed with, e.g. ages and `$referencedate` (YYYY-MM-DD)
+    value_date: &'a str,
+    /// Date selecting which law versions apply, parsed once at construction.
+    /// Equal to `value_date` unless evaluating as of a publication date (see
+    /// [`LawExecutionService::evaluate_law_output_as_of`]).
+    version_date: Option<NaiveDate>,
     /// Set of law#output keys already being resolved (cycle detection)
     visited: HashSet<String>,
     /// Current resolution depth
@@ -85,40 +109,104 @@ struct ResolutionContext<'a> {
     /// The law that initiated the current execution chain (for override scoping).
     /// Overrides only apply when declared by this law.
     contextual_law_id: Option<String>,
+    /// Optional deadline (and the timeout it was derived from), checked
+    /// cooperatively between resolution steps.
+    deadline: Option<(Instant, Duration)>,
+    /// Warnings raised while resolving inputs, not yet attached to a result
+    warnings: Vec<Warning>,
+    /// Data-source values taken so far (only when capturing a snapshot)
+    capture: Option<Vec<CapturedInput>>,
+    /// Captured values to use instead of querying the data sources
+    replay: Option<&'a [CapturedInput]>,
+    /// Cross-law resolutions so far
+    call_counts: CallCounts,
+    /// valid_from of every law version an article was evaluated from, by law id
+    law_versions: BTreeMap<String, BTreeSet<String>>,
 }
 
 impl<'a> ResolutionContext<'a> {
     /// Create a new resolution context.
     fn new(calculation_date: &'a str) -> Self {
-        let reference_date = NaiveDate::parse_from_str(calculation_date, "%Y-%m-%d").ok();
+        let version_date = NaiveDate::parse_from_str(calculation_date, "%Y-%m-%d").ok();
         Self {
-            calculation_date,
-            reference_date,
+            value_date: calculation_date,
+            version_date,
             visited: HashSet::new(),
             depth: 0,
             trace: None,
             cache: HashMap::new(),
             contextual_law_id: None,
+            deadline: None,
+            warnings: Vec::new(),
+            capture: None,
+            replay: None,
+            call_counts: CallCounts::default(),
+            law_versions: BTreeMap::new(),
         }
     }
 
     /// Create a new resolution context with trace builder.
     fn with_trace(calculation_date: &'a str, trace: Rc<RefCell<TraceBuilder>>) -> Self {
-        let reference_date = NaiveDate::parse_from_str(calculation_date, "%Y-%m-%d").ok();
+        let version_date = NaiveDate::parse_from_str(calculation_date, "%Y-%m-%d").ok();
         Self {
-            calculation_date,
-            reference_date,
+            value_date: calculation_date,
+            version_date,
             visited: HashSet::new(),
             depth: 0,
             trace: Some(trace),
             cache: HashMap::new(),
             contextual_law_id: None,
+            deadline: None,
+            warnings: Vec::new(),
+            capture: None,
+            replay: None,
+            call_counts: CallCounts::default(),
+            law_versions: BTreeMap::new(),
         }
     }
 
     /// Return the cached parsed date for version selection.
-    fn reference_date(&self) -> Option<NaiveDate> {
-        self.reference_date
+    fn version_date(&self) -> Option<NaiveDate> {
+        self.version_date
+    }
+
+    /// Fail with `Timeout` once the deadline (if any) has passed.
+    fn check_deadline(&self) -> Result<()> {
+        match self.deadline {
+            Some((deadline, timeout)) if Instant::now() >= deadline => {
+                Err(EngineError::Timeout(timeout))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Count a cross-law reference or URI_CALL, failing once more than
+    /// `budget` cross-law calls have been made.
+    fn record_uri_call(&mut self, budget: u64) -> Result<()> {
+        self.call_counts.uri_calls += 1;
+        self.check_call_budget(budget)
+    }
+
+    /// Count an open-term delegation, failing once more than `budget`
+    /// cross-law calls have been made.
+    fn record_delegation_call(&mut self, budget: u64) -> Result<()> {
+        self.call_counts.delegation_calls += 1;
+        self.check_call_budget(budget)
+    }
+
+    fn check_call_budget(&self, budget: u64) -> Result<()> {
+        if self.call_counts.uri_calls + self.call_counts.delegation_calls > budget {
+            return Err(EngineError::CallBudgetExceeded(budget));
+        }
+        Ok(())
+    }
+
+    /// Note that an article of `law` is being evaluated, for the audit record.
+    fn record_law_version(&mut self, law: &ArticleBasedLaw) {
+        self.law_versions
+            .entry(law.id.clone())
+            .or_default()
+            .extend(law.valid_from.clone());
     }
 
     /// Enter a cross-law resolution scope: mark key as visited and increment depth.
@@ -197,6 +285,27 @@ impl Drop for TraceGuard {
     }
 }
 
+/// The calculation date of a request, taken from its `referencedate`
+/// parameter object when `calculation_date` is empty (see [`RuleContext::new`]).
+///
+/// Resolved once when a request enters the service, so that law versions are
+/// selected on it and cross-law evaluations receive it as a plain date.
+fn request_date<'d>(
+    parameters: &BTreeMap<String, Value>,
+    calculation_date: &'d str,
+) -> Result<Cow<'d, str>> {
+    if !calculation_date.is_empty() {
+        return Ok(Cow::Borrowed(calculation_date));
+    }
+    let date = reference_date_of(parameters, calculation_date)?;
+    Ok(Cow::Owned(format_date(date)))
+}
+
+/// Format a date as `YYYY-MM-DD`.
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
 /// Build a cache key from law_id, output_name, and parameters.
 ///
 /// The cache key includes output_name because different outputs within the same
@@ -278,6 +387,13 @@ pub trait ServiceProvider {
     /// Get a law by ID.
     fn get_law(&self, law_id: &str) -> Option<&ArticleBasedLaw>;
 
+    /// Get the `valid_from` of the version of a law that applies on
+    /// `calculation_date`; `None` when that version declares no `valid_from`.
+    ///
+    /// Fails like [`RuleResolver::require_law_for_date`] when the law is not
+    /// loaded or no version applies.
+    fn applicable_version(&self, law_id: &str, calculation_date: &str) -> Result<Option<String>>;
+
     /// Resolve an external input source.
     ///
     /// This is the main entry point for resolving `source.regulation` references.
@@ -298,6 +414,106 @@ pub trait ServiceProvider {
     ) -> Result<Value>;
 }
 
+/// `ServiceProvider` handed to the engine while an article executes.
+///
+/// Resolves URIs within the caller's `ResolutionContext` rather than starting
+/// a fresh one, so inline cross-law calls share cycle detection, depth limits,
+/// caching, deadline and trace with declared cross-law inputs. Law IDs are
+/// followed from the executing law through
+/// [`RuleResolver::referenced_law_id`], so namespaced laws reach their siblings.
+struct ScopedServiceProvider<'s, 'c> {
+    service: &'s LawExecutionService,
+    /// ID of the law whose article is executing
+    law_id: &'s str,
+    res_ctx: RefCell<&'s mut ResolutionContext<'c>>,
+}
+
+impl ServiceProvider for ScopedServiceProvider<'_, '_> {
+    fn evaluate_uri(
+        &self,
+        uri: &str,
+        parameters: &BTreeMap<String, Value>,
+        _calculation_date: &str,
+    ) -> Result<ArticleResult> {
+        // The calculation date is fixed by the enclosing resolution context.
+        let parsed = RegelrechtUri::parse(uri)?;
+        let law_id = self
+            .service
+            .resolver
+            .referenced_law_id(parsed.law_id(), self.law_id);
+        let mut res_ctx = self.res_ctx.borrow_mut();
+
+        let key = format!("{}#{}", law_id, parsed.output());
+        if res_ctx.is_visited(&key) {
+            return Err(EngineError::CircularReference(format!(
+                "Circular cross-law reference detected: {} is already being resolved",
+                key
+            )));
+        }
+
+        res_ctx.record_uri_call(self.service.max_cross_law_calls)?;
+        let _guard = res_ctx.trace_guard(key.clone(), PathNodeType::CrossLawReference);
+        res_ctx.trace_set_message(format!("URI_CALL {}", uri));
+
+        res_ctx.enter(key.clone());
+        let result = self.service.evaluate_law_output_internal(
+            &law_id,
+            parsed.output(),
+            parameters.clone(),
+            &mut res_ctx,
+        );
+        res_ctx.leave(&key);
+
+        match &result {
+            Ok(r) => {
+                let output = parsed.field().unwrap_or(parsed.output());
+                if let Some(value) = r.outputs.get(output) {
+                    res_ctx.trace_set_result(value.clone());
+                }
+            }
+            Err(e) => res_ctx.trace_set_message(format!("Execution failed: {}", e)),
+        }
+        result
+    }
+
+    fn get_law(&self, law_id: &str) -> Option<&ArticleBasedLaw> {
+        self.service
+            .get_law(&self.service.resolver.referenced_law_id(law_id, self.law_id))
+    }
+
+    fn applicable_version(&self, law_id: &str, _calculation_date: &str) -> Result<Option<String>> {
+        // The calculation date is fixed by the enclosing resolution context.
+        let reference_date = self.res_ctx.borrow().version_date();
+        let resolver = &self.service.resolver;
+        let law = resolver.require_law_for_date(
+            &resolver.referenced_law_id(law_id, self.law_id),
+            reference_date,
+        )?;
+        Ok(law.valid_from.clone())
+    }
+
+    fn resolve_external_input(
+        &self,
+        regulation: &str,
+        output: &str,
+        source_parameters: Option<&BTreeMap<String, String>>,
+        context: &RuleContext,
+        _calculation_date: &str,
+    ) -> Result<Value> {
+        self.service.resolve_external_input_internal(
+            &self
+                .service
+                .resolver
+                .referenced_law_id(regulation, self.law_id),
+            output,
+            source_parameters,
+            None,
+            context,
+            &mut self.res_ctx.borrow_mut(),
+        )
+    }
+}
+
 /// Metadata about a loaded law.
 ///
 /// Provides summary information about a law without requiring full article access.
@@ -309,16 +525,49 @@ pub struct LawInfo {
     pub regulatory_layer: RegulatoryLayer,
     /// Publication date
     pub publication_date: String,
+    /// Date from which this version applies (`None` for unversioned laws)
+    pub valid_from: Option<String>,
     /// BWB identifier (for national laws)
     pub bwb_id: Option<String>,
     /// URL to official source
     pub url: Option<String>,
+    /// Organisation that executes the law, from the `authority` (or
+    /// `uitvoerder`) header, falling back to a named `competent_authority`
+    pub authority: Option<String>,
     /// List of output names produced by this law
     pub outputs: Vec<String>,
     /// Number of articles in the law
     pub article_count: usize,
 }
 
+/// Outcome of [`LawExecutionService::load_and_validate_directory`], e.g. for a
+/// health endpoint.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StartupReport {
+    /// Number of law files loaded from the directory
+    pub loaded: usize,
+    /// Number of unique law IDs loaded afterwards
+    pub law_count: usize,
+    /// `legal_basis` entries citing a law or article that isn't loaded
+    pub broken_legal_bases: Vec<BrokenBasis>,
+    /// Circular references between outputs, see [`RuleResolver::find_cycles`]
+    pub cycles: Vec<Vec<(String, String)>>,
+    /// Input references that cannot be resolved, each reported once
+    pub missing_references: Vec<MissingRef>,
+    /// Overrides targeting a law, article or output that doesn't exist
+    pub invalid_overrides: Vec<String>,
+}
+
+impl StartupReport {
+    /// `true` when no integrity check found an issue.
+    pub fn is_healthy(&self) -> bool {
+        self.broken_legal_bases.is_empty()
+            && self.cycles.is_empty()
+            && self.missing_references.is_empty()
+            && self.invalid_overrides.is_empty()
+    }
+}
+
 /// State of a decision progressing through an AWB-defined procedure lifecycle (RFC-008).
 ///
 /// The engine is stateless — this struct is passed in by the caller and returned
@@ -369,6 +618,22 @@ pub struct LawExecutionService {
     source_info: HashMap<String, (String, String)>,
     /// How to handle articles with untranslatable constructs (RFC-012)
     untranslatable_mode: UntranslatableMode,
+    /// Options passed to every article evaluation
+    evaluation_options: EvaluationOptions,
+    /// Source of "today" for callers that don't pass a calculation date
+    clock: Box<dyn Clock>,
+    /// Variable names whose values are withheld from traces (e.g. `bsn`)
+    trace_redactions: BTreeSet<String>,
+    /// Ignore implementing regulations so open terms fall back to their defaults
+    simulate_delegation_absence: bool,
+    /// Attach a `ResolutionSnapshot` of the data-source values to each result
+    capture_resolution_snapshot: bool,
+    /// Cross-law calls one evaluation may make before failing
+    max_cross_law_calls: u64,
+    /// Handlers for custom operations
+    operations: Arc<OperationRegistry>,
+    /// Receiver of one record per top-level evaluation
+    audit_sink: Option<Arc<dyn AuditSink>>,
 }
 
 impl Default for LawExecutionService {
@@ -385,14 +650,180 @@ impl LawExecutionService {
             data_registry: DataSourceRegistry::new(),
             source_info: HashMap::new(),
             untranslatable_mode: UntranslatableMode::default(),
+            evaluation_options: EvaluationOptions::default(),
+            clock: Box::new(SystemClock),
+            trace_redactions: BTreeSet::new(),
+            simulate_delegation_absence: false,
+            capture_resolution_snapshot: false,
+            max_cross_law_calls: config::MAX_CROSS_LAW_CALLS,
+            operations: Arc::new(OperationRegistry::new()),
+            audit_sink: None,
         }
     }
 
+    /// Replace the clock used to determine today's date (default: system clock).
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Today's date according to the service's clock.
+    pub fn today(&self) -> NaiveDate {
+        self.clock.today()
+    }
+
     /// Set the untranslatable handling mode (RFC-012).
     pub fn set_untranslatable_mode(&mut self, mode: UntranslatableMode) {
         self.untranslatable_mode = mode;
     }
 
+    /// Set the variable names whose values are redacted from traces.
+    ///
+    /// Applies to every traced evaluation, in both the rendered text and the
+    /// serialized JSON trace, and to the parameters of audit records (see
+    /// [`set_audit_sink`](Self::set_audit_sink)). Names match case-insensitively.
+    pub fn set_trace_redactions<I, S>(&mut self, names: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.trace_redactions = names
+            .into_iter()
+            .map(|name| name.as_ref().to_lowercase())
+            .collect();
+    }
+
+    /// Install a sink that receives an [`AuditRecord`] for every top-level
+    /// evaluation, including failed ones: each `evaluate_law*` entry point
+    /// (traced, timed, as-of, parallel and snapshot replay included) emits
+    /// one record per call. Parameters named in
+    /// [`set_trace_redactions`](Self::set_trace_redactions) are redacted.
+    pub fn set_audit_sink(&mut self, sink: Arc<dyn AuditSink>) {
+        self.audit_sink = Some(sink);
+    }
+
+    /// Resolve every open term as if no implementing regulation were loaded.
+    ///
+    /// Open terms then use their declared `default`, resolve to null when
+    /// optional, or fail when required. Lets teams verify the fallback path
+    /// without unloading the implementing regulations.
+    pub fn set_simulate_delegation_absence(&mut self, simulate: bool) {
+        self.simulate_delegation_absence = simulate;
+    }
+
+    /// Set how boolean contexts treat non-boolean condition values.
+    pub fn set_bool_coercion(&mut self, mode: BoolCoercion) {
+        self.evaluation_options.bool_coercion = mode;
+    }
+
+    /// Set whether DIVIDE on integer operands returns a float or a rounded integer.
+    pub fn set_integer_division(&mut self, mode: IntegerDivision) {
+        self.evaluation_options.integer_division = mode;
+    }
+
+    /// Set the number of decimals amounts are stored at (default 2, eurocents).
+    ///
+    /// `amount` parameters passed as text are parsed at this scale, so with
+    /// the default `"21.12"` becomes `2112`. Numeric `amount` parameters are
+    /// taken to be at this scale already: pass `2112`, not `21.12`.
+    ///
+    /// # Errors
+    /// Returns `InvalidOperation` for a scale above
+    /// [`regelrecht_shared::MAX_MONEY_SCALE`]; the scale is left unchanged.
+    pub fn set_money_scale(&mut self, scale: u32) -> Result<()> {
+        if scale > regelrecht_shared::MAX_MONEY_SCALE {
+            return Err(EngineError::InvalidOperation(format!(
+                "Money scale {} exceeds the maximum of {}",
+                scale,
+                regelrecht_shared::MAX_MONEY_SCALE
+            )));
+        }
+        self.evaluation_options.money_scale = scale;
+        Ok(())
+    }
+
+    /// Set whether an output outside its declared `enum` set is an error
+    /// (the default) or a warning.
+    pub fn set_enum_validation(&mut self, mode: EnumValidation) {
+        self.evaluation_options.enum_validation = mode;
+    }
+
+    /// Set whether a parameter (the default) or a definition of the same name
+    /// is used. Shadowing is reported as a warning either way.
+    pub fn set_shadow_precedence(&mut self, precedence: ShadowPrecedence) {
+        self.evaluation_options.shadow_precedence = precedence;
+    }
+
+    /// Set when people born on February 29 have their birthday in non-leap
+    /// years (February 28 by default).
+    pub fn set_anniversary_policy(&mut self, policy: AnniversaryPolicy) {
+        self.evaluation_options.anniversary_policy = policy;
+    }
+
+    /// Set whether a cross-law reference to a loaded law that lacks the
+    /// requested output is an error (the default) or resolves to null with
+    /// a warning.
+    pub fn set_missing_cross_law_output(&mut self, mode: MissingCrossLawOutput) {
+        self.evaluation_options.missing_cross_law_output = mode;
+    }
+
+    /// Record the wall-clock duration of each computed output in
+    /// `ArticleResult::timings`.
+    pub fn set_record_timings(&mut self, record: bool) {
+        self.evaluation_options.record_timings = record;
+    }
+
+    /// Make an article that finishes without producing every declared output
+    /// fail with `MissingOutputs` instead of silently omitting them.
+    pub fn set_require_all_outputs(&mut self, require: bool) {
+        self.evaluation_options.require_all_outputs = require;
+    }
+
+    /// Attach a [`ResolutionSnapshot`] to every result of
+    /// [`evaluate_law`](Self::evaluate_law) and its variants, recording the
+    /// request and each value taken from a data source, so that the decision
+    /// can be reproduced with [`evaluate_from_snapshot`](Self::evaluate_from_snapshot).
+    pub fn set_capture_resolution_snapshot(&mut self, capture: bool) {
+        self.capture_resolution_snapshot = capture;
+    }
+
+    /// Limit the number of cross-law calls (references, URI_CALLs and
+    /// open-term delegations) one evaluation may make; exceeding it fails
+    /// with `CallBudgetExceeded`. Defaults to [`config::MAX_CROSS_LAW_CALLS`].
+    pub fn set_max_cross_law_calls(&mut self, max: u64) {
+        self.max_cross_law_calls = max;
+    }
+
+    /// Register the handler for a custom operation.
+    ///
+    /// Laws can then use `operation: <name>` with any further fields; the
+    /// handler reads them from the [`CustomOperation`](crate::CustomOperation)
+    /// and evaluates them with [`evaluate_value`](crate::evaluate_value).
+    /// Names of built-in operations cannot be overridden.
+    ///
+    /// Register an operation before loading the laws that use it: loading a
+    /// law with an unregistered operation name fails, so that a misspelled
+    /// built-in operation is caught at load time.
+    pub fn register_operation(
+        &mut self,
+        name: impl Into<String>,
+        handler: impl Fn(&ActionOperation, &dyn ValueResolver, usize) -> Result<Value>
+            + Send
+            + Sync
+            + 'static,
+    ) {
+        let name = name.into();
+        self.resolver.register_custom_operation(name.clone());
+        Arc::make_mut(&mut self.operations).register(name, handler);
+    }
+
+    /// Reject laws whose operations carry fields irrelevant to their type.
+    ///
+    /// Applies to every YAML loader, including directory loads; see
+    /// [`RuleResolver::set_strict_operation_fields`].
+    pub fn set_strict_operation_fields(&mut self, strict: bool) {
+        self.resolver.set_strict_operation_fields(strict);
+    }
+
     /// Load a law from YAML string.
     ///
     /// # Returns
@@ -401,8 +832,48 @@ impl LawExecutionService {
         self.resolver.load_from_yaml(yaml)
     }
 
+    /// Load a law from YAML under a namespace (e.g. `nl`, `eu`, `gm0363`).
+    ///
+    /// The law is registered as `namespace:id`; see
+    /// [`RuleResolver::load_law_in_namespace`].
+    ///
+    /// # Returns
+    /// The qualified law ID on success.
+    pub fn load_law_in_namespace(&mut self, yaml: &str, namespace: &str) -> Result<String> {
+        let law = self.resolver.parse_law(yaml)?;
+        let law_id = format!("{}{}{}", namespace, NAMESPACE_SEPARATOR, law.id);
+        self.resolver.load_law_in_namespace(law, namespace)?;
+        Ok(law_id)
+    }
+
+    /// Reload an already loaded law from YAML, replacing the version with the same
+    /// `valid_from` (or adding it as a new version).
+    ///
+    /// The YAML is parsed before anything is touched, so a failed reload leaves the
+    /// previously loaded versions in place.
+    ///
+    /// # Returns
+    /// The law ID on success.
+    ///
+    /// # Errors
+    /// Returns a parse error for malformed YAML, or `LawNotFound` if no version of
+    /// the law is loaded yet (use [`load_law`](Self::load_law) for new laws).
+    pub fn reload_law(&mut self, yaml: &str) -> Result<String> {
+        let law = self.resolver.parse_law(yaml)?;
+        if !self.resolver.has_law(&law.id) {
+            return Err(EngineError::LawNotFound(law.id));
+        }
+        let law_id = law.id.clone();
+        self.resolver.load_law(law)?;
+        tracing::info!(law_id = %law_id, "Law reloaded");
+        Ok(law_id)
+    }
+
     /// Load a law struct directly.
     ///
+    /// The strict operation-field check needs the raw YAML, so it does not
+    /// apply here.
+    ///
     /// # Returns
     /// `Ok(())` on success, `Err` if the maximum number of laws would be exceeded.
     pub fn load_law_struct(&mut self, law: ArticleBasedLaw) -> Result<()> {
@@ -545,7 +1016,8 @@ impl LawExecutionService {
                 "output_names must not be empty".to_string(),
             ));
         }
-        let mut res_ctx = ResolutionContext::new(calculation_date);
+        let calculation_date = request_date(&parameters, calculation_date)?;
+        let mut res_ctx = ResolutionContext::new(&calculation_date);
         res_ctx.contextual_law_id = Some(law_id.to_string());
         self.evaluate_law_multi_internal(law_id, output_names, parameters, &mut res_ctx)
     }
@@ -575,6 +1047,24 @@ impl LawExecutionService {
         parameters: BTreeMap<String, Value>,
         calculation_date: &str,
         trace_builder: TraceBuilder,
+    ) -> Result<ArticleResult> {
+        self.evaluate_law_with_shared_trace_builder(
+            law_id,
+            output_names,
+            parameters,
+            calculation_date,
+            Rc::new(RefCell::new(trace_builder)),
+        )
+    }
+
+    /// Evaluate multiple outputs, tracing into a builder the caller keeps a handle to.
+    fn evaluate_law_with_shared_trace_builder(
+        &self,
+        law_id: &str,
+        output_names: &[&str],
+        parameters: BTreeMap<String, Value>,
+        calculation_date: &str,
+        trace: Rc<RefCell<TraceBuilder>>,
     ) -> Result<ArticleResult> {
         if output_names.is_empty() {
             return Err(EngineError::InvalidOperation(
@@ -582,19 +1072,28 @@ impl LawExecutionService {
             ));
         }
 
+        let calculation_date = request_date(&parameters, calculation_date)?;
         let outputs_label = output_names.join(", ");
-        let trace = Rc::new(RefCell::new(trace_builder));
 
         // Push the top-level article node
         {
             let mut tb = trace.borrow_mut();
+            for name in &self.trace_redactions {
+                tb.redact(name);
+            }
             tb.push(
                 format!("{} ({})", law_id, outputs_label),
                 PathNodeType::Article,
             );
             let mut sorted_params: Vec<_> = parameters
                 .iter()
-                .map(|(k, v)| format!("{}: {}", k, v))
+                .map(|(k, v)| {
+                    if tb.is_redacted(k) {
+                        format!("{}: {}", k, REDACTED)
+                    } else {
+                        format!("{}: {}", k, v)
+                    }
+                })
                 .collect();
             sorted_params.sort();
             tb.set_message(format!(
@@ -606,7 +1105,7 @@ impl LawExecutionService {
             ));
         }
 
-        let mut res_ctx = ResolutionContext::with_trace(calculation_date, Rc::clone(&trace));
+        let mut res_ctx = ResolutionContext::with_trace(&calculation_date, Rc::clone(&trace));
         res_ctx.contextual_law_id = Some(law_id.to_string());
 
         // Execute: group outputs by article, evaluate each once, merge + filter
@@ -635,7 +1134,7 @@ impl LawExecutionService {
             Err(e) => {
                 let mut tb = trace.borrow_mut();
                 tb.set_message(format!("Execution failed: {}", e));
-                let partial_trace = tb.pop();
+                let partial_trace = tb.pop().or_else(|| tb.materialize_failure_trace());
                 Err(EngineError::TracedError {
                     source: Box::new(e),
                     trace: partial_trace.map(Box::new),
@@ -663,80 +1162,317 @@ impl LawExecutionService {
         self.evaluate_law(law_id, &[output_name], parameters, calculation_date)
     }
 
-    /// Execute a single law output with tracing enabled.
-    pub fn evaluate_law_output_with_trace(
+    /// Execute a law for a single output, defaulting the calculation date to today.
+    ///
+    /// Same as [`evaluate_law_output`](Self::evaluate_law_output), but `None` uses
+    /// today's date according to the service's clock (see [`set_clock`](Self::set_clock)),
+    /// so "current rules" callers need not format the date themselves.
+    pub fn evaluate_law_output_at(
         &self,
         law_id: &str,
         output_name: &str,
         parameters: BTreeMap<String, Value>,
-        calculation_date: &str,
+        calculation_date: Option<&str>,
     ) -> Result<ArticleResult> {
-        self.evaluate_law_with_trace(law_id, &[output_name], parameters, calculation_date)
+        match calculation_date {
+            Some(date) => self.evaluate_law_output(law_id, output_name, parameters, date),
+            None => {
+                let today = self.today().format("%Y-%m-%d").to_string();
+                self.evaluate_law_output(law_id, output_name, parameters, &today)
+            }
+        }
     }
 
-    /// Execute a single law output with a caller-provided trace builder.
-    pub fn evaluate_law_output_with_trace_builder(
+    /// Resolve one variable as an article's actions would see it, without
+    /// running the actions.
+    ///
+    /// Builds the article's context exactly as article execution does for
+    /// `parameters` on `calculation_date` — definitions, regulatory layer,
+    /// `amount` parameters parsed at the money scale, input sources (cross-law
+    /// inputs included) and open terms — and resolves `name` in it. As in
+    /// [`evaluate_law`](Self::evaluate_law), an empty `calculation_date` is
+    /// taken from a `referencedate` parameter. A leading `$` is optional and
+    /// dot notation works as in actions. Outputs are not available, since no
+    /// action runs.
+    ///
+    /// # Errors
+    /// Returns the resolution error, e.g. `VariableNotFound` for an unknown
+    /// name, or the error of a failing input source.
+    pub fn resolve_variable(
         &self,
         law_id: &str,
-        output_name: &str,
+        article_number: &str,
+        name: &str,
         parameters: BTreeMap<String, Value>,
         calculation_date: &str,
-        trace_builder: TraceBuilder,
-    ) -> Result<ArticleResult> {
-        self.evaluate_law_with_trace_builder(
-            law_id,
-            &[output_name],
-            parameters,
-            calculation_date,
-            trace_builder,
-        )
+    ) -> Result<Value> {
+        let calculation_date = request_date(&parameters, calculation_date)?;
+        let mut res_ctx = ResolutionContext::new(&calculation_date);
+        res_ctx.contextual_law_id = Some(law_id.to_string());
+        let law = self
+            .resolver
+            .require_law_for_date(law_id, res_ctx.version_date())?;
+        let article = law.find_article_by_number(article_number).ok_or_else(|| {
+            EngineError::ArticleNotFound {
+                law_id: law_id.to_string(),
+                article: article_number.to_string(),
+            }
+        })?;
+
+        // Same steps as evaluate_article_with_service up to action execution
+        let context = self.prepare_article_context(article, law, &parameters, &mut res_ctx)?;
+        let open_terms =
+            self.resolve_open_terms(article, law, &context, &mut res_ctx, &mut Vec::new())?;
+        let mut combined_params = parameters;
+        for (name, value) in context.resolved_inputs() {
+            combined_params.insert(name.clone(), value.clone());
+        }
+        combined_params.extend(open_terms);
+
+        let calculation_date = res_ctx.value_date;
+        let scoped = ScopedServiceProvider {
+            service: self,
+            law_id: &law.id,
+            res_ctx: RefCell::new(&mut res_ctx),
+        };
+        let context = ArticleEngine::new(article, law)
+            .with_options(self.evaluation_options)
+            .with_service(&scoped)
+            .with_operations(Arc::clone(&self.operations))
+            .build_context(combined_params, calculation_date)?;
+        context.resolve(name.strip_prefix('$').unwrap_or(name))
     }
 
-    /// Execute a law output using an existing shared trace builder.
+    /// Resolve one variable as an article's actions would see it, defaulting
+    /// the calculation date to today.
     ///
-    /// Unlike `evaluate_law_output_with_trace` which creates its own root trace node,
-    /// this method appends to an existing trace tree. Used by `execute_stage_internal`
-    /// when falling through to non-procedure execution.
-    fn evaluate_law_output_with_shared_trace(
+    /// Same as [`resolve_variable`](Self::resolve_variable), but `None` uses
+    /// today's date according to the service's clock (see [`set_clock`](Self::set_clock)).
+    pub fn resolve_variable_at(
         &self,
         law_id: &str,
-        output_name: &str,
+        article_number: &str,
+        name: &str,
         parameters: BTreeMap<String, Value>,
-        calculation_date: &str,
-        trace: Rc<RefCell<TraceBuilder>>,
-    ) -> Result<ArticleResult> {
-        let mut res_ctx = ResolutionContext::with_trace(calculation_date, trace);
-        res_ctx.contextual_law_id = Some(law_id.to_string());
-        self.evaluate_law_output_internal(law_id, output_name, parameters, &mut res_ctx)
+        calculation_date: Option<&str>,
+    ) -> Result<Value> {
+        match calculation_date {
+            Some(date) => self.resolve_variable(law_id, article_number, name, parameters, date),
+            None => {
+                let today = self.today().format("%Y-%m-%d").to_string();
+                self.resolve_variable(law_id, article_number, name, parameters, &today)
+            }
+        }
     }
 
-    /// Execute a single lifecycle stage of a procedure-aware law (RFC-008).
-    ///
-    /// For laws that produce a `legal_character` with an associated AWB procedure,
-    /// this method executes one stage at a time and yields when external input is needed.
+    /// Check, without evaluating, that every law an output transitively
+    /// references has a version producing the referenced output on
+    /// `calculation_date`.
     ///
-    /// # Arguments
-    /// * `law_id` - The law being executed (e.g., "vreemdelingenwet_2000")
-    /// * `output_name` - The output to calculate
-    /// * `state` - Current decision state (None for initial execution)
-    /// * `parameters` - Input parameters (merged with accumulated outputs if resuming)
-    /// * `calculation_date` - Date for calculations
+    /// Meant as a preflight before a batch run at a fixed date. See
+    /// [`RuleResolver::missing_references`] for which references are followed.
     ///
     /// # Returns
-    /// `ExecutionOutcome::Complete` if all stages are done, or
-    /// `ExecutionOutcome::Yielded` if waiting for external input.
-    #[cfg_attr(feature = "otel", tracing::instrument(skip(self, state, parameters), fields(law_id = %law_id, output = %output_name)))]
-    pub fn execute_stage(
+    /// Every unresolvable reference found; empty when the output resolves.
+    ///
+    /// # Errors
+    /// Returns [`EngineError::InvalidDate`] if `calculation_date` is not a
+    /// `YYYY-MM-DD` date.
+    pub fn check_resolvable(
         &self,
         law_id: &str,
-        output_name: &str,
-        state: Option<StageState>,
-        parameters: BTreeMap<String, Value>,
+        output: &str,
         calculation_date: &str,
-    ) -> Result<ExecutionOutcome> {
-        self.execute_stage_internal(
-            law_id,
-            output_name,
+    ) -> Result<Vec<MissingRef>> {
+        let reference_date = NaiveDate::parse_from_str(calculation_date, "%Y-%m-%d")
+            .map_err(|e| EngineError::InvalidDate(format!("{}: {}", calculation_date, e)))?;
+        Ok(self
+            .resolver
+            .missing_references(law_id, output, Some(reference_date)))
+    }
+
+    /// Reproduce an evaluation captured in a [`ResolutionSnapshot`].
+    ///
+    /// Evaluates the snapshot's request against the loaded laws, taking
+    /// data-source inputs only from the snapshot: the registered data sources
+    /// are not queried, so the result does not depend on their current state.
+    pub fn evaluate_from_snapshot(&self, snapshot: &ResolutionSnapshot) -> Result<ArticleResult> {
+        let output_names: Vec<&str> = snapshot.outputs.iter().map(String::as_str).collect();
+        if output_names.is_empty() {
+            return Err(EngineError::InvalidOperation(
+                "snapshot has no outputs to evaluate".to_string(),
+            ));
+        }
+        let mut res_ctx = ResolutionContext::new(&snapshot.calculation_date);
+        if let Some(version_date) = &snapshot.version_date {
+            res_ctx.version_date = Some(
+                NaiveDate::parse_from_str(version_date, "%Y-%m-%d")
+                    .map_err(|e| EngineError::InvalidDate(format!("{}: {}", version_date, e)))?,
+            );
+        }
+        res_ctx.contextual_law_id = Some(snapshot.law_id.clone());
+        res_ctx.replay = Some(&snapshot.inputs);
+        self.evaluate_law_multi_internal(
+            &snapshot.law_id,
+            &output_names,
+            snapshot.parameters.clone(),
+            &mut res_ctx,
+        )
+    }
+
+    /// Execute a single law output, failing if it takes longer than `timeout`.
+    ///
+    /// The deadline is checked cooperatively before each cross-law call and
+    /// each input resolution, so a slow fan-out stops at the next step, and
+    /// once more when the evaluation finishes. A step that is already running
+    /// (a blocking data source, a large FOREACH) is not interrupted: the call
+    /// may return later than `timeout`, but then always with a timeout error,
+    /// never with a late result.
+    ///
+    /// # Errors
+    /// Returns [`EngineError::Timeout`] when the deadline passes.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn evaluate_law_output_with_timeout(
+        &self,
+        law_id: &str,
+        output_name: &str,
+        parameters: BTreeMap<String, Value>,
+        calculation_date: &str,
+        timeout: Duration,
+    ) -> Result<ArticleResult> {
+        let calculation_date = request_date(&parameters, calculation_date)?;
+        let mut res_ctx = ResolutionContext::new(&calculation_date);
+        res_ctx.contextual_law_id = Some(law_id.to_string());
+        res_ctx.deadline = Some((Instant::now() + timeout, timeout));
+        let result =
+            self.evaluate_law_multi_internal(law_id, &[output_name], parameters, &mut res_ctx)?;
+        // The last step may have run past the deadline without a check after it
+        res_ctx.check_deadline()?;
+        Ok(result)
+    }
+
+    /// Execute a law for a single output, selecting law versions on a
+    /// different date than the one values are computed with.
+    ///
+    /// Some rules apply the law text in force on `version_date` (e.g. the
+    /// publication date of a decision) to the facts as of `value_date` (e.g.
+    /// the date of the event): every law version, including cross-law
+    /// references, is selected on `version_date`, while `$referencedate`,
+    /// ages and other date computations use `value_date`.
+    /// [`evaluate_law_output`](Self::evaluate_law_output) uses one date for both.
+    pub fn evaluate_law_output_as_of(
+        &self,
+        law_id: &str,
+        output_name: &str,
+        parameters: BTreeMap<String, Value>,
+        value_date: &str,
+        version_date: &str,
+    ) -> Result<ArticleResult> {
+        let version_date = NaiveDate::parse_from_str(version_date, "%Y-%m-%d")
+            .map_err(|e| EngineError::InvalidDate(format!("{}: {}", version_date, e)))?;
+        let value_date = request_date(&parameters, value_date)?;
+        let mut res_ctx = ResolutionContext::new(&value_date);
+        res_ctx.version_date = Some(version_date);
+        res_ctx.contextual_law_id = Some(law_id.to_string());
+        self.evaluate_law_multi_internal(law_id, &[output_name], parameters, &mut res_ctx)
+    }
+
+    /// Execute a single law output with tracing enabled.
+    pub fn evaluate_law_output_with_trace(
+        &self,
+        law_id: &str,
+        output_name: &str,
+        parameters: BTreeMap<String, Value>,
+        calculation_date: &str,
+    ) -> Result<ArticleResult> {
+        self.evaluate_law_with_trace(law_id, &[output_name], parameters, calculation_date)
+    }
+
+    /// Execute a single law output, keeping a trace only if it fails.
+    ///
+    /// Records the last [`config::FAILURE_TRACE_STEPS`] steps in a ring buffer
+    /// (see [`TraceBuilder::failure_only`]) instead of building a full trace.
+    /// On success the result carries no trace; on failure the buffered steps
+    /// are materialized and returned in [`EngineError::TracedError`].
+    pub fn evaluate_law_output_with_failure_trace(
+        &self,
+        law_id: &str,
+        output_name: &str,
+        parameters: BTreeMap<String, Value>,
+        calculation_date: &str,
+    ) -> Result<ArticleResult> {
+        self.evaluate_law_output_with_trace_builder(
+            law_id,
+            output_name,
+            parameters,
+            calculation_date,
+            TraceBuilder::failure_only(config::FAILURE_TRACE_STEPS),
+        )
+    }
+
+    /// Execute a single law output with a caller-provided trace builder.
+    pub fn evaluate_law_output_with_trace_builder(
+        &self,
+        law_id: &str,
+        output_name: &str,
+        parameters: BTreeMap<String, Value>,
+        calculation_date: &str,
+        trace_builder: TraceBuilder,
+    ) -> Result<ArticleResult> {
+        self.evaluate_law_with_trace_builder(
+            law_id,
+            &[output_name],
+            parameters,
+            calculation_date,
+            trace_builder,
+        )
+    }
+
+    /// Execute a law output using an existing shared trace builder.
+    ///
+    /// Unlike `evaluate_law_output_with_trace` which creates its own root trace node,
+    /// this method appends to an existing trace tree. Used by `execute_stage_internal`
+    /// when falling through to non-procedure execution.
+    fn evaluate_law_output_with_shared_trace(
+        &self,
+        law_id: &str,
+        output_name: &str,
+        parameters: BTreeMap<String, Value>,
+        calculation_date: &str,
+        trace: Rc<RefCell<TraceBuilder>>,
+    ) -> Result<ArticleResult> {
+        let mut res_ctx = ResolutionContext::with_trace(calculation_date, trace);
+        res_ctx.contextual_law_id = Some(law_id.to_string());
+        self.evaluate_law_output_internal(law_id, output_name, parameters, &mut res_ctx)
+    }
+
+    /// Execute a single lifecycle stage of a procedure-aware law (RFC-008).
+    ///
+    /// For laws that produce a `legal_character` with an associated AWB procedure,
+    /// this method executes one stage at a time and yields when external input is needed.
+    ///
+    /// # Arguments
+    /// * `law_id` - The law being executed (e.g., "vreemdelingenwet_2000")
+    /// * `output_name` - The output to calculate
+    /// * `state` - Current decision state (None for initial execution)
+    /// * `parameters` - Input parameters (merged with accumulated outputs if resuming)
+    /// * `calculation_date` - Date for calculations
+    ///
+    /// # Returns
+    /// `ExecutionOutcome::Complete` if all stages are done, or
+    /// `ExecutionOutcome::Yielded` if waiting for external input.
+    #[cfg_attr(feature = "otel", tracing::instrument(skip(self, state, parameters), fields(law_id = %law_id, output = %output_name)))]
+    pub fn execute_stage(
+        &self,
+        law_id: &str,
+        output_name: &str,
+        state: Option<StageState>,
+        parameters: BTreeMap<String, Value>,
+        calculation_date: &str,
+    ) -> Result<ExecutionOutcome> {
+        self.execute_stage_internal(
+            law_id,
+            output_name,
             state,
             parameters,
             calculation_date,
@@ -761,6 +1497,9 @@ impl LawExecutionService {
         // mirroring evaluate_law_output_with_trace_builder.
         {
             let mut tb = trace.borrow_mut();
+            for name in &self.trace_redactions {
+                tb.redact(name);
+            }
             tb.push(
                 format!("{} ({}) [stage]", law_id, output_name),
                 PathNodeType::Article,
@@ -789,7 +1528,7 @@ impl LawExecutionService {
             Err(e) => {
                 let mut tb = trace.borrow_mut();
                 tb.set_message(format!("Stage execution failed: {}", e));
-                let partial_trace = tb.pop();
+                let partial_trace = tb.pop().or_else(|| tb.materialize_failure_trace());
                 Err(EngineError::TracedError {
                     source: Box::new(e),
                     trace: partial_trace.map(Box::new),
@@ -808,12 +1547,10 @@ impl LawExecutionService {
         calculation_date: &str,
         trace: Option<Rc<RefCell<TraceBuilder>>>,
     ) -> Result<ExecutionOutcome> {
+        let calculation_date = &*request_date(&parameters, calculation_date)?;
         // Look up the law and article
         let ref_date = NaiveDate::parse_from_str(calculation_date, "%Y-%m-%d").ok();
-        let law = self
-            .resolver
-            .get_law_for_date(law_id, ref_date)
-            .ok_or_else(|| EngineError::LawNotFound(law_id.to_string()))?;
+        let law = self.resolver.require_law_for_date(law_id, ref_date)?;
         let article = self
             .resolver
             .get_article_by_output(law_id, output_name, ref_date)
@@ -977,10 +1714,100 @@ impl LawExecutionService {
 
     /// Internal method for multi-output evaluation.
     ///
+    /// Every top-level evaluation goes through here (or through
+    /// [`evaluate_law_parallel`](Self::evaluate_law_parallel)), so this is
+    /// where the audit record is emitted.
+    fn evaluate_law_multi_internal(
+        &self,
+        law_id: &str,
+        output_names: &[&str],
+        parameters: BTreeMap<String, Value>,
+        res_ctx: &mut ResolutionContext<'_>,
+    ) -> Result<ArticleResult> {
+        let audit = self.begin_audit(&parameters, res_ctx.value_date, res_ctx.version_date());
+        let result = self.evaluate_law_multi_unaudited(law_id, output_names, parameters, res_ctx);
+        if let Some(audit) = audit {
+            self.finish_audit(
+                audit,
+                law_id,
+                output_names,
+                &result,
+                std::mem::take(&mut res_ctx.law_versions),
+            );
+        }
+        result
+    }
+
+    /// Start auditing a top-level evaluation, or `None` when no audit sink is
+    /// installed. Parameters are redacted as in traces.
+    fn begin_audit(
+        &self,
+        parameters: &BTreeMap<String, Value>,
+        calculation_date: &str,
+        version_date: Option<NaiveDate>,
+    ) -> Option<PendingAudit> {
+        self.audit_sink.as_ref()?;
+        let parameters = parameters
+            .iter()
+            .map(|(name, value)| {
+                let value = if self.trace_redactions.contains(&name.to_lowercase()) {
+                    Value::String(REDACTED.to_string())
+                } else {
+                    value.clone()
+                };
+                (name.clone(), value)
+            })
+            .collect();
+        Some(PendingAudit {
+            started: Instant::now(),
+            calculation_date: calculation_date.to_string(),
+            version_date: version_date.map(format_date),
+            parameters,
+        })
+    }
+
+    /// Hand the audit sink the record of a finished top-level evaluation.
+    fn finish_audit(
+        &self,
+        audit: PendingAudit,
+        law_id: &str,
+        output_names: &[&str],
+        result: &Result<ArticleResult>,
+        law_versions: BTreeMap<String, BTreeSet<String>>,
+    ) {
+        let Some(sink) = &self.audit_sink else {
+            return;
+        };
+        let (outputs, error, regulation_valid_from, regulation_hash) = match result {
+            Ok(r) => (
+                Some(r.outputs.clone()),
+                None,
+                r.regulation_valid_from.clone(),
+                r.regulation_hash.clone(),
+            ),
+            Err(e) => (None, Some(e.to_string()), None, None),
+        };
+        sink.record(&AuditRecord {
+            law_id: law_id.to_string(),
+            requested_outputs: output_names.iter().map(|s| s.to_string()).collect(),
+            parameters: audit.parameters,
+            calculation_date: audit.calculation_date,
+            version_date: audit.version_date,
+            outputs,
+            error,
+            engine_version: crate::VERSION.to_string(),
+            regulation_valid_from,
+            regulation_hash,
+            law_versions,
+            duration_us: u64::try_from(audit.started.elapsed().as_micros()).unwrap_or(u64::MAX),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        });
+    }
+
     /// Groups requested outputs by producing article, executes each article
     /// once, merges results, and filters to only the requested outputs
     /// (privacy-by-design).
-    fn evaluate_law_multi_internal(
+    fn evaluate_law_multi_unaudited(
         &self,
         law_id: &str,
         output_names: &[&str],
@@ -990,76 +1817,231 @@ impl LawExecutionService {
         // Validate that the law exists
         let _law = self
             .resolver
-            .get_law_for_date(law_id, res_ctx.reference_date())
-            .ok_or_else(|| EngineError::LawNotFound(law_id.to_string()))?;
+            .require_law_for_date(law_id, res_ctx.version_date())?;
 
         // Group outputs by their producing article number to avoid redundant evaluations
-        let mut article_to_outputs: BTreeMap<String, Vec<&str>> = BTreeMap::new();
-        for &output_name in output_names {
-            let article = self
-                .resolver
-                .get_article_by_output(law_id, output_name, res_ctx.reference_date())
-                .ok_or_else(|| EngineError::OutputNotFound {
-                    law_id: law_id.to_string(),
-                    output: output_name.to_string(),
-                })?;
-            article_to_outputs
-                .entry(article.number.clone())
-                .or_default()
-                .push(output_name);
-        }
-
-        // Collect the article numbers for the merged result
-        let article_numbers: Vec<&str> = article_to_outputs.keys().map(|s| s.as_str()).collect();
+        let article_to_outputs =
+            self.group_outputs_by_article(law_id, output_names, res_ctx.version_date())?;
 
-        // Execute each unique article once and merge results
-        let mut merged_result: Option<ArticleResult> = None;
+        let request = self.capture_resolution_snapshot.then(|| {
+            res_ctx.capture = Some(Vec::new());
+            parameters.clone()
+        });
 
+        // Execute each unique article once
+        let mut results = Vec::with_capacity(article_to_outputs.len());
         for outputs in article_to_outputs.values() {
             // Use the first output name for the internal call (output_name is used
             // for article lookup and tracing, but all outputs are computed regardless)
             let primary_output = outputs[0];
-            let result = self.evaluate_law_output_internal(
+            results.push(self.evaluate_law_output_internal(
                 law_id,
                 primary_output,
                 parameters.clone(),
                 res_ctx,
-            )?;
+            )?);
+        }
 
-            match &mut merged_result {
-                None => {
-                    merged_result = Some(result);
-                }
-                Some(merged) => {
-                    // Merge outputs, provenance, and resolved_inputs from additional articles
-                    merged.outputs.extend(result.outputs);
-                    merged.output_provenance.extend(result.output_provenance);
-                    merged.resolved_inputs.extend(result.resolved_inputs);
+        let article_numbers: Vec<&str> = article_to_outputs.keys().map(|s| s.as_str()).collect();
+        let mut result = merge_article_results(results, &article_numbers)?;
+        attach_evaluation_summary(
+            &mut result,
+            law_id,
+            output_names,
+            request.zip(res_ctx.capture.take()),
+            res_ctx.value_date,
+            res_ctx.version_date(),
+            res_ctx.call_counts,
+        );
+        Ok(result)
+    }
+
+    /// Evaluate multiple outputs, running mutually independent articles concurrently.
+    ///
+    /// Produces the same result as [`evaluate_law`](Self::evaluate_law). The
+    /// producing articles are partitioned using [`RuleResolver::dependents_of`]:
+    /// articles that read each other's outputs are evaluated in order on one
+    /// thread (sharing its memoization cache), and the independent partitions
+    /// run on separate threads. The call counts and resolution snapshot cover
+    /// all threads; since partitions do not share a cache, a cross-law result
+    /// two partitions need is computed (and counted) once in each.
+    #[cfg(feature = "parallel")]
+    pub fn evaluate_law_parallel(
+        &self,
+        law_id: &str,
+        output_names: &[&str],
+        parameters: BTreeMap<String, Value>,
+        calculation_date: &str,
+    ) -> Result<ArticleResult> {
+        if output_names.is_empty() {
+            return Err(EngineError::InvalidOperation(
+                "output_names must not be empty".to_string(),
+            ));
+        }
+        let calculation_date = request_date(&parameters, calculation_date)?;
+        let version_date = NaiveDate::parse_from_str(&calculation_date, "%Y-%m-%d").ok();
+        let audit = self.begin_audit(&parameters, &calculation_date, version_date);
+        let mut law_versions = BTreeMap::new();
+        let result = self.evaluate_law_parallel_unaudited(
+            law_id,
+            output_names,
+            parameters,
+            &calculation_date,
+            &mut law_versions,
+        );
+        if let Some(audit) = audit {
+            self.finish_audit(audit, law_id, output_names, &result, law_versions);
+        }
+        result
+    }
+
+    /// [`evaluate_law_parallel`](Self::evaluate_law_parallel) without the
+    /// audit record, collecting the law versions used into `law_versions`.
+    #[cfg(feature = "parallel")]
+    fn evaluate_law_parallel_unaudited(
+        &self,
+        law_id: &str,
+        output_names: &[&str],
+        parameters: BTreeMap<String, Value>,
+        calculation_date: &str,
+        law_versions: &mut BTreeMap<String, BTreeSet<String>>,
+    ) -> Result<ArticleResult> {
+        let reference_date = NaiveDate::parse_from_str(calculation_date, "%Y-%m-%d").ok();
+        self.resolver.require_law_for_date(law_id, reference_date)?;
+
+        let article_to_outputs =
+            self.group_outputs_by_article(law_id, output_names, reference_date)?;
+        let groups: Vec<&Vec<&str>> = article_to_outputs.values().collect();
+
+        // Union-find over article groups: a group reading another group's
+        // output must run after it, in the same partition.
+        let mut partition_of: Vec<usize> = (0..groups.len()).collect();
+        fn find(partition_of: &mut [usize], i: usize) -> usize {
+            let mut root = i;
+            while partition_of[root] != root {
+                root = partition_of[root];
+            }
+            partition_of[i] = root;
+            root
+        }
+        for (i, group) in groups.iter().enumerate() {
+            let dependents: HashSet<String> = group
+                .iter()
+                .flat_map(|output| self.resolver.dependents_of(law_id, output, reference_date))
+                .filter(|(dep_law, _)| dep_law == law_id)
+                .map(|(_, dep_output)| dep_output)
+                .collect();
+            for (j, other) in groups.iter().enumerate() {
+                if i != j && other.iter().any(|o| dependents.contains(*o)) {
+                    let (a, b) = (find(&mut partition_of, i), find(&mut partition_of, j));
+                    partition_of[a] = b;
                 }
             }
         }
-
-        let mut result = merged_result.ok_or_else(|| {
-            EngineError::InvalidOperation("output_names must not be empty".to_string())
-        })?;
-
-        // When outputs came from multiple articles, set article_number to the
-        // comma-separated list so callers don't see a misleading single number.
-        if article_numbers.len() > 1 {
-            result.article_number = article_numbers.join(", ");
+        let mut partitions: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for i in 0..groups.len() {
+            let root = find(&mut partition_of, i);
+            partitions.entry(root).or_default().push(i);
         }
 
-        // No output filtering: the engine only executes articles that produce
-        // the requested outputs. All outputs from those articles are returned,
-        // including co-products (multiple outputs from the same article) and
-        // causally-entailed outputs (hooks, overrides). A beschikking is legally
-        // indivisible per AWB 1:3 — its consequences cannot be stripped.
+        let mut results: Vec<Option<Result<ArticleResult>>> =
+            (0..groups.len()).map(|_| None).collect();
+        let mut call_counts = CallCounts::default();
+        let mut captured: Option<Vec<CapturedInput>> = None;
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = partitions
+                .values()
+                .map(|members| {
+                    let groups = &groups;
+                    let parameters = &parameters;
+                    scope.spawn(move || {
+                        let mut res_ctx = ResolutionContext::new(calculation_date);
+                        res_ctx.contextual_law_id = Some(law_id.to_string());
+                        res_ctx.capture = self.capture_resolution_snapshot.then(Vec::new);
+                        let done = members
+                            .iter()
+                            .map(|&i| {
+                                let result = self.evaluate_law_output_internal(
+                                    law_id,
+                                    groups[i][0],
+                                    parameters.clone(),
+                                    &mut res_ctx,
+                                );
+                                (i, result)
+                            })
+                            .collect::<Vec<_>>();
+                        (
+                            done,
+                            res_ctx.law_versions,
+                            res_ctx.call_counts,
+                            res_ctx.capture,
+                        )
+                    })
+                })
+                .collect();
+            for handle in handles {
+                match handle.join() {
+                    Ok((done, versions, counts, capture)) => {
+                        for (i, result) in done {
+                            results[i] = Some(result);
+                        }
+                        for (law, valid_from) in versions {
+                            law_versions.entry(law).or_default().extend(valid_from);
+                        }
+                        call_counts += counts;
+                        if let Some(inputs) = capture {
+                            captured.get_or_insert_with(Vec::new).extend(inputs);
+                        }
+                    }
+                    Err(panic) => std::panic::resume_unwind(panic),
+                }
+            }
+        });
 
+        // Report the first failure in article order, as the sequential path does
+        let results = results
+            .into_iter()
+            .flatten()
+            .collect::<Result<Vec<ArticleResult>>>()?;
+        let article_numbers: Vec<&str> = article_to_outputs.keys().map(|s| s.as_str()).collect();
+        let mut result = merge_article_results(results, &article_numbers)?;
+        attach_evaluation_summary(
+            &mut result,
+            law_id,
+            output_names,
+            captured.map(|inputs| (parameters, inputs)),
+            calculation_date,
+            reference_date,
+            call_counts,
+        );
         Ok(result)
     }
 
+    /// Group requested outputs by the number of the article producing them.
+    fn group_outputs_by_article<'o>(
+        &self,
+        law_id: &str,
+        output_names: &[&'o str],
+        reference_date: Option<NaiveDate>,
+    ) -> Result<BTreeMap<String, Vec<&'o str>>> {
+        let mut article_to_outputs: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+        for &output_name in output_names {
+            let article = self
+                .resolver
+                .get_article_by_output(law_id, output_name, reference_date)
+                .ok_or_else(|| EngineError::OutputNotFound {
+                    law_id: law_id.to_string(),
+                    output: output_name.to_string(),
+                })?;
+            article_to_outputs
+                .entry(article.number.clone())
+                .or_default()
+                .push(output_name);
+        }
+        Ok(article_to_outputs)
+    }
+
     /// Internal method with cycle tracking (single-output).
-    #[cfg_attr(feature = "otel", tracing::instrument(skip(self, parameters, res_ctx), fields(law_id = %law_id, output = %output_name, depth = res_ctx.depth)))]
     fn evaluate_law_output_internal(
         &self,
         law_id: &str,
@@ -1067,13 +2049,35 @@ impl LawExecutionService {
         parameters: BTreeMap<String, Value>,
         res_ctx: &mut ResolutionContext<'_>,
     ) -> Result<ArticleResult> {
-        // --- Cache check (before depth check: cached results don't increase depth) ---
-        let key = cache_key(law_id, output_name, &parameters);
-        if let Some(cached) = res_ctx.cache.get(&key) {
-            // Runtime collision check: hash keys are u64 so collisions are
-            // theoretically possible. For legally binding decisions, we must
-            // never silently return wrong results.
-            if cached.law_id != law_id
+        self.evaluate_law_version_output_internal(law_id, output_name, None, parameters, res_ctx)
+    }
+
+    /// Single-output evaluation of either the version valid on the reference
+    /// date (`version: None`) or the version with exactly the given `valid_from`.
+    #[cfg_attr(feature = "otel", tracing::instrument(skip(self, parameters, res_ctx), fields(law_id = %law_id, output = %output_name, version = ?version, depth = res_ctx.depth)))]
+    fn evaluate_law_version_output_internal(
+        &self,
+        law_id: &str,
+        output_name: &str,
+        version: Option<&str>,
+        parameters: BTreeMap<String, Value>,
+        res_ctx: &mut ResolutionContext<'_>,
+    ) -> Result<ArticleResult> {
+        res_ctx.check_deadline()?;
+
+        // Pinned versions are cached separately from date-selected ones
+        let cache_law_id = match version {
+            Some(v) => format!("{}@{}", law_id, v),
+            None => law_id.to_string(),
+        };
+
+        // --- Cache check (before depth check: cached results don't increase depth) ---
+        let key = cache_key(&cache_law_id, output_name, &parameters);
+        if let Some(cached) = res_ctx.cache.get(&key) {
+            // Runtime collision check: hash keys are u64 so collisions are
+            // theoretically possible. For legally binding decisions, we must
+            // never silently return wrong results.
+            if cached.law_id != cache_law_id
                 || cached.output_name != output_name
                 || cached.parameters != parameters
             {
@@ -1087,6 +2091,7 @@ impl LawExecutionService {
                 // Fall through to re-evaluate
             } else {
                 tracing::debug!(law_id, output_name, "Cache hit");
+                res_ctx.call_counts.cache_hits += 1;
                 let _guard = res_ctx
                     .trace_guard(format!("{}#{}", law_id, output_name), PathNodeType::Cached);
                 if let Some(val) = cached.outputs.get(output_name) {
@@ -1104,6 +2109,13 @@ impl LawExecutionService {
                     schema_version: None,
                     regulation_hash: None,
                     regulation_valid_from: None,
+                    operations_evaluated: 0,
+                    short_circuits: 0,
+                    indicative_outputs: cached.indicative_outputs.clone(),
+                    warnings: Vec::new(),
+                    resolution_snapshot: None,
+                    call_counts: CallCounts::default(),
+                    timings: BTreeMap::new(),
                 });
             }
         }
@@ -1142,20 +2154,32 @@ impl LawExecutionService {
             )));
         }
 
-        // Get the law (version-aware: use the same reference date as the article lookup)
-        let law = self
-            .resolver
-            .get_law_for_date(law_id, res_ctx.reference_date())
-            .ok_or_else(|| EngineError::LawNotFound(law_id.to_string()))?;
-
-        // Find the article
-        let article = self
-            .resolver
-            .get_article_by_output(law_id, output_name, res_ctx.reference_date())
-            .ok_or_else(|| EngineError::OutputNotFound {
-                law_id: law_id.to_string(),
-                output: output_name.to_string(),
-            })?;
+        // Get the law and the article producing the output. A pinned version is
+        // looked up by its valid_from; otherwise the version valid on the
+        // reference date is used for both lookups.
+        let (law, article) = match version {
+            Some(v) => {
+                let law = self.resolver.get_law_version(law_id, v).ok_or_else(|| {
+                    EngineError::LawNotFound(format!("{} (version {})", law_id, v))
+                })?;
+                (law, law.find_article_by_output(output_name))
+            }
+            None => {
+                let law = self
+                    .resolver
+                    .require_law_for_date(law_id, res_ctx.version_date())?;
+                let article = self.resolver.get_article_by_output(
+                    law_id,
+                    output_name,
+                    res_ctx.version_date(),
+                );
+                (law, article)
+            }
+        };
+        let article = article.ok_or_else(|| EngineError::OutputNotFound {
+            law_id: law_id.to_string(),
+            output: output_name.to_string(),
+        })?;
 
         // Clone parameters for cache storage before moving into evaluation
         let params_for_cache = parameters.clone();
@@ -1178,10 +2202,11 @@ impl LawExecutionService {
         res_ctx.cache.insert(
             key,
             CacheEntry {
-                law_id: law_id.to_string(),
+                law_id: cache_law_id,
                 output_name: output_name.to_string(),
                 outputs: result.outputs.clone(),
                 output_provenance: result.output_provenance.clone(),
+                indicative_outputs: result.indicative_outputs.clone(),
                 parameters: params_for_cache,
             },
         );
@@ -1259,7 +2284,7 @@ impl LawExecutionService {
             }
 
             // Look up the hook article
-            let ref_date = res_ctx.reference_date();
+            let ref_date = res_ctx.version_date();
             let Some(hook_law) = self.resolver.get_law_for_date(hook_law_id, ref_date) else {
                 tracing::warn!(hook_law_id = %hook_law_id, "Hook law not found");
                 continue;
@@ -1474,7 +2499,7 @@ impl LawExecutionService {
             }
 
             // Look up overriding article
-            let ref_date = res_ctx.reference_date();
+            let ref_date = res_ctx.version_date();
             let Some(ovr_law) = self.resolver.get_law_for_date(ovr_law_id, ref_date) else {
                 continue;
             };
@@ -1517,6 +2542,11 @@ impl LawExecutionService {
                     "Override applied"
                 );
                 result.outputs.insert(output_name.clone(), value.clone());
+                if ovr_output.is_indicative(&output_name) {
+                    result.indicative_outputs.insert(output_name.clone());
+                } else {
+                    result.indicative_outputs.remove(&output_name);
+                }
                 result.output_provenance.insert(
                     output_name.clone(),
                     OutputProvenance::Override {
@@ -1531,6 +2561,33 @@ impl LawExecutionService {
         Ok(())
     }
 
+    /// Build an article's execution context: parameters, definitions and
+    /// input sources resolved through this service.
+    fn prepare_article_context(
+        &self,
+        article: &Article,
+        law: &ArticleBasedLaw,
+        parameters: &BTreeMap<String, Value>,
+        res_ctx: &mut ResolutionContext<'_>,
+    ) -> Result<RuleContext> {
+        let mut context = RuleContext::new(parameters.clone(), res_ctx.value_date)?;
+        context.set_options(self.evaluation_options);
+
+        // Attach trace builder if available
+        if let Some(ref tb) = res_ctx.trace {
+            context.set_trace(Rc::clone(tb));
+        }
+
+        // Set definitions from article
+        context.set_definition_values(article.definition_values());
+        context.set_formulas(law.formulas_for(article));
+
+        // Resolve inputs with sources using ServiceProvider
+        self.resolve_inputs_with_service(article, law, &mut context, parameters, res_ctx)?;
+
+        Ok(context)
+    }
+
     /// Execute an article with ServiceProvider support.
     ///
     /// The `stage` parameter controls which lifecycle stage hooks fire at.
@@ -1544,6 +2601,8 @@ impl LawExecutionService {
         stage: &str,
         res_ctx: &mut ResolutionContext<'_>,
     ) -> Result<ArticleResult> {
+        res_ctx.record_law_version(law);
+
         // RFC-012: Check for untranslatable constructs before execution
         let taints = if let Some(untranslatables) = article
             .machine_readable
@@ -1560,27 +2619,16 @@ impl LawExecutionService {
         };
 
         // Create execution context — pass parameters by reference, only clone
-        // into combined_params below when we need ownership.
-        let mut context = RuleContext::new(parameters.clone(), res_ctx.calculation_date)?;
-
-        // Attach trace builder if available
-        if let Some(ref tb) = res_ctx.trace {
-            context.set_trace(Rc::clone(tb));
-        }
-
-        // Set definitions from article
-        if let Some(definitions) = article.get_definitions() {
-            context.set_definitions(definitions);
-        }
-
-        // Resolve inputs with sources using ServiceProvider
-        self.resolve_inputs_with_service(article, law, &mut context, &parameters, res_ctx)?;
+        // into combined_params below when we need ownership. Warnings raised
+        // while resolving this article's inputs are attached to its result.
+        let warnings_before = res_ctx.warnings.len();
+        let context = self.prepare_article_context(article, law, &parameters, res_ctx)?;
+        let input_warnings = res_ctx.warnings.split_off(warnings_before);
 
         // Resolve open terms via IoC (implements index lookup)
-        let open_term_values = self.resolve_open_terms(article, law, &context, res_ctx)?;
-
-        // Use ArticleEngine for action execution (it handles the internal logic)
-        let engine = ArticleEngine::new(article, law);
+        let mut open_term_warnings = Vec::new();
+        let open_term_values =
+            self.resolve_open_terms(article, law, &context, res_ctx, &mut open_term_warnings)?;
 
         // Build combined_params: start with owned parameters, merge in resolved data.
         let mut combined_params = parameters;
@@ -1608,21 +2656,30 @@ impl LawExecutionService {
         // Clone for post-hook params before moving combined_params into the engine.
         let mut post_params = combined_params.clone();
 
+        // Use ArticleEngine for action execution (it handles the internal logic).
+        // URI_CALL operations are resolved through this service within the
+        // current resolution context, so cycle detection and tracing still apply.
+        let calculation_date = res_ctx.value_date;
+        let trace = res_ctx.trace.clone();
+        let scoped = ScopedServiceProvider {
+            service: self,
+            law_id: &law.id,
+            res_ctx: RefCell::new(&mut *res_ctx),
+        };
+        let engine = ArticleEngine::new(article, law)
+            .with_options(self.evaluation_options)
+            .with_service(&scoped)
+            .with_operations(Arc::clone(&self.operations));
+
         // Use traced evaluation if trace is available
-        let mut result = if let Some(ref tb) = res_ctx.trace {
-            engine.evaluate_with_trace(
-                combined_params,
-                res_ctx.calculation_date,
-                requested_output,
-                Rc::clone(tb),
-            )?
+        let mut result = if let Some(tb) = trace {
+            engine.evaluate_with_trace(combined_params, calculation_date, requested_output, tb)?
         } else {
-            engine.evaluate_with_output(
-                combined_params,
-                res_ctx.calculation_date,
-                requested_output,
-            )?
+            engine.evaluate_with_output(combined_params, calculation_date, requested_output)?
         };
+        result
+            .warnings
+            .splice(0..0, input_warnings.into_iter().chain(open_term_warnings));
 
         // Fire post_actions hooks (between action execution and result return).
         // Post-hooks receive both parameters and article outputs.
@@ -1728,6 +2785,7 @@ impl LawExecutionService {
         law: &ArticleBasedLaw,
         context: &RuleContext,
         res_ctx: &mut ResolutionContext<'_>,
+        warnings: &mut Vec<Warning>,
     ) -> Result<BTreeMap<String, Value>> {
         let mut resolved = BTreeMap::new();
 
@@ -1781,9 +2839,16 @@ impl LawExecutionService {
                 &law.id,
                 &article.number,
                 &term.id,
-                res_ctx.reference_date(),
+                res_ctx.version_date(),
                 &scope,
             ) {
+                Ok(_) if self.simulate_delegation_absence => {
+                    tracing::debug!(
+                        open_term = %term.id,
+                        "Simulating delegation absence, ignoring implementations"
+                    );
+                    Vec::new()
+                }
                 Ok(impls) => impls,
                 Err(e) => {
                     res_ctx.trace_set_message(format!(
@@ -1820,6 +2885,11 @@ impl LawExecutionService {
                     "Found implementation for open term"
                 );
 
+                if let Err(e) = res_ctx.record_delegation_call(self.max_cross_law_calls) {
+                    res_ctx.leave(&ot_key);
+                    return Err(e);
+                }
+
                 // Execute the implementing article to get the value.
                 // Only forward parameters that the implementing article declares
                 // in its execution.parameters — principle of least privilege.
@@ -1847,8 +2917,9 @@ impl LawExecutionService {
                 if let Some(value) = result.outputs.get(&term.id) {
                     res_ctx.trace_set_result(value.clone());
                     res_ctx.trace_set_message(format!(
-                        "Open term '{}' implemented by {} article {}",
-                        term.id, impl_law.id, impl_article.number
+                        "Open term '{}' implemented by {}",
+                        term.id,
+                        describe_implementation(impl_law, impl_article)
                     ));
                     resolved.insert(term.id.clone(), value.clone());
                 } else {
@@ -1869,6 +2940,14 @@ impl LawExecutionService {
                     open_term = %term.id,
                     "No implementation found, using default"
                 );
+                warnings.push(Warning {
+                    kind: WarningKind::DefaultedInput,
+                    subject: term.id.clone(),
+                    message: format!(
+                        "Open term '{}' on {}#{} has no implementation; using its default",
+                        term.id, law.id, article.number
+                    ),
+                });
 
                 if let Some(ref actions) = default.actions {
                     // Build a synthetic article from the default actions and evaluate
@@ -1880,6 +2959,7 @@ impl LawExecutionService {
                         url: None,
                         machine_readable: Some(MachineReadable {
                             definitions: None,
+                            formulas: None,
                             execution: Some(Execution {
                                 produces: None,
                                 parameters: None,
@@ -1894,10 +2974,14 @@ impl LawExecutionService {
                             hooks: None,
                             overrides: None,
                             untranslatables: None,
+                            classifications: None,
                         }),
+                        definition_cache: Default::default(),
                     };
 
-                    let engine = ArticleEngine::new(&synthetic_article, law);
+                    let engine = ArticleEngine::new(&synthetic_article, law)
+                        .with_options(self.evaluation_options)
+                        .with_operations(Arc::clone(&self.operations));
 
                     // Pass current context parameters so default actions can
                     // reference variables like $type_beplanting
@@ -1909,7 +2993,7 @@ impl LawExecutionService {
 
                     let default_result = match engine.evaluate_with_output(
                         default_params,
-                        res_ctx.calculation_date,
+                        res_ctx.value_date,
                         Some(&term.id),
                     ) {
                         Ok(r) => r,
@@ -1996,29 +3080,52 @@ impl LawExecutionService {
                 continue;
             }
 
-            // Check DataSourceRegistry before cross-law resolution
-            if self.data_registry.source_count() > 0 {
-                if let Some(data_match) = self.data_registry.resolve(&input.name, parameters) {
-                    tracing::debug!(
-                        input = %input.name,
-                        source = %data_match.source_name,
-                        "Resolved input from data registry"
-                    );
+            res_ctx.check_deadline()?;
+
+            // Check DataSourceRegistry before cross-law resolution; when
+            // replaying a snapshot, its captured values stand in for the registry
+            let data_match = match res_ctx.replay {
+                Some(captured) => captured
+                    .iter()
+                    .find(|c| {
+                        c.law_id == law.id && c.input == input.name && &c.parameters == parameters
+                    })
+                    .map(|c| (c.source.clone(), c.value.clone())),
+                None if self.data_registry.source_count() > 0 => self
+                    .data_registry
+                    .resolve(&input.name, parameters)
+                    .map(|m| (m.source_name, m.value)),
+                None => None,
+            };
+            if let Some((source_name, value)) = data_match {
+                tracing::debug!(
+                    input = %input.name,
+                    source = %source_name,
+                    "Resolved input from data registry"
+                );
 
-                    // Trace the data source resolution
-                    {
-                        let _guard = res_ctx.trace_guard(&input.name, PathNodeType::Resolve);
-                        res_ctx.trace_set_resolve_type(ResolveType::DataSource);
-                        res_ctx.trace_set_result(data_match.value.clone());
-                        res_ctx.trace_set_message(format!(
-                            "Resolving from SOURCE {}: {}",
-                            data_match.source_name, data_match.value
-                        ));
-                    }
+                // Trace the data source resolution
+                {
+                    let _guard = res_ctx.trace_guard(&input.name, PathNodeType::Resolve);
+                    res_ctx.trace_set_resolve_type(ResolveType::DataSource);
+                    res_ctx.trace_set_result(value.clone());
+                    res_ctx.trace_set_message(format!(
+                        "Resolving from SOURCE {}: {}",
+                        source_name, value
+                    ));
+                }
 
-                    context.set_resolved_input(&input.name, data_match.value);
-                    continue;
+                if let Some(capture) = &mut res_ctx.capture {
+                    capture.push(CapturedInput {
+                        law_id: law.id.clone(),
+                        input: input.name.clone(),
+                        source: source_name,
+                        parameters: parameters.clone(),
+                        value: value.clone(),
+                    });
                 }
+                context.set_resolved_input(&input.name, value);
+                continue;
             }
 
             // For cross-law resolution, output defaults to input name
@@ -2026,10 +3133,12 @@ impl LawExecutionService {
 
             if let Some(regulation) = &source.regulation {
                 // External reference
+                let regulation = self.resolver.referenced_law_id(regulation, &law.id);
                 let value = self.resolve_external_input_internal(
-                    regulation,
+                    &regulation,
                     output_name,
                     source.parameters.as_ref(),
+                    source.version.as_deref(),
                     context,
                     res_ctx,
                 )?;
@@ -2099,11 +3208,14 @@ impl LawExecutionService {
     }
 
     /// Internal method for external input resolution with depth tracking.
+    ///
+    /// `version` pins the regulation to the version with that `valid_from` date.
     fn resolve_external_input_internal(
         &self,
         regulation: &str,
         output: &str,
         source_parameters: Option<&BTreeMap<String, String>>,
+        version: Option<&str>,
         context: &RuleContext,
         res_ctx: &mut ResolutionContext<'_>,
     ) -> Result<Value> {
@@ -2116,6 +3228,8 @@ impl LawExecutionService {
             )));
         }
 
+        res_ctx.record_uri_call(self.max_cross_law_calls)?;
+
         // Trace cross-law call (guard auto-pops on all exit paths)
         let _guard = res_ctx.trace_guard(
             format!("{}#{}", regulation, output),
@@ -2135,7 +3249,13 @@ impl LawExecutionService {
         res_ctx.enter(key.clone());
 
         // Execute the target article
-        let result = self.evaluate_law_output_internal(regulation, output, target_params, res_ctx);
+        let result = self.evaluate_law_version_output_internal(
+            regulation,
+            output,
+            version,
+            target_params,
+            res_ctx,
+        );
 
         // Leave scope (even on error, for correct cycle tracking)
         res_ctx.leave(&key);
@@ -2148,12 +3268,17 @@ impl LawExecutionService {
                         "Output '{}' not found in result from {}",
                         output, regulation
                     ));
-                    return Err(EngineError::OutputNotFound {
-                        law_id: regulation.to_string(),
-                        output: output.to_string(),
-                    });
+                    return self.missing_cross_law_output(regulation, output, res_ctx);
                 }
             },
+            Err(EngineError::OutputNotFound {
+                law_id,
+                output: missing,
+            }) if law_id == regulation && missing == output => {
+                res_ctx
+                    .trace_set_message(format!("Output '{}' not found in {}", output, regulation));
+                return self.missing_cross_law_output(regulation, output, res_ctx);
+            }
             Err(e) => {
                 res_ctx.trace_set_message(format!("Execution failed: {}", e));
                 return Err(e);
@@ -2166,6 +3291,35 @@ impl LawExecutionService {
         Ok(value)
     }
 
+    /// Handle a referenced law that does not produce `output`: fail with
+    /// `OutputNotFound`, or resolve to null with a warning, depending on
+    /// [`EvaluationOptions::missing_cross_law_output`].
+    fn missing_cross_law_output(
+        &self,
+        regulation: &str,
+        output: &str,
+        res_ctx: &mut ResolutionContext<'_>,
+    ) -> Result<Value> {
+        match self.evaluation_options.missing_cross_law_output {
+            MissingCrossLawOutput::Error => Err(EngineError::OutputNotFound {
+                law_id: regulation.to_string(),
+                output: output.to_string(),
+            }),
+            MissingCrossLawOutput::Warn => {
+                res_ctx.warnings.push(Warning {
+                    kind: WarningKind::MissingCrossLawOutput,
+                    subject: format!("{}#{}", regulation, output),
+                    message: format!(
+                        "Law '{}' does not produce output '{}'; resolved as null",
+                        regulation, output
+                    ),
+                });
+                res_ctx.trace_set_result(Value::Null);
+                Ok(Value::Null)
+            }
+        }
+    }
+
     /// Filter execution parameters to only those declared by the target article.
     ///
     /// When resolving open terms, we don't want to forward all parameters from
@@ -2231,11 +3385,106 @@ impl LawExecutionService {
         self.resolver.has_law(law_id)
     }
 
-    /// Unload a law.
+    /// Build the definition values of every article of every loaded law
+    /// version ahead of time.
+    ///
+    /// These are otherwise built on an article's first evaluation (see
+    /// [`Article::definition_values`]); calling this after loading keeps that
+    /// work out of the first request. Actions and inputs are already parsed
+    /// when a law is loaded, so there is nothing else to prepare. Results are
+    /// unaffected. Returns the number of articles whose definition values were
+    /// built by this call, so a second call returns 0.
+    pub fn warm_up(&self) -> usize {
+        let mut built = 0;
+        for article in self
+            .resolver
+            .all_law_versions()
+            .flat_map(|law| &law.articles)
+        {
+            if !article.has_definition_values() {
+                article.definition_values();
+                built += 1;
+            }
+        }
+        tracing::debug!(built, "Warmed up loaded laws");
+        built
+    }
+
+    /// Load every law in a directory and run the corpus integrity checks, as a
+    /// single startup call.
+    ///
+    /// Laws are loaded as by [`RuleResolver::load_from_directory`], on top of
+    /// any already loaded. The checks cover the most recent version of each
+    /// law: legal bases, cycles between outputs, unresolvable input references
+    /// and override targets. Issues are reported, not raised, so a service can
+    /// start and expose them.
+    ///
+    /// # Errors
+    /// Returns the errors of `load_from_directory`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_and_validate_directory(&mut self, path: &std::path::Path) -> Result<StartupReport> {
+        let loaded = self.resolver.load_from_directory(path)?;
+
+        let mut missing_references: Vec<MissingRef> = Vec::new();
+        for (law_id, output) in self.resolver.list_all_outputs() {
+            for missing in self.resolver.missing_references(law_id, output, None) {
+                if !missing_references.contains(&missing) {
+                    missing_references.push(missing);
+                }
+            }
+        }
+
+        let report = StartupReport {
+            loaded,
+            law_count: self.resolver.law_count(),
+            broken_legal_bases: self.resolver.check_legal_basis_integrity(),
+            cycles: self.resolver.find_cycles(None),
+            missing_references,
+            invalid_overrides: self.resolver.validate_override_targets(),
+        };
+        tracing::info!(
+            loaded = report.loaded,
+            healthy = report.is_healthy(),
+            "Loaded and validated law directory"
+        );
+        Ok(report)
+    }
+
+    /// Unload a law, all versions.
     pub fn unload_law(&mut self, law_id: &str) -> bool {
         self.resolver.unload_law(law_id)
     }
 
+    /// Unload all versions of a law, returning how many were removed.
+    pub fn unload_all_versions(&mut self, law_id: &str) -> usize {
+        self.resolver.unload_all_versions(law_id)
+    }
+
+    /// Values of the definitions of an article, in the law version valid on
+    /// `calculation_date`.
+    ///
+    /// Definitions are constants, so this needs no parameters; tooling uses
+    /// it to show what each definition resolves to.
+    pub fn article_definitions(
+        &self,
+        law_id: &str,
+        article_number: &str,
+        calculation_date: &str,
+    ) -> Result<BTreeMap<String, Value>> {
+        let reference_date = NaiveDate::parse_from_str(calculation_date, "%Y-%m-%d")
+            .map_err(|e| EngineError::InvalidDate(format!("{}: {}", calculation_date, e)))?;
+        let law = self
+            .resolver
+            .require_law_for_date(law_id, Some(reference_date))?;
+        let article = law.find_article_by_number(article_number).ok_or_else(|| {
+            EngineError::ArticleNotFound {
+                law_id: law_id.to_string(),
+                article: article_number.to_string(),
+            }
+        })?;
+        Ok(article.definition_values().as_ref().clone())
+    }
+
     /// Get direct access to the resolver.
     pub fn resolver(&self) -> &RuleResolver {
         &self.resolver
@@ -2247,10 +3496,31 @@ impl LawExecutionService {
     /// * `law_id` - The law identifier
     ///
     /// # Returns
-    /// `LawInfo` with metadata, or `None` if the law is not loaded.
+    /// `LawInfo` with metadata (of the most recent version), or `None` if the law
+    /// is not loaded.
     pub fn get_law_info(&self, law_id: &str) -> Option<LawInfo> {
-        let law = self.resolver.get_law(law_id)?;
+        self.resolver.get_law(law_id).map(Self::law_info)
+    }
+
+    /// Get metadata about every loaded law version.
+    ///
+    /// Sorted by law ID, then newest `valid_from` first. Intended for
+    /// diagnostics, e.g. showing operators what an embedding server has loaded.
+    pub fn list_law_info(&self) -> Vec<LawInfo> {
+        let mut infos: Vec<LawInfo> = self
+            .resolver
+            .all_law_versions()
+            .map(Self::law_info)
+            .collect();
+        infos.sort_by(|a, b| {
+            a.id.cmp(&b.id)
+                .then_with(|| b.valid_from.cmp(&a.valid_from))
+        });
+        infos
+    }
 
+    /// Build the metadata summary for a single law version.
+    fn law_info(law: &ArticleBasedLaw) -> LawInfo {
         // Collect output names from all articles
         let mut outputs = Vec::new();
         for article in &law.articles {
@@ -2263,15 +3533,23 @@ impl LawExecutionService {
             }
         }
 
-        Some(LawInfo {
+        LawInfo {
             id: law.id.clone(),
             regulatory_layer: law.regulatory_layer,
             publication_date: law.publication_date.clone(),
+            valid_from: law.valid_from.clone(),
             bwb_id: law.bwb_id.clone(),
             url: law.url.clone(),
+            authority: law
+                .authority
+                .clone()
+                .or_else(|| match &law.competent_authority {
+                    Some(CompetentAuthority::Structured { name }) => Some(name.clone()),
+                    _ => None,
+                }),
             outputs,
             article_count: law.articles.len(),
-        })
+        }
     }
 
     /// List all (law_id, output_name) pairs across all loaded laws.
@@ -2384,6 +3662,12 @@ impl ServiceProvider for LawExecutionService {
         self.resolver.get_law(law_id)
     }
 
+    fn applicable_version(&self, law_id: &str, calculation_date: &str) -> Result<Option<String>> {
+        let reference_date = NaiveDate::parse_from_str(calculation_date, "%Y-%m-%d").ok();
+        let law = self.resolver.require_law_for_date(law_id, reference_date)?;
+        Ok(law.valid_from.clone())
+    }
+
     #[cfg_attr(feature = "otel", tracing::instrument(skip(self, source_parameters, context), fields(regulation = %regulation, output = %output)))]
     fn resolve_external_input(
         &self,
@@ -2398,15 +3682,98 @@ impl ServiceProvider for LawExecutionService {
             regulation,
             output,
             source_parameters,
+            None,
             context,
             &mut res_ctx,
         )
     }
 }
 
+/// Describe the article implementing an open term for trace output:
+/// regulation, article and, when known, the version's `valid_from` and BWB id.
+fn describe_implementation(law: &ArticleBasedLaw, article: &Article) -> String {
+    let mut details = Vec::new();
+    if let Some(valid_from) = &law.valid_from {
+        details.push(format!("valid from {}", valid_from));
+    }
+    if let Some(bwb_id) = &law.bwb_id {
+        details.push(format!("bwb_id {}", bwb_id));
+    }
+
+    let mut description = format!("{} article {}", law.id, article.number);
+    if !details.is_empty() {
+        description.push_str(&format!(" ({})", details.join(", ")));
+    }
+    description
+}
+
+/// Attach what a whole evaluation gathered to its merged result: the
+/// cross-law call counts and, when capturing, the resolution snapshot of the
+/// request and its captured inputs.
+///
+/// Shared by the sequential and parallel paths, so their results carry the same.
+fn attach_evaluation_summary(
+    result: &mut ArticleResult,
+    law_id: &str,
+    output_names: &[&str],
+    capture: Option<(BTreeMap<String, Value>, Vec<CapturedInput>)>,
+    calculation_date: &str,
+    version_date: Option<NaiveDate>,
+    call_counts: CallCounts,
+) {
+    result.call_counts = call_counts;
+    if let Some((parameters, inputs)) = capture {
+        result.resolution_snapshot = Some(ResolutionSnapshot {
+            law_id: law_id.to_string(),
+            outputs: output_names.iter().map(|s| s.to_string()).collect(),
+            parameters,
+            calculation_date: calculation_date.to_string(),
+            version_date: version_date.map(format_date),
+            inputs,
+        });
+    }
+}
+
+/// Merge the results of the articles producing a multi-output request.
+///
+/// Outputs, provenance and resolved inputs of later articles are added to the
+/// first result. No output filtering is applied: the engine only executes
+/// articles that produce the requested outputs, and all outputs from those
+/// articles are returned, including co-products (multiple outputs from the
+/// same article) and causally-entailed outputs (hooks, overrides). A
+/// beschikking is legally indivisible per AWB 1:3 — its consequences cannot
+/// be stripped.
+fn merge_article_results(
+    results: Vec<ArticleResult>,
+    article_numbers: &[&str],
+) -> Result<ArticleResult> {
+    let mut results = results.into_iter();
+    let mut merged = results.next().ok_or_else(|| {
+        EngineError::InvalidOperation("output_names must not be empty".to_string())
+    })?;
+    for result in results {
+        merged.outputs.extend(result.outputs);
+        merged.output_provenance.extend(result.output_provenance);
+        merged.indicative_outputs.extend(result.indicative_outputs);
+        merged.warnings.extend(result.warnings);
+        merged.resolved_inputs.extend(result.resolved_inputs);
+        merged.timings.extend(result.timings);
+    }
+
+    // When outputs came from multiple articles, set article_number to the
+    // comma-separated list so callers don't see a misleading single number.
+    if article_numbers.len() > 1 {
+        merged.article_number = article_numbers.join(", ");
+    }
+
+    Ok(merged)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::KeyCase;
+    use crate::resolver::MissingRefKind;
 
     fn make_base_law() -> &'static str {
         r#"
@@ -2458,291 +3825,1726 @@ articles:
 "#
     }
 
-    // -------------------------------------------------------------------------
-    // Basic Tests
-    // -------------------------------------------------------------------------
-
     #[test]
-    fn test_service_basic() {
+    fn test_set_money_scale_rejects_scale_above_maximum() {
         let mut service = LawExecutionService::new();
-        service.load_law(make_base_law()).unwrap();
-
-        assert!(service.has_law("base_law"));
-        assert_eq!(service.law_count(), 1);
-
-        let laws = service.list_laws();
-        assert_eq!(laws, vec!["base_law"]);
+        service.set_money_scale(0).unwrap();
+        let err = service
+            .set_money_scale(regelrecht_shared::MAX_MONEY_SCALE + 1)
+            .unwrap_err();
+        assert!(matches!(err, EngineError::InvalidOperation(_)));
+        assert_eq!(service.evaluation_options.money_scale, 0);
     }
 
     #[test]
-    fn test_service_execute_simple() {
+    fn test_strict_operation_fields_rejects_stray_cases() {
+        let yaml = r#"
+$id: stray_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Adult check
+    machine_readable:
+      execution:
+        parameters:
+          - name: age
+            type: number
+        output:
+          - name: is_adult
+            type: boolean
+        actions:
+          - output: is_adult
+            value:
+              operation: EQUALS
+              subject: $age
+              value: 18
+              cases:
+                - when: true
+                  then: false
+"#;
+        // Lenient by default
         let mut service = LawExecutionService::new();
-        service.load_law(make_base_law()).unwrap();
-
-        let result = service
-            .evaluate_law_output("base_law", "base_value", BTreeMap::new(), "2025-01-01")
-            .unwrap();
+        assert!(service.load_law(yaml).is_ok());
 
-        assert_eq!(result.outputs.get("base_value"), Some(&Value::Int(100)));
+        let mut service = LawExecutionService::new();
+        service.set_strict_operation_fields(true);
+        let err = service.load_law(yaml).unwrap_err();
+        assert!(matches!(err, EngineError::LoadError(ref msg) if msg.contains("EQUALS.cases")));
+        assert!(!service.has_law("stray_law"));
+
+        // Directory loads apply the same check
+        let dir =
+            std::env::temp_dir().join(format!("regelrecht_strict_fields_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("stray_law.yaml"), yaml).unwrap();
+        let report = service.load_and_validate_directory(&dir);
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(report.unwrap().loaded, 0);
+        assert!(!service.has_law("stray_law"));
     }
 
-    // -------------------------------------------------------------------------
-    // Cross-Law Resolution Tests
-    // -------------------------------------------------------------------------
-
     #[test]
-    fn test_service_cross_law_resolution() {
+    fn test_resolve_variable_without_running_actions() {
         let mut service = LawExecutionService::new();
-        service.load_law(make_base_law()).unwrap();
-        service.load_law(make_dependent_law()).unwrap();
-
-        // Execute dependent law - should automatically resolve base_law reference
-        let result = service
-            .evaluate_law_output(
-                "dependent_law",
-                "doubled_value",
-                BTreeMap::new(),
-                "2025-01-01",
+        service
+            .load_law(
+                r#"
+$id: inkomen_wet
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Toetsingsinkomen
+    machine_readable:
+      execution:
+        parameters:
+          - name: bsn
+            type: string
+        output:
+          - name: toetsingsinkomen
+            type: amount
+        actions:
+          - output: toetsingsinkomen
+            value: 3000000
+"#,
             )
             .unwrap();
-
-        // doubled_value = base_value (100) * 2 = 200
-        assert_eq!(result.outputs.get("doubled_value"), Some(&Value::Int(200)));
+        service
+            .load_law(
+                r#"
+$id: toeslag_wet
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '2'
+    text: Toeslag
+    machine_readable:
+      definitions:
+        DREMPELINKOMEN:
+          value: 2500000
+      execution:
+        parameters:
+          - name: bsn
+            type: string
+        input:
+          - name: toetsingsinkomen
+            type: amount
+            source:
+              regulation: inkomen_wet
+              output: toetsingsinkomen
+              parameters:
+                bsn: $bsn
+        output:
+          - name: toeslag
+            type: amount
+        actions:
+          - output: toeslag
+            operation: DIVIDE
+            values:
+              - $toetsingsinkomen
+              - 0
+"#,
+            )
+            .unwrap();
+        let params = || BTreeMap::from([("bsn".to_string(), Value::from("999993653"))]);
+        let resolve =
+            |name: &str| service.resolve_variable("toeslag_wet", "2", name, params(), "2025-06-01");
+
+        assert_eq!(resolve("DREMPELINKOMEN").unwrap(), Value::Int(2500000));
+        assert_eq!(resolve("$toetsingsinkomen").unwrap(), Value::Int(3000000));
+        assert!(matches!(
+            resolve("toeslag"),
+            Err(EngineError::VariableNotFound(_))
+        ));
+        assert!(matches!(
+            service.resolve_variable("toeslag_wet", "9", "bsn", params(), "2025-06-01"),
+            Err(EngineError::ArticleNotFound { .. })
+        ));
     }
 
     #[test]
-    fn test_service_missing_dependency() {
+    fn test_resolve_variable_uses_article_execution_context() {
         let mut service = LawExecutionService::new();
-        // Only load dependent law, not base law
-        service.load_law(make_dependent_law()).unwrap();
-
-        let result = service.evaluate_law_output(
-            "dependent_law",
-            "doubled_value",
-            BTreeMap::new(),
-            "2025-01-01",
-        );
+        service
+            .load_law(
+                r#"
+$id: huur_regeling
+regulatory_layer: MINISTERIELE_REGELING
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Huur
+    machine_readable:
+      execution:
+        parameters:
+          - name: huur
+            type: amount
+        output:
+          - name: huur_in_cent
+            type: amount
+        actions:
+          - output: huur_in_cent
+            value: $huur
+"#,
+            )
+            .unwrap();
+        service.set_clock(Box::new(crate::clock::FixedClock(
+            NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
+        )));
+        let params = BTreeMap::from([("huur".to_string(), Value::from("21,12"))]);
+        let resolve = |name: &str| {
+            service.resolve_variable_at("huur_regeling", "1", name, params.clone(), None)
+        };
 
-        assert!(
-            matches!(result, Err(EngineError::LawNotFound(_))),
-            "Expected LawNotFound error, got: {:?}",
-            result
+        assert_eq!(resolve("huur").unwrap(), Value::Int(2112));
+        assert_eq!(
+            resolve("regulatory_layer").unwrap(),
+            Value::from("MINISTERIELE_REGELING")
         );
+        assert_eq!(resolve("referencedate.year").unwrap(), Value::Int(2025));
     }
 
-    // -------------------------------------------------------------------------
-    // URI Resolution Tests
-    // -------------------------------------------------------------------------
-
     #[test]
-    fn test_service_uri_resolution() {
+    fn test_failure_trace_only_materialized_on_error() {
         let mut service = LawExecutionService::new();
-        service.load_law(make_base_law()).unwrap();
+        service
+            .load_law(
+                r#"
+$id: share_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Splits an amount
+    machine_readable:
+      execution:
+        parameters:
+          - name: parts
+            type: number
+        output:
+          - name: share
+            type: number
+        actions:
+          - output: share
+            operation: DIVIDE
+            values:
+              - 1000
+              - $parts
+"#,
+            )
+            .unwrap();
+        let params = |parts: i64| BTreeMap::from([("parts".to_string(), Value::Int(parts))]);
 
+        let trace = Rc::new(RefCell::new(TraceBuilder::failure_only(64)));
         let result = service
-            .evaluate_uri(
-                "regelrecht://base_law/base_value",
-                &BTreeMap::new(),
-                "2025-01-01",
+            .evaluate_law_with_shared_trace_builder(
+                "share_law",
+                &["share"],
+                params(4),
+                "2025-06-01",
+                Rc::clone(&trace),
             )
             .unwrap();
-
-        assert_eq!(result.outputs.get("base_value"), Some(&Value::Int(100)));
+        assert!(result.trace.is_none());
+        assert_eq!(trace.borrow().materialized_steps(), 0);
+
+        let trace = Rc::new(RefCell::new(TraceBuilder::failure_only(64)));
+        let err = service
+            .evaluate_law_with_shared_trace_builder(
+                "share_law",
+                &["share"],
+                params(0),
+                "2025-06-01",
+                Rc::clone(&trace),
+            )
+            .unwrap_err();
+        assert!(trace.borrow().materialized_steps() > 0);
+        let EngineError::TracedError { source, trace } = err else {
+            unreachable!("expected a traced error");
+        };
+        assert!(matches!(*source, EngineError::DivisionByZero));
+        let root = trace.unwrap();
+        assert_eq!(root.name, "share_law (share)");
+        assert!(root.render_box_drawing().contains("DIVIDE"));
+
+        // The public entry point behaves the same way.
+        assert!(service
+            .evaluate_law_output_with_failure_trace("share_law", "share", params(4), "2025-06-01")
+            .unwrap()
+            .trace
+            .is_none());
     }
 
     // -------------------------------------------------------------------------
-    // Circular Reference Detection Tests
+    // Basic Tests
     // -------------------------------------------------------------------------
 
     #[test]
-    fn test_service_cross_law_circular_reference() {
-        // Law A references Law B, which references Law A
-        let law_a = r#"
-$id: law_a
+    fn test_dependents_of_base_value() {
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law(make_dependent_law()).unwrap();
+        service
+            .load_law(
+                r#"
+$id: quadrupled_law
 regulatory_layer: WET
 publication_date: '2025-01-01'
 articles:
   - number: '1'
-    text: References law_b
+    text: Uses the doubled value
     machine_readable:
       execution:
         input:
-          - name: from_b
+          - name: doubled
             type: number
             source:
-              regulation: law_b
-              output: output_b
+              regulation: dependent_law
+              output: doubled_value
         output:
-          - name: output_a
+          - name: quadrupled_value
             type: number
         actions:
-          - output: output_a
-            value: $from_b
-"#;
+          - output: quadrupled_value
+            operation: MULTIPLY
+            values:
+              - $doubled
+              - 2
+"#,
+            )
+            .unwrap();
 
-        let law_b = r#"
-$id: law_b
+        let dependents = service
+            .resolver()
+            .dependents_of("base_law", "base_value", None);
+        assert_eq!(
+            dependents,
+            vec![
+                ("dependent_law".to_string(), "doubled_value".to_string()),
+                ("quadrupled_law".to_string(), "quadrupled_value".to_string()),
+            ]
+        );
+
+        assert!(service
+            .resolver()
+            .dependents_of("quadrupled_law", "quadrupled_value", None)
+            .is_empty());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_evaluation_matches_sequential() {
+        let mut service = LawExecutionService::new();
+        service
+            .load_law(
+                r#"
+$id: multi_output_law
 regulatory_layer: WET
 publication_date: '2025-01-01'
 articles:
   - number: '1'
-    text: References law_a
+    text: Doubles the input
+    machine_readable:
+      execution:
+        parameters:
+          - name: x
+            type: number
+        output:
+          - name: doubled
+            type: number
+        actions:
+          - output: doubled
+            operation: MULTIPLY
+            values: [$x, 2]
+  - number: '2'
+    text: Builds on article 1
     machine_readable:
       execution:
         input:
-          - name: from_a
+          - name: doubled_input
             type: number
             source:
-              regulation: law_a
-              output: output_a
+              output: doubled
+              parameters:
+                x: $x
+        parameters:
+          - name: x
+            type: number
         output:
-          - name: output_b
+          - name: quadrupled
             type: number
         actions:
-          - output: output_b
-            value: $from_a
-"#;
-
-        let mut service = LawExecutionService::new();
-        service.load_law(law_a).unwrap();
-        service.load_law(law_b).unwrap();
-
-        let result =
-            service.evaluate_law_output("law_a", "output_a", BTreeMap::new(), "2025-01-01");
-
-        assert!(
-            matches!(result, Err(EngineError::CircularReference(_))),
-            "Expected CircularReference error, got: {:?}",
-            result
-        );
-    }
-
-    // -------------------------------------------------------------------------
-    // Parameter Override Tests
-    // -------------------------------------------------------------------------
-
-    #[test]
-    fn test_service_parameter_override() {
-        // When a parameter is provided that matches an input name,
-        // the parameter value should be used instead of resolving the source
-        let law = r#"
-$id: override_test_law
-regulatory_layer: WET
-publication_date: '2025-01-01'
-articles:
-  - number: '1'
-    text: Has external reference but can be overridden
+          - output: quadrupled
+            operation: MULTIPLY
+            values: [$doubled_input, 2]
+  - number: '3'
+    text: Independent of the others
     machine_readable:
       execution:
         parameters:
-          - name: external_value
+          - name: x
             type: number
         input:
-          - name: external_value
+          - name: bonus
             type: number
-            source:
-              regulation: nonexistent_law
-              output: some_output
+            source: {}
         output:
-          - name: result
+          - name: incremented
             type: number
         actions:
-          - output: result
-            operation: MULTIPLY
-            values:
-              - $external_value
-              - 2
-"#;
-
-        let mut service = LawExecutionService::new();
-        service.load_law(law).unwrap();
-
-        // Provide the value directly - should skip external resolution
-        let mut params = BTreeMap::new();
-        params.insert("external_value".to_string(), Value::Int(50));
+          - output: incremented
+            operation: ADD
+            values: [$x, $bonus]
+"#,
+            )
+            .unwrap();
+        service
+            .register_dict_source(
+                "bonussen",
+                "x",
+                vec![BTreeMap::from([
+                    ("x".to_string(), Value::Int(5)),
+                    ("bonus".to_string(), Value::Int(1)),
+                ])],
+            )
+            .unwrap();
+        service.set_capture_resolution_snapshot(true);
+        let outputs = ["doubled", "quadrupled", "incremented"];
+        let params = BTreeMap::from([("x".to_string(), Value::Int(5))]);
 
-        let result = service
-            .evaluate_law_output("override_test_law", "result", params, "2025-01-01")
+        let sequential = service
+            .evaluate_law("multi_output_law", &outputs, params.clone(), "2025-01-01")
+            .unwrap();
+        let parallel = service
+            .evaluate_law_parallel("multi_output_law", &outputs, params, "2025-01-01")
             .unwrap();
 
-        // result = 50 * 2 = 100
-        assert_eq!(result.outputs.get("result"), Some(&Value::Int(100)));
+        assert_eq!(parallel.outputs, sequential.outputs);
+        assert_eq!(parallel.output_provenance, sequential.output_provenance);
+        assert_eq!(parallel.article_number, sequential.article_number);
+        assert_eq!(parallel.call_counts, sequential.call_counts);
+        assert_eq!(parallel.resolution_snapshot, sequential.resolution_snapshot);
+        assert_eq!(parallel.outputs.get("quadrupled"), Some(&Value::Int(20)));
+        assert_eq!(parallel.outputs.get("incremented"), Some(&Value::Int(6)));
+        let snapshot = parallel.resolution_snapshot.unwrap();
+        assert_eq!(snapshot.inputs.len(), 1);
+        assert_eq!(snapshot.inputs[0].value, Value::Int(1));
     }
 
-    // -------------------------------------------------------------------------
-    // API Method Tests
-    // -------------------------------------------------------------------------
-
     #[test]
-    fn test_get_law_info() {
+    fn test_service_basic() {
         let mut service = LawExecutionService::new();
         service.load_law(make_base_law()).unwrap();
 
-        let info = service.get_law_info("base_law").unwrap();
-        assert_eq!(info.id, "base_law");
-        assert_eq!(info.regulatory_layer, RegulatoryLayer::Wet);
-        assert_eq!(info.publication_date, "2025-01-01");
-        assert!(info.bwb_id.is_none());
-        assert!(info.url.is_none());
-        assert_eq!(info.outputs, vec!["base_value"]);
-        assert_eq!(info.article_count, 1);
+        assert!(service.has_law("base_law"));
+        assert_eq!(service.law_count(), 1);
 
-        // Non-existent law
-        assert!(service.get_law_info("nonexistent").is_none());
+        let laws = service.list_laws();
+        assert_eq!(laws, vec!["base_law"]);
     }
 
     #[test]
-    fn test_list_all_outputs() {
+    fn test_service_execute_simple() {
         let mut service = LawExecutionService::new();
         service.load_law(make_base_law()).unwrap();
-        service.load_law(make_dependent_law()).unwrap();
 
-        let outputs = service.list_all_outputs();
-        assert!(outputs.contains(&("base_law", "base_value")));
-        assert!(outputs.contains(&("dependent_law", "doubled_value")));
-        assert_eq!(outputs.len(), 2);
+        let result = service
+            .evaluate_law_output("base_law", "base_value", BTreeMap::new(), "2025-01-01")
+            .unwrap();
+
+        assert_eq!(result.outputs.get("base_value"), Some(&Value::Int(100)));
     }
 
     #[test]
-    fn test_output_count() {
+    fn test_load_and_validate_directory() {
+        let dir =
+            std::env::temp_dir().join(format!("regelrecht_startup_report_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("base_law.yaml"), make_base_law()).unwrap();
+        std::fs::write(dir.join("dependent_law.yaml"), make_dependent_law()).unwrap();
+        std::fs::write(
+            dir.join("orphan.yaml"),
+            make_dependent_law()
+                .replace("$id: dependent_law", "$id: orphan_law")
+                .replace("regulation: base_law", "regulation: missing_law")
+                .replace(
+                    "articles:",
+                    "legal_basis:\n  - law_id: missing_law\n    article: '2'\narticles:",
+                ),
+        )
+        .unwrap();
+
         let mut service = LawExecutionService::new();
-        assert_eq!(service.get_output_count(), 0);
+        let report = service.load_and_validate_directory(&dir);
+        let _ = std::fs::remove_dir_all(&dir);
+        let report = report.unwrap();
+
+        assert_eq!(report.loaded, 3);
+        assert_eq!(report.law_count, 3);
+        assert!(!report.is_healthy());
+        assert!(report.cycles.is_empty());
+        assert!(report.invalid_overrides.is_empty());
+        assert_eq!(report.broken_legal_bases.len(), 1);
+        assert_eq!(report.broken_legal_bases[0].law_id, "orphan_law");
+        assert_eq!(report.missing_references.len(), 1);
+        assert_eq!(report.missing_references[0].law_id, "missing_law");
+        assert_eq!(
+            report.missing_references[0].kind,
+            MissingRefKind::LawNotLoaded
+        );
+    }
 
+    #[test]
+    fn test_article_definitions() {
+        let mut service = LawExecutionService::new();
         service.load_law(make_base_law()).unwrap();
-        assert_eq!(service.get_output_count(), 1);
 
-        service.load_law(make_dependent_law()).unwrap();
-        assert_eq!(service.get_output_count(), 2);
+        let law = service.resolver().get_law("base_law").unwrap();
+        let article = law.find_article_by_number("1").unwrap();
+        assert!(article.definitions().contains_key("BASE_VALUE"));
+
+        let values = service
+            .article_definitions("base_law", "1", "2025-01-01")
+            .unwrap();
+        assert_eq!(values.get("BASE_VALUE"), Some(&Value::Int(100)));
+
+        assert!(matches!(
+            service.article_definitions("base_law", "99", "2025-01-01"),
+            Err(EngineError::ArticleNotFound { .. })
+        ));
     }
 
     // -------------------------------------------------------------------------
-    // Integration Tests with Real Regulation Files
+    // Cross-Law Resolution Tests
     // -------------------------------------------------------------------------
 
-    mod integration {
-        use super::*;
-        use std::path::PathBuf;
+    #[test]
+    fn test_service_cross_law_resolution() {
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law(make_dependent_law()).unwrap();
 
-        fn get_regulation_path() -> PathBuf {
-            std::env::var("REGULATION_PATH")
-                .map(PathBuf::from)
-                .unwrap_or_else(|_| {
-                    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-                        .join("..")
-                        .join("..")
-                        .join("corpus")
-                        .join("regulation")
-                })
-        }
+        // Execute dependent law - should automatically resolve base_law reference
+        let result = service
+            .evaluate_law_output(
+                "dependent_law",
+                "doubled_value",
+                BTreeMap::new(),
+                "2025-01-01",
+            )
+            .unwrap();
 
-        #[test]
-        fn test_service_resolve_with_real_files() {
-            // Integration test: load real zorgtoeslagwet + regeling_standaardpremie
-            // and verify resolve action works end-to-end
-            let regulation_path = get_regulation_path();
+        // doubled_value = base_value (100) * 2 = 200
+        assert_eq!(result.outputs.get("doubled_value"), Some(&Value::Int(200)));
+    }
 
-            let zorgtoeslagwet_path =
-                regulation_path.join("nl/wet/wet_op_de_zorgtoeslag/2025-01-01.yaml");
+    #[derive(Default)]
+    struct RecordingAuditSink(std::sync::Mutex<Vec<AuditRecord>>);
+
+    impl AuditSink for RecordingAuditSink {
+        fn record(&self, record: &AuditRecord) {
+            self.0.lock().unwrap().push(record.clone());
+        }
+    }
+
+    #[test]
+    fn test_audit_sink_records_each_evaluation() {
+        let sink = Arc::new(RecordingAuditSink::default());
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law(make_dependent_law()).unwrap();
+        service.set_trace_redactions(["bsn"]);
+        service.set_audit_sink(sink.clone());
+
+        let params = BTreeMap::from([("bsn".to_string(), Value::from("999993653"))]);
+        service
+            .evaluate_law_output("dependent_law", "doubled_value", params, "2025-01-01")
+            .unwrap();
+
+        let records = sink.0.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record.law_id, "dependent_law");
+        assert_eq!(record.requested_outputs, vec!["doubled_value".to_string()]);
+        assert_eq!(record.calculation_date, "2025-01-01");
+        assert_eq!(record.parameters.get("bsn"), Some(&Value::from(REDACTED)));
+        assert_eq!(
+            record.outputs.as_ref().unwrap().get("doubled_value"),
+            Some(&Value::Int(200))
+        );
+        assert!(record.error.is_none());
+        assert_eq!(record.engine_version, crate::VERSION);
+        assert!(record.regulation_hash.is_some());
+        assert_eq!(
+            record.law_versions.keys().collect::<Vec<_>>(),
+            vec!["base_law", "dependent_law"]
+        );
+        assert!(!record.timestamp.is_empty());
+    }
+
+    #[test]
+    fn test_audit_sink_records_every_entry_point() {
+        let sink = Arc::new(RecordingAuditSink::default());
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law(make_dependent_law()).unwrap();
+        service.set_capture_resolution_snapshot(true);
+        service.set_audit_sink(sink.clone());
+
+        let (law, output, date) = ("dependent_law", "doubled_value", "2025-01-01");
+        let snapshot = service
+            .evaluate_law(law, &[output], BTreeMap::new(), date)
+            .unwrap()
+            .resolution_snapshot
+            .unwrap();
+        service
+            .evaluate_law_output_with_trace(law, output, BTreeMap::new(), date)
+            .unwrap();
+        service
+            .evaluate_law_output_with_failure_trace(law, output, BTreeMap::new(), date)
+            .unwrap();
+        service
+            .evaluate_law_output_with_timeout(
+                law,
+                output,
+                BTreeMap::new(),
+                date,
+                Duration::from_secs(60),
+            )
+            .unwrap();
+        service
+            .evaluate_law_output_as_of(law, output, BTreeMap::new(), date, date)
+            .unwrap();
+        service.evaluate_from_snapshot(&snapshot).unwrap();
+        assert!(service
+            .evaluate_law_output(law, "missing_output", BTreeMap::new(), date)
+            .is_err());
+
+        let records = sink.0.lock().unwrap();
+        assert_eq!(records.len(), 7);
+        assert!(records[..6]
+            .iter()
+            .all(|r| r.error.is_none() && r.law_versions.contains_key("base_law")));
+        assert!(records[6].error.is_some());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_audit_sink_records_parallel_evaluation() {
+        let sink = Arc::new(RecordingAuditSink::default());
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law(make_dependent_law()).unwrap();
+        service.set_audit_sink(sink.clone());
+
+        service
+            .evaluate_law_parallel(
+                "dependent_law",
+                &["doubled_value"],
+                BTreeMap::new(),
+                "2025-01-01",
+            )
+            .unwrap();
+
+        let records = sink.0.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        assert!(records[0].law_versions.contains_key("base_law"));
+    }
+
+    #[test]
+    fn test_timings_cover_outputs_of_every_article() {
+        let law = r#"
+$id: two_article_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: First output
+    machine_readable:
+      execution:
+        output:
+          - name: first
+            type: number
+        actions:
+          - output: first
+            value: 1
+  - number: '2'
+    text: Second output
+    machine_readable:
+      execution:
+        output:
+          - name: second
+            type: number
+        actions:
+          - output: second
+            value: 2
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(law).unwrap();
+        service.set_record_timings(true);
+
+        let result = service
+            .evaluate_law(
+                "two_article_law",
+                &["first", "second"],
+                BTreeMap::new(),
+                "2025-01-01",
+            )
+            .unwrap();
+
+        assert_eq!(result.article_number, "1, 2");
+        assert_eq!(
+            result.timings.keys().collect::<Vec<_>>(),
+            vec!["first", "second"]
+        );
+    }
+
+    #[test]
+    fn test_output_keys_in_camel_case() {
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law(make_dependent_law()).unwrap();
+        service.set_record_timings(true);
+
+        let result = service
+            .evaluate_law_output(
+                "dependent_law",
+                "doubled_value",
+                BTreeMap::new(),
+                "2025-01-01",
+            )
+            .unwrap()
+            .with_key_case(KeyCase::Camel);
+
+        assert_eq!(result.outputs.get("doubledValue"), Some(&Value::Int(200)));
+        assert!(!result.outputs.contains_key("doubled_value"));
+        assert!(result.output_provenance.contains_key("doubledValue"));
+        assert_eq!(
+            result.timings.keys().collect::<Vec<_>>(),
+            vec!["doubledValue"]
+        );
+        assert_eq!(KeyCase::Snake.apply("doubledValue"), "doubled_value");
+    }
+
+    #[test]
+    fn test_service_missing_dependency() {
+        let mut service = LawExecutionService::new();
+        // Only load dependent law, not base law
+        service.load_law(make_dependent_law()).unwrap();
+
+        let result = service.evaluate_law_output(
+            "dependent_law",
+            "doubled_value",
+            BTreeMap::new(),
+            "2025-01-01",
+        );
+
+        assert!(
+            matches!(result, Err(EngineError::LawNotFound(_))),
+            "Expected LawNotFound error, got: {:?}",
+            result
+        );
+    }
+
+    // -------------------------------------------------------------------------
+    // URI Resolution Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_service_uri_resolution() {
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+
+        let result = service
+            .evaluate_uri(
+                "regelrecht://base_law/base_value",
+                &BTreeMap::new(),
+                "2025-01-01",
+            )
+            .unwrap();
+
+        assert_eq!(result.outputs.get("base_value"), Some(&Value::Int(100)));
+    }
+
+    #[test]
+    fn test_uri_call_inline() {
+        let inline_law = r#"
+$id: inline_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Adds one to the base value without declaring an input
+    machine_readable:
+      execution:
+        output:
+          - name: base_plus_one
+            type: number
+        actions:
+          - output: base_plus_one
+            operation: ADD
+            values:
+              - operation: URI_CALL
+                uri: regelrecht://base_law/base_value
+              - 1
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law(inline_law).unwrap();
+
+        let result = service
+            .evaluate_law_output("inline_law", "base_plus_one", BTreeMap::new(), "2025-01-01")
+            .unwrap();
+
+        assert_eq!(result.outputs.get("base_plus_one"), Some(&Value::Int(101)));
+    }
+
+    #[test]
+    fn test_any_entitled_stops_at_first_truthy_reference() {
+        let scheme = |id: &str, entitled: bool| {
+            format!(
+                r#"
+$id: {id}
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Entitlement
+    machine_readable:
+      execution:
+        output:
+          - name: is_entitled
+            type: boolean
+        actions:
+          - output: is_entitled
+            value: {entitled}
+"#
+            )
+        };
+        let umbrella_law = r#"
+$id: umbrella_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Entitled to any of the schemes
+    machine_readable:
+      execution:
+        output:
+          - name: any_scheme
+            type: boolean
+        actions:
+          - output: any_scheme
+            value:
+              operation: ANY_ENTITLED
+              references:
+                - uri: regelrecht://scheme_a/is_entitled
+                - uri: regelrecht://scheme_b/is_entitled
+                - uri: regelrecht://scheme_not_loaded/is_entitled
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(&scheme("scheme_a", false)).unwrap();
+        service.load_law(&scheme("scheme_b", true)).unwrap();
+        service.load_law(umbrella_law).unwrap();
+
+        // The third reference targets a law that is not loaded, so evaluating
+        // it would fail: reaching `true` proves evaluation stopped at scheme_b.
+        let result = service
+            .evaluate_law_output("umbrella_law", "any_scheme", BTreeMap::new(), "2025-01-01")
+            .unwrap();
+        assert_eq!(result.outputs.get("any_scheme"), Some(&Value::Bool(true)));
+
+        // Without the truthy scheme, the unloaded law is reached.
+        let mut service = LawExecutionService::new();
+        service.load_law(&scheme("scheme_a", false)).unwrap();
+        service.load_law(&scheme("scheme_b", false)).unwrap();
+        service.load_law(umbrella_law).unwrap();
+        assert!(service
+            .evaluate_law_output("umbrella_law", "any_scheme", BTreeMap::new(), "2025-01-01")
+            .is_err());
+    }
+
+    #[test]
+    fn test_first_resolved_stops_at_first_non_null_reference() {
+        let source = |id: &str, income: &str| {
+            format!(
+                r#"
+$id: {id}
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Registered income
+    machine_readable:
+      execution:
+        output:
+          - name: inkomen
+            type: amount
+        actions:
+          - output: inkomen
+            value: {income}
+"#
+            )
+        };
+        let consumer_law = r#"
+$id: income_consumer
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Income from the first source that has it
+    machine_readable:
+      execution:
+        output:
+          - name: inkomen
+            type: amount
+        actions:
+          - output: inkomen
+            value:
+              operation: FIRST_RESOLVED
+              references:
+                - uri: regelrecht://bron_a/inkomen
+                - uri: regelrecht://bron_b/inkomen
+                - uri: regelrecht://bron_not_loaded/inkomen
+              default: 0
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(&source("bron_a", "null")).unwrap();
+        service.load_law(&source("bron_b", "4200000")).unwrap();
+        service.load_law(consumer_law).unwrap();
+
+        // The third reference targets a law that is not loaded, so evaluating
+        // it would fail: succeeding proves evaluation stopped at bron_b.
+        let result = service
+            .evaluate_law_output("income_consumer", "inkomen", BTreeMap::new(), "2025-01-01")
+            .unwrap();
+        assert_eq!(result.outputs.get("inkomen"), Some(&Value::Int(4_200_000)));
+        assert_eq!(result.call_counts.uri_calls, 2);
+    }
+
+    #[test]
+    fn test_applicable_version_reports_selected_valid_from() {
+        let base_law = |valid_from: &str, rate: f64| {
+            format!(
+                r#"
+$id: base_law
+regulatory_layer: WET
+publication_date: '{valid_from}'
+valid_from: '{valid_from}'
+articles:
+  - number: '1'
+    text: Rate
+    machine_readable:
+      execution:
+        output:
+          - name: rate
+            type: number
+        actions:
+          - output: rate
+            value: {rate}
+"#
+            )
+        };
+        let audit_law = r#"
+$id: audit_law
+regulatory_layer: WET
+publication_date: '2024-01-01'
+articles:
+  - number: '1'
+    text: Records the version of the base law that was used
+    machine_readable:
+      execution:
+        output:
+          - name: base_version
+            type: string
+        actions:
+          - output: base_version
+            value:
+              operation: APPLICABLE_VERSION
+              law_id: base_law
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(&base_law("2024-01-01", 0.1)).unwrap();
+        service.load_law(&base_law("2025-01-01", 0.2)).unwrap();
+        service.load_law(audit_law).unwrap();
+
+        for (date, expected) in [("2024-06-30", "2024-01-01"), ("2025-03-01", "2025-01-01")] {
+            let result = service
+                .evaluate_law_output("audit_law", "base_version", BTreeMap::new(), date)
+                .unwrap();
+            assert_eq!(
+                result.outputs.get("base_version"),
+                Some(&Value::String(expected.to_string())),
+                "version applicable on {}",
+                date
+            );
+            assert_eq!(
+                service.applicable_version("base_law", date).unwrap(),
+                Some(expected.to_string())
+            );
+        }
+
+        assert!(matches!(
+            service.applicable_version("base_law", "2023-12-31"),
+            Err(EngineError::NoApplicableVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_warm_up_builds_definitions_ahead_of_first_evaluation() {
+        let law = r#"
+$id: warm_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Norm
+    machine_readable:
+      definitions:
+        norm:
+          value: 100
+      execution:
+        output:
+          - name: bedrag
+            type: number
+        actions:
+          - output: bedrag
+            value:
+              operation: MULTIPLY
+              values: [$norm, 2]
+  - number: '2'
+    text: No machine-readable content
+"#;
+        let warmed_articles = |service: &LawExecutionService| {
+            let law = service.resolver().get_law("warm_law").unwrap();
+            law.articles
+                .iter()
+                .filter(|a| a.has_definition_values())
+                .count()
+        };
+        let evaluate = |service: &LawExecutionService| {
+            service
+                .evaluate_law_output("warm_law", "bedrag", BTreeMap::new(), "2025-01-01")
+                .unwrap()
+                .outputs
+        };
+
+        // Without warm-up, the first evaluation builds the definitions of the article it runs
+        let mut cold = LawExecutionService::new();
+        cold.load_law(law).unwrap();
+        assert_eq!(warmed_articles(&cold), 0);
+        let cold_outputs = evaluate(&cold);
+        assert_eq!(warmed_articles(&cold), 1);
+
+        // With warm-up, every article's definitions are built before the first evaluation
+        let mut warm = LawExecutionService::new();
+        warm.load_law(law).unwrap();
+        assert_eq!(warm.warm_up(), 2);
+        assert_eq!(warmed_articles(&warm), 2);
+        assert_eq!(evaluate(&warm), cold_outputs);
+        assert_eq!(cold_outputs.get("bedrag"), Some(&Value::Int(200)));
+        assert_eq!(warm.warm_up(), 0);
+
+        // Built definitions do not change an article's identity
+        let articles = |service: &LawExecutionService| {
+            service
+                .resolver()
+                .get_law("warm_law")
+                .unwrap()
+                .articles
+                .clone()
+        };
+        assert_eq!(articles(&cold), articles(&warm));
+    }
+
+    #[test]
+    fn test_uri_call_circular_reference() {
+        let self_law = r#"
+$id: self_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Calls its own output inline
+    machine_readable:
+      execution:
+        output:
+          - name: loop_value
+            type: number
+        actions:
+          - output: loop_value
+            value:
+              operation: URI_CALL
+              uri: regelrecht://self_law/loop_value
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(self_law).unwrap();
+
+        let result =
+            service.evaluate_law_output("self_law", "loop_value", BTreeMap::new(), "2025-01-01");
+
+        assert!(
+            matches!(result, Err(EngineError::CircularReference(_))),
+            "Expected CircularReference error, got: {:?}",
+            result
+        );
+    }
+
+    // -------------------------------------------------------------------------
+    // Circular Reference Detection Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_service_cross_law_circular_reference() {
+        // Law A references Law B, which references Law A
+        let law_a = r#"
+$id: law_a
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: References law_b
+    machine_readable:
+      execution:
+        input:
+          - name: from_b
+            type: number
+            source:
+              regulation: law_b
+              output: output_b
+        output:
+          - name: output_a
+            type: number
+        actions:
+          - output: output_a
+            value: $from_b
+"#;
+
+        let law_b = r#"
+$id: law_b
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: References law_a
+    machine_readable:
+      execution:
+        input:
+          - name: from_a
+            type: number
+            source:
+              regulation: law_a
+              output: output_a
+        output:
+          - name: output_b
+            type: number
+        actions:
+          - output: output_b
+            value: $from_a
+"#;
+
+        let mut service = LawExecutionService::new();
+        service.load_law(law_a).unwrap();
+        service.load_law(law_b).unwrap();
+
+        let result =
+            service.evaluate_law_output("law_a", "output_a", BTreeMap::new(), "2025-01-01");
+
+        assert!(
+            matches!(result, Err(EngineError::CircularReference(_))),
+            "Expected CircularReference error, got: {:?}",
+            result
+        );
+    }
+
+    // -------------------------------------------------------------------------
+    // Parameter Override Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_service_parameter_override() {
+        // When a parameter is provided that matches an input name,
+        // the parameter value should be used instead of resolving the source
+        let law = r#"
+$id: override_test_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Has external reference but can be overridden
+    machine_readable:
+      execution:
+        parameters:
+          - name: external_value
+            type: number
+        input:
+          - name: external_value
+            type: number
+            source:
+              regulation: nonexistent_law
+              output: some_output
+        output:
+          - name: result
+            type: number
+        actions:
+          - output: result
+            operation: MULTIPLY
+            values:
+              - $external_value
+              - 2
+"#;
+
+        let mut service = LawExecutionService::new();
+        service.load_law(law).unwrap();
+
+        // Provide the value directly - should skip external resolution
+        let mut params = BTreeMap::new();
+        params.insert("external_value".to_string(), Value::Int(50));
+
+        let result = service
+            .evaluate_law_output("override_test_law", "result", params, "2025-01-01")
+            .unwrap();
+
+        // result = 50 * 2 = 100
+        assert_eq!(result.outputs.get("result"), Some(&Value::Int(100)));
+    }
+
+    // -------------------------------------------------------------------------
+    // API Method Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_get_law_info() {
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+
+        let info = service.get_law_info("base_law").unwrap();
+        assert_eq!(info.id, "base_law");
+        assert_eq!(info.regulatory_layer, RegulatoryLayer::Wet);
+        assert_eq!(info.publication_date, "2025-01-01");
+        assert!(info.bwb_id.is_none());
+        assert!(info.url.is_none());
+        assert!(info.authority.is_none());
+        assert_eq!(info.outputs, vec!["base_value"]);
+        assert_eq!(info.article_count, 1);
+
+        // Non-existent law
+        assert!(service.get_law_info("nonexistent").is_none());
+    }
+
+    #[test]
+    fn test_get_law_info_reports_authority() {
+        let mut service = LawExecutionService::new();
+        service
+            .load_law(&make_base_law().replace(
+                "publication_date: '2025-01-01'",
+                "publication_date: '2025-01-01'\nuitvoerder: Belastingdienst",
+            ))
+            .unwrap();
+        service
+            .load_law(&make_dependent_law().replace(
+                "publication_date: '2025-01-01'",
+                "publication_date: '2025-01-01'\ncompetent_authority:\n  name: Dienst Toeslagen",
+            ))
+            .unwrap();
+
+        let info = service.get_law_info("base_law").unwrap();
+        assert_eq!(info.authority.as_deref(), Some("Belastingdienst"));
+        let info = service.get_law_info("dependent_law").unwrap();
+        assert_eq!(info.authority.as_deref(), Some("Dienst Toeslagen"));
+    }
+
+    #[test]
+    fn test_clamp_with_cap_from_another_law() {
+        let consumer = r#"
+$id: capped_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Amount capped by the base law, with a fixed floor
+    machine_readable:
+      execution:
+        parameters:
+          - name: bedrag
+            type: number
+        input:
+          - name: plafond
+            type: number
+            source:
+              regulation: base_law
+              output: base_value
+        output:
+          - name: begrensd_bedrag
+            type: number
+        actions:
+          - output: begrensd_bedrag
+            value:
+              operation: CLAMP
+              subject: $bedrag
+              min: 20
+              max: $plafond
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law(consumer).unwrap();
+        let clamped = |bedrag: i64| {
+            let mut params = BTreeMap::new();
+            params.insert("bedrag".to_string(), Value::Int(bedrag));
+            service
+                .evaluate_law_output("capped_law", "begrensd_bedrag", params, "2025-01-01")
+                .unwrap()
+                .outputs
+                .get("begrensd_bedrag")
+                .cloned()
+        };
+
+        assert_eq!(clamped(250), Some(Value::Int(100)));
+        assert_eq!(clamped(5), Some(Value::Int(20)));
+        assert_eq!(clamped(60), Some(Value::Int(60)));
+    }
+
+    #[test]
+    fn test_registered_custom_operation() {
+        let law = r#"
+$id: custom_op_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Doubles the amount with a deployment-specific operation
+    machine_readable:
+      execution:
+        parameters:
+          - name: bedrag
+            type: number
+        output:
+          - name: verdubbeld
+            type: number
+        actions:
+          - output: verdubbeld
+            value:
+              operation: DOUBLE
+              value: $bedrag
+"#;
+        let mut service = LawExecutionService::new();
+        let err = service.load_law(law).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unknown operation(s) in law 'custom_op_law': DOUBLE"));
+
+        service.register_operation("DOUBLE", |op, resolver, depth| {
+            let ActionOperation::Custom(custom) = op else {
+                unreachable!("handlers only receive custom operations");
+            };
+            let operand = custom.field("value").ok_or_else(|| {
+                EngineError::InvalidOperation("DOUBLE requires 'value'".to_string())
+            })?;
+            match crate::evaluate_value(operand, &resolver, depth + 1)? {
+                Value::Int(n) => Ok(Value::Int(n * 2)),
+                other => Err(EngineError::TypeMismatch {
+                    expected: "integer".to_string(),
+                    actual: other.type_name().to_string(),
+                }),
+            }
+        });
+        service.load_law(law).unwrap();
+        let mut params = BTreeMap::new();
+        params.insert("bedrag".to_string(), Value::Int(21));
+        let result = service
+            .evaluate_law_output("custom_op_law", "verdubbeld", params, "2025-01-01")
+            .unwrap();
+        assert_eq!(result.outputs.get("verdubbeld"), Some(&Value::Int(42)));
+    }
+
+    #[test]
+    fn test_misspelled_operation_fails_to_load() {
+        let law = r#"
+$id: typo_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Multiplies with a misspelled operation name
+    machine_readable:
+      execution:
+        parameters:
+          - name: bedrag
+            type: number
+        output:
+          - name: product
+            type: number
+        actions:
+          - output: product
+            value:
+              operation: MULTIPLLY
+              values: [$bedrag, 2]
+"#;
+        let mut service = LawExecutionService::new();
+        let err = service.load_law(law).unwrap_err();
+        assert!(matches!(err, EngineError::LoadError(_)));
+        assert!(err.to_string().contains("MULTIPLLY"));
+        assert!(!service.has_law("typo_law"));
+    }
+
+    #[test]
+    fn test_missing_cross_law_output_errors_or_warns() {
+        let consumer = r#"
+$id: consumer_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Reads an output the base law does not have
+    machine_readable:
+      execution:
+        input:
+          - name: extra
+            type: number
+            source:
+              regulation: base_law
+              output: extra_value
+        output:
+          - name: extra_or_zero
+            type: number
+        actions:
+          - output: extra_or_zero
+            value:
+              operation: IF
+              cases:
+                - when:
+                    operation: IS_NULL
+                    subject: $extra
+                  then: 0
+              default: $extra
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law(consumer).unwrap();
+        let evaluate = |service: &LawExecutionService| {
+            service.evaluate_law_output(
+                "consumer_law",
+                "extra_or_zero",
+                BTreeMap::new(),
+                "2025-01-01",
+            )
+        };
+
+        let result = evaluate(&service);
+        assert!(
+            matches!(
+                &result,
+                Err(EngineError::OutputNotFound { law_id, output })
+                    if law_id == "base_law" && output == "extra_value"
+            ),
+            "expected OutputNotFound, got {:?}",
+            result
+        );
+
+        service.set_missing_cross_law_output(MissingCrossLawOutput::Warn);
+        let result = evaluate(&service).unwrap();
+        assert_eq!(result.outputs.get("extra_or_zero"), Some(&Value::Int(0)));
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].kind, WarningKind::MissingCrossLawOutput);
+        assert_eq!(result.warnings[0].subject, "base_law#extra_value");
+    }
+
+    #[test]
+    fn test_namespaced_laws_with_the_same_bare_id() {
+        let mut service = LawExecutionService::new();
+        let nl_id = service
+            .load_law_in_namespace(make_base_law(), "nl")
+            .unwrap();
+        assert_eq!(nl_id, "nl:base_law");
+        let municipal_base = make_base_law().replace("value: 100", "value: 7");
+        service
+            .load_law_in_namespace(&municipal_base, "gm0363")
+            .unwrap();
+        service
+            .load_law_in_namespace(make_dependent_law(), "nl")
+            .unwrap();
+        service
+            .load_law_in_namespace(make_dependent_law(), "gm0363")
+            .unwrap();
+        service
+            .load_law(
+                &make_dependent_law()
+                    .replace("regulation: base_law", "regulation: 'gm0363:base_law'"),
+            )
+            .unwrap();
+        assert!(!service.has_law("base_law"));
+
+        let output = |service: &LawExecutionService, law_id: &str, output: &str| {
+            service
+                .evaluate_law_output(law_id, output, BTreeMap::new(), "2025-01-01")
+                .unwrap()
+                .outputs
+                .get(output)
+                .cloned()
+        };
+        assert_eq!(
+            output(&service, "nl:base_law", "base_value"),
+            Some(Value::Int(100))
+        );
+        assert_eq!(
+            output(&service, "gm0363:base_law", "base_value"),
+            Some(Value::Int(7))
+        );
+        // Bare references resolve within the referring law's namespace
+        assert_eq!(
+            output(&service, "nl:dependent_law", "doubled_value"),
+            Some(Value::Int(200))
+        );
+        assert_eq!(
+            output(&service, "gm0363:dependent_law", "doubled_value"),
+            Some(Value::Int(14))
+        );
+        // Qualified references resolve across namespaces
+        assert_eq!(
+            output(&service, "dependent_law", "doubled_value"),
+            Some(Value::Int(14))
+        );
+
+        assert!(matches!(
+            service.load_law_in_namespace(make_base_law(), "nl:eu"),
+            Err(EngineError::LoadError(_))
+        ));
+    }
+
+    #[test]
+    fn test_namespaced_uri_call_and_implements_reach_siblings() {
+        let inline_law = r#"
+$id: inline_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Adds one to the base value without declaring an input
+    machine_readable:
+      execution:
+        output:
+          - name: base_plus_one
+            type: number
+        actions:
+          - output: base_plus_one
+            operation: ADD
+            values:
+              - operation: URI_CALL
+                uri: regelrecht://base_law/base_value
+              - 1
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law_in_namespace(inline_law, "gm0363").unwrap();
+        // Loaded after the referring law, so the lookup cannot be fixed at load time
+        service
+            .load_law_in_namespace(&make_base_law().replace("value: 100", "value: 7"), "gm0363")
+            .unwrap();
+        service
+            .load_law_in_namespace(make_implementing_regulation(), "nl")
+            .unwrap();
+        service
+            .load_law_in_namespace(make_law_with_open_term(), "nl")
+            .unwrap();
+        service.load_law(make_law_with_open_term()).unwrap();
+
+        let result = service
+            .evaluate_law_output(
+                "gm0363:inline_law",
+                "base_plus_one",
+                BTreeMap::new(),
+                "2025-01-01",
+            )
+            .unwrap();
+        assert_eq!(result.outputs.get("base_plus_one"), Some(&Value::Int(8)));
+
+        let result = service
+            .evaluate_law_output(
+                "nl:zorgtoeslag_ioc",
+                "standaardpremie",
+                BTreeMap::new(),
+                "2025-01-01",
+            )
+            .unwrap();
+        assert_eq!(
+            result.outputs.get("standaardpremie"),
+            Some(&Value::Int(1928))
+        );
+        // The namespaced regulation implements its sibling, not the bare law
+        assert!(service
+            .evaluate_law_output(
+                "zorgtoeslag_ioc",
+                "standaardpremie",
+                BTreeMap::new(),
+                "2025-01-01",
+            )
+            .is_err());
+        assert_eq!(
+            service
+                .resolver()
+                .list_delegated_regulations("nl:zorgtoeslag_ioc", "4", None)
+                .iter()
+                .map(|law| law.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["nl:regeling_sp_ioc"]
+        );
+    }
+
+    #[test]
+    fn test_list_law_info_includes_all_versions() {
+        let version = |valid_from: &str| {
+            make_base_law().replace(
+                "publication_date: '2025-01-01'",
+                &format!("publication_date: '{valid_from}'\nvalid_from: '{valid_from}'"),
+            )
+        };
+        let mut service = LawExecutionService::new();
+        service.load_law(&version("2024-01-01")).unwrap();
+        service.load_law(&version("2025-01-01")).unwrap();
+        service.load_law(make_dependent_law()).unwrap();
+
+        let infos = service.list_law_info();
+        let versions: Vec<(&str, Option<&str>)> = infos
+            .iter()
+            .map(|info| (info.id.as_str(), info.valid_from.as_deref()))
+            .collect();
+        assert_eq!(
+            versions,
+            vec![
+                ("base_law", Some("2025-01-01")),
+                ("base_law", Some("2024-01-01")),
+                ("dependent_law", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_list_all_outputs() {
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law(make_dependent_law()).unwrap();
+
+        let outputs = service.list_all_outputs();
+        assert!(outputs.contains(&("base_law", "base_value")));
+        assert!(outputs.contains(&("dependent_law", "doubled_value")));
+        assert_eq!(outputs.len(), 2);
+    }
+
+    #[test]
+    fn test_output_count() {
+        let mut service = LawExecutionService::new();
+        assert_eq!(service.get_output_count(), 0);
+
+        service.load_law(make_base_law()).unwrap();
+        assert_eq!(service.get_output_count(), 1);
+
+        service.load_law(make_dependent_law()).unwrap();
+        assert_eq!(service.get_output_count(), 2);
+    }
+
+    #[test]
+    fn test_reload_law_uses_new_definitions() {
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law(make_dependent_law()).unwrap();
+
+        let reloaded = make_base_law().replace("value: 100", "value: 150");
+        assert_eq!(service.reload_law(&reloaded).unwrap(), "base_law");
+        assert_eq!(service.law_count(), 2);
+
+        let result = service
+            .evaluate_law_output(
+                "dependent_law",
+                "doubled_value",
+                BTreeMap::new(),
+                "2025-01-01",
+            )
+            .unwrap();
+        assert_eq!(result.outputs.get("doubled_value"), Some(&Value::Int(300)));
+    }
+
+    #[test]
+    fn test_reload_law_malformed_keeps_old_version() {
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+
+        let malformed = make_base_law().replace("articles:", "articles: [");
+        assert!(service.reload_law(&malformed).is_err());
+
+        let result = service
+            .evaluate_law_output("base_law", "base_value", BTreeMap::new(), "2025-01-01")
+            .unwrap();
+        assert_eq!(result.outputs.get("base_value"), Some(&Value::Int(100)));
+    }
+
+    #[test]
+    fn test_evaluate_before_first_version_is_no_applicable_version() {
+        let mut service = LawExecutionService::new();
+        service
+            .load_law(&make_base_law().replace(
+                "publication_date: '2025-01-01'",
+                "publication_date: '2025-01-01'\nvalid_from: '2025-01-01'",
+            ))
+            .unwrap();
+
+        let result =
+            service.evaluate_law_output("base_law", "base_value", BTreeMap::new(), "2020-01-01");
+
+        assert!(
+            matches!(
+                result,
+                Err(EngineError::NoApplicableVersion { ref law_id, ref date })
+                    if law_id == "base_law" && date == "2020-01-01"
+            ),
+            "Expected NoApplicableVersion error, got: {:?}",
+            result
+        );
+        assert!(matches!(
+            service.evaluate_law_output("unknown_law", "x", BTreeMap::new(), "2020-01-01"),
+            Err(EngineError::LawNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_reload_law_requires_loaded_law() {
+        let mut service = LawExecutionService::new();
+
+        let result = service.reload_law(make_base_law());
+        assert!(matches!(result, Err(EngineError::LawNotFound(_))));
+        assert!(!service.has_law("base_law"));
+    }
+
+    // -------------------------------------------------------------------------
+    // Integration Tests with Real Regulation Files
+    // -------------------------------------------------------------------------
+
+    mod integration {
+        use super::*;
+        use std::path::PathBuf;
+
+        fn get_regulation_path() -> PathBuf {
+            std::env::var("REGULATION_PATH")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| {
+                    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                        .join("..")
+                        .join("..")
+                        .join("corpus")
+                        .join("regulation")
+                })
+        }
+
+        #[test]
+        fn test_service_resolve_with_real_files() {
+            // Integration test: load real zorgtoeslagwet + regeling_standaardpremie
+            // and verify resolve action works end-to-end
+            let regulation_path = get_regulation_path();
+
+            let zorgtoeslagwet_path =
+                regulation_path.join("nl/wet/wet_op_de_zorgtoeslag/2025-01-01.yaml");
             let regeling_path = regulation_path
                 .join("nl/ministeriele_regeling/regeling_standaardpremie/2025-01-01.yaml");
 
@@ -2801,38 +5603,278 @@ articles:
             let mut service = LawExecutionService::new();
             service.load_law_struct(law).unwrap();
 
-            let info = service.get_law_info("zorgtoeslagwet").unwrap();
-            assert_eq!(info.id, "zorgtoeslagwet");
-            assert_eq!(info.regulatory_layer, RegulatoryLayer::Wet);
-            assert!(info.article_count > 0);
-            assert!(!info.outputs.is_empty());
-            // Should include standaardpremie output
-            assert!(
-                info.outputs.contains(&"standaardpremie".to_string()),
-                "Expected standaardpremie in outputs: {:?}",
-                info.outputs
-            );
-        }
+            let info = service.get_law_info("zorgtoeslagwet").unwrap();
+            assert_eq!(info.id, "zorgtoeslagwet");
+            assert_eq!(info.regulatory_layer, RegulatoryLayer::Wet);
+            assert!(info.article_count > 0);
+            assert!(!info.outputs.is_empty());
+            // Should include standaardpremie output
+            assert!(
+                info.outputs.contains(&"standaardpremie".to_string()),
+                "Expected standaardpremie in outputs: {:?}",
+                info.outputs
+            );
+        }
+    }
+
+    #[test]
+    fn test_cross_law_uses_version_aware_lookup() {
+        // Two versions of a referenced law with different definitions.
+        // Cross-law resolution should pick the correct version based on
+        // the reference date, not just the latest version.
+        // Version selection uses `valid_from` to determine applicability.
+        let base_v1 = r#"
+$id: versioned_base
+regulatory_layer: WET
+publication_date: '2024-01-01'
+valid_from: '2024-01-01'
+articles:
+  - number: '1'
+    text: Base value v1
+    machine_readable:
+      definitions:
+        BASE_VALUE:
+          value: 100
+      execution:
+        output:
+          - name: base_value
+            type: number
+        actions:
+          - output: base_value
+            value: $BASE_VALUE
+"#;
+        let base_v2 = r#"
+$id: versioned_base
+regulatory_layer: WET
+publication_date: '2025-01-01'
+valid_from: '2025-01-01'
+articles:
+  - number: '1'
+    text: Base value v2
+    machine_readable:
+      definitions:
+        BASE_VALUE:
+          value: 200
+      execution:
+        output:
+          - name: base_value
+            type: number
+        actions:
+          - output: base_value
+            value: $BASE_VALUE
+"#;
+        let dependent = r#"
+$id: cross_law_consumer
+regulatory_layer: WET
+publication_date: '2024-01-01'
+articles:
+  - number: '1'
+    text: Uses versioned base
+    machine_readable:
+      execution:
+        input:
+          - name: external_base
+            type: number
+            source:
+              regulation: versioned_base
+              output: base_value
+        output:
+          - name: result
+            type: number
+        actions:
+          - output: result
+            value: $external_base
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(base_v1).unwrap();
+        service.load_law(base_v2).unwrap();
+        service.load_law(dependent).unwrap();
+
+        // Reference date 2024-06-15 should use v1 (BASE_VALUE=100)
+        let result = service
+            .evaluate_law_output(
+                "cross_law_consumer",
+                "result",
+                BTreeMap::new(),
+                "2024-06-15",
+            )
+            .unwrap();
+        assert_eq!(
+            result.outputs.get("result"),
+            Some(&Value::Int(100)),
+            "2024 reference date should resolve to v1 (100)"
+        );
+
+        // Reference date 2025-06-15 should use v2 (BASE_VALUE=200)
+        let result = service
+            .evaluate_law_output(
+                "cross_law_consumer",
+                "result",
+                BTreeMap::new(),
+                "2025-06-15",
+            )
+            .unwrap();
+        assert_eq!(
+            result.outputs.get("result"),
+            Some(&Value::Int(200)),
+            "2025 reference date should resolve to v2 (200)"
+        );
+    }
+
+    #[test]
+    fn test_version_date_and_value_date_differ() {
+        let version = |valid_from: &str, value: i64| {
+            format!(
+                r#"
+$id: uitkering
+regulatory_layer: WET
+publication_date: '{valid_from}'
+valid_from: '{valid_from}'
+articles:
+  - number: '1'
+    text: Uitkering
+    machine_readable:
+      definitions:
+        BEDRAG:
+          value: {value}
+      execution:
+        parameters:
+          - name: geboortedatum
+            type: date
+            required: true
+        input:
+          - name: tarief
+            type: number
+            source:
+              regulation: tarieven
+              output: tarief
+        output:
+          - name: bedrag
+            type: number
+          - name: leeftijd
+            type: number
+          - name: toeslag
+            type: number
+        actions:
+          - output: bedrag
+            value: $BEDRAG
+          - output: leeftijd
+            value:
+              operation: AGE
+              date_of_birth: $geboortedatum
+              reference_date: $referencedate
+          - output: toeslag
+            value: $tarief
+"#
+            )
+        };
+        let tarieven = |valid_from: &str, value: i64| {
+            format!(
+                r#"
+$id: tarieven
+regulatory_layer: MINISTERIELE_REGELING
+publication_date: '{valid_from}'
+valid_from: '{valid_from}'
+articles:
+  - number: '1'
+    text: Tarief
+    machine_readable:
+      definitions:
+        TARIEF:
+          value: {value}
+      execution:
+        output:
+          - name: tarief
+            type: number
+        actions:
+          - output: tarief
+            value: $TARIEF
+"#
+            )
+        };
+        let mut service = LawExecutionService::new();
+        service.load_law(&version("2024-01-01", 100)).unwrap();
+        service.load_law(&version("2025-01-01", 200)).unwrap();
+        service.load_law(&tarieven("2024-01-01", 10)).unwrap();
+        service.load_law(&tarieven("2025-01-01", 20)).unwrap();
+        service.set_capture_resolution_snapshot(true);
+        let sink = Arc::new(RecordingAuditSink::default());
+        service.set_audit_sink(sink.clone());
+        let params = || {
+            BTreeMap::from([(
+                "geboortedatum".to_string(),
+                Value::String("2000-01-01".to_string()),
+            )])
+        };
+
+        // Event in 2024 judged under the 2025 text, of both laws
+        let result = service
+            .evaluate_law_output_as_of("uitkering", "bedrag", params(), "2024-06-15", "2025-03-01")
+            .unwrap();
+        assert_eq!(result.outputs.get("bedrag"), Some(&Value::Int(200)));
+        assert_eq!(result.regulation_valid_from.as_deref(), Some("2025-01-01"));
+        let result = service
+            .evaluate_law_output_as_of(
+                "uitkering",
+                "leeftijd",
+                params(),
+                "2024-06-15",
+                "2025-03-01",
+            )
+            .unwrap();
+        assert_eq!(result.outputs.get("leeftijd"), Some(&Value::Int(24)));
+        let result = service
+            .evaluate_law_output_as_of("uitkering", "toeslag", params(), "2024-06-15", "2025-03-01")
+            .unwrap();
+        assert_eq!(result.outputs.get("toeslag"), Some(&Value::Int(20)));
+
+        // Both dates are recorded, and replay selects the same versions
+        let snapshot = result.resolution_snapshot.clone().unwrap();
+        assert_eq!(snapshot.calculation_date, "2024-06-15");
+        assert_eq!(snapshot.version_date.as_deref(), Some("2025-03-01"));
+        let replayed = service.evaluate_from_snapshot(&snapshot).unwrap();
+        assert_eq!(replayed.outputs, result.outputs);
+        let records = sink.0.lock().unwrap();
+        let record = records.last().unwrap();
+        assert_eq!(record.calculation_date, "2024-06-15");
+        assert_eq!(record.version_date.as_deref(), Some("2025-03-01"));
+        assert_eq!(
+            record.law_versions.get("tarieven"),
+            Some(&BTreeSet::from(["2025-01-01".to_string()]))
+        );
+        drop(records);
+
+        // A single date selects the version and computes the values
+        let result = service
+            .evaluate_law_output("uitkering", "bedrag", params(), "2024-06-15")
+            .unwrap();
+        assert_eq!(result.outputs.get("bedrag"), Some(&Value::Int(100)));
+        let result = service
+            .evaluate_law_output("uitkering", "toeslag", params(), "2024-06-15")
+            .unwrap();
+        assert_eq!(result.outputs.get("toeslag"), Some(&Value::Int(10)));
+        let result = service
+            .evaluate_law_output("uitkering", "leeftijd", params(), "2025-03-01")
+            .unwrap();
+        assert_eq!(result.outputs.get("leeftijd"), Some(&Value::Int(25)));
     }
 
     #[test]
-    fn test_cross_law_uses_version_aware_lookup() {
-        // Two versions of a referenced law with different definitions.
-        // Cross-law resolution should pick the correct version based on
-        // the reference date, not just the latest version.
-        // Version selection uses `valid_from` to determine applicability.
-        let base_v1 = r#"
+    fn test_cross_law_version_pin_overrides_date_selection() {
+        let base = |valid_from: &str, value: i64| {
+            format!(
+                r#"
 $id: versioned_base
 regulatory_layer: WET
-publication_date: '2024-01-01'
-valid_from: '2024-01-01'
+publication_date: '{valid_from}'
+valid_from: '{valid_from}'
 articles:
   - number: '1'
-    text: Base value v1
+    text: Base value
     machine_readable:
       definitions:
         BASE_VALUE:
-          value: 100
+          value: {value}
       execution:
         output:
           - name: base_value
@@ -2840,19 +5882,75 @@ articles:
         actions:
           - output: base_value
             value: $BASE_VALUE
-"#;
-        let base_v2 = r#"
+"#
+            )
+        };
+        let dependent = |version: &str| {
+            format!(
+                r#"
+$id: pinned_consumer
+regulatory_layer: WET
+publication_date: '2024-01-01'
+articles:
+  - number: '1'
+    text: Uses the base law as published in 2024
+    machine_readable:
+      execution:
+        input:
+          - name: external_base
+            type: number
+            source:
+              regulation: versioned_base
+              output: base_value
+              version: '{version}'
+        output:
+          - name: result
+            type: number
+        actions:
+          - output: result
+            value: $external_base
+"#
+            )
+        };
+        let mut service = LawExecutionService::new();
+        service.load_law(&base("2024-01-01", 100)).unwrap();
+        service.load_law(&base("2025-01-01", 200)).unwrap();
+        service.load_law(&dependent("2024-01-01")).unwrap();
+
+        // The 2025 version is valid on this date, but the pin selects 2024
+        let result = service
+            .evaluate_law_output("pinned_consumer", "result", BTreeMap::new(), "2025-06-15")
+            .unwrap();
+        assert_eq!(result.outputs.get("result"), Some(&Value::Int(100)));
+
+        // Pinning a version that isn't loaded is an error, not a fallback
+        service.reload_law(&dependent("2023-01-01")).unwrap();
+        let err = service
+            .evaluate_law_output("pinned_consumer", "result", BTreeMap::new(), "2025-06-15")
+            .unwrap_err();
+        assert!(
+            matches!(err, EngineError::LawNotFound(ref id) if id.contains("2023-01-01")),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_referencedate_parameter_selects_versions_across_laws() {
+        let base = |valid_from: &str, value: i64| {
+            format!(
+                r#"
 $id: versioned_base
 regulatory_layer: WET
-publication_date: '2025-01-01'
-valid_from: '2025-01-01'
+publication_date: '{valid_from}'
+valid_from: '{valid_from}'
 articles:
   - number: '1'
-    text: Base value v2
+    text: Base value
     machine_readable:
       definitions:
         BASE_VALUE:
-          value: 200
+          value: {value}
       execution:
         output:
           - name: base_value
@@ -2860,14 +5958,17 @@ articles:
         actions:
           - output: base_value
             value: $BASE_VALUE
-"#;
-        let dependent = r#"
-$id: cross_law_consumer
+"#
+            )
+        };
+        let consumer = r#"
+$id: referencedate_consumer
 regulatory_layer: WET
 publication_date: '2024-01-01'
+valid_from: '2024-01-01'
 articles:
   - number: '1'
-    text: Uses versioned base
+    text: Uses the base law in force on the reference date
     machine_readable:
       execution:
         input:
@@ -2884,57 +5985,343 @@ articles:
             value: $external_base
 "#;
         let mut service = LawExecutionService::new();
-        service.load_law(base_v1).unwrap();
-        service.load_law(base_v2).unwrap();
-        service.load_law(dependent).unwrap();
+        service.load_law(&base("2024-01-01", 100)).unwrap();
+        service.load_law(&base("2025-01-01", 200)).unwrap();
+        service.load_law(consumer).unwrap();
+        let evaluate = |referencedate: BTreeMap<String, Value>| {
+            let params =
+                BTreeMap::from([("referencedate".to_string(), Value::Object(referencedate))]);
+            service
+                .evaluate_law_output("referencedate_consumer", "result", params, "")
+                .unwrap()
+        };
 
-        // Reference date 2024-06-15 should use v1 (BASE_VALUE=100)
+        let result = evaluate(BTreeMap::from([(
+            "iso".to_string(),
+            Value::String("2024-06-15".to_string()),
+        )]));
+        assert_eq!(result.outputs.get("result"), Some(&Value::Int(100)));
+        assert_eq!(result.regulation_valid_from.as_deref(), Some("2024-01-01"));
+
+        let result = evaluate(BTreeMap::from([
+            ("year".to_string(), Value::Int(2025)),
+            ("month".to_string(), Value::Int(3)),
+            ("day".to_string(), Value::Int(1)),
+        ]));
+        assert_eq!(result.outputs.get("result"), Some(&Value::Int(200)));
+    }
+
+    #[test]
+    fn test_evaluate_law_output_at_defaults_to_today() {
+        let version = |valid_from: &str, value: i64| {
+            format!(
+                r#"
+$id: dated_law
+regulatory_layer: WET
+publication_date: '{valid_from}'
+valid_from: '{valid_from}'
+articles:
+  - number: '1'
+    text: Dated value
+    machine_readable:
+      definitions:
+        VALUE:
+          value: {value}
+      execution:
+        output:
+          - name: value
+            type: number
+        actions:
+          - output: value
+            value: $VALUE
+"#
+            )
+        };
+        let mut service = LawExecutionService::new();
+        service.load_law(&version("2024-01-01", 1)).unwrap();
+        service.load_law(&version("2025-01-01", 2)).unwrap();
+        service.load_law(&version("2026-01-01", 3)).unwrap();
+        service.set_clock(Box::new(crate::clock::FixedClock(
+            NaiveDate::from_ymd_opt(2025, 6, 15).unwrap(),
+        )));
+
+        // None: today (2025-06-15) selects the latest version already in force
         let result = service
-            .evaluate_law_output(
-                "cross_law_consumer",
-                "result",
-                BTreeMap::new(),
-                "2024-06-15",
+            .evaluate_law_output_at("dated_law", "value", BTreeMap::new(), None)
+            .unwrap();
+        assert_eq!(result.outputs.get("value"), Some(&Value::Int(2)));
+        assert_eq!(result.regulation_valid_from.as_deref(), Some("2025-01-01"));
+
+        // An explicit date still wins over the clock
+        let result = service
+            .evaluate_law_output_at("dated_law", "value", BTreeMap::new(), Some("2024-06-15"))
+            .unwrap();
+        assert_eq!(result.outputs.get("value"), Some(&Value::Int(1)));
+    }
+
+    // -------------------------------------------------------------------------
+    // DataSourceRegistry Integration Tests
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn test_data_registry_provides_input() {
+        // A law references a regulation for an input, but the data registry
+        // provides the value directly. The referenced regulation is NOT loaded,
+        // proving the registry short-circuits cross-law resolution.
+        let law = r#"
+$id: registry_test_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Has external reference resolved by registry
+    machine_readable:
+      execution:
+        parameters:
+          - name: BSN
+            type: string
+            required: true
+        input:
+          - name: external_value
+            type: number
+            source:
+              regulation: nonexistent_law
+              output: some_output
+              parameters:
+                BSN: $BSN
+        output:
+          - name: result
+            type: number
+        actions:
+          - output: result
+            operation: MULTIPLY
+            values:
+              - $external_value
+              - 3
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(law).unwrap();
+
+        // Register data source with the input field
+        let mut record = BTreeMap::new();
+        record.insert("BSN".to_string(), Value::String("123".to_string()));
+        record.insert("external_value".to_string(), Value::Int(42));
+
+        service
+            .register_dict_source("test_data", "BSN", vec![record])
+            .unwrap();
+
+        let mut params = BTreeMap::new();
+        params.insert("BSN".to_string(), Value::String("123".to_string()));
+
+        let result = service
+            .evaluate_law_output("registry_test_law", "result", params, "2025-01-01")
+            .unwrap();
+
+        // result = 42 * 3 = 126
+        assert_eq!(result.outputs.get("result"), Some(&Value::Int(126)));
+    }
+
+    #[test]
+    fn test_check_resolvable_reports_missing_version() {
+        let mut service = LawExecutionService::new();
+        service.load_law(make_dependent_law()).unwrap();
+        service
+            .load_law(&make_base_law().replace(
+                "publication_date: '2025-01-01'",
+                "publication_date: '2025-01-01'\nvalid_from: '2026-01-01'",
+            ))
+            .unwrap();
+
+        assert!(service
+            .check_resolvable("dependent_law", "doubled_value", "2026-06-01")
+            .unwrap()
+            .is_empty());
+        let missing = service
+            .check_resolvable("dependent_law", "doubled_value", "2025-06-01")
+            .unwrap();
+        assert_eq!(
+            missing,
+            vec![MissingRef {
+                law_id: "base_law".to_string(),
+                output: "base_value".to_string(),
+                referenced_by: Some(("dependent_law".to_string(), "1".to_string())),
+                kind: MissingRefKind::NoApplicableVersion,
+            }]
+        );
+
+        let missing = service
+            .check_resolvable("unknown_law", "anything", "2026-06-01")
+            .unwrap();
+        assert_eq!(missing[0].kind, MissingRefKind::LawNotLoaded);
+
+        // A malformed date is an error, not a check of the latest versions
+        assert!(matches!(
+            service.check_resolvable("dependent_law", "doubled_value", "2025-13-01"),
+            Err(EngineError::InvalidDate(_))
+        ));
+    }
+
+    #[test]
+    fn test_call_counts_reflect_memoization_in_a_diamond() {
+        // diamond_law reads left_law and right_law, which both read base_law
+        let branch = |id: &str| {
+            format!(
+                r#"
+$id: {id}
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Doubles the base value
+    machine_readable:
+      execution:
+        input:
+          - name: base
+            type: number
+            source:
+              regulation: base_law
+              output: base_value
+        output:
+          - name: {id}_value
+            type: number
+        actions:
+          - output: {id}_value
+            value:
+              operation: MULTIPLY
+              values: [$base, 2]
+"#
             )
+        };
+        let diamond = r#"
+$id: diamond_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Adds both branches
+    machine_readable:
+      execution:
+        input:
+          - name: left
+            type: number
+            source:
+              regulation: left_law
+              output: left_law_value
+          - name: right
+            type: number
+            source:
+              regulation: right_law
+              output: right_law_value
+        output:
+          - name: total
+            type: number
+        actions:
+          - output: total
+            value:
+              operation: ADD
+              values: [$left, $right]
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(make_base_law()).unwrap();
+        service.load_law(&branch("left_law")).unwrap();
+        service.load_law(&branch("right_law")).unwrap();
+        service.load_law(diamond).unwrap();
+
+        let result = service
+            .evaluate_law_output("diamond_law", "total", BTreeMap::new(), "2025-01-01")
             .unwrap();
+
+        assert_eq!(result.outputs.get("total"), Some(&Value::Int(400)));
         assert_eq!(
-            result.outputs.get("result"),
-            Some(&Value::Int(100)),
-            "2024 reference date should resolve to v1 (100)"
+            result.call_counts,
+            CallCounts {
+                uri_calls: 4,
+                delegation_calls: 0,
+                cache_hits: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_cross_law_call_budget() {
+        let sub_law = |i: usize| {
+            format!(
+                r#"
+$id: sub_law_{i}
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Generated sub-law
+    machine_readable:
+      execution:
+        output:
+          - name: amount
+            type: number
+        actions:
+          - output: amount
+            value: {i}
+"#
+            )
+        };
+        let inputs: String = (0..5)
+            .map(|i| {
+                format!(
+                    "          - name: amount_{i}\n            type: number\n            source:\n              regulation: sub_law_{i}\n              output: amount\n"
+                )
+            })
+            .collect();
+        let fan_out = format!(
+            r#"
+$id: fan_out_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Reads every sub-law
+    machine_readable:
+      execution:
+        input:
+{inputs}        output:
+          - name: total
+            type: number
+        actions:
+          - output: total
+            value:
+              operation: ADD
+              values: [$amount_0, $amount_1, $amount_2, $amount_3, $amount_4]
+"#
         );
+        let mut service = LawExecutionService::new();
+        for i in 0..5 {
+            service.load_law(&sub_law(i)).unwrap();
+        }
+        service.load_law(&fan_out).unwrap();
+        let evaluate = |service: &LawExecutionService| {
+            service.evaluate_law_output("fan_out_law", "total", BTreeMap::new(), "2025-01-01")
+        };
 
-        // Reference date 2025-06-15 should use v2 (BASE_VALUE=200)
-        let result = service
-            .evaluate_law_output(
-                "cross_law_consumer",
-                "result",
-                BTreeMap::new(),
-                "2025-06-15",
-            )
-            .unwrap();
         assert_eq!(
-            result.outputs.get("result"),
-            Some(&Value::Int(200)),
-            "2025 reference date should resolve to v2 (200)"
+            evaluate(&service).unwrap().outputs.get("total"),
+            Some(&Value::Int(10))
         );
+        service.set_max_cross_law_calls(3);
+        assert!(matches!(
+            evaluate(&service),
+            Err(EngineError::CallBudgetExceeded(3))
+        ));
     }
 
-    // -------------------------------------------------------------------------
-    // DataSourceRegistry Integration Tests
-    // -------------------------------------------------------------------------
-
     #[test]
-    fn test_data_registry_provides_input() {
-        // A law references a regulation for an input, but the data registry
-        // provides the value directly. The referenced regulation is NOT loaded,
-        // proving the registry short-circuits cross-law resolution.
+    fn test_replay_resolution_snapshot_without_data_source() {
         let law = r#"
-$id: registry_test_law
+$id: snapshot_law
 regulatory_layer: WET
 publication_date: '2025-01-01'
 articles:
   - number: '1'
-    text: Has external reference resolved by registry
+    text: Triples a value supplied by a data source
     machine_readable:
       execution:
         parameters:
@@ -2942,44 +6329,187 @@ articles:
             type: string
             required: true
         input:
-          - name: external_value
+          - name: inkomen
             type: number
-            source:
-              regulation: nonexistent_law
-              output: some_output
-              parameters:
-                BSN: $BSN
+            source: {}
         output:
           - name: result
             type: number
         actions:
           - output: result
-            operation: MULTIPLY
-            values:
-              - $external_value
-              - 3
+            value:
+              operation: MULTIPLY
+              values:
+                - $inkomen
+                - 3
 "#;
         let mut service = LawExecutionService::new();
         service.load_law(law).unwrap();
-
-        // Register data source with the input field
+        service.set_capture_resolution_snapshot(true);
         let mut record = BTreeMap::new();
         record.insert("BSN".to_string(), Value::String("123".to_string()));
-        record.insert("external_value".to_string(), Value::Int(42));
-
+        record.insert("inkomen".to_string(), Value::Int(42));
         service
-            .register_dict_source("test_data", "BSN", vec![record])
+            .register_dict_source("brp", "BSN", vec![record])
             .unwrap();
-
         let mut params = BTreeMap::new();
         params.insert("BSN".to_string(), Value::String("123".to_string()));
 
+        let original = service
+            .evaluate_law_output("snapshot_law", "result", params, "2025-01-01")
+            .unwrap();
+        let snapshot = original.resolution_snapshot.clone().unwrap();
+        assert_eq!(snapshot.inputs.len(), 1);
+        assert_eq!(snapshot.inputs[0].source, "brp");
+        assert_eq!(snapshot.inputs[0].value, Value::Int(42));
+
+        assert!(service.remove_data_source("brp"));
+        let replayed = service.evaluate_from_snapshot(&snapshot).unwrap();
+        assert_eq!(replayed.outputs, original.outputs);
+        assert_eq!(replayed.outputs.get("result"), Some(&Value::Int(126)));
+    }
+
+    /// Data source that sleeps on every lookup, simulating a slow backend.
+    struct SlowDataSource {
+        delay: std::time::Duration,
+    }
+
+    impl DataSource for SlowDataSource {
+        fn name(&self) -> &str {
+            "slow"
+        }
+
+        fn priority(&self) -> i32 {
+            0
+        }
+
+        fn source_type(&self) -> &str {
+            "test"
+        }
+
+        fn has_field(&self, _field: &str) -> bool {
+            true
+        }
+
+        fn get(&self, _field: &str, _criteria: &BTreeMap<String, Value>) -> Option<Value> {
+            std::thread::sleep(self.delay);
+            Some(Value::Int(1))
+        }
+
+        fn fields(&self) -> Vec<&str> {
+            vec!["a", "b", "c"]
+        }
+    }
+
+    #[test]
+    fn test_evaluate_with_timeout() {
+        let law = r#"
+$id: slow_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Needs three slow lookups
+    machine_readable:
+      execution:
+        input:
+          - name: a
+            type: number
+            source: {}
+          - name: b
+            type: number
+            source: {}
+          - name: c
+            type: number
+            source: {}
+        output:
+          - name: total
+            type: number
+        actions:
+          - output: total
+            operation: ADD
+            values:
+              - $a
+              - $b
+              - $c
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(law).unwrap();
+        service.add_data_source(Box::new(SlowDataSource {
+            delay: std::time::Duration::from_millis(50),
+        }));
+
+        let timeout = std::time::Duration::from_millis(20);
+        let result = service.evaluate_law_output_with_timeout(
+            "slow_law",
+            "total",
+            BTreeMap::new(),
+            "2025-01-01",
+            timeout,
+        );
+        assert!(
+            matches!(result, Err(EngineError::Timeout(t)) if t == timeout),
+            "expected timeout, got {:?}",
+            result
+        );
+
+        // A generous limit lets the evaluation finish
         let result = service
-            .evaluate_law_output("registry_test_law", "result", params, "2025-01-01")
+            .evaluate_law_output_with_timeout(
+                "slow_law",
+                "total",
+                BTreeMap::new(),
+                "2025-01-01",
+                std::time::Duration::from_secs(30),
+            )
             .unwrap();
+        assert_eq!(result.outputs.get("total"), Some(&Value::Int(3)));
+    }
 
-        // result = 42 * 3 = 126
-        assert_eq!(result.outputs.get("result"), Some(&Value::Int(126)));
+    #[test]
+    fn test_timeout_reported_after_a_step_runs_past_it() {
+        let law = r#"
+$id: one_slow_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Needs one slow lookup
+    machine_readable:
+      execution:
+        input:
+          - name: a
+            type: number
+            source: {}
+        output:
+          - name: total
+            type: number
+        actions:
+          - output: total
+            value: $a
+"#;
+        let mut service = LawExecutionService::new();
+        service.load_law(law).unwrap();
+        let delay = std::time::Duration::from_millis(50);
+        service.add_data_source(Box::new(SlowDataSource { delay }));
+
+        // The only lookup starts before the deadline and runs past it; the
+        // finished result is discarded in favour of a timeout
+        let started = Instant::now();
+        let timeout = std::time::Duration::from_millis(10);
+        let result = service.evaluate_law_output_with_timeout(
+            "one_slow_law",
+            "total",
+            BTreeMap::new(),
+            "2025-01-01",
+            timeout,
+        );
+        assert!(started.elapsed() >= delay);
+        assert!(
+            matches!(result, Err(EngineError::Timeout(t)) if t == timeout),
+            "expected timeout, got {:?}",
+            result
+        );
     }
 
     #[test]
@@ -3241,6 +6771,9 @@ articles:
             result.outputs.get("redelijk_percentage"),
             Some(&Value::Int(6))
         );
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].kind, WarningKind::DefaultedInput);
+        assert_eq!(result.warnings[0].subject, "redelijk_percentage");
     }
 
     #[test]
@@ -3307,6 +6840,233 @@ articles:
             .unwrap();
 
         assert_eq!(result.outputs.get("percentage"), Some(&Value::Int(4)));
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_ioc_simulate_delegation_absence() {
+        let law_yaml = r#"
+$id: simulated_absence_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Delegated percentage
+    machine_readable:
+      open_terms:
+        - id: percentage
+          type: number
+          required: true
+          default:
+            actions:
+              - output: percentage
+                value: 6
+      execution:
+        output:
+          - name: percentage
+            type: number
+        actions:
+          - output: percentage
+            value: "$percentage"
+"#;
+
+        let impl_yaml = r#"
+$id: simulated_absence_verordening
+regulatory_layer: GEMEENTELIJKE_VERORDENING
+publication_date: '2025-01-01'
+valid_from: '2025-01-01'
+articles:
+  - number: '1'
+    text: Het percentage bedraagt 4
+    machine_readable:
+      implements:
+        - law: simulated_absence_law
+          article: '1'
+          open_term: percentage
+      execution:
+        output:
+          - name: percentage
+            type: number
+        actions:
+          - output: percentage
+            value: 4
+"#;
+
+        let mut service = LawExecutionService::new();
+        service.load_law(law_yaml).unwrap();
+        service.load_law(impl_yaml).unwrap();
+
+        let evaluate = |service: &LawExecutionService| {
+            service
+                .evaluate_law_output(
+                    "simulated_absence_law",
+                    "percentage",
+                    BTreeMap::new(),
+                    "2025-07-01",
+                )
+                .unwrap()
+                .outputs
+                .get("percentage")
+                .cloned()
+        };
+
+        assert_eq!(evaluate(&service), Some(Value::Int(4)));
+
+        service.set_simulate_delegation_absence(true);
+        assert_eq!(evaluate(&service), Some(Value::Int(6)));
+    }
+
+    #[test]
+    fn test_ioc_delegated_lookup_by_key() {
+        let law_yaml = r#"
+$id: keyed_delegation_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Het bedrag per gemeente wordt bij ministeriele regeling vastgesteld
+    machine_readable:
+      open_terms:
+        - id: bedragen_per_gemeente
+          type: object
+          required: true
+      execution:
+        parameters:
+          - name: gemeente_code
+            type: string
+            required: true
+        output:
+          - name: bedrag
+            type: amount
+        actions:
+          - output: bedrag
+            value:
+              operation: DELEGATED_LOOKUP
+              subject: $bedragen_per_gemeente
+              select_on: $gemeente_code
+"#;
+
+        let impl_yaml = r#"
+$id: keyed_delegation_regeling
+regulatory_layer: MINISTERIELE_REGELING
+publication_date: '2025-01-01'
+valid_from: '2025-01-01'
+articles:
+  - number: '1'
+    text: Bedragen per gemeente
+    machine_readable:
+      implements:
+        - law: keyed_delegation_law
+          article: '1'
+          open_term: bedragen_per_gemeente
+      execution:
+        output:
+          - name: bedragen_per_gemeente
+            type: object
+        actions:
+          - output: bedragen_per_gemeente
+            value:
+              GM0344: 95000
+              GM0363: 120000
+"#;
+
+        let mut service = LawExecutionService::new();
+        service.load_law(law_yaml).unwrap();
+        service.load_law(impl_yaml).unwrap();
+
+        let bedrag = |code: &str| {
+            let mut params = BTreeMap::new();
+            params.insert("gemeente_code".to_string(), Value::String(code.to_string()));
+            service
+                .evaluate_law_output("keyed_delegation_law", "bedrag", params, "2025-07-01")
+                .unwrap()
+                .outputs
+                .get("bedrag")
+                .cloned()
+        };
+
+        assert_eq!(bedrag("GM0363"), Some(Value::Int(120000)));
+        assert_eq!(bedrag("GM0344"), Some(Value::Int(95000)));
+        assert_eq!(bedrag("GM0599"), Some(Value::Null));
+    }
+
+    #[test]
+    fn test_ioc_delegated_or_default() {
+        let law_yaml = r#"
+$id: local_percentage_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Bij verordening kan een percentage worden vastgesteld
+    machine_readable:
+      open_terms:
+        - id: lokaal_percentage
+          type: number
+          required: false
+      execution:
+        output:
+          - name: percentage
+            type: number
+        actions:
+          - output: percentage
+            value:
+              operation: DELEGATED_OR_DEFAULT
+              subject: $lokaal_percentage
+              default: 0
+"#;
+
+        let impl_yaml = r#"
+$id: local_percentage_verordening
+regulatory_layer: GEMEENTELIJKE_VERORDENING
+publication_date: '2025-01-01'
+valid_from: '2025-01-01'
+articles:
+  - number: '1'
+    text: Het percentage bedraagt 3
+    machine_readable:
+      implements:
+        - law: local_percentage_law
+          article: '1'
+          open_term: lokaal_percentage
+      execution:
+        output:
+          - name: lokaal_percentage
+            type: number
+        actions:
+          - output: lokaal_percentage
+            value: 3
+"#;
+
+        let evaluate = |service: &LawExecutionService| {
+            let result = service
+                .evaluate_law_output_with_trace(
+                    "local_percentage_law",
+                    "percentage",
+                    BTreeMap::new(),
+                    "2025-07-01",
+                )
+                .unwrap();
+            let trace = result.trace.as_ref().unwrap().render_box_drawing();
+            (result.outputs.get("percentage").cloned(), trace)
+        };
+
+        let mut service = LawExecutionService::new();
+        service.load_law(law_yaml).unwrap();
+        let (percentage, trace) = evaluate(&service);
+        assert_eq!(percentage, Some(Value::Int(0)));
+        assert!(
+            trace.contains("DELEGATED_OR_DEFAULT(default) = 0"),
+            "{trace}"
+        );
+
+        service.load_law(impl_yaml).unwrap();
+        let (percentage, trace) = evaluate(&service);
+        assert_eq!(percentage, Some(Value::Int(3)));
+        assert!(
+            trace.contains("DELEGATED_OR_DEFAULT(delegated) = 3"),
+            "{trace}"
+        );
     }
 
     #[test]
@@ -20,13 +20,28 @@
 //! let _guard = init_otel_subscriber("regelrecht-engine")?;
 //! // Now all tracing events are exported as OTel spans/events
 //! ```
+//!
+//! To export the engine's execution trace itself (one span per resolution,
+//! operation and cross-law call), forward a trace builder to an [`OtelTraceSink`]:
+//!
+//! ```ignore
+//! let sink = Rc::new(OtelTraceSink::new(opentelemetry::global::tracer("regelrecht")));
+//! let builder = TraceBuilder::new_untimed().with_sink(sink);
+//! service.evaluate_law_output_with_trace_builder(law_id, output, params, date, builder)?;
+//! ```
 
-use opentelemetry::trace::TracerProvider as _;
+use std::cell::RefCell;
+
+use opentelemetry::trace::{TraceContextExt, Tracer, TracerProvider as _};
+use opentelemetry::{Context, KeyValue};
 use opentelemetry_sdk::trace::SdkTracerProvider;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::EnvFilter;
 
+use crate::trace::{resolve_type_name, TraceEvent, TraceSink};
+use crate::types::PathNodeType;
+
 /// Initialize an OpenTelemetry subscriber with OTLP export over HTTP.
 ///
 /// Sets up a composed `tracing_subscriber` with:
@@ -96,3 +111,183 @@ impl Drop for OtelGuard {
         }
     }
 }
+
+/// Trace sink that mirrors the execution trace as OpenTelemetry spans.
+///
+/// Each pushed trace node starts a span named after the node, as a child of
+/// the enclosing node's span (or of the current OTel context for the root),
+/// and ends it when the node is popped. Node type, result, message and resolve
+/// type are recorded as `regelrecht.*` attributes.
+pub struct OtelTraceSink<T: Tracer> {
+    tracer: T,
+    /// Contexts of the spans that are still open, innermost last
+    open: RefCell<Vec<Context>>,
+}
+
+impl<T> OtelTraceSink<T>
+where
+    T: Tracer,
+    T::Span: Send + Sync + 'static,
+{
+    /// Create a sink that starts its spans with `tracer`.
+    pub fn new(tracer: T) -> Self {
+        Self {
+            tracer,
+            open: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl<T: Tracer> std::fmt::Debug for OtelTraceSink<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OtelTraceSink")
+            .field("open_spans", &self.open.borrow().len())
+            .finish()
+    }
+}
+
+impl<T> TraceSink for OtelTraceSink<T>
+where
+    T: Tracer,
+    T::Span: Send + Sync + 'static,
+{
+    fn record(&self, event: TraceEvent<'_>) {
+        let mut open = self.open.borrow_mut();
+        match event {
+            TraceEvent::Push { name, node_type } => {
+                let parent = open.last().cloned().unwrap_or_else(Context::current);
+                let span = self.tracer.start_with_context(name.to_string(), &parent);
+                let cx = parent.with_span(span);
+                cx.span().set_attribute(KeyValue::new(
+                    "regelrecht.node_type",
+                    node_type_name(&node_type),
+                ));
+                open.push(cx);
+            }
+            TraceEvent::Pop => {
+                if let Some(cx) = open.pop() {
+                    cx.span().end();
+                }
+            }
+            TraceEvent::Result(value) => {
+                if let Some(cx) = open.last() {
+                    cx.span()
+                        .set_attribute(KeyValue::new("regelrecht.result", value.to_string()));
+                }
+            }
+            TraceEvent::Message(msg) => {
+                if let Some(cx) = open.last() {
+                    cx.span()
+                        .set_attribute(KeyValue::new("regelrecht.message", msg.to_string()));
+                }
+            }
+            TraceEvent::ResolveType(rt) => {
+                if let Some(cx) = open.last() {
+                    cx.span().set_attribute(KeyValue::new(
+                        "regelrecht.resolve_type",
+                        resolve_type_name(&rt),
+                    ));
+                }
+            }
+            TraceEvent::SourceSpan(span) => {
+                if let Some(cx) = open.last() {
+                    cx.span()
+                        .set_attribute(KeyValue::new("regelrecht.source_text", span.text.clone()));
+                }
+            }
+        }
+    }
+}
+
+/// Attribute value for a trace node type (matches its serialized form).
+fn node_type_name(node_type: &PathNodeType) -> &'static str {
+    match node_type {
+        PathNodeType::Resolve => "resolve",
+        PathNodeType::Operation => "operation",
+        PathNodeType::Action => "action",
+        PathNodeType::Requirement => "requirement",
+        PathNodeType::CrossLawReference => "cross_law_reference",
+        PathNodeType::Article => "article",
+        PathNodeType::Cached => "cached",
+        PathNodeType::OpenTermResolution => "open_term_resolution",
+        PathNodeType::HookResolution => "hook_resolution",
+        PathNodeType::OverrideResolution => "override_resolution",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::LawExecutionService;
+    use crate::trace::TraceBuilder;
+    use crate::types::Value;
+    use opentelemetry_sdk::trace::InMemorySpanExporter;
+    use std::collections::BTreeMap;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_otel_sink_mirrors_trace_hierarchy() {
+        let exporter = InMemorySpanExporter::default();
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+
+        let mut service = LawExecutionService::new();
+        service
+            .load_law(
+                r#"
+$id: otel_law
+regulatory_layer: WET
+publication_date: '2025-01-01'
+articles:
+  - number: '1'
+    text: Doubles the input
+    machine_readable:
+      execution:
+        parameters:
+          - name: amount
+            type: number
+        output:
+          - name: doubled
+            type: number
+        actions:
+          - output: doubled
+            operation: MULTIPLY
+            values:
+              - $amount
+              - 2
+"#,
+            )
+            .unwrap();
+
+        let sink = Rc::new(OtelTraceSink::new(provider.tracer("test")));
+        let builder = TraceBuilder::new_untimed().with_sink(sink);
+        let result = service
+            .evaluate_law_output_with_trace_builder(
+                "otel_law",
+                "doubled",
+                BTreeMap::from([("amount".to_string(), Value::Int(21))]),
+                "2025-06-01",
+                builder,
+            )
+            .unwrap();
+        assert_eq!(result.outputs.get("doubled"), Some(&Value::Int(42)));
+
+        let spans = exporter.get_finished_spans().unwrap();
+        let find = |name: &str| spans.iter().find(|s| s.name == name).unwrap();
+        let root = find("otel_law (doubled)");
+        let multiply = find("MULTIPLY");
+        let amount = find("amount");
+
+        assert_eq!(amount.parent_span_id, multiply.span_context.span_id());
+        assert!(spans
+            .iter()
+            .any(|s| s.parent_span_id == root.span_context.span_id()));
+        assert!(multiply
+            .attributes
+            .contains(&KeyValue::new("regelrecht.node_type", "operation")));
+        assert!(multiply
+            .attributes
+            .contains(&KeyValue::new("regelrecht.result", "42")));
+    }
+}
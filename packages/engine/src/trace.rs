@@ -31,8 +31,13 @@
 
 use crate::types::{PathNodeType, ResolveType, Value};
 use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::rc::Rc;
 use std::time::Instant;
 
+/// Placeholder rendered in place of a redacted value.
+pub const REDACTED: &str = "[REDACTED]";
+
 /// A node in the execution trace tree.
 ///
 /// Each node represents a single step in the execution process, such as:
@@ -69,6 +74,38 @@ pub struct PathNode {
     /// Free-form message for trace output (e.g., "Resolving from PARAMETERS: 999993653")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
+
+    /// Whether the result and message were withheld because the name is
+    /// configured as sensitive (see [`TraceBuilder::redact`])
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub redacted: bool,
+
+    /// Decimal scale of a monetary result, rendered with that many decimals
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub money: Option<u32>,
+
+    /// Tag selecting a formatter from a [`FormatterRegistry`] for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// Legal text that the step implements, set on the action node of each output
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_span: Option<SourceSpan>,
+}
+
+/// The part of an article's text an output implements.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SourceSpan {
+    /// Law containing the article
+    pub law_id: String,
+    /// Article number
+    pub article: String,
+    /// Start of the span in the article text, in characters
+    pub start: usize,
+    /// End of the span in the article text (exclusive), in characters
+    pub end: usize,
+    /// The quoted text
+    pub text: String,
 }
 
 impl PathNode {
@@ -82,6 +119,10 @@ impl PathNode {
             children: Vec::new(),
             duration_us: None,
             message: None,
+            redacted: false,
+            money: None,
+            tag: None,
+            source_span: None,
         }
     }
 
@@ -115,6 +156,12 @@ impl PathNode {
         self
     }
 
+    /// Set the tag used to pick a formatter for the result.
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
     /// Render the trace as a human-readable tree string.
     ///
     /// Produces output like:
@@ -193,8 +240,14 @@ impl PathNode {
         }
 
         // Add result if present
-        if let Some(ref result) = self.result {
-            line.push_str(&format!(" = {}", format_value_compact(result)));
+        if self.redacted {
+            line.push_str(&format!(" = {}", REDACTED));
+        } else if let Some(ref result) = self.result {
+            if let Some(scale) = self.money {
+                line.push_str(&format!(" = {}", result.format_money(scale)));
+            } else {
+                line.push_str(&format!(" = {}", format_value_compact(result)));
+            }
         }
 
         // Add duration if present (and significant)
@@ -255,15 +308,101 @@ impl ScopeColumn {
     }
 }
 
+/// Renders a traced result value for display.
+pub type ValueFormatter = Box<dyn Fn(&Value) -> String>;
+
+/// Tag a node marked with [`TraceBuilder::mark_money`] is rendered under.
+pub const MONEY_TAG: &str = "money";
+
+/// Value formatters by tag, consulted when rendering a box-drawing trace.
+///
+/// A node whose tag has no registered formatter keeps the built-in
+/// formatting, so an empty registry renders exactly like
+/// [`PathNode::render_box_drawing`]. Money results use the [`MONEY_TAG`]
+/// formatter when no explicit tag is set.
+#[derive(Default)]
+pub struct FormatterRegistry {
+    formatters: HashMap<String, ValueFormatter>,
+}
+
+impl std::fmt::Debug for FormatterRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut tags: Vec<&String> = self.formatters.keys().collect();
+        tags.sort();
+        f.debug_struct("FormatterRegistry")
+            .field("tags", &tags)
+            .finish()
+    }
+}
+
+impl FormatterRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `formatter` for values tagged `tag`, replacing any earlier one.
+    pub fn register(
+        &mut self,
+        tag: impl Into<String>,
+        formatter: impl Fn(&Value) -> String + 'static,
+    ) {
+        self.formatters.insert(tag.into(), Box::new(formatter));
+    }
+
+    /// Get the formatter registered for `tag`.
+    pub fn get(&self, tag: &str) -> Option<&ValueFormatter> {
+        self.formatters.get(tag)
+    }
+}
+
 impl PathNode {
     /// Render the trace using box-drawing characters for human-readable output.
     pub fn render_box_drawing(&self) -> String {
+        self.render_box_drawing_with(&FormatterRegistry::default())
+    }
+
+    /// Render the trace using box-drawing characters, formatting tagged
+    /// results with the matching formatter from `formatters`.
+    pub fn render_box_drawing_with(&self, formatters: &FormatterRegistry) -> String {
         let mut lines = Vec::new();
         let mut cols = Vec::new();
-        self.render_node(&mut lines, &mut cols, true, false);
+        self.render_node(&mut lines, &mut cols, true, false, formatters);
         lines.join("\n")
     }
 
+    /// Render a title line followed by sibling trees, using box-drawing characters.
+    pub(crate) fn render_box_drawing_forest(title: &str, nodes: &[PathNode]) -> String {
+        let formatters = FormatterRegistry::default();
+        let mut lines = vec![title.to_string()];
+        let mut cols = Vec::new();
+        for (i, node) in nodes.iter().enumerate() {
+            node.render_node(
+                &mut lines,
+                &mut cols,
+                i == nodes.len() - 1,
+                false,
+                &formatters,
+            );
+        }
+        lines.join("\n")
+    }
+
+    /// Format this node's result, preferring the formatter registered for its tag.
+    fn display_result(&self, value: &Value, formatters: &FormatterRegistry) -> String {
+        let tag = self
+            .tag
+            .as_deref()
+            .or_else(|| self.money.map(|_| MONEY_TAG));
+        match tag.and_then(|tag| formatters.get(tag)) {
+            Some(format) => format(value),
+            None => match self.money {
+                Some(scale) => value.format_money(scale),
+                None => format_value_display(value),
+            },
+        }
+    }
+
     fn prefix(cols: &[ScopeColumn]) -> String {
         cols.iter().map(|c| c.as_str()).collect()
     }
@@ -281,10 +420,15 @@ impl PathNode {
     /// Render children in a double (cross-law) scope.
     /// Children get `is_double=true` so they use ╟──/╙── connectors and ║ continuation.
     /// No column manipulation — each child pushes its own continuation column.
-    fn render_double_children(&self, lines: &mut Vec<String>, cols: &mut Vec<ScopeColumn>) {
+    fn render_double_children(
+        &self,
+        lines: &mut Vec<String>,
+        cols: &mut Vec<ScopeColumn>,
+        formatters: &FormatterRegistry,
+    ) {
         let child_count = self.children.len();
         for (i, child) in self.children.iter().enumerate() {
-            child.render_node(lines, cols, i == child_count - 1, true);
+            child.render_node(lines, cols, i == child_count - 1, true, formatters);
         }
     }
 
@@ -293,11 +437,12 @@ impl PathNode {
         lines: &mut Vec<String>,
         cols: &mut Vec<ScopeColumn>,
         extra_last: bool,
+        formatters: &FormatterRegistry,
     ) {
         let child_count = self.children.len();
         for (i, child) in self.children.iter().enumerate() {
             let is_last = i == child_count - 1 && !extra_last;
-            child.render_node(lines, cols, is_last, false);
+            child.render_node(lines, cols, is_last, false, formatters);
         }
     }
 
@@ -307,6 +452,7 @@ impl PathNode {
         cols: &mut Vec<ScopeColumn>,
         is_last: bool,
         is_double: bool,
+        formatters: &FormatterRegistry,
     ) {
         let pfx = Self::prefix(cols);
         let connector = match (is_double, is_last) {
@@ -324,7 +470,7 @@ impl PathNode {
                 }
                 lines.push(format!("{}╟──Evaluating rules for {}", pfx, self.name));
                 cols.push(ScopeColumn::Double);
-                self.render_double_children(lines, cols);
+                self.render_double_children(lines, cols, formatters);
                 cols.pop();
                 if let Some(ref result) = self.result {
                     let pfx = Self::prefix(cols);
@@ -337,7 +483,7 @@ impl PathNode {
                         "{}╙──Result: {} = {}",
                         pfx,
                         output_name,
-                        format_value_display(result)
+                        self.display_result(result, formatters)
                     ));
                 }
             }
@@ -349,7 +495,7 @@ impl PathNode {
                 }
                 let has_result = self.result.is_some();
                 cols.push(Self::continuation_col(is_last, is_double));
-                self.render_single_children(lines, cols, has_result);
+                self.render_single_children(lines, cols, has_result, formatters);
                 if let Some(ref result) = self.result {
                     let pfx = Self::prefix(cols);
                     if result.to_bool() {
@@ -360,6 +506,18 @@ impl PathNode {
                 }
                 cols.pop();
             }
+            PathNodeType::Resolve if self.redacted => {
+                lines.push(format!(
+                    "{}{}Resolving ${}: {}",
+                    pfx,
+                    connector,
+                    self.name.to_uppercase(),
+                    REDACTED
+                ));
+                cols.push(Self::continuation_col(is_last, is_double));
+                self.render_single_children(lines, cols, false, formatters);
+                cols.pop();
+            }
             PathNodeType::Resolve => {
                 let child_count = self.children.len();
                 if child_count == 0 {
@@ -368,7 +526,7 @@ impl PathNode {
                         let val_str = self
                             .result
                             .as_ref()
-                            .map(format_value_display)
+                            .map(|v| self.display_result(v, formatters))
                             .unwrap_or_else(|| "?".to_string());
                         lines.push(format!(
                             "{}{}Resolving from {}: ${} = {}",
@@ -402,7 +560,7 @@ impl PathNode {
                         let val_str = self
                             .result
                             .as_ref()
-                            .map(format_value_display)
+                            .map(|v| self.display_result(v, formatters))
                             .unwrap_or_else(|| "?".to_string());
                         lines.push(format!(
                             "{}├──Resolving from {}: {}",
@@ -411,7 +569,7 @@ impl PathNode {
                     } else if let Some(ref msg) = self.message {
                         lines.push(format!("{}├──{}", child_pfx, msg));
                     }
-                    self.render_single_children(lines, cols, false);
+                    self.render_single_children(lines, cols, false, formatters);
                     cols.pop();
                 }
             }
@@ -422,7 +580,7 @@ impl PathNode {
                     let result_str = self
                         .result
                         .as_ref()
-                        .map(format_value_display)
+                        .map(|v| self.display_result(v, formatters))
                         .unwrap_or_else(|| "?".to_string());
                     lines.push(format!(
                         "{}{}Compute {} = {}",
@@ -430,7 +588,7 @@ impl PathNode {
                     ));
                 }
                 cols.push(Self::continuation_col(is_last, is_double));
-                self.render_single_children(lines, cols, false);
+                self.render_single_children(lines, cols, false, formatters);
                 cols.pop();
             }
             PathNodeType::Action => {
@@ -439,17 +597,16 @@ impl PathNode {
                 } else {
                     lines.push(format!("{}{}Computing {}", pfx, connector, self.name));
                 }
-                let has_result = self.result.is_some();
+                let has_result = self.result.is_some() || self.redacted;
                 cols.push(Self::continuation_col(is_last, is_double));
-                self.render_single_children(lines, cols, has_result);
-                if let Some(ref result) = self.result {
+                self.render_single_children(lines, cols, has_result, formatters);
+                if self.redacted {
                     let pfx = Self::prefix(cols);
-                    lines.push(format!(
-                        "{}└──Result: {} = {}",
-                        pfx,
-                        self.name,
-                        format_value_display(result)
-                    ));
+                    lines.push(format!("{}└──Result: {} = {}", pfx, self.name, REDACTED));
+                } else if let Some(ref result) = self.result {
+                    let pfx = Self::prefix(cols);
+                    let rendered = self.display_result(result, formatters);
+                    lines.push(format!("{}└──Result: {} = {}", pfx, self.name, rendered));
                 }
                 cols.pop();
             }
@@ -460,14 +617,14 @@ impl PathNode {
                     lines.push(format!("{}{}Reference: {}", pfx, connector, self.name));
                 }
                 cols.push(Self::continuation_col(is_last, is_double));
-                self.render_double_children(lines, cols);
+                self.render_double_children(lines, cols, formatters);
                 cols.pop();
             }
             PathNodeType::Cached => {
                 let result_str = self
                     .result
                     .as_ref()
-                    .map(|v| format!(": {}", format_value_display(v)))
+                    .map(|v| format!(": {}", self.display_result(v, formatters)))
                     .unwrap_or_default();
                 lines.push(format!(
                     "{}{}Cached: {}{}",
@@ -478,7 +635,7 @@ impl PathNode {
                 let result_str = self
                     .result
                     .as_ref()
-                    .map(|v| format!(": {}", format_value_display(v)))
+                    .map(|v| format!(": {}", self.display_result(v, formatters)))
                     .unwrap_or_default();
                 let msg = self.message.as_deref().unwrap_or(&self.name);
                 lines.push(format!(
@@ -486,26 +643,26 @@ impl PathNode {
                     pfx, connector, msg, result_str
                 ));
                 cols.push(Self::continuation_col(is_last, is_double));
-                self.render_double_children(lines, cols);
+                self.render_double_children(lines, cols, formatters);
                 cols.pop();
             }
             PathNodeType::HookResolution => {
                 let result_str = self
                     .result
                     .as_ref()
-                    .map(|v| format!(": {}", format_value_display(v)))
+                    .map(|v| format!(": {}", self.display_result(v, formatters)))
                     .unwrap_or_default();
                 let msg = self.message.as_deref().unwrap_or(&self.name);
                 lines.push(format!("{}{}HOOK: {}{}", pfx, connector, msg, result_str));
                 cols.push(Self::continuation_col(is_last, is_double));
-                self.render_double_children(lines, cols);
+                self.render_double_children(lines, cols, formatters);
                 cols.pop();
             }
             PathNodeType::OverrideResolution => {
                 let result_str = self
                     .result
                     .as_ref()
-                    .map(|v| format!(": {}", format_value_display(v)))
+                    .map(|v| format!(": {}", self.display_result(v, formatters)))
                     .unwrap_or_default();
                 let msg = self.message.as_deref().unwrap_or(&self.name);
                 lines.push(format!(
@@ -513,7 +670,7 @@ impl PathNode {
                     pfx, connector, msg, result_str
                 ));
                 cols.push(Self::continuation_col(is_last, is_double));
-                self.render_single_children(lines, cols, false);
+                self.render_single_children(lines, cols, false, formatters);
                 cols.pop();
             }
         }
@@ -534,16 +691,74 @@ impl PathNode {
             PathNodeType::OverrideResolution => "ovr",
         };
 
-        let result_str = self
-            .result
-            .as_ref()
-            .map(|v| format!("={}", format_value_compact(v)))
-            .unwrap_or_default();
+        let result_str = if self.redacted {
+            format!("={}", REDACTED)
+        } else {
+            self.result
+                .as_ref()
+                .map(|v| format!("={}", format_value_compact(v)))
+                .unwrap_or_default()
+        };
 
         format!("{}:{}{}", type_str, self.name, result_str)
     }
 }
 
+/// How [`PathNode::to_json`] writes floating-point numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FloatFormat {
+    /// serde_json's shortest round-trippable form of the exact `f64`.
+    #[default]
+    Native,
+    /// Rounded to [`CANONICAL_FLOAT_DIGITS`] significant digits, with `-0.0`
+    /// written as `0.0`. Differences in the last bits of a float, such as
+    /// those between platform math libraries or in summation order, vanish,
+    /// so snapshots of the JSON stay stable.
+    Canonical,
+}
+
+/// Significant digits kept by [`FloatFormat::Canonical`].
+pub const CANONICAL_FLOAT_DIGITS: usize = 15;
+
+impl PathNode {
+    /// Serialize the trace to JSON, writing floats as `floats` prescribes.
+    ///
+    /// [`FloatFormat::Native`] is the plain serde serialization. The canonical
+    /// form also writes object keys in sorted order.
+    pub fn to_json(&self, floats: FloatFormat) -> serde_json::Result<String> {
+        match floats {
+            FloatFormat::Native => serde_json::to_string(self),
+            FloatFormat::Canonical => {
+                let mut json = serde_json::to_value(self)?;
+                canonicalize_floats(&mut json);
+                serde_json::to_string(&json)
+            }
+        }
+    }
+}
+
+/// Round every float in a JSON tree to [`CANONICAL_FLOAT_DIGITS`] significant digits.
+fn canonicalize_floats(json: &mut serde_json::Value) {
+    match json {
+        serde_json::Value::Number(n) if n.is_f64() => {
+            let rounded = n
+                .as_f64()
+                .and_then(|f| {
+                    format!("{:.*e}", CANONICAL_FLOAT_DIGITS - 1, f)
+                        .parse::<f64>()
+                        .ok()
+                })
+                .map(|f| if f == 0.0 { 0.0 } else { f });
+            if let Some(number) = rounded.and_then(serde_json::Number::from_f64) {
+                *n = number;
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(canonicalize_floats),
+        serde_json::Value::Object(fields) => fields.values_mut().for_each(canonicalize_floats),
+        _ => {}
+    }
+}
+
 /// Format a Value compactly for trace output.
 fn format_value_compact(value: &Value) -> String {
     match value {
@@ -626,7 +841,7 @@ fn format_value_display(value: &Value) -> String {
 }
 
 /// Get a human-readable name for a ResolveType.
-fn resolve_type_name(rt: &ResolveType) -> &'static str {
+pub(crate) fn resolve_type_name(rt: &ResolveType) -> &'static str {
     match rt {
         ResolveType::Uri => "URI",
         ResolveType::Parameter => "PARAMETERS",
@@ -643,6 +858,77 @@ fn resolve_type_name(rt: &ResolveType) -> &'static str {
     }
 }
 
+/// A structured trace event, as delivered to a [`TraceSink`].
+///
+/// Events mirror the [`TraceBuilder`] calls: every `Push` is matched by a `Pop`,
+/// and `Result`/`Message`/`ResolveType`/`SourceSpan` apply to the most recently
+/// pushed node.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceEvent<'a> {
+    /// A new step started (variable resolution, operation, action, ...)
+    Push {
+        name: &'a str,
+        node_type: PathNodeType,
+    },
+    /// The current step finished
+    Pop,
+    /// The current step produced a value
+    Result(&'a Value),
+    /// Free-form message for the current step
+    Message(&'a str),
+    /// How the current (resolve) step obtained its value
+    ResolveType(ResolveType),
+    /// The legal text the current step implements
+    SourceSpan(&'a SourceSpan),
+}
+
+/// Receiver for trace events, for embedding applications that want to forward
+/// the engine's execution trace to their own logger or `tracing` spans.
+///
+/// Install one with [`TraceBuilder::with_sink`] or
+/// [`RuleContext::set_trace_sink`](crate::context::RuleContext::set_trace_sink); both
+/// attach it to the trace builder, which withholds values of redacted nodes.
+/// Sinks receive events through `&self`; use interior mutability to record them.
+pub trait TraceSink: std::fmt::Debug {
+    /// Handle a single trace event.
+    fn record(&self, event: TraceEvent<'_>);
+}
+
+/// A step recorded by a failure-only [`TraceBuilder`], replayed into a tree on demand.
+#[derive(Debug, Clone)]
+enum BufferedStep {
+    Push(String, PathNodeType),
+    Pop,
+    Result(Value),
+    Money(u32),
+    Tag(String),
+    Message(String),
+    ResolveType(ResolveType),
+    SourceSpan(Box<SourceSpan>),
+}
+
+/// Bounded buffer of the most recent steps, used by [`TraceBuilder::failure_only`].
+#[derive(Debug)]
+struct StepRing {
+    steps: VecDeque<BufferedStep>,
+    capacity: usize,
+}
+
+impl StepRing {
+    fn record(&mut self, step: BufferedStep) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.steps.len() == self.capacity {
+            self.steps.pop_front();
+        }
+        self.steps.push_back(step);
+    }
+}
+
+/// Name of the synthetic node that adopts steps whose parent fell out of the ring buffer.
+pub const EARLIER_STEPS: &str = "(earlier steps not retained)";
+
 /// A node being built, with timing information.
 #[derive(Debug)]
 struct BuildingNode {
@@ -665,6 +951,21 @@ pub struct TraceBuilder {
 
     /// Whether to record timing (Instant::now on push/pop)
     timed: bool,
+
+    /// Lowercased names whose values must not appear in the trace
+    redacted: BTreeSet<String>,
+
+    /// Failure-only mode: recent steps, materialized only on request
+    ring: Option<StepRing>,
+
+    /// Number of steps turned into trace nodes by `materialize_failure_trace`
+    materialized_steps: usize,
+
+    /// Sink that receives every recorded step as a [`TraceEvent`]
+    sink: Option<Rc<dyn TraceSink>>,
+
+    /// Redaction flag per open node, so the sink never sees redacted values
+    sink_redacted: Vec<bool>,
 }
 
 impl Default for TraceBuilder {
@@ -680,6 +981,11 @@ impl TraceBuilder {
             stack: Vec::new(),
             enabled: true,
             timed: true,
+            redacted: BTreeSet::new(),
+            ring: None,
+            materialized_steps: 0,
+            sink: None,
+            sink_redacted: Vec::new(),
         }
     }
 
@@ -692,6 +998,11 @@ impl TraceBuilder {
             stack: Vec::new(),
             enabled: true,
             timed: false,
+            redacted: BTreeSet::new(),
+            ring: None,
+            materialized_steps: 0,
+            sink: None,
+            sink_redacted: Vec::new(),
         }
     }
 
@@ -701,6 +1012,125 @@ impl TraceBuilder {
             stack: Vec::new(),
             enabled: false,
             timed: false,
+            redacted: BTreeSet::new(),
+            ring: None,
+            materialized_steps: 0,
+            sink: None,
+            sink_redacted: Vec::new(),
+        }
+    }
+
+    /// Create a TraceBuilder that only keeps the last `capacity` steps.
+    ///
+    /// No tree is built while evaluating: push/pop/set calls are appended to a
+    /// bounded ring buffer, and [`pop`](Self::pop) never returns a node. When an
+    /// evaluation fails, [`materialize_failure_trace`](Self::materialize_failure_trace)
+    /// replays the buffer into a partial trace. Steps whose parent was evicted
+    /// are grouped under a node named [`EARLIER_STEPS`].
+    pub fn failure_only(capacity: usize) -> Self {
+        Self {
+            stack: Vec::new(),
+            enabled: true,
+            timed: false,
+            redacted: BTreeSet::new(),
+            ring: Some(StepRing {
+                steps: VecDeque::with_capacity(capacity),
+                capacity,
+            }),
+            materialized_steps: 0,
+            sink: None,
+            sink_redacted: Vec::new(),
+        }
+    }
+
+    /// Create a TraceBuilder that builds no trace itself and only feeds `sink`.
+    ///
+    /// Behaves like a [`failure_only`](Self::failure_only) builder that retains
+    /// no steps, so nothing is materialized.
+    pub fn sink_only(sink: Rc<dyn TraceSink>) -> Self {
+        Self::failure_only(0).with_sink(sink)
+    }
+
+    /// Forward every recorded step to `sink` as well.
+    ///
+    /// Every push/pop/result/message/resolve-type call on this builder is
+    /// delivered as a [`TraceEvent`], whatever the builder's own mode (full,
+    /// untimed or failure-only). Values of redacted nodes are withheld.
+    /// Used to mirror a service evaluation into an external tracing system.
+    pub fn with_sink(mut self, sink: Rc<dyn TraceSink>) -> Self {
+        self.set_sink(sink);
+        self
+    }
+
+    /// Replace the sink receiving this builder's steps (see [`with_sink`](Self::with_sink)).
+    ///
+    /// Redaction bookkeeping starts from the current depth, so nodes already
+    /// open are treated as not redacted.
+    pub fn set_sink(&mut self, sink: Rc<dyn TraceSink>) {
+        self.sink = Some(sink);
+        self.sink_redacted = vec![false; self.stack.len()];
+    }
+
+    /// Check whether this builder only records recent steps (see [`failure_only`](Self::failure_only)).
+    pub fn is_failure_only(&self) -> bool {
+        self.ring.is_some()
+    }
+
+    /// Number of steps turned into trace nodes so far by
+    /// [`materialize_failure_trace`](Self::materialize_failure_trace).
+    pub fn materialized_steps(&self) -> usize {
+        self.materialized_steps
+    }
+
+    /// Replay the buffered steps of a failure-only builder into a trace tree.
+    ///
+    /// Drains the buffer; returns `None` for regular builders or when nothing
+    /// was recorded. Nodes still open (the failing step and its ancestors) are
+    /// closed, so the returned root is the outermost retained step.
+    pub fn materialize_failure_trace(&mut self) -> Option<PathNode> {
+        let steps: Vec<BufferedStep> = self.ring.as_mut()?.steps.drain(..).collect();
+        let mut replay = TraceBuilder::new_untimed().with_redacted(&self.redacted);
+        let mut completed: Vec<PathNode> = Vec::new();
+
+        for step in steps {
+            if let BufferedStep::Push(name, node_type) = step {
+                self.materialized_steps += 1;
+                replay.push(name, node_type);
+                continue;
+            }
+            if replay.is_empty() {
+                if matches!(step, BufferedStep::Pop) && completed.is_empty() {
+                    continue;
+                }
+                // The matching push was evicted: adopt what we have so far.
+                replay.push(EARLIER_STEPS, PathNodeType::Action);
+                if let Some(current) = replay.stack.last_mut() {
+                    current.node.children.append(&mut completed);
+                }
+            }
+            match step {
+                BufferedStep::Push(..) => {}
+                BufferedStep::Pop => completed.extend(replay.pop()),
+                BufferedStep::Result(value) => replay.set_result(value),
+                BufferedStep::Money(scale) => replay.mark_money(scale),
+                BufferedStep::Tag(tag) => replay.set_tag(tag),
+                BufferedStep::Message(msg) => replay.set_message(msg),
+                BufferedStep::ResolveType(rt) => replay.set_resolve_type(rt),
+                BufferedStep::SourceSpan(span) => replay.set_source_span(*span),
+            }
+        }
+        while !replay.is_empty() {
+            completed.extend(replay.pop());
+        }
+
+        match completed.len() {
+            0 => None,
+            1 => completed.pop(),
+            _ => {
+                let mut root = PathNode::new(PathNodeType::Action, EARLIER_STEPS);
+                root.children = completed;
+                Some(root)
+            }
         }
     }
 
@@ -709,6 +1139,32 @@ impl TraceBuilder {
         self.enabled
     }
 
+    /// Withhold the value of every node called `name` (case-insensitive).
+    ///
+    /// Redacted nodes keep their position in the tree but drop their result and
+    /// message, so a sensitive input like `bsn` renders as
+    /// `Resolving $BSN: [REDACTED]` in text traces and without a `result` in JSON.
+    pub fn redact(&mut self, name: impl AsRef<str>) {
+        self.redacted.insert(name.as_ref().to_lowercase());
+    }
+
+    /// Builder-style variant of [`redact`](Self::redact) for several names.
+    pub fn with_redacted<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for name in names {
+            self.redact(name);
+        }
+        self
+    }
+
+    /// Check whether values for `name` are redacted.
+    pub fn is_redacted(&self, name: &str) -> bool {
+        !self.redacted.is_empty() && self.redacted.contains(&name.to_lowercase())
+    }
+
     /// Push a new node onto the stack.
     ///
     /// Call this when entering a new execution scope (resolving a variable,
@@ -717,8 +1173,18 @@ impl TraceBuilder {
         if !self.enabled {
             return;
         }
+        let name = name.into();
+        self.emit(TraceEvent::Push {
+            name: &name,
+            node_type: node_type.clone(),
+        });
+        if let Some(ref mut ring) = self.ring {
+            ring.record(BufferedStep::Push(name, node_type));
+            return;
+        }
 
-        let node = PathNode::new(node_type, name);
+        let mut node = PathNode::new(node_type, name);
+        node.redacted = self.is_redacted(&node.name);
         self.stack.push(BuildingNode {
             node,
             start_time: if self.timed {
@@ -734,9 +1200,63 @@ impl TraceBuilder {
         if !self.enabled {
             return;
         }
+        self.emit(TraceEvent::Result(&result));
+        if let Some(ref mut ring) = self.ring {
+            ring.record(BufferedStep::Result(result));
+            return;
+        }
+
+        if let Some(current) = self.stack.last_mut() {
+            if !current.node.redacted {
+                current.node.result = Some(result);
+            }
+        }
+    }
+
+    /// Mark the result of the current node as a monetary amount with `scale` decimals.
+    pub fn mark_money(&mut self, scale: u32) {
+        if !self.enabled {
+            return;
+        }
+        if let Some(ref mut ring) = self.ring {
+            ring.record(BufferedStep::Money(scale));
+            return;
+        }
 
         if let Some(current) = self.stack.last_mut() {
-            current.node.result = Some(result);
+            current.node.money = Some(scale);
+        }
+    }
+
+    /// Attach the legal text span the current node implements.
+    pub fn set_source_span(&mut self, span: SourceSpan) {
+        if !self.enabled {
+            return;
+        }
+        self.emit(TraceEvent::SourceSpan(&span));
+        if let Some(ref mut ring) = self.ring {
+            ring.record(BufferedStep::SourceSpan(Box::new(span)));
+            return;
+        }
+
+        if let Some(current) = self.stack.last_mut() {
+            current.node.source_span = Some(span);
+        }
+    }
+
+    /// Tag the result of the current node for a [`FormatterRegistry`] formatter.
+    pub fn set_tag(&mut self, tag: impl Into<String>) {
+        if !self.enabled {
+            return;
+        }
+        let tag = tag.into();
+        if let Some(ref mut ring) = self.ring {
+            ring.record(BufferedStep::Tag(tag));
+            return;
+        }
+
+        if let Some(current) = self.stack.last_mut() {
+            current.node.tag = Some(tag);
         }
     }
 
@@ -745,9 +1265,17 @@ impl TraceBuilder {
         if !self.enabled {
             return;
         }
+        let msg = msg.into();
+        self.emit(TraceEvent::Message(&msg));
+        if let Some(ref mut ring) = self.ring {
+            ring.record(BufferedStep::Message(msg));
+            return;
+        }
 
         if let Some(current) = self.stack.last_mut() {
-            current.node.message = Some(msg.into());
+            if !current.node.redacted {
+                current.node.message = Some(msg);
+            }
         }
     }
 
@@ -764,6 +1292,11 @@ impl TraceBuilder {
         if !self.enabled {
             return;
         }
+        self.emit(TraceEvent::ResolveType(resolve_type.clone()));
+        if let Some(ref mut ring) = self.ring {
+            ring.record(BufferedStep::ResolveType(resolve_type));
+            return;
+        }
 
         if let Some(current) = self.stack.last_mut() {
             current.node.resolve_type = Some(resolve_type);
@@ -778,6 +1311,11 @@ impl TraceBuilder {
         if !self.enabled {
             return None;
         }
+        self.emit(TraceEvent::Pop);
+        if let Some(ref mut ring) = self.ring {
+            ring.record(BufferedStep::Pop);
+            return None;
+        }
 
         let building = self.stack.pop()?;
 
@@ -810,6 +1348,26 @@ impl TraceBuilder {
         result
     }
 
+    /// Forward an event to the sink, unless it carries a redacted value.
+    fn emit(&mut self, event: TraceEvent<'_>) {
+        let Some(sink) = self.sink.as_ref() else {
+            return;
+        };
+        match event {
+            TraceEvent::Push { name, .. } => self.sink_redacted.push(self.is_redacted(name)),
+            TraceEvent::Pop => {
+                self.sink_redacted.pop();
+            }
+            TraceEvent::Result(_) | TraceEvent::Message(_)
+                if self.sink_redacted.last().copied().unwrap_or(false) =>
+            {
+                return;
+            }
+            _ => {}
+        }
+        sink.record(event);
+    }
+
     /// Get the current depth of the trace stack.
     pub fn depth(&self) -> usize {
         self.stack.len()
@@ -862,6 +1420,93 @@ mod tests {
         assert!(node.duration_us.is_some());
     }
 
+    #[test]
+    fn test_failure_only_builder_keeps_recent_steps() {
+        let mut builder = TraceBuilder::failure_only(5);
+        builder.push("root", PathNodeType::Article);
+        builder.push("a", PathNodeType::Resolve);
+        builder.set_result(Value::Int(1));
+        assert!(builder.pop().is_none());
+        builder.push("b", PathNodeType::Resolve);
+        builder.set_message("failed");
+        assert_eq!(builder.materialized_steps(), 0);
+
+        // The push of "root" was evicted, so "a" and "b" are grouped under a synthetic root.
+        let root = builder.materialize_failure_trace().unwrap();
+        assert_eq!(root.name, EARLIER_STEPS);
+        let names: Vec<&str> = root.children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+        assert_eq!(root.children[0].result, Some(Value::Int(1)));
+        assert_eq!(root.children[1].message.as_deref(), Some("failed"));
+        assert_eq!(builder.materialized_steps(), 2);
+        assert!(builder.materialize_failure_trace().is_none());
+    }
+
+    #[derive(Debug, Default)]
+    struct CollectingSink {
+        events: std::cell::RefCell<Vec<String>>,
+    }
+
+    impl TraceSink for CollectingSink {
+        fn record(&self, event: TraceEvent<'_>) {
+            self.events.borrow_mut().push(match event {
+                TraceEvent::Push { name, .. } => format!("push {}", name),
+                TraceEvent::Pop => "pop".to_string(),
+                TraceEvent::Result(value) => format!("result {}", value),
+                TraceEvent::Message(msg) => format!("message {}", msg),
+                TraceEvent::ResolveType(rt) => format!("resolve {}", resolve_type_name(&rt)),
+                TraceEvent::SourceSpan(span) => format!("source {}", span.text),
+            });
+        }
+    }
+
+    #[test]
+    fn test_trace_builder_forwards_to_sink_without_redacted_values() {
+        let sink = Rc::new(CollectingSink::default());
+        let mut builder = TraceBuilder::new_untimed()
+            .with_redacted(["bsn"])
+            .with_sink(sink.clone());
+        builder.push("root", PathNodeType::Article);
+        builder.push("bsn", PathNodeType::Resolve);
+        builder.set_resolve_type(ResolveType::Parameter);
+        builder.set_result(Value::String("999993653".to_string()));
+        builder.pop();
+        builder.set_result(Value::Int(1));
+        builder.pop();
+
+        assert_eq!(
+            *sink.events.borrow(),
+            vec![
+                "push root",
+                "push bsn",
+                "resolve PARAMETERS",
+                "pop",
+                "result 1",
+                "pop"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trace_builder_forwards_source_span_to_sink() {
+        let sink = Rc::new(CollectingSink::default());
+        let mut builder = TraceBuilder::sink_only(sink.clone());
+        builder.push("toeslag", PathNodeType::Action);
+        builder.set_source_span(SourceSpan {
+            law_id: "span_law".to_string(),
+            article: "3".to_string(),
+            start: 3,
+            end: 10,
+            text: "toeslag".to_string(),
+        });
+        builder.pop();
+
+        assert_eq!(
+            *sink.events.borrow(),
+            vec!["push toeslag", "source toeslag", "pop"]
+        );
+    }
+
     #[test]
     fn test_trace_builder_nested() {
         let mut builder = TraceBuilder::new();
@@ -920,6 +1565,39 @@ mod tests {
         assert!(json.contains("\"result\":42"));
     }
 
+    #[test]
+    fn test_canonical_float_serialization_is_stable() {
+        let sum = [0.1, 0.2, 0.3].iter().sum::<f64>();
+        let reversed = [0.3, 0.2, 0.1].iter().sum::<f64>();
+        assert_ne!(sum, reversed, "summation order changes the last bits");
+
+        let trace = |result: f64| {
+            PathNode::new(PathNodeType::Action, "toeslag")
+                .with_child(
+                    PathNode::new(PathNodeType::Operation, "ADD").with_result(Value::Float(result)),
+                )
+                .with_result(Value::Array(vec![Value::Float(-0.0), Value::Int(3)]))
+        };
+
+        let canonical = trace(sum).to_json(FloatFormat::Canonical).unwrap();
+        assert!(canonical.contains("\"result\":0.6"), "{}", canonical);
+        assert!(canonical.contains("[0.0,3]"), "{}", canonical);
+        for _ in 0..3 {
+            assert_eq!(
+                trace(sum).to_json(FloatFormat::Canonical).unwrap(),
+                canonical
+            );
+        }
+        assert_eq!(
+            trace(reversed).to_json(FloatFormat::Canonical).unwrap(),
+            canonical
+        );
+
+        // The native format keeps the exact float
+        let native = trace(sum).to_json(FloatFormat::Native).unwrap();
+        assert!(native.contains("0.6000000000000001"), "{}", native);
+    }
+
     #[test]
     fn test_deeply_nested_trace() {
         let mut builder = TraceBuilder::new();
@@ -1284,4 +1962,84 @@ mod tests {
             rendered
         );
     }
+
+    #[test]
+    fn test_money_result_keeps_two_decimals() {
+        let mut builder = TraceBuilder::new();
+        builder.push("toeslag", PathNodeType::Action);
+        builder.set_result(Value::Float(2.0));
+        builder.mark_money(2);
+        let node = builder.pop().unwrap();
+
+        assert_eq!(node.money, Some(2));
+        let rendered = node.render_box_drawing();
+        assert!(
+            rendered.contains("Result: toeslag = 2.00"),
+            "Money result should keep its cents in:\n{}",
+            rendered
+        );
+        assert!(node.render(0, true).contains("= 2.00"));
+        assert_eq!(Value::Float(2.0).format_money(2), "2.00");
+        assert_eq!(Value::Int(200).format_money(2), "200");
+    }
+
+    #[test]
+    fn test_money_result_uses_its_scale() {
+        let mut builder = TraceBuilder::new();
+        builder.push("toeslag", PathNodeType::Action);
+        builder.set_result(Value::Float(2.5));
+        builder.mark_money(0);
+        let node = builder.pop().unwrap();
+
+        let rendered = node.render_box_drawing();
+        assert!(rendered.contains("Result: toeslag = 2"), "{}", rendered);
+        assert!(!rendered.contains("2.5"), "{}", rendered);
+        assert_eq!(Value::Float(2.125).format_money(3), "2.125");
+    }
+
+    #[test]
+    fn test_formatter_registry_renders_tagged_values() {
+        let mut builder = TraceBuilder::new();
+        builder.push("berekening", PathNodeType::Action);
+        builder.push("huurtoeslag", PathNodeType::Action);
+        builder.set_result(Value::Int(2112));
+        builder.set_tag("eurocent");
+        builder.pop();
+        builder.push("aantal_kinderen", PathNodeType::Action);
+        builder.set_result(Value::Int(2));
+        builder.pop();
+        let node = builder.pop().unwrap();
+
+        let mut formatters = FormatterRegistry::new();
+        formatters.register("eurocent", |value| match value {
+            Value::Int(cents) => format!("€ {},{:02}", cents / 100, cents % 100),
+            other => format_value_display(other),
+        });
+
+        let rendered = node.render_box_drawing_with(&formatters);
+        assert!(
+            rendered.contains("Result: huurtoeslag = € 21,12"),
+            "Tagged result should use the registered formatter in:\n{}",
+            rendered
+        );
+        assert!(rendered.contains("Result: aantal_kinderen = 2"));
+        assert!(!node.render_box_drawing().contains('€'));
+    }
+
+    #[test]
+    fn test_formatter_registry_overrides_money_formatting() {
+        let mut builder = TraceBuilder::new();
+        builder.push("toeslag", PathNodeType::Action);
+        builder.set_result(Value::Float(2.5));
+        builder.mark_money(2);
+        let node = builder.pop().unwrap();
+
+        let mut formatters = FormatterRegistry::new();
+        formatters.register(MONEY_TAG, |value| format!("€ {}", value.format_money(2)));
+
+        assert!(node
+            .render_box_drawing_with(&formatters)
+            .contains("Result: toeslag = € 2.50"));
+        assert!(node.render_box_drawing().contains("Result: toeslag = 2.50"));
+    }
 }
@@ -254,8 +254,19 @@ impl Value {
 
     /// Convert value to boolean (Python-style truthiness)
     ///
-    /// Note: NaN is treated as falsy (unlike Python where `bool(float('nan'))` is True).
-    /// This is intentional for law execution where NaN represents invalid/missing data.
+    /// | Value | Result |
+    /// |-------|--------|
+    /// | `Null` | `false` |
+    /// | `Bool(b)` | `b` |
+    /// | `Int` / `Float` | `false` only for zero (and NaN) |
+    /// | `String` | `false` only for `""` — note that `"false"` is **truthy** |
+    /// | `Array` / `Object` | `false` only when empty |
+    /// | `Untranslatable` | `false` (callers propagate the taint separately) |
+    ///
+    /// Unlike Python, NaN is falsy: in law execution it represents invalid or missing data.
+    ///
+    /// Boolean operations use [`BoolCoercion`] to decide whether this coercion is
+    /// applied to non-boolean values at all.
     pub fn to_bool(&self) -> bool {
         match self {
             Value::Null => false,
@@ -268,6 +279,21 @@ impl Value {
             Value::Untranslatable { .. } => false,
         }
     }
+
+    /// Render a monetary amount, keeping `scale` decimals of floats (`2.0` -> `2.00` at scale 2).
+    ///
+    /// Integers (amounts in minor units) and non-numeric values use the regular display.
+    pub fn format_money(&self, scale: u32) -> String {
+        match self {
+            Value::Float(f) => format!("{:.*}", scale as usize, f),
+            other => other.to_string(),
+        }
+    }
+
+    /// Convert to a `serde_json::Value` (untranslatables keep their sentinel form).
+    pub fn to_json(&self) -> serde_json::Value {
+        self.into()
+    }
 }
 
 impl From<bool> for Value {
@@ -499,22 +525,31 @@ impl fmt::Display for Value {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Operation {
-    // Comparison operations (5)
+    // Comparison operations (6)
     Equals,
     GreaterThan,
     LessThan,
     GreaterThanOrEqual,
     LessThanOrEqual,
+    ApproxEquals,
 
-    // Arithmetic operations (4)
+    // Arithmetic operations (11)
     Add,
     Subtract,
     Multiply,
     Divide,
-
-    // Aggregate operations (2)
+    IntDivide,
+    CompoundIndex,
+    RoundToMultiple,
+    LinearTaper,
+    Clamp,
+    ProgressiveBrackets,
+    Negate,
+
+    // Aggregate operations (3)
     Max,
     Min,
+    WeightedAverage,
 
     // Logical operations (3)
     And,
@@ -526,15 +561,71 @@ pub enum Operation {
     #[serde(alias = "SWITCH")]
     If,
 
-    // Collection operations (2)
+    // Collection operations (14)
     In,
     List,
-
-    // Date operations (4)
+    CountWhere,
+    Flatten,
+    Distinct,
+    Label,
+    Slice,
+    Split,
+    Sort,
+    CumulativeSum,
+    SumField,
+    IsSubset,
+    IsSuperset,
+    SetEquals,
+
+    // Date operations (24)
     Age,
+    HasReachedAge,
+    AgeInMonths,
     DateAdd,
     Date,
     DayOfWeek,
+    SubtractDate,
+    DateBefore,
+    DateAfter,
+    DateOnOrBefore,
+    DateOnOrAfter,
+    ProRata,
+    ClampDate,
+    NthWeekday,
+    NextBusinessDay,
+    YearFraction,
+    DateInPeriods,
+    PeriodLookup,
+    IsoWeek,
+    Quarter,
+    ConvertDuration,
+    PeriodOverlapDays,
+    MinDate,
+    MaxDate,
+
+    // Cross-law operations (6)
+    UriCall,
+    AnyEntitled,
+    FirstResolved,
+    DelegatedLookup,
+    DelegatedOrDefault,
+    ApplicableVersion,
+
+    // Validation operations (3)
+    ValidateBsn,
+    NormalizePostcode,
+    Matches,
+
+    // Emptiness operations (2)
+    IsEmpty,
+    NotEmpty,
+
+    // Type operations (2)
+    Cast,
+    ParseBool,
+
+    // Formula operations (1)
+    CallFormula,
 
     // Engine-only compat aliases — accepted during deserialization but NOT in the
     // v0.5.0 schema operationType enum. YAML using these will execute correctly but
@@ -561,22 +652,77 @@ impl Operation {
         Operation::LessThan,
         Operation::GreaterThanOrEqual,
         Operation::LessThanOrEqual,
+        Operation::ApproxEquals,
         Operation::Add,
         Operation::Subtract,
         Operation::Multiply,
         Operation::Divide,
+        Operation::IntDivide,
+        Operation::CompoundIndex,
+        Operation::RoundToMultiple,
+        Operation::LinearTaper,
+        Operation::Clamp,
+        Operation::ProgressiveBrackets,
+        Operation::Negate,
         Operation::Max,
         Operation::Min,
+        Operation::WeightedAverage,
         Operation::And,
         Operation::Or,
         Operation::Not,
         Operation::If,
         Operation::In,
         Operation::List,
+        Operation::CountWhere,
+        Operation::Flatten,
+        Operation::Distinct,
+        Operation::Label,
+        Operation::Slice,
+        Operation::Split,
+        Operation::Sort,
+        Operation::CumulativeSum,
+        Operation::SumField,
+        Operation::IsSubset,
+        Operation::IsSuperset,
+        Operation::SetEquals,
         Operation::Age,
+        Operation::HasReachedAge,
+        Operation::AgeInMonths,
         Operation::DateAdd,
         Operation::Date,
         Operation::DayOfWeek,
+        Operation::SubtractDate,
+        Operation::DateBefore,
+        Operation::DateAfter,
+        Operation::DateOnOrBefore,
+        Operation::DateOnOrAfter,
+        Operation::ProRata,
+        Operation::ClampDate,
+        Operation::NthWeekday,
+        Operation::NextBusinessDay,
+        Operation::YearFraction,
+        Operation::DateInPeriods,
+        Operation::PeriodLookup,
+        Operation::IsoWeek,
+        Operation::Quarter,
+        Operation::ConvertDuration,
+        Operation::PeriodOverlapDays,
+        Operation::MinDate,
+        Operation::MaxDate,
+        Operation::UriCall,
+        Operation::AnyEntitled,
+        Operation::FirstResolved,
+        Operation::DelegatedLookup,
+        Operation::DelegatedOrDefault,
+        Operation::ApplicableVersion,
+        Operation::ValidateBsn,
+        Operation::NormalizePostcode,
+        Operation::Matches,
+        Operation::IsEmpty,
+        Operation::NotEmpty,
+        Operation::Cast,
+        Operation::ParseBool,
+        Operation::CallFormula,
     ];
 
     /// Compat aliases accepted by the engine but not in the schema.
@@ -601,22 +747,77 @@ impl Operation {
         Operation::LessThan,
         Operation::GreaterThanOrEqual,
         Operation::LessThanOrEqual,
+        Operation::ApproxEquals,
         Operation::Add,
         Operation::Subtract,
         Operation::Multiply,
         Operation::Divide,
+        Operation::IntDivide,
+        Operation::CompoundIndex,
+        Operation::RoundToMultiple,
+        Operation::LinearTaper,
+        Operation::Clamp,
+        Operation::ProgressiveBrackets,
+        Operation::Negate,
         Operation::Max,
         Operation::Min,
+        Operation::WeightedAverage,
         Operation::And,
         Operation::Or,
         Operation::Not,
         Operation::If,
         Operation::In,
         Operation::List,
+        Operation::CountWhere,
+        Operation::Flatten,
+        Operation::Distinct,
+        Operation::Label,
+        Operation::Slice,
+        Operation::Split,
+        Operation::Sort,
+        Operation::CumulativeSum,
+        Operation::SumField,
+        Operation::IsSubset,
+        Operation::IsSuperset,
+        Operation::SetEquals,
         Operation::Age,
+        Operation::HasReachedAge,
+        Operation::AgeInMonths,
         Operation::DateAdd,
         Operation::Date,
         Operation::DayOfWeek,
+        Operation::SubtractDate,
+        Operation::DateBefore,
+        Operation::DateAfter,
+        Operation::DateOnOrBefore,
+        Operation::DateOnOrAfter,
+        Operation::ProRata,
+        Operation::ClampDate,
+        Operation::NthWeekday,
+        Operation::NextBusinessDay,
+        Operation::YearFraction,
+        Operation::DateInPeriods,
+        Operation::PeriodLookup,
+        Operation::IsoWeek,
+        Operation::Quarter,
+        Operation::ConvertDuration,
+        Operation::PeriodOverlapDays,
+        Operation::MinDate,
+        Operation::MaxDate,
+        Operation::UriCall,
+        Operation::AnyEntitled,
+        Operation::FirstResolved,
+        Operation::DelegatedLookup,
+        Operation::DelegatedOrDefault,
+        Operation::ApplicableVersion,
+        Operation::ValidateBsn,
+        Operation::NormalizePostcode,
+        Operation::Matches,
+        Operation::IsEmpty,
+        Operation::NotEmpty,
+        Operation::Cast,
+        Operation::ParseBool,
+        Operation::CallFormula,
         Operation::NotEquals,
         Operation::IsNull,
         Operation::NotNull,
@@ -633,6 +834,7 @@ impl Operation {
                 | Operation::LessThan
                 | Operation::GreaterThanOrEqual
                 | Operation::LessThanOrEqual
+                | Operation::ApproxEquals
         )
     }
 
@@ -640,13 +842,26 @@ impl Operation {
     pub fn is_arithmetic(&self) -> bool {
         matches!(
             self,
-            Operation::Add | Operation::Subtract | Operation::Multiply | Operation::Divide
+            Operation::Add
+                | Operation::Subtract
+                | Operation::Multiply
+                | Operation::Divide
+                | Operation::IntDivide
+                | Operation::CompoundIndex
+                | Operation::RoundToMultiple
+                | Operation::LinearTaper
+                | Operation::Clamp
+                | Operation::ProgressiveBrackets
+                | Operation::Negate
         )
     }
 
     /// Check if this is an aggregate operation
     pub fn is_aggregate(&self) -> bool {
-        matches!(self, Operation::Max | Operation::Min)
+        matches!(
+            self,
+            Operation::Max | Operation::Min | Operation::WeightedAverage
+        )
     }
 
     /// Check if this is a logical operation
@@ -661,7 +876,36 @@ impl Operation {
 
     /// Check if this is a collection operation
     pub fn is_collection(&self) -> bool {
-        matches!(self, Operation::In | Operation::List)
+        matches!(
+            self,
+            Operation::In
+                | Operation::List
+                | Operation::CountWhere
+                | Operation::Flatten
+                | Operation::Distinct
+                | Operation::Label
+                | Operation::Slice
+                | Operation::Split
+                | Operation::Sort
+                | Operation::CumulativeSum
+                | Operation::SumField
+                | Operation::IsSubset
+                | Operation::IsSuperset
+                | Operation::SetEquals
+        )
+    }
+
+    /// Check if this is a validation operation
+    pub fn is_validation(&self) -> bool {
+        matches!(
+            self,
+            Operation::ValidateBsn | Operation::NormalizePostcode | Operation::Matches
+        )
+    }
+
+    /// Check if this is a type conversion operation
+    pub fn is_type_conversion(&self) -> bool {
+        matches!(self, Operation::Cast | Operation::ParseBool)
     }
 
     /// Check if this is a null-check operation
@@ -679,22 +923,77 @@ impl Operation {
             Operation::LessThan => "LESS_THAN",
             Operation::GreaterThanOrEqual => "GREATER_THAN_OR_EQUAL",
             Operation::LessThanOrEqual => "LESS_THAN_OR_EQUAL",
+            Operation::ApproxEquals => "APPROX_EQUALS",
             Operation::Add => "ADD",
             Operation::Subtract => "SUBTRACT",
             Operation::Multiply => "MULTIPLY",
             Operation::Divide => "DIVIDE",
+            Operation::IntDivide => "INT_DIVIDE",
+            Operation::CompoundIndex => "COMPOUND_INDEX",
+            Operation::RoundToMultiple => "ROUND_TO_MULTIPLE",
+            Operation::LinearTaper => "LINEAR_TAPER",
+            Operation::Clamp => "CLAMP",
+            Operation::ProgressiveBrackets => "PROGRESSIVE_BRACKETS",
+            Operation::Negate => "NEGATE",
             Operation::Max => "MAX",
             Operation::Min => "MIN",
+            Operation::WeightedAverage => "WEIGHTED_AVERAGE",
             Operation::And => "AND",
             Operation::Or => "OR",
             Operation::Not => "NOT",
             Operation::If => "IF",
             Operation::In => "IN",
             Operation::List => "LIST",
+            Operation::CountWhere => "COUNT_WHERE",
+            Operation::Flatten => "FLATTEN",
+            Operation::Distinct => "DISTINCT",
+            Operation::Label => "LABEL",
+            Operation::Slice => "SLICE",
+            Operation::Split => "SPLIT",
+            Operation::Sort => "SORT",
+            Operation::CumulativeSum => "CUMULATIVE_SUM",
+            Operation::SumField => "SUM_FIELD",
+            Operation::IsSubset => "IS_SUBSET",
+            Operation::IsSuperset => "IS_SUPERSET",
+            Operation::SetEquals => "SET_EQUALS",
             Operation::Age => "AGE",
+            Operation::HasReachedAge => "HAS_REACHED_AGE",
+            Operation::AgeInMonths => "AGE_IN_MONTHS",
             Operation::DateAdd => "DATE_ADD",
             Operation::Date => "DATE",
             Operation::DayOfWeek => "DAY_OF_WEEK",
+            Operation::SubtractDate => "SUBTRACT_DATE",
+            Operation::DateBefore => "DATE_BEFORE",
+            Operation::DateAfter => "DATE_AFTER",
+            Operation::DateOnOrBefore => "DATE_ON_OR_BEFORE",
+            Operation::DateOnOrAfter => "DATE_ON_OR_AFTER",
+            Operation::ProRata => "PRO_RATA",
+            Operation::ClampDate => "CLAMP_DATE",
+            Operation::NthWeekday => "NTH_WEEKDAY",
+            Operation::NextBusinessDay => "NEXT_BUSINESS_DAY",
+            Operation::YearFraction => "YEAR_FRACTION",
+            Operation::DateInPeriods => "DATE_IN_PERIODS",
+            Operation::PeriodLookup => "PERIOD_LOOKUP",
+            Operation::IsoWeek => "ISO_WEEK",
+            Operation::Quarter => "QUARTER",
+            Operation::ConvertDuration => "CONVERT_DURATION",
+            Operation::PeriodOverlapDays => "PERIOD_OVERLAP_DAYS",
+            Operation::MinDate => "MIN_DATE",
+            Operation::MaxDate => "MAX_DATE",
+            Operation::UriCall => "URI_CALL",
+            Operation::AnyEntitled => "ANY_ENTITLED",
+            Operation::FirstResolved => "FIRST_RESOLVED",
+            Operation::DelegatedLookup => "DELEGATED_LOOKUP",
+            Operation::DelegatedOrDefault => "DELEGATED_OR_DEFAULT",
+            Operation::ApplicableVersion => "APPLICABLE_VERSION",
+            Operation::ValidateBsn => "VALIDATE_BSN",
+            Operation::NormalizePostcode => "NORMALIZE_POSTCODE",
+            Operation::IsEmpty => "IS_EMPTY",
+            Operation::NotEmpty => "NOT_EMPTY",
+            Operation::Matches => "MATCHES",
+            Operation::Cast => "CAST",
+            Operation::ParseBool => "PARSE_BOOL",
+            Operation::CallFormula => "CALL_FORMULA",
             Operation::NotEquals => "NOT_EQUALS",
             Operation::IsNull => "IS_NULL",
             Operation::NotNull => "NOT_NULL",
@@ -739,6 +1038,234 @@ impl std::str::FromStr for UntranslatableMode {
     }
 }
 
+/// How boolean contexts (AND, OR, NOT, IF conditions) treat non-boolean values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BoolCoercion {
+    /// Apply [`Value::to_bool`] truthiness: `0`, `""`, `null` and empty
+    /// collections are false, everything else is true.
+    #[default]
+    Lenient,
+    /// Only `Bool` values are accepted; anything else is an `InvalidOperation` error.
+    Strict,
+}
+
+impl std::str::FromStr for BoolCoercion {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "lenient" => Ok(BoolCoercion::Lenient),
+            "strict" => Ok(BoolCoercion::Strict),
+            _ => Err(format!(
+                "unknown bool coercion '{s}', expected: lenient, strict"
+            )),
+        }
+    }
+}
+
+/// How DIVIDE rounds its quotient when every operand is an integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegerDivision {
+    /// Return the exact quotient as a `Float`, like Python's `/`.
+    #[default]
+    Float,
+    /// Round toward negative infinity and return an `Int`.
+    Floor,
+    /// Round toward positive infinity and return an `Int`.
+    Ceil,
+    /// Round to the nearest integer, ties to even, and return an `Int`.
+    HalfEven,
+}
+
+impl std::str::FromStr for IntegerDivision {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "float" => Ok(IntegerDivision::Float),
+            "floor" => Ok(IntegerDivision::Floor),
+            "ceil" => Ok(IntegerDivision::Ceil),
+            "half_even" => Ok(IntegerDivision::HalfEven),
+            _ => Err(format!(
+                "unknown integer division '{s}', expected: float, floor, ceil, half_even"
+            )),
+        }
+    }
+}
+
+/// What happens when an output's value is outside its declared `enum` set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EnumValidation {
+    /// The evaluation fails with an `InvalidOperation` error.
+    #[default]
+    Error,
+    /// The output is kept and an `enum_violation` warning is reported.
+    Warn,
+}
+
+impl std::str::FromStr for EnumValidation {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(EnumValidation::Error),
+            "warn" => Ok(EnumValidation::Warn),
+            _ => Err(format!(
+                "unknown enum validation '{s}', expected: error, warn"
+            )),
+        }
+    }
+}
+
+/// What happens when a referenced law is loaded but does not produce the
+/// requested output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MissingCrossLawOutput {
+    /// The evaluation fails with an `OutputNotFound` error.
+    #[default]
+    Error,
+    /// The input resolves to null and a `missing_cross_law_output` warning
+    /// is reported.
+    Warn,
+}
+
+impl std::str::FromStr for MissingCrossLawOutput {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(MissingCrossLawOutput::Error),
+            "warn" => Ok(MissingCrossLawOutput::Warn),
+            _ => Err(format!(
+                "unknown missing cross-law output handling '{s}', expected: error, warn"
+            )),
+        }
+    }
+}
+
+/// When someone born on February 29 has their birthday in a non-leap year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnniversaryPolicy {
+    /// On February 28, as BW art. 1:2 prescribes for Dutch law.
+    #[default]
+    February28,
+    /// On March 1, as some foreign and older regulations count it.
+    March1,
+}
+
+impl std::str::FromStr for AnniversaryPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "february_28" => Ok(AnniversaryPolicy::February28),
+            "march_1" => Ok(AnniversaryPolicy::March1),
+            _ => Err(format!(
+                "unknown anniversary policy '{s}', expected: february_28, march_1"
+            )),
+        }
+    }
+}
+
+/// Which value wins when a parameter has the same name as an article definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShadowPrecedence {
+    /// The supplied parameter is used.
+    #[default]
+    Parameter,
+    /// The article definition is used and the parameter is ignored.
+    Definition,
+}
+
+impl std::str::FromStr for ShadowPrecedence {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "parameter" => Ok(ShadowPrecedence::Parameter),
+            "definition" => Ok(ShadowPrecedence::Definition),
+            _ => Err(format!(
+                "unknown shadow precedence '{s}', expected: parameter, definition"
+            )),
+        }
+    }
+}
+
+/// Engine-wide evaluation options that influence how operations behave.
+///
+/// Set on [`LawExecutionService`](crate::LawExecutionService) or
+/// [`ArticleEngine`](crate::ArticleEngine) and exposed to operations through
+/// [`ValueResolver::options`](crate::ValueResolver::options).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvaluationOptions {
+    /// Treatment of non-boolean values in boolean contexts.
+    pub bool_coercion: BoolCoercion,
+    /// Largest integer magnitude that numeric operations accept before raising
+    /// `ArithmeticOverflow`, and up to which integers compare equal to floats.
+    ///
+    /// Defaults to 2^53, the largest range in which every integer is exactly
+    /// representable as `f64`. Raising it only widens operations that stay in
+    /// exact integer arithmetic (all-integer ADD, SUBTRACT, MULTIPLY,
+    /// CUMULATIVE_SUM, SUM_FIELD, ROUND_TO_MULTIPLE, comparisons and
+    /// equality); wherever a value is converted to `f64` the guard remains
+    /// capped at 2^53, so no integer is ever rounded silently.
+    pub max_safe_integer: i64,
+    /// Result of DIVIDE when every operand is an integer: a float (the
+    /// default) or an integer rounded with the given mode.
+    pub integer_division: IntegerDivision,
+    /// Number of decimals in a stored amount. `amount` parameters passed as
+    /// text (e.g. `"21.12"`) are parsed into integers at this scale; numeric
+    /// ones are taken to be at this scale already.
+    ///
+    /// Defaults to 2, so amounts are stored in eurocents.
+    pub money_scale: u32,
+    /// Handling of outputs whose value is not in their declared `enum` set.
+    pub enum_validation: EnumValidation,
+    /// Whether a parameter or a definition of the same name is resolved.
+    /// Either way the shadowing is reported as a warning.
+    pub shadow_precedence: ShadowPrecedence,
+    /// Birthday of people born on February 29 in non-leap years, used by
+    /// AGE, HAS_REACHED_AGE and SUBTRACT_DATE in years.
+    pub anniversary_policy: AnniversaryPolicy,
+    /// Fail with `MissingOutputs` when an article finishes without producing
+    /// every output it declares. Off by default.
+    pub require_all_outputs: bool,
+    /// Handling of a cross-law reference to a loaded law that does not
+    /// produce the requested output.
+    pub missing_cross_law_output: MissingCrossLawOutput,
+    /// Record the wall-clock duration of each output's action in
+    /// `ArticleResult::timings`. Off by default, so no clock is read.
+    pub record_timings: bool,
+}
+
+impl EvaluationOptions {
+    /// The default options, usable in const contexts.
+    pub const DEFAULT: EvaluationOptions = EvaluationOptions {
+        bool_coercion: BoolCoercion::Lenient,
+        max_safe_integer: 9_007_199_254_740_992,
+        integer_division: IntegerDivision::Float,
+        money_scale: regelrecht_shared::DEFAULT_MONEY_SCALE,
+        enum_validation: EnumValidation::Error,
+        shadow_precedence: ShadowPrecedence::Parameter,
+        anniversary_policy: AnniversaryPolicy::February28,
+        require_all_outputs: false,
+        missing_cross_law_output: MissingCrossLawOutput::Error,
+        record_timings: false,
+    };
+}
+
+impl Default for EvaluationOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// Engine connectivity mode — whether this engine resolves cross-law references.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -879,8 +1406,12 @@ mod tests {
     fn test_operation_categories() {
         assert!(Operation::Equals.is_comparison());
         assert!(Operation::NotEquals.is_comparison());
+        assert!(Operation::ApproxEquals.is_comparison());
         assert!(Operation::Add.is_arithmetic());
+        assert!(Operation::IntDivide.is_arithmetic());
+        assert!(Operation::CompoundIndex.is_arithmetic());
         assert!(Operation::Max.is_aggregate());
+        assert!(Operation::WeightedAverage.is_aggregate());
         assert!(Operation::And.is_logical());
         assert!(Operation::Not.is_logical());
         assert!(Operation::If.is_conditional());
@@ -888,6 +1419,10 @@ mod tests {
         assert!(Operation::List.is_collection());
         assert!(Operation::IsNull.is_null_check());
         assert!(Operation::NotNull.is_null_check());
+        assert!(Operation::ValidateBsn.is_validation());
+        assert!(Operation::NormalizePostcode.is_validation());
+        assert!(Operation::Cast.is_type_conversion());
+        assert!(Operation::ParseBool.is_type_conversion());
     }
 
     #[test]
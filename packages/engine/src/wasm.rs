@@ -8,6 +8,8 @@
 //!
 //! - **No filesystem access in WASM**: Laws must be passed as YAML strings via `load_law()`
 //! - **Efficient serialization**: Uses `serde-wasm-bindgen` for Rust <-> JavaScript conversion
+//! - **No client clock**: Every execute method requires an explicit calculation date, so
+//!   results are reproducible and never depend on the browser's notion of "today"
 //!
 //! # Cross-Law Resolution
 //!
@@ -55,11 +57,11 @@
 
 use serde::Serialize;
 use serde_wasm_bindgen::Serializer;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use wasm_bindgen::prelude::*;
 
 use crate::config;
-use crate::engine::OutputProvenance;
+use crate::engine::{OutputProvenance, Warning};
 use crate::error::EngineError;
 use crate::service::LawExecutionService;
 use crate::trace::{PathNode, TraceBuilder};
@@ -75,6 +77,22 @@ fn wasm_error(msg: &str) -> JsValue {
     JsValue::from_str(msg)
 }
 
+/// Require an explicit calculation date from the caller.
+///
+/// A missing or blank date is rejected rather than defaulted to today, which
+/// would make results depend on the client clock. The date format itself is
+/// validated by the service.
+fn require_calculation_date(calculation_date: Option<&str>) -> Result<&str, String> {
+    match calculation_date.map(str::trim) {
+        Some(date) if !date.is_empty() => Ok(date),
+        _ => Err(
+            "calculation_date is required (YYYY-MM-DD); the engine does not \
+             default to the client's clock"
+                .to_string(),
+        ),
+    }
+}
+
 /// Convert internal EngineError to user-friendly WASM error.
 fn engine_error_to_wasm(err: EngineError) -> JsValue {
     match err {
@@ -82,6 +100,13 @@ fn engine_error_to_wasm(err: EngineError) -> JsValue {
             "Law '{}' not found. Load it first with loadLaw().",
             law_id
         )),
+        EngineError::NoApplicableVersion {
+            ref law_id,
+            ref date,
+        } => wasm_error(&format!(
+            "No version of law '{}' is valid on {}. Load a version in force on that date with loadLaw().",
+            law_id, date
+        )),
         other => wasm_error(&other.to_string()),
     }
 }
@@ -92,6 +117,10 @@ struct WasmExecuteResult {
     outputs: BTreeMap<String, Value>,
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     output_provenance: BTreeMap<String, OutputProvenance>,
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    indicative_outputs: BTreeSet<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<Warning>,
     resolved_inputs: BTreeMap<String, Value>,
     article_number: String,
     law_id: String,
@@ -112,6 +141,10 @@ struct WasmExecuteResultWithTrace {
     outputs: BTreeMap<String, Value>,
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     output_provenance: BTreeMap<String, OutputProvenance>,
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    indicative_outputs: BTreeSet<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<Warning>,
     resolved_inputs: BTreeMap<String, Value>,
     article_number: String,
     law_id: String,
@@ -140,6 +173,8 @@ struct WasmLawInfo {
     bwb_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authority: Option<String>,
     outputs: Vec<String>,
     article_count: usize,
 }
@@ -196,7 +231,8 @@ impl WasmEngine {
     /// * `law_id` - ID of the loaded law
     /// * `output_name` - Name of the output to calculate
     /// * `parameters` - JavaScript object with input parameters
-    /// * `calculation_date` - Date string (YYYY-MM-DD) for which to calculate
+    /// * `calculation_date` - Date string (YYYY-MM-DD) for which to calculate; required,
+    ///   there is no default to today
     ///
     /// # Returns
     /// * `Ok(JsValue)` - JavaScript object with `outputs`, `resolved_inputs`, etc.
@@ -207,8 +243,10 @@ impl WasmEngine {
         law_id: &str,
         output_name: &str,
         parameters: JsValue,
-        calculation_date: &str,
+        calculation_date: Option<String>,
     ) -> Result<JsValue, JsValue> {
+        let calculation_date =
+            require_calculation_date(calculation_date.as_deref()).map_err(|e| wasm_error(&e))?;
         let params: BTreeMap<String, Value> = serde_wasm_bindgen::from_value(parameters)
             .map_err(|e| wasm_error(&format!("Failed to parse parameters: {}", e)))?;
 
@@ -220,6 +258,8 @@ impl WasmEngine {
         let wasm_result = WasmExecuteResult {
             outputs: result.outputs,
             output_provenance: result.output_provenance,
+            indicative_outputs: result.indicative_outputs,
+            warnings: result.warnings,
             resolved_inputs: result.resolved_inputs,
             article_number: result.article_number,
             law_id: result.law_id,
@@ -253,8 +293,10 @@ impl WasmEngine {
         law_id: &str,
         output_name: &str,
         parameters: JsValue,
-        calculation_date: &str,
+        calculation_date: Option<String>,
     ) -> Result<JsValue, JsValue> {
+        let calculation_date =
+            require_calculation_date(calculation_date.as_deref()).map_err(|e| wasm_error(&e))?;
         let params: BTreeMap<String, Value> = serde_wasm_bindgen::from_value(parameters)
             .map_err(|e| wasm_error(&format!("Failed to parse parameters: {}", e)))?;
 
@@ -272,6 +314,8 @@ impl WasmEngine {
                 let wasm_result = WasmExecuteResultWithTrace {
                     outputs: result.outputs,
                     output_provenance: result.output_provenance,
+                    indicative_outputs: result.indicative_outputs,
+                    warnings: result.warnings,
                     resolved_inputs: result.resolved_inputs,
                     article_number: result.article_number,
                     law_id: result.law_id,
@@ -330,15 +374,17 @@ impl WasmEngine {
     /// * `law_id` - ID of the loaded law
     /// * `output_names` - JavaScript array of output name strings
     /// * `parameters` - JavaScript object with input parameters
-    /// * `calculation_date` - Date string (YYYY-MM-DD)
+    /// * `calculation_date` - Date string (YYYY-MM-DD); required
     #[wasm_bindgen(js_name = executeMultiple)]
     pub fn execute_multiple(
         &self,
         law_id: &str,
         output_names: JsValue,
         parameters: JsValue,
-        calculation_date: &str,
+        calculation_date: Option<String>,
     ) -> Result<JsValue, JsValue> {
+        let calculation_date =
+            require_calculation_date(calculation_date.as_deref()).map_err(|e| wasm_error(&e))?;
         let names: Vec<String> = serde_wasm_bindgen::from_value(output_names)
             .map_err(|e| wasm_error(&format!("Failed to parse output_names: {}", e)))?;
         let name_refs: Vec<&str> = names.iter().map(|s| s.as_str()).collect();
@@ -353,6 +399,8 @@ impl WasmEngine {
         let wasm_result = WasmExecuteResult {
             outputs: result.outputs,
             output_provenance: result.output_provenance,
+            indicative_outputs: result.indicative_outputs,
+            warnings: result.warnings,
             resolved_inputs: result.resolved_inputs,
             article_number: result.article_number,
             law_id: result.law_id,
@@ -378,8 +426,10 @@ impl WasmEngine {
         law_id: &str,
         output_names: JsValue,
         parameters: JsValue,
-        calculation_date: &str,
+        calculation_date: Option<String>,
     ) -> Result<JsValue, JsValue> {
+        let calculation_date =
+            require_calculation_date(calculation_date.as_deref()).map_err(|e| wasm_error(&e))?;
         let names: Vec<String> = serde_wasm_bindgen::from_value(output_names)
             .map_err(|e| wasm_error(&format!("Failed to parse output_names: {}", e)))?;
         let name_refs: Vec<&str> = names.iter().map(|s| s.as_str()).collect();
@@ -398,6 +448,8 @@ impl WasmEngine {
                 let wasm_result = WasmExecuteResultWithTrace {
                     outputs: result.outputs,
                     output_provenance: result.output_provenance,
+                    indicative_outputs: result.indicative_outputs,
+                    warnings: result.warnings,
                     resolved_inputs: result.resolved_inputs,
                     article_number: result.article_number,
                     law_id: result.law_id,
@@ -469,6 +521,7 @@ impl WasmEngine {
             publication_date: law_info.publication_date,
             bwb_id: law_info.bwb_id,
             url: law_info.url,
+            authority: law_info.authority,
             outputs: law_info.outputs,
             article_count: law_info.article_count,
         };
@@ -584,6 +637,17 @@ articles:
         engine.service.load_law(yaml).unwrap()
     }
 
+    #[test]
+    fn test_wasm_calculation_date_is_required() {
+        let err = require_calculation_date(None).unwrap_err();
+        assert!(err.contains("calculation_date is required"), "{}", err);
+        assert!(require_calculation_date(Some("  ")).is_err());
+        assert_eq!(
+            require_calculation_date(Some("2025-01-01")),
+            Ok("2025-01-01")
+        );
+    }
+
     #[test]
     fn test_wasm_engine_new() {
         let engine = WasmEngine::new();
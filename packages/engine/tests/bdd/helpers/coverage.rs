@@ -0,0 +1,131 @@
+//! Law output coverage for BDD runs
+//!
+//! Records every `(law_id, output)` pair that a scenario evaluated successfully
+//! and compares that set against the outputs declared by the loaded laws.
+//! The runner prints the summary after all scenarios pass; set
+//! `BDD_COVERAGE_MIN` to a percentage (e.g. `60`) to fail the run when
+//! coverage drops below it.
+
+use regelrecht_engine::LawExecutionService;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+
+/// Environment variable holding the optional minimum coverage percentage.
+pub const COVERAGE_MIN_ENV: &str = "BDD_COVERAGE_MIN";
+
+/// Global set of outputs exercised across all scenarios in this run.
+fn touched() -> &'static Mutex<BTreeSet<(String, String)>> {
+    static TOUCHED: OnceLock<Mutex<BTreeSet<(String, String)>>> = OnceLock::new();
+    TOUCHED.get_or_init(|| Mutex::new(BTreeSet::new()))
+}
+
+/// Record that `output` of `law_id` was evaluated by a scenario.
+pub fn record(law_id: &str, output: &str) {
+    touched()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert((law_id.to_string(), output.to_string()));
+}
+
+/// Coverage of declared law outputs by the recorded scenario evaluations.
+#[derive(Debug, Clone)]
+pub struct CoverageReport {
+    /// Declared outputs that at least one scenario evaluated
+    pub covered: Vec<(String, String)>,
+    /// Declared outputs that no scenario evaluated
+    pub missing: Vec<(String, String)>,
+}
+
+impl CoverageReport {
+    /// Build a report from the outputs declared in `service` and the pairs
+    /// recorded so far.
+    pub fn collect(service: &LawExecutionService) -> Self {
+        let touched = touched().lock().unwrap_or_else(|e| e.into_inner());
+
+        let declared: BTreeSet<(String, String)> = service
+            .list_all_outputs()
+            .into_iter()
+            .map(|(law, output)| (law.to_string(), output.to_string()))
+            .collect();
+
+        let (covered, missing) = declared
+            .into_iter()
+            .partition(|pair| touched.contains(pair));
+
+        Self { covered, missing }
+    }
+
+    /// Total number of declared outputs.
+    pub fn total(&self) -> usize {
+        self.covered.len() + self.missing.len()
+    }
+
+    /// Percentage of declared outputs covered (100 when nothing is declared).
+    pub fn percentage(&self) -> f64 {
+        if self.total() == 0 {
+            return 100.0;
+        }
+        self.covered.len() as f64 * 100.0 / self.total() as f64
+    }
+
+    /// Read the minimum coverage percentage from `BDD_COVERAGE_MIN`, if set.
+    pub fn threshold_from_env() -> Option<f64> {
+        let raw = std::env::var(COVERAGE_MIN_ENV).ok()?;
+        match raw.trim().parse::<f64>() {
+            Ok(min) => Some(min),
+            Err(_) => panic!("{} must be a percentage, got '{}'", COVERAGE_MIN_ENV, raw),
+        }
+    }
+}
+
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Law output coverage: {}/{} ({:.1}%)",
+            self.covered.len(),
+            self.total(),
+            self.percentage()
+        )?;
+        for (law, output) in &self.missing {
+            writeln!(f, "  not covered: {}.{}", law, output)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used, clippy::panic, clippy::unwrap_used)]
+mod tests {
+    use super::CoverageReport;
+    use crate::world::RegelrechtWorld;
+    use regelrecht_engine::Value;
+
+    #[test]
+    fn test_scenario_outputs_are_recorded() {
+        let mut world = RegelrechtWorld::new();
+        world.calculation_date = "2024-06-01".to_string();
+        world.parameters.insert(
+            "gemeente_code".to_string(),
+            Value::String("GM0363".to_string()),
+        );
+        world.parameters.insert(
+            "type_beplanting".to_string(),
+            Value::String("boom".to_string()),
+        );
+        // As the "Given a query" step converts the table cell
+        world
+            .parameters
+            .insert("postcode".to_string(), Value::Int(1012));
+
+        world.execute_law("burgerlijk_wetboek_boek_5", "minimale_afstand_cm");
+        assert!(world.is_success(), "{:?}", world.error_message());
+
+        let report = CoverageReport::collect(&world.service);
+        assert!(report.covered.contains(&(
+            "burgerlijk_wetboek_boek_5".to_string(),
+            "minimale_afstand_cm".to_string()
+        )));
+    }
+}
@@ -3,5 +3,6 @@
 // Allow unused imports in test modules - they're used conditionally
 #![allow(unused_imports)]
 
+pub mod coverage;
 pub mod regulation_loader;
 pub mod value_conversion;
@@ -127,7 +127,7 @@ mod tests {
 
     #[test]
     fn test_convert_float() {
-        assert_eq!(convert_gherkin_value("3.14"), Value::Float(3.14));
+        assert_eq!(convert_gherkin_value("2.75"), Value::Float(2.75));
         assert_eq!(convert_gherkin_value("-1.5"), Value::Float(-1.5));
         assert_eq!(convert_gherkin_value("0.5"), Value::Float(0.5));
     }
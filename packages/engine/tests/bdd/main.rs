@@ -53,4 +53,29 @@ async fn main() {
         .with_default_cli()
         .run_and_exit(features_dir)
         .await;
+
+    // Only reached when every scenario passed
+    report_coverage();
+}
+
+/// Print which declared law outputs the scenarios exercised, failing the run
+/// when coverage is below `BDD_COVERAGE_MIN`.
+fn report_coverage() {
+    let mut service = regelrecht_engine::LawExecutionService::new();
+    helpers::regulation_loader::load_all_regulations(&mut service)
+        .expect("Failed to load regulations for coverage report");
+
+    let report = helpers::coverage::CoverageReport::collect(&service);
+    eprintln!("{}", report);
+
+    if let Some(min) = helpers::coverage::CoverageReport::threshold_from_env() {
+        if report.percentage() < min {
+            eprintln!(
+                "Law output coverage {:.1}% is below the required {:.1}%",
+                report.percentage(),
+                min
+            );
+            std::process::exit(1);
+        }
+    }
 }
@@ -14,6 +14,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 /// Global scenario counter for unique trace file names.
 static SCENARIO_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
+use crate::helpers::coverage;
 use crate::helpers::regulation_loader::load_all_regulations;
 
 /// Test world that holds state across steps in a Cucumber scenario.
@@ -145,6 +146,9 @@ impl RegelrechtWorld {
                     let output_label = output_names.first().copied().unwrap_or("multi");
                     self.write_trace(&result, law_id, output_label);
                 }
+                for output_name in output_names {
+                    coverage::record(law_id, output_name);
+                }
                 self.result = Some(result);
                 self.error = None;
             }
@@ -0,0 +1,16 @@
+//! Unit tests of the BDD support code.
+//!
+//! The `bdd` target runs cucumber without the libtest harness, so `#[test]`
+//! functions in its modules never run there. This target mounts the same
+//! modules under the regular harness to run them.
+
+#![allow(clippy::expect_used, clippy::panic, clippy::unwrap_used)]
+// Only the modules' tests are used here, not the code the step definitions call
+#![allow(dead_code)]
+
+#[path = "common/mod.rs"]
+mod common;
+#[path = "bdd/helpers/mod.rs"]
+mod helpers;
+#[path = "bdd/world.rs"]
+mod world;
@@ -238,3 +238,72 @@ fn test_simple_law_trace() {
         expected
     );
 }
+
+#[test]
+fn test_trace_redacts_configured_variables() {
+    let mut service = setup_zorgtoeslag_service();
+    service.set_trace_redactions(["BSN"]);
+
+    let mut params = BTreeMap::new();
+    params.insert("bsn".to_string(), Value::String("999993653".to_string()));
+
+    let result = service
+        .evaluate_law_output_with_trace(
+            "zorgtoeslagwet",
+            "hoogte_zorgtoeslag",
+            params,
+            "2025-01-01",
+        )
+        .expect("Traced evaluation should succeed");
+
+    let trace = result.trace.expect("Trace should be populated");
+    let rendered = trace.render_box_drawing();
+    let json = serde_json::to_string(&trace).expect("Trace should serialize");
+
+    assert!(
+        rendered.contains("Resolving $BSN: [REDACTED]"),
+        "{}",
+        rendered
+    );
+    assert!(rendered.contains("{bsn: [REDACTED]}"), "{}", rendered);
+    assert!(!rendered.contains("999993653"), "{}", rendered);
+    assert!(!json.contains("999993653"), "{}", json);
+    assert!(json.contains("\"redacted\":true"));
+
+    // Non-sensitive values still render normally
+    assert!(
+        rendered.contains("Resolving from DATA_SOURCE: $POLIS_STATUS = 'ACTIEF'"),
+        "{}",
+        rendered
+    );
+}
+
+#[test]
+fn test_open_term_trace_names_implementing_version() {
+    let service = setup_zorgtoeslag_service();
+
+    let mut params = BTreeMap::new();
+    params.insert("bsn".to_string(), Value::String("999993653".to_string()));
+
+    let result = service
+        .evaluate_law_output_with_trace(
+            "zorgtoeslagwet",
+            "hoogte_zorgtoeslag",
+            params,
+            "2025-01-01",
+        )
+        .expect("Traced evaluation should succeed");
+
+    let trace = result.trace.expect("Trace should be populated");
+    let rendered = trace.render_box_drawing();
+
+    // The 2025 version of the regeling implements the open term, not the 2024 one
+    assert!(
+        rendered.contains(
+            "Delegation: Open term 'standaardpremie' implemented by regeling_standaardpremie \
+             article 1 (valid from 2025-01-01, bwb_id BWBR0050536): 211200"
+        ),
+        "{}",
+        rendered
+    );
+}
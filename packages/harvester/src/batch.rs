@@ -0,0 +1,430 @@
+//! Batch harvesting from a list of law IDs.
+//!
+//! Operators bootstrap a corpus from a spreadsheet of BWB/CVDR IDs and dates.
+//! The list is either CSV (one `law_id[,date]` row per line, `,` or `;`
+//! separated, optional header, `#` comments) or YAML (a sequence of
+//! `{bwb_id, date}` mappings). Each row is downloaded through its
+//! [`LawSource`] and saved in the regular `{layer}/{slug}/{date}.yaml` layout.
+
+use std::path::{Path, PathBuf};
+
+use reqwest::Client;
+use serde::Deserialize;
+use tokio::task::JoinSet;
+
+use crate::config::validate_date;
+use crate::error::{HarvesterError, Result};
+use crate::source::LawSource;
+use crate::yaml::{plan_yaml, save_yaml, WriteChange};
+
+/// One row of a batch list.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BatchEntry {
+    /// BWB or CVDR identifier.
+    #[serde(alias = "bwb_id")]
+    pub law_id: String,
+
+    /// Effective date (YYYY-MM-DD); falls back to the batch default when absent.
+    #[serde(default)]
+    pub date: Option<String>,
+}
+
+/// Result of downloading a single batch entry.
+#[derive(Debug)]
+pub struct BatchOutcome {
+    /// The entry that was processed.
+    pub entry: BatchEntry,
+
+    /// Effective date used for the download.
+    pub date: String,
+
+    /// Path of the saved YAML file, or the error that prevented it.
+    ///
+    /// In a dry run this is the path that would have been written.
+    pub result: Result<PathBuf>,
+
+    /// In a dry run, the effect the write would have had; `None` otherwise.
+    pub change: Option<WriteChange>,
+}
+
+/// Outcomes of a batch run, in list order.
+#[derive(Debug, Default)]
+pub struct BatchSummary {
+    pub outcomes: Vec<BatchOutcome>,
+}
+
+impl BatchSummary {
+    /// Number of entries that were downloaded and saved.
+    pub fn success_count(&self) -> usize {
+        self.outcomes.iter().filter(|o| o.result.is_ok()).count()
+    }
+
+    /// Number of entries that failed.
+    pub fn failure_count(&self) -> usize {
+        self.outcomes.len() - self.success_count()
+    }
+}
+
+/// Read and parse a batch list file.
+///
+/// Files ending in `.yaml`/`.yml` are parsed as YAML, everything else as CSV.
+pub fn read_batch_list(path: &Path) -> Result<Vec<BatchEntry>> {
+    let content = std::fs::read_to_string(path)?;
+    let is_yaml = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"));
+
+    if is_yaml {
+        parse_yaml_list(&content)
+    } else {
+        parse_csv_list(&content)
+    }
+}
+
+/// Parse a CSV batch list.
+///
+/// Each non-empty, non-comment line holds a law ID and an optional date.
+/// A first row whose ID column reads `bwb_id` or `law_id` is treated as a header.
+pub fn parse_csv_list(content: &str) -> Result<Vec<BatchEntry>> {
+    let mut entries = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut columns = line.split([',', ';']).map(str::trim);
+        let law_id = columns.next().unwrap_or_default();
+        let date = columns.next().filter(|d| !d.is_empty());
+
+        if entries.is_empty()
+            && (law_id.eq_ignore_ascii_case("bwb_id") || law_id.eq_ignore_ascii_case("law_id"))
+        {
+            continue;
+        }
+        if law_id.is_empty() {
+            return Err(HarvesterError::InvalidBatchList(format!(
+                "line {}: missing law ID",
+                index + 1
+            )));
+        }
+
+        entries.push(entry(law_id, date)?);
+    }
+
+    Ok(entries)
+}
+
+/// Parse a YAML batch list: a sequence of `{bwb_id, date}` mappings.
+pub fn parse_yaml_list(content: &str) -> Result<Vec<BatchEntry>> {
+    let rows: Vec<BatchEntry> = serde_yaml_ng::from_str(content)
+        .map_err(|e| HarvesterError::InvalidBatchList(e.to_string()))?;
+
+    rows.into_iter()
+        .map(|row| entry(&row.law_id, row.date.as_deref()))
+        .collect()
+}
+
+/// Build an entry, validating the date when present.
+fn entry(law_id: &str, date: Option<&str>) -> Result<BatchEntry> {
+    if let Some(date) = date {
+        validate_date(date)?;
+    }
+    Ok(BatchEntry {
+        law_id: law_id.to_string(),
+        date: date.map(String::from),
+    })
+}
+
+/// Result of one download task: the written (or planned) path, and the
+/// planned change in a dry run.
+type TaskResult = Result<(PathBuf, Option<WriteChange>)>;
+
+/// Download and save every entry, running up to `jobs` downloads at once.
+///
+/// `source_for` picks the [`LawSource`] for each ID; entries it rejects are
+/// recorded as failures without downloading. Failures never abort the batch.
+/// With `dry_run`, laws are downloaded and converted but nothing is written;
+/// each outcome reports the planned path and [`WriteChange`] instead.
+///
+/// # Arguments
+/// * `client` - HTTP client shared by all downloads
+/// * `entries` - Rows to harvest
+/// * `default_date` - Effective date for rows without one
+/// * `output` - Output base directory (default: regulation/nl/)
+/// * `jobs` - Maximum number of concurrent downloads
+/// * `dry_run` - Plan the writes instead of performing them
+/// * `source_for` - Source selection per law ID
+pub async fn harvest_batch<F>(
+    client: &Client,
+    entries: &[BatchEntry],
+    default_date: &str,
+    output: Option<&Path>,
+    jobs: usize,
+    dry_run: bool,
+    source_for: F,
+) -> BatchSummary
+where
+    F: Fn(&str) -> Result<Box<dyn LawSource>>,
+{
+    let mut results: Vec<Option<TaskResult>> = entries.iter().map(|_| None).collect();
+    let mut tasks = JoinSet::new();
+
+    for (index, entry) in entries.iter().enumerate() {
+        while tasks.len() >= jobs.max(1) {
+            collect_next(&mut tasks, &mut results).await;
+        }
+
+        let source = match source_for(&entry.law_id) {
+            Ok(source) => source,
+            Err(e) => {
+                results[index] = Some(Err(e));
+                continue;
+            }
+        };
+
+        let client = client.clone();
+        let law_id = entry.law_id.clone();
+        let date = effective_date(entry, default_date);
+        let output = output.map(Path::to_path_buf);
+
+        tasks.spawn(async move {
+            let result = match source.download(&client, &law_id, Some(&date)).await {
+                Ok(law) if dry_run => plan_yaml(&law, &date, output.as_deref())
+                    .map(|plan| (plan.path, Some(plan.change))),
+                Ok(law) => save_yaml(&law, &date, output.as_deref()).map(|path| (path, None)),
+                Err(e) => Err(e),
+            };
+            (index, result)
+        });
+    }
+
+    while !tasks.is_empty() {
+        collect_next(&mut tasks, &mut results).await;
+    }
+
+    let outcomes = entries
+        .iter()
+        .zip(results)
+        .map(|(entry, result)| {
+            let result = result.unwrap_or_else(|| {
+                Err(HarvesterError::BatchTask(format!(
+                    "download of {} did not complete",
+                    entry.law_id
+                )))
+            });
+            let (result, change) = match result {
+                Ok((path, change)) => (Ok(path), change),
+                Err(e) => (Err(e), None),
+            };
+            BatchOutcome {
+                entry: entry.clone(),
+                date: effective_date(entry, default_date),
+                result,
+                change,
+            }
+        })
+        .collect();
+
+    BatchSummary { outcomes }
+}
+
+/// Wait for one download task and store its result.
+///
+/// A panicked task has no index to report; its entry keeps `None` and is
+/// reported as incomplete by the caller.
+async fn collect_next(
+    tasks: &mut JoinSet<(usize, TaskResult)>,
+    results: &mut [Option<TaskResult>],
+) {
+    match tasks.join_next().await {
+        Some(Ok((index, result))) => results[index] = Some(result),
+        Some(Err(e)) => tracing::warn!(error = %e, "Batch download task failed"),
+        None => {}
+    }
+}
+
+/// Effective date for an entry.
+fn effective_date(entry: &BatchEntry, default_date: &str) -> String {
+    entry
+        .date
+        .clone()
+        .unwrap_or_else(|| default_date.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::BwbSource;
+    use tempfile::tempdir;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const BWB_ID: &str = "BWBR0018451";
+
+    fn fixture(name: &str) -> String {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/zorgtoeslag")
+            .join(name);
+        std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Failed to load {}: {}", path.display(), e))
+    }
+
+    /// Mock BWB repository serving the zorgtoeslag fixtures on each of `dates`.
+    ///
+    /// Any other law or date is answered with 404.
+    async fn mock_repository(dates: &[&str]) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/{BWB_ID}/{BWB_ID}.WTI")))
+            .respond_with(ResponseTemplate::new(200).set_body_string(fixture("wti.xml")))
+            .mount(&server)
+            .await;
+        for date in dates {
+            Mock::given(method("GET"))
+                .and(path(format!(
+                    "/{BWB_ID}/{date}_0/xml/{BWB_ID}_{date}_0.xml"
+                )))
+                .respond_with(ResponseTemplate::new(200).set_body_string(fixture("content.xml")))
+                .mount(&server)
+                .await;
+        }
+        server
+    }
+
+    /// Source selection that sends every BWB download to `server`.
+    fn mock_source(server: &MockServer) -> impl Fn(&str) -> Result<Box<dyn LawSource>> {
+        let repository_url = server.uri();
+        move |_id| {
+            Ok(Box::new(BwbSource {
+                max_size_mb: None,
+                repository_url: Some(repository_url.clone()),
+            }))
+        }
+    }
+
+    fn law_file(base: &Path, date: &str) -> PathBuf {
+        base.join("wet")
+            .join("wet_op_de_zorgtoeslag")
+            .join(format!("{date}.yaml"))
+    }
+
+    #[test]
+    fn test_parse_csv_list() {
+        let entries =
+            parse_csv_list("bwb_id,date\n# zorgtoeslag\nBWBR0018451,2025-01-01\n\nBWBR0002656;\n")
+                .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                BatchEntry {
+                    law_id: "BWBR0018451".to_string(),
+                    date: Some("2025-01-01".to_string()),
+                },
+                BatchEntry {
+                    law_id: "BWBR0002656".to_string(),
+                    date: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_list_invalid_date() {
+        let err = parse_csv_list("BWBR0018451,01-01-2025\n").unwrap_err();
+        assert!(matches!(err, HarvesterError::InvalidDate(_)));
+    }
+
+    #[test]
+    fn test_parse_yaml_list() {
+        let entries =
+            parse_yaml_list("- bwb_id: BWBR0018451\n  date: '2025-01-01'\n- bwb_id: CVDR681386\n")
+                .unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].law_id, "BWBR0018451");
+        assert_eq!(entries[0].date.as_deref(), Some("2025-01-01"));
+        assert_eq!(entries[1].law_id, "CVDR681386");
+        assert!(entries[1].date.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_harvest_batch_saves_every_entry() {
+        let server = mock_repository(&["2025-01-01", "2024-07-01"]).await;
+        let entries = parse_csv_list("BWBR0018451,2025-01-01\nBWBR0018451\n").unwrap();
+        let temp_dir = tempdir().unwrap();
+        let client = Client::new();
+
+        let summary = harvest_batch(
+            &client,
+            &entries,
+            "2024-07-01",
+            Some(temp_dir.path()),
+            2,
+            false,
+            mock_source(&server),
+        )
+        .await;
+
+        assert_eq!(summary.success_count(), 2, "{:?}", summary.outcomes);
+        assert_eq!(summary.failure_count(), 0);
+        assert!(law_file(temp_dir.path(), "2025-01-01").exists());
+        assert!(law_file(temp_dir.path(), "2024-07-01").exists());
+    }
+
+    #[tokio::test]
+    async fn test_harvest_batch_reports_failures() {
+        let server = mock_repository(&["2025-01-01"]).await;
+        let entries = parse_csv_list("BWBR9999999,2025-01-01\nBWBR0018451,2025-01-01\n").unwrap();
+        let temp_dir = tempdir().unwrap();
+        let client = Client::new();
+
+        let summary = harvest_batch(
+            &client,
+            &entries,
+            "2025-01-01",
+            Some(temp_dir.path()),
+            1,
+            false,
+            mock_source(&server),
+        )
+        .await;
+
+        assert_eq!(summary.success_count(), 1);
+        assert_eq!(summary.failure_count(), 1);
+        assert_eq!(summary.outcomes[0].entry.law_id, "BWBR9999999");
+        assert!(summary.outcomes[0].result.is_err());
+        assert!(summary.outcomes[1].result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_harvest_batch_dry_run_writes_nothing() {
+        let server = mock_repository(&["2025-01-01"]).await;
+        let entries = parse_csv_list("BWBR0018451,2025-01-01\n").unwrap();
+        let temp_dir = tempdir().unwrap();
+        let client = Client::new();
+
+        let summary = harvest_batch(
+            &client,
+            &entries,
+            "2025-01-01",
+            Some(temp_dir.path()),
+            1,
+            true,
+            mock_source(&server),
+        )
+        .await;
+
+        assert_eq!(summary.success_count(), 1);
+        let outcome = &summary.outcomes[0];
+        assert_eq!(
+            outcome.result.as_ref().unwrap(),
+            &law_file(temp_dir.path(), "2025-01-01")
+        );
+        assert_eq!(outcome.change, Some(WriteChange::Create));
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+    }
+}
@@ -1,16 +1,17 @@
 //! Command-line interface for the harvester.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use clap::{Parser, Subcommand};
 use console::style;
 use indicatif::{ProgressBar, ProgressStyle};
 
+use crate::batch::{harvest_batch, read_batch_list};
 use crate::config::DEFAULT_MAX_RESPONSE_SIZE;
 use crate::error::{HarvesterError, Result};
-use crate::http::create_client;
+use crate::http::{create_client, enable_cache};
 use crate::source::{self, BwbSource};
-use crate::yaml::save_yaml;
+use crate::yaml::{plan_yaml, save_yaml};
 
 /// RegelRecht Harvester - Download Dutch legislation from BWB and CVDR repositories.
 #[derive(Parser)]
@@ -26,9 +27,12 @@ pub enum Commands {
     /// Download a law by BWB or CVDR ID and convert to YAML.
     Download {
         /// Law identifier: BWB ID (e.g., BWBR0018451) or CVDR ID (e.g., CVDR681386)
-        law_id: String,
+        #[arg(required_unless_present = "from_list", conflicts_with = "from_list")]
+        law_id: Option<String>,
 
         /// Effective date in YYYY-MM-DD format (default: today)
+        ///
+        /// With `--from-list`, used for rows that do not specify a date.
         #[arg(short, long)]
         date: Option<String>,
 
@@ -42,6 +46,24 @@ pub enum Commands {
         /// toezicht (52.6 MB). Most laws are under 5 MB.
         #[arg(long, default_value_t = DEFAULT_MAX_RESPONSE_SIZE / (1024 * 1024))]
         max_size: u64,
+
+        /// Download every law in a CSV or YAML list of `(bwb_id, date)` rows
+        #[arg(long, value_name = "FILE")]
+        from_list: Option<PathBuf>,
+
+        /// Maximum number of concurrent downloads with `--from-list`
+        #[arg(long, default_value_t = 4)]
+        jobs: usize,
+
+        /// Download and convert, but only report which files would be
+        /// created or overwritten instead of writing them
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Directory for cached responses; unchanged content is revalidated
+        /// with ETag/Last-Modified instead of downloaded again
+        #[arg(long, value_name = "DIR")]
+        cache_dir: Option<PathBuf>,
     },
 }
 
@@ -55,7 +77,40 @@ pub async fn run() -> Result<()> {
             date,
             output,
             max_size,
-        } => download_command(&law_id, date.as_deref(), output.as_deref(), max_size).await,
+            from_list,
+            jobs,
+            dry_run,
+            cache_dir,
+        } => {
+            if let Some(dir) = cache_dir {
+                enable_cache(dir)?;
+            }
+            match (from_list, law_id) {
+                (Some(list), _) => {
+                    batch_command(
+                        &list,
+                        date.as_deref(),
+                        output.as_deref(),
+                        max_size,
+                        jobs,
+                        dry_run,
+                    )
+                    .await
+                }
+                (None, Some(law_id)) => {
+                    download_command(
+                        &law_id,
+                        date.as_deref(),
+                        output.as_deref(),
+                        max_size,
+                        dry_run,
+                    )
+                    .await
+                }
+                // Normally rejected by clap already (`required_unless_present`)
+                (None, None) => Err(HarvesterError::MissingLawId),
+            }
+        }
     }
 }
 
@@ -68,28 +123,15 @@ fn build_cli_source(law_id: &str, max_size_mb: u64) -> Result<Box<dyn source::La
     if law_source.source_type() == source::LawSourceType::Bwb {
         Ok(Box::new(BwbSource {
             max_size_mb: Some(max_size_mb),
+            ..Default::default()
         }))
     } else {
         Ok(law_source)
     }
 }
 
-/// Execute the download command.
-async fn download_command(
-    law_id: &str,
-    date: Option<&str>,
-    output: Option<&std::path::Path>,
-    max_size_mb: u64,
-) -> Result<()> {
-    // Build source with CLI-specific max_size override (detect_source validates the ID)
-    let law_source = build_cli_source(law_id, max_size_mb)?;
-
-    // Use today if no date provided
-    let effective_date = date
-        .map(String::from)
-        .unwrap_or_else(|| chrono::Local::now().format("%Y-%m-%d").to_string());
-
-    // Validate output directory exists (if specified) before downloading
+/// Validate that the output directory (if specified) exists before downloading.
+fn validate_output_dir(output: Option<&Path>) -> Result<()> {
     if let Some(output_dir) = output {
         if !output_dir.exists() {
             return Err(HarvesterError::Io(std::io::Error::new(
@@ -104,6 +146,29 @@ async fn download_command(
             )));
         }
     }
+    Ok(())
+}
+
+/// Today's date in YYYY-MM-DD format.
+fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+/// Execute the download command.
+async fn download_command(
+    law_id: &str,
+    date: Option<&str>,
+    output: Option<&Path>,
+    max_size_mb: u64,
+    dry_run: bool,
+) -> Result<()> {
+    // Build source with CLI-specific max_size override (detect_source validates the ID)
+    let law_source = build_cli_source(law_id, max_size_mb)?;
+
+    // Use today if no date provided
+    let effective_date = date.map(String::from).unwrap_or_else(today);
+
+    validate_output_dir(output)?;
 
     println!(
         "{} {} ({}) for date {}",
@@ -149,6 +214,26 @@ async fn download_command(
         println!("  Warnings: {}", style(law.warnings.len()).yellow().bold());
     }
 
+    if dry_run {
+        let plan = match plan_yaml(&law, &effective_date, output) {
+            Ok(plan) => plan,
+            Err(e) => {
+                pb.finish_and_clear();
+                return Err(e);
+            }
+        };
+        pb.finish_and_clear();
+
+        println!();
+        println!(
+            "{} {} ({})",
+            style("Would write:").yellow().bold(),
+            plan.path.display(),
+            plan.change
+        );
+        return Ok(());
+    }
+
     // Save to YAML
     pb.set_message("Saving YAML...");
 
@@ -172,6 +257,86 @@ async fn download_command(
     Ok(())
 }
 
+/// Execute the download command for every row of a batch list.
+async fn batch_command(
+    list: &Path,
+    date: Option<&str>,
+    output: Option<&Path>,
+    max_size_mb: u64,
+    jobs: usize,
+    dry_run: bool,
+) -> Result<()> {
+    let entries = read_batch_list(list)?;
+    let default_date = date.map(String::from).unwrap_or_else(today);
+
+    validate_output_dir(output)?;
+
+    println!(
+        "{} {} laws from {} ({} concurrent)",
+        style("Downloading").bold(),
+        style(entries.len()).cyan(),
+        list.display(),
+        jobs.max(1)
+    );
+    println!();
+
+    let client = create_client()?;
+    let summary = harvest_batch(
+        &client,
+        &entries,
+        &default_date,
+        output,
+        jobs,
+        dry_run,
+        |law_id| build_cli_source(law_id, max_size_mb),
+    )
+    .await;
+
+    for outcome in &summary.outcomes {
+        match (&outcome.result, &outcome.change) {
+            (Ok(path), Some(change)) => println!(
+                "  {} {} ({}) -> {} ({})",
+                style("~").yellow().bold(),
+                outcome.entry.law_id,
+                outcome.date,
+                path.display(),
+                change
+            ),
+            (Ok(path), None) => println!(
+                "  {} {} ({}) -> {}",
+                style("✓").green().bold(),
+                outcome.entry.law_id,
+                outcome.date,
+                path.display()
+            ),
+            (Err(e), _) => println!(
+                "  {} {} ({}): {}",
+                style("✗").red().bold(),
+                outcome.entry.law_id,
+                outcome.date,
+                e
+            ),
+        }
+    }
+
+    println!();
+    println!(
+        "{} {} succeeded, {} failed",
+        style("Summary:").bold(),
+        style(summary.success_count()).green(),
+        style(summary.failure_count()).red()
+    );
+
+    if summary.failure_count() > 0 {
+        return Err(HarvesterError::BatchFailed {
+            failed: summary.failure_count(),
+            total: summary.outcomes.len(),
+        });
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,11 +350,46 @@ mod tests {
             date,
             output,
             max_size,
+            from_list,
+            jobs,
+            dry_run,
+            cache_dir,
         } = cli.command;
-        assert_eq!(law_id, "BWBR0018451");
+        assert_eq!(law_id.as_deref(), Some("BWBR0018451"));
         assert!(date.is_none());
         assert!(output.is_none());
         assert_eq!(max_size, 100); // Default 100 MB
+        assert!(from_list.is_none());
+        assert_eq!(jobs, 4);
+        assert!(!dry_run);
+        assert!(cache_dir.is_none());
+    }
+
+    #[test]
+    fn test_cli_parse_download_dry_run() {
+        let cli = Cli::parse_from([
+            "regelrecht-harvester",
+            "download",
+            "BWBR0018451",
+            "--dry-run",
+        ]);
+
+        let Commands::Download { dry_run, .. } = cli.command;
+        assert!(dry_run);
+    }
+
+    #[test]
+    fn test_cli_parse_download_cache_dir() {
+        let cli = Cli::parse_from([
+            "regelrecht-harvester",
+            "download",
+            "BWBR0018451",
+            "--cache-dir",
+            ".cache/harvester",
+        ]);
+
+        let Commands::Download { cache_dir, .. } = cli.command;
+        assert_eq!(cache_dir, Some(PathBuf::from(".cache/harvester")));
     }
 
     #[test]
@@ -197,7 +397,7 @@ mod tests {
         let cli = Cli::parse_from(["regelrecht-harvester", "download", "CVDR681386"]);
 
         let Commands::Download { law_id, .. } = cli.command;
-        assert_eq!(law_id, "CVDR681386");
+        assert_eq!(law_id.as_deref(), Some("CVDR681386"));
     }
 
     #[test]
@@ -211,7 +411,7 @@ mod tests {
         ]);
 
         let Commands::Download { law_id, date, .. } = cli.command;
-        assert_eq!(law_id, "BWBR0018451");
+        assert_eq!(law_id.as_deref(), Some("BWBR0018451"));
         assert_eq!(date, Some("2025-01-01".to_string()));
     }
 
@@ -229,6 +429,41 @@ mod tests {
         assert_eq!(max_size, 200);
     }
 
+    #[test]
+    fn test_cli_parse_download_from_list() {
+        let cli = Cli::parse_from([
+            "regelrecht-harvester",
+            "download",
+            "--from-list",
+            "laws.csv",
+            "--jobs",
+            "8",
+        ]);
+
+        let Commands::Download {
+            law_id,
+            from_list,
+            jobs,
+            ..
+        } = cli.command;
+        assert!(law_id.is_none());
+        assert_eq!(from_list, Some(PathBuf::from("laws.csv")));
+        assert_eq!(jobs, 8);
+    }
+
+    #[test]
+    fn test_cli_requires_law_id_or_list() {
+        assert!(Cli::try_parse_from(["regelrecht-harvester", "download"]).is_err());
+        assert!(Cli::try_parse_from([
+            "regelrecht-harvester",
+            "download",
+            "BWBR0018451",
+            "--from-list",
+            "laws.csv",
+        ])
+        .is_err());
+    }
+
     #[test]
     fn test_build_cli_source_bwb() {
         let src = build_cli_source("BWBR0018451", 100).unwrap();
@@ -127,6 +127,7 @@ pub fn manifest_url(bwb_id: &str) -> String {
 /// Build WTI (metadata) URL for a law.
 ///
 /// # Arguments
+/// * `repository_url` - Base URL of the repository (usually [`BWB_REPOSITORY_URL`])
 /// * `bwb_id` - The BWB identifier (should be validated with `validate_bwb_id` first)
 ///
 /// # Returns
@@ -134,17 +135,18 @@ pub fn manifest_url(bwb_id: &str) -> String {
 ///
 /// # Panics
 /// Debug builds panic if bwb_id doesn't match expected format.
-pub fn wti_url(bwb_id: &str) -> String {
+pub fn wti_url(repository_url: &str, bwb_id: &str) -> String {
     debug_assert!(
         BWB_ID_PATTERN.is_match(bwb_id),
         "bwb_id should be validated before calling wti_url"
     );
-    format!("{BWB_REPOSITORY_URL}/{bwb_id}/{bwb_id}.WTI")
+    format!("{repository_url}/{bwb_id}/{bwb_id}.WTI")
 }
 
 /// Build content (consolidated XML) URL for a law at a specific date.
 ///
 /// # Arguments
+/// * `repository_url` - Base URL of the repository (usually [`BWB_REPOSITORY_URL`])
 /// * `bwb_id` - The BWB identifier (should be validated with `validate_bwb_id` first)
 /// * `date` - The effective date in YYYY-MM-DD format (should be validated with `validate_date` first)
 ///
@@ -153,7 +155,7 @@ pub fn wti_url(bwb_id: &str) -> String {
 ///
 /// # Panics
 /// Debug builds panic if inputs don't match expected formats.
-pub fn content_url(bwb_id: &str, date: &str) -> String {
+pub fn content_url(repository_url: &str, bwb_id: &str, date: &str) -> String {
     debug_assert!(
         BWB_ID_PATTERN.is_match(bwb_id),
         "bwb_id should be validated before calling content_url"
@@ -162,7 +164,7 @@ pub fn content_url(bwb_id: &str, date: &str) -> String {
         DATE_PATTERN.is_match(date),
         "date should be validated before calling content_url"
     );
-    format!("{BWB_REPOSITORY_URL}/{bwb_id}/{date}_0/xml/{bwb_id}_{date}_0.xml")
+    format!("{repository_url}/{bwb_id}/{date}_0/xml/{bwb_id}_{date}_0.xml")
 }
 
 /// Sanitize a URL fragment identifier by removing problematic characters.
@@ -338,7 +340,7 @@ mod tests {
     #[test]
     fn test_wti_url() {
         assert_eq!(
-            wti_url("BWBR0018451"),
+            wti_url(BWB_REPOSITORY_URL, "BWBR0018451"),
             "https://repository.officiele-overheidspublicaties.nl/bwb/BWBR0018451/BWBR0018451.WTI"
         );
     }
@@ -346,7 +348,7 @@ mod tests {
     #[test]
     fn test_content_url() {
         assert_eq!(
-            content_url("BWBR0018451", "2025-01-01"),
+            content_url(BWB_REPOSITORY_URL, "BWBR0018451", "2025-01-01"),
             "https://repository.officiele-overheidspublicaties.nl/bwb/BWBR0018451/2025-01-01_0/xml/BWBR0018451_2025-01-01_0.xml"
         );
     }
@@ -15,6 +15,7 @@ use crate::http::{bytes_to_string, download_bytes};
 ///
 /// # Arguments
 /// * `client` - HTTP client to use
+/// * `repository_url` - Base URL of the repository (usually [`BWB_REPOSITORY_URL`](crate::config::BWB_REPOSITORY_URL))
 /// * `bwb_id` - The BWB identifier (e.g., "BWBR0018451")
 /// * `date` - The effective date in YYYY-MM-DD format
 /// * `max_size` - Maximum response size in bytes
@@ -23,11 +24,12 @@ use crate::http::{bytes_to_string, download_bytes};
 /// Raw XML content as a string
 pub async fn download_content_xml(
     client: &Client,
+    repository_url: &str,
     bwb_id: &str,
     date: &str,
     max_size: u64,
 ) -> Result<String> {
-    let url = content_url(bwb_id, date);
+    let url = content_url(repository_url, bwb_id, date);
     let bytes = download_bytes(client, &url, max_size).await.map_err(|e| {
         if let HarvesterError::Http(source) = e {
             HarvesterError::ContentDownload {
@@ -103,6 +103,22 @@ pub enum HarvesterError {
         #[source]
         source: reqwest::Error,
     },
+
+    /// Batch list file could not be parsed.
+    #[error("Invalid batch list: {0}")]
+    InvalidBatchList(String),
+
+    /// A batch download task did not complete.
+    #[error("Batch task failed: {0}")]
+    BatchTask(String),
+
+    /// Neither a law ID nor a batch list was given.
+    #[error("No law to download: pass a BWB or CVDR ID, or --from-list <FILE>")]
+    MissingLawId,
+
+    /// One or more entries of a batch download failed.
+    #[error("{failed} of {total} batch downloads failed")]
+    BatchFailed { failed: usize, total: usize },
 }
 
 /// Result type alias for harvester operations.
@@ -4,7 +4,9 @@ use roxmltree::Document;
 
 use reqwest::Client;
 
-use crate::config::{validate_bwb_id, validate_date, wetten_url, DEFAULT_MAX_RESPONSE_SIZE};
+use crate::config::{
+    validate_bwb_id, validate_date, wetten_url, BWB_REPOSITORY_URL, DEFAULT_MAX_RESPONSE_SIZE,
+};
 use crate::content::download_content_xml;
 use crate::error::Result;
 use crate::splitting::{create_dutch_law_hierarchy, LeafSplitStrategy, SplitContext, SplitEngine};
@@ -24,6 +26,7 @@ use crate::xml::{find_bijlage_context, find_by_path, find_children, get_tag_name
 pub async fn download_law(client: &Client, bwb_id: &str, date: &str) -> Result<Law> {
     download_law_with_max_size(
         client,
+        BWB_REPOSITORY_URL,
         bwb_id,
         date,
         DEFAULT_MAX_RESPONSE_SIZE / (1024 * 1024),
@@ -31,10 +34,11 @@ pub async fn download_law(client: &Client, bwb_id: &str, date: &str) -> Result<L
     .await
 }
 
-/// Download and parse a Dutch law with configurable max response size.
+/// Download and parse a Dutch law with configurable repository and max response size.
 ///
 /// # Arguments
 /// * `client` - HTTP client to use
+/// * `repository_url` - Base URL of the repository (usually [`BWB_REPOSITORY_URL`])
 /// * `bwb_id` - The BWB identifier (e.g., "BWBR0018451")
 /// * `date` - The effective date in YYYY-MM-DD format
 /// * `max_size_mb` - Maximum response size in megabytes
@@ -43,6 +47,7 @@ pub async fn download_law(client: &Client, bwb_id: &str, date: &str) -> Result<L
 /// A `Law` object containing metadata, articles, and any warnings encountered during parsing
 pub async fn download_law_with_max_size(
     client: &Client,
+    repository_url: &str,
     bwb_id: &str,
     date: &str,
     max_size_mb: u64,
@@ -54,10 +59,11 @@ pub async fn download_law_with_max_size(
     let max_size_bytes = max_size_mb * 1024 * 1024;
 
     // Download and parse WTI metadata
-    let wti_result = download_wti(client, bwb_id).await?;
+    let wti_result = download_wti(client, repository_url, bwb_id).await?;
 
     // Download content XML
-    let content_xml = download_content_xml(client, bwb_id, date, max_size_bytes).await?;
+    let content_xml =
+        download_content_xml(client, repository_url, bwb_id, date, max_size_bytes).await?;
 
     // Parse articles from content
     let parsed = parse_articles(&content_xml, bwb_id, date)?;
@@ -1,8 +1,13 @@
 //! HTTP client wrapper for downloading from BWB repository.
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
 use std::time::Duration;
 
-use reqwest::Client;
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
 
 use crate::config::{DEFAULT_MAX_RESPONSE_SIZE, HTTP_TIMEOUT_SECS};
 use crate::error::{HarvesterError, Result};
@@ -16,6 +21,185 @@ const MAX_RETRIES: u32 = 3;
 /// Base delay for exponential backoff (milliseconds).
 const RETRY_BASE_DELAY_MS: u64 = 500;
 
+/// Validators and body of a previously downloaded response.
+#[derive(Debug, Clone)]
+struct CachedResponse {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    /// `None` when the body is only kept on disk
+    body: Option<Vec<u8>>,
+}
+
+/// On-disk metadata of a cached response; the body is stored next to it.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheMeta {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+/// HTTP cache keyed by URL.
+///
+/// Stores the `ETag` and `Last-Modified` validators of each downloaded
+/// response together with its body, so [`download_bytes_cached`] can send a
+/// conditional request and reuse the body when the server answers
+/// `304 Not Modified`. Responses without either validator are not stored.
+///
+/// A cache created with [`HttpCache::persistent`] writes every entry to a
+/// directory instead (`<key>.meta` with the validators, `<key>.body` with the
+/// content), so later harvester runs can revalidate instead of downloading
+/// again. It keeps only the validators in memory and reads a body back from
+/// disk when the server reports it unchanged.
+#[derive(Debug, Default)]
+pub struct HttpCache {
+    entries: Mutex<HashMap<String, CachedResponse>>,
+    dir: Option<PathBuf>,
+}
+
+/// Cache used by [`download_bytes`], set once by [`enable_cache`].
+static SHARED_CACHE: OnceLock<HttpCache> = OnceLock::new();
+
+impl HttpCache {
+    /// Create an empty in-memory cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a cache backed by `dir`, creating the directory if needed.
+    pub fn persistent(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self {
+            entries: Mutex::default(),
+            dir: Some(dir),
+        })
+    }
+
+    /// Number of responses cached in memory during this run.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether no responses were cached in memory during this run.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, CachedResponse>> {
+        // A panic while holding the lock cannot leave an entry half-written
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn get(&self, url: &str) -> Option<CachedResponse> {
+        if let Some(cached) = self.lock().get(url) {
+            return Some(cached.clone());
+        }
+        let cached = self.load(url)?;
+        self.lock().insert(url.to_string(), cached.clone());
+        Some(cached)
+    }
+
+    /// Body of a cached response, from memory or from its body file.
+    ///
+    /// An entry whose body file can no longer be read is dropped.
+    fn body(&self, url: &str, cached: &CachedResponse) -> Option<Vec<u8>> {
+        if let Some(body) = &cached.body {
+            return Some(body.clone());
+        }
+        let (_, body_path) = self.entry_paths(url)?;
+        let body = std::fs::read(body_path).ok();
+        if body.is_none() {
+            self.lock().remove(url);
+        }
+        body
+    }
+
+    fn store(&self, url: &str, etag: Option<String>, last_modified: Option<String>, body: &[u8]) {
+        let mut response = CachedResponse {
+            etag,
+            last_modified,
+            body: None,
+        };
+        match self.save(url, &response, body) {
+            Ok(true) => {}
+            Ok(false) => response.body = Some(body.to_vec()),
+            Err(e) => {
+                tracing::warn!(url, error = %e, "Failed to write HTTP cache entry");
+                response.body = Some(body.to_vec());
+            }
+        }
+        self.lock().insert(url.to_string(), response);
+    }
+
+    /// Paths of the metadata and body files for `url`, if the cache is persistent.
+    fn entry_paths(&self, url: &str) -> Option<(PathBuf, PathBuf)> {
+        let dir = self.dir.as_ref()?;
+        let key = cache_key(url);
+        Some((
+            dir.join(format!("{key}.meta")),
+            dir.join(format!("{key}.body")),
+        ))
+    }
+
+    fn load(&self, url: &str) -> Option<CachedResponse> {
+        let (meta_path, body_path) = self.entry_paths(url)?;
+        let meta: CacheMeta =
+            serde_yaml_ng::from_str(&std::fs::read_to_string(meta_path).ok()?).ok()?;
+        // Different URLs may share a key; only use the entry written for this one
+        if meta.url != url || !body_path.is_file() {
+            return None;
+        }
+        Some(CachedResponse {
+            etag: meta.etag,
+            last_modified: meta.last_modified,
+            body: None,
+        })
+    }
+
+    /// Write an entry to the cache directory; `Ok(false)` if the cache is in-memory only.
+    fn save(&self, url: &str, response: &CachedResponse, body: &[u8]) -> Result<bool> {
+        let Some((meta_path, body_path)) = self.entry_paths(url) else {
+            return Ok(false);
+        };
+        let meta = CacheMeta {
+            url: url.to_string(),
+            etag: response.etag.clone(),
+            last_modified: response.last_modified.clone(),
+        };
+        // Body first, so a readable meta file never points at a missing body
+        std::fs::write(body_path, body)?;
+        std::fs::write(meta_path, serde_yaml_ng::to_string(&meta)?)?;
+        Ok(true)
+    }
+}
+
+/// Stable file name for a cached URL (64-bit FNV-1a, hex encoded).
+fn cache_key(url: &str) -> String {
+    let hash = url.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Make [`download_bytes`] (and every BWB/CVDR fetcher built on it) cache
+/// responses in `dir`, reusing entries written by earlier runs.
+///
+/// Can be called once per process; later calls return an error.
+pub fn enable_cache(dir: impl Into<PathBuf>) -> Result<()> {
+    let dir = dir.into();
+    SHARED_CACHE.set(HttpCache::persistent(&dir)?).map_err(|_| {
+        HarvesterError::Io(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            format!(
+                "HTTP cache already enabled, cannot switch to {}",
+                dir.display()
+            ),
+        ))
+    })
+}
+
 /// Create a configured HTTP client.
 ///
 /// # Returns
@@ -31,6 +215,8 @@ pub fn create_client() -> Result<Client> {
 /// Download content from a URL with retry logic.
 ///
 /// Uses exponential backoff for transient failures (network errors, 5xx responses).
+/// Once [`enable_cache`] has been called, responses are cached and revalidated
+/// as in [`download_bytes_cached`].
 ///
 /// # Arguments
 /// * `client` - HTTP client to use
@@ -40,7 +226,41 @@ pub fn create_client() -> Result<Client> {
 /// # Returns
 /// Raw bytes of the response body
 pub async fn download_bytes(client: &Client, url: &str, max_size: u64) -> Result<Vec<u8>> {
+    fetch(client, url, max_size, SHARED_CACHE.get()).await
+}
+
+/// Download content from a URL with retry logic, honouring HTTP caching.
+///
+/// When `cache` holds an earlier response for `url`, the request carries
+/// `If-None-Match` / `If-Modified-Since` headers and a `304 Not Modified`
+/// answer returns the cached body without downloading it again. A full
+/// response with an `ETag` or `Last-Modified` header replaces the cache entry.
+///
+/// # Arguments
+/// * `client` - HTTP client to use
+/// * `url` - URL to download from
+/// * `max_size` - Maximum response size in bytes
+/// * `cache` - Cache of earlier responses
+///
+/// # Returns
+/// Raw bytes of the response body
+pub async fn download_bytes_cached(
+    client: &Client,
+    url: &str,
+    max_size: u64,
+    cache: &HttpCache,
+) -> Result<Vec<u8>> {
+    fetch(client, url, max_size, Some(cache)).await
+}
+
+async fn fetch(
+    client: &Client,
+    url: &str,
+    max_size: u64,
+    cache: Option<&HttpCache>,
+) -> Result<Vec<u8>> {
     let mut last_error: Option<String> = None;
+    let mut cached = cache.and_then(|c| c.get(url));
 
     for attempt in 0..MAX_RETRIES {
         if attempt > 0 {
@@ -50,10 +270,33 @@ pub async fn download_bytes(client: &Client, url: &str, max_size: u64) -> Result
             tokio::time::sleep(Duration::from_millis(delay)).await;
         }
 
-        match client.get(url).send().await {
+        let mut request = client.get(url);
+        if let Some(cached) = &cached {
+            if let Some(etag) = &cached.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        match request.send().await {
             Ok(response) => {
                 let status = response.status();
 
+                if status == StatusCode::NOT_MODIFIED {
+                    if let (Some(cache), Some(entry)) = (cache, cached.take()) {
+                        if let Some(body) = cache.body(url, &entry) {
+                            tracing::debug!(url, "Not modified, using cached content");
+                            return Ok(body);
+                        }
+                        // The body file went missing; ask again without validators
+                        tracing::warn!(url, "Cached body unavailable, downloading again");
+                        last_error = Some("Cached body unavailable".to_string());
+                        continue;
+                    }
+                }
+
                 // Retry on server errors (5xx)
                 if status.is_server_error() {
                     tracing::warn!(
@@ -79,6 +322,16 @@ pub async fn download_bytes(client: &Client, url: &str, max_size: u64) -> Result
                     }
                 }
 
+                let header = |name| {
+                    response
+                        .headers()
+                        .get(name)
+                        .and_then(|v| v.to_str().ok())
+                        .map(str::to_string)
+                };
+                let etag = header(ETAG);
+                let last_modified = header(LAST_MODIFIED);
+
                 let bytes = response.bytes().await?;
 
                 // Also check actual size (Content-Length may be missing or wrong)
@@ -89,7 +342,13 @@ pub async fn download_bytes(client: &Client, url: &str, max_size: u64) -> Result
                     });
                 }
 
-                return Ok(bytes.to_vec());
+                let body = bytes.to_vec();
+                if let Some(cache) = cache {
+                    if etag.is_some() || last_modified.is_some() {
+                        cache.store(url, etag, last_modified, &body);
+                    }
+                }
+                return Ok(body);
             }
             Err(e) => {
                 // Retry on connection/timeout errors
@@ -153,6 +412,8 @@ pub fn bytes_to_string(bytes: Vec<u8>, source: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[test]
     fn test_create_client() {
@@ -174,4 +435,100 @@ mod tests {
         // Should not panic, should return something
         assert!(!result.is_empty());
     }
+
+    /// Mock a URL that answers with `validator: value` and `hello`, or with
+    /// `304 Not Modified` when the request carries `conditional: value`.
+    async fn mock_cached_url(
+        validator: &'static str,
+        conditional: &'static str,
+        value: &'static str,
+    ) -> (MockServer, String) {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/wti"))
+            .and(header(conditional, value))
+            .respond_with(ResponseTemplate::new(304))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/wti"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(validator, value)
+                    .set_body_string("hello"),
+            )
+            .mount(&server)
+            .await;
+        let url = format!("{}/wti", server.uri());
+        (server, url)
+    }
+
+    /// Value of `name` in each request the server received, in order.
+    async fn request_headers(server: &MockServer, name: &str) -> Vec<Option<String>> {
+        server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| {
+                request
+                    .headers
+                    .get(name)
+                    .map(|v| v.to_str().unwrap().to_string())
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_download_bytes_cached_reuses_body_on_304() {
+        let (server, url) = mock_cached_url("etag", "if-none-match", "\"v1\"").await;
+        let client = create_client().unwrap();
+        let cache = HttpCache::new();
+
+        let first = download_bytes_cached(&client, &url, 1024, &cache)
+            .await
+            .unwrap();
+        let second = download_bytes_cached(&client, &url, 1024, &cache)
+            .await
+            .unwrap();
+
+        assert_eq!(first, b"hello");
+        assert_eq!(second, b"hello");
+        assert_eq!(cache.len(), 1);
+        assert_eq!(
+            request_headers(&server, "if-none-match").await,
+            vec![None, Some("\"v1\"".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_persistent_cache_revalidates_across_runs() {
+        const LAST_MODIFIED_VALUE: &str = "Wed, 01 Jan 2025 00:00:00 GMT";
+        let (server, url) =
+            mock_cached_url("last-modified", "if-modified-since", LAST_MODIFIED_VALUE).await;
+        let client = create_client().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+
+        let first_run = HttpCache::persistent(dir.path()).unwrap();
+        let first = download_bytes_cached(&client, &url, 1024, &first_run)
+            .await
+            .unwrap();
+        // Only the validators are kept in memory
+        assert!(first_run.lock().values().all(|entry| entry.body.is_none()));
+        drop(first_run);
+
+        let second_run = HttpCache::persistent(dir.path()).unwrap();
+        assert!(second_run.is_empty());
+        let second = download_bytes_cached(&client, &url, 1024, &second_run)
+            .await
+            .unwrap();
+
+        assert_eq!(first, b"hello");
+        assert_eq!(second, b"hello");
+        assert_eq!(
+            request_headers(&server, "if-modified-since").await,
+            vec![None, Some(LAST_MODIFIED_VALUE.to_string())]
+        );
+    }
 }
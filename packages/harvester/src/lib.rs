@@ -37,7 +37,9 @@
 //! - [`cli`]: Command-line interface
 //! - [`harvester`]: Main harvester service
 //! - [`source`]: Strategy trait for law sources (BWB, CVDR)
+//! - [`batch`]: Batch downloads from a list of law IDs
 
+pub mod batch;
 pub mod cli;
 pub mod config;
 pub mod content;
@@ -8,7 +8,8 @@ use async_trait::async_trait;
 use reqwest::Client;
 
 use crate::config::{
-    lokaleregelgeving_url, validate_bwb_id, validate_cvdr_id, DEFAULT_MAX_RESPONSE_SIZE,
+    lokaleregelgeving_url, validate_bwb_id, validate_cvdr_id, BWB_REPOSITORY_URL,
+    DEFAULT_MAX_RESPONSE_SIZE,
 };
 use crate::error::{HarvesterError, Result};
 use crate::types::Law;
@@ -46,6 +47,9 @@ pub trait LawSource: Send + Sync {
 pub struct BwbSource {
     /// Maximum response size in megabytes.
     pub max_size_mb: Option<u64>,
+
+    /// Base URL of the BWB repository (default: [`BWB_REPOSITORY_URL`]).
+    pub repository_url: Option<String>,
 }
 
 #[async_trait]
@@ -64,7 +68,15 @@ impl LawSource for BwbSource {
             .max_size_mb
             .unwrap_or(DEFAULT_MAX_RESPONSE_SIZE / (1024 * 1024));
 
-        crate::harvester::download_law_with_max_size(client, id, &effective_date, max_mb).await
+        let repository_url = self.repository_url.as_deref().unwrap_or(BWB_REPOSITORY_URL);
+        crate::harvester::download_law_with_max_size(
+            client,
+            repository_url,
+            id,
+            &effective_date,
+            max_mb,
+        )
+        .await
     }
 
     fn public_url(&self, id: &str) -> String {
@@ -200,6 +212,7 @@ mod tests {
     fn bwb_source_custom_max_size() {
         let source = BwbSource {
             max_size_mb: Some(200),
+            ..Default::default()
         };
         assert_eq!(source.max_size_mb, Some(200));
     }
@@ -18,12 +18,17 @@ use crate::types::{LawMetadata, RegulatoryLayer};
 ///
 /// # Arguments
 /// * `client` - HTTP client to use
+/// * `repository_url` - Base URL of the repository (usually [`BWB_REPOSITORY_URL`](crate::config::BWB_REPOSITORY_URL))
 /// * `bwb_id` - The BWB identifier (e.g., "BWBR0018451")
 ///
 /// # Returns
 /// Raw XML content as a string
-pub async fn download_wti_xml(client: &Client, bwb_id: &str) -> Result<String> {
-    let url = wti_url(bwb_id);
+pub async fn download_wti_xml(
+    client: &Client,
+    repository_url: &str,
+    bwb_id: &str,
+) -> Result<String> {
+    let url = wti_url(repository_url, bwb_id);
     let bytes = download_bytes_default(client, &url).await.map_err(|e| {
         if let HarvesterError::Http(source) = e {
             HarvesterError::WtiDownload {
@@ -48,12 +53,17 @@ pub async fn download_wti_xml(client: &Client, bwb_id: &str) -> Result<String> {
 ///
 /// # Arguments
 /// * `client` - HTTP client to use
+/// * `repository_url` - Base URL of the repository (usually [`BWB_REPOSITORY_URL`](crate::config::BWB_REPOSITORY_URL))
 /// * `bwb_id` - The BWB identifier (e.g., "BWBR0018451")
 ///
 /// # Returns
 /// `WtiParseResult` with extracted metadata and any warnings
-pub async fn download_wti(client: &Client, bwb_id: &str) -> Result<WtiParseResult> {
-    let xml = download_wti_xml(client, bwb_id).await?;
+pub async fn download_wti(
+    client: &Client,
+    repository_url: &str,
+    bwb_id: &str,
+) -> Result<WtiParseResult> {
+    let xml = download_wti_xml(client, repository_url, bwb_id).await?;
     let doc = Document::parse(&xml)?;
     Ok(parse_wti_metadata(&doc))
 }
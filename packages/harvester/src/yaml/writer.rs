@@ -1,5 +1,7 @@
 //! YAML writer for law files.
 
+use std::collections::HashMap;
+use std::fmt;
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -366,6 +368,94 @@ pub fn generate_yaml(law: &Law, effective_date: &str) -> Result<String> {
     Ok(content)
 }
 
+/// Path of the YAML file for a law: `{output_base}/{layer}/{slug}/{date}.yaml`.
+fn yaml_output_path(law: &Law, effective_date: &str, output_base: Option<&Path>) -> PathBuf {
+    let output_base = output_base.unwrap_or(Path::new("regulation/nl"));
+    output_base
+        .join(law.metadata.regulatory_layer.as_dir_name())
+        .join(law.metadata.to_slug())
+        .join(format!("{effective_date}.yaml"))
+}
+
+/// What saving a law's YAML would do to the file at its output path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WriteChange {
+    /// No file exists yet; it would be created.
+    Create,
+    /// The file exists with identical content.
+    Unchanged,
+    /// The file exists and would be overwritten. Line counts compare the
+    /// multisets of lines, so a moved line counts as neither added nor removed.
+    Overwrite {
+        added_lines: usize,
+        removed_lines: usize,
+    },
+}
+
+impl fmt::Display for WriteChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WriteChange::Create => write!(f, "create"),
+            WriteChange::Unchanged => write!(f, "unchanged"),
+            WriteChange::Overwrite {
+                added_lines,
+                removed_lines,
+            } => write!(f, "overwrite (+{added_lines} -{removed_lines} lines)"),
+        }
+    }
+}
+
+/// A file [`save_yaml`] would write, as reported by [`plan_yaml`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedWrite {
+    /// Output path of the YAML file.
+    pub path: PathBuf,
+    /// Effect on the existing file at that path.
+    pub change: WriteChange,
+}
+
+/// Determine what [`save_yaml`] would write, without touching the filesystem.
+///
+/// Generates the YAML content and compares it with the file currently at the
+/// output path. No directories or files are created.
+pub fn plan_yaml(
+    law: &Law,
+    effective_date: &str,
+    output_base: Option<&Path>,
+) -> Result<PlannedWrite> {
+    let path = yaml_output_path(law, effective_date, output_base);
+    let content = generate_yaml(law, effective_date)?;
+
+    let change = match fs::read_to_string(&path) {
+        Ok(existing) if existing == content => WriteChange::Unchanged,
+        Ok(existing) => {
+            let mut counts: HashMap<&str, isize> = HashMap::new();
+            for line in content.lines() {
+                *counts.entry(line).or_default() += 1;
+            }
+            for line in existing.lines() {
+                *counts.entry(line).or_default() -= 1;
+            }
+            WriteChange::Overwrite {
+                added_lines: counts
+                    .values()
+                    .filter(|&&n| n > 0)
+                    .map(|&n| n.unsigned_abs())
+                    .sum(),
+                removed_lines: counts
+                    .values()
+                    .filter(|&&n| n < 0)
+                    .map(|&n| n.unsigned_abs())
+                    .sum(),
+            }
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => WriteChange::Create,
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(PlannedWrite { path, change })
+}
+
 /// Save a Law object as a YAML file.
 ///
 /// Uses atomic write pattern: writes to temp file, syncs to disk, then renames.
@@ -379,15 +469,16 @@ pub fn generate_yaml(law: &Law, effective_date: &str) -> Result<String> {
 /// # Returns
 /// Path to the saved file
 pub fn save_yaml(law: &Law, effective_date: &str, output_base: Option<&Path>) -> Result<PathBuf> {
-    let output_base = output_base.unwrap_or(Path::new("regulation/nl"));
-
-    // Determine directory structure
-    let layer_dir = law.metadata.regulatory_layer.as_dir_name();
-    let law_id = law.metadata.to_slug();
-    let output_dir = output_base.join(layer_dir).join(&law_id);
-    fs::create_dir_all(&output_dir)?;
+    let output_file = yaml_output_path(law, effective_date, output_base);
+    let Some(output_dir) = output_file.parent() else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("Output path has no directory: {}", output_file.display()),
+        )
+        .into());
+    };
+    fs::create_dir_all(output_dir)?;
 
-    let output_file = output_dir.join(format!("{effective_date}.yaml"));
     let temp_file = output_dir.join(format!(".{effective_date}.yaml.tmp"));
 
     // Generate YAML content
@@ -468,6 +559,41 @@ mod tests {
         assert!(content.starts_with("---\n"));
     }
 
+    #[test]
+    fn test_plan_yaml_writes_nothing() {
+        let law = create_test_law();
+        let temp_dir = tempdir().unwrap();
+
+        let plan = plan_yaml(&law, "2025-01-01", Some(temp_dir.path())).unwrap();
+        assert_eq!(plan.change, WriteChange::Create);
+        assert_eq!(
+            plan.path,
+            temp_dir
+                .path()
+                .join("wet")
+                .join("wet_op_de_zorgtoeslag")
+                .join("2025-01-01.yaml")
+        );
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+
+        // The planned path is where save_yaml writes
+        assert_eq!(
+            save_yaml(&law, "2025-01-01", Some(temp_dir.path())).unwrap(),
+            plan.path
+        );
+        let plan = plan_yaml(&law, "2025-01-01", Some(temp_dir.path())).unwrap();
+        assert_eq!(plan.change, WriteChange::Unchanged);
+
+        let mut amended = law.clone();
+        amended.add_article(Article::new("2", "Nieuw artikel.", "https://example.com"));
+        let plan = plan_yaml(&amended, "2025-01-01", Some(temp_dir.path())).unwrap();
+        assert!(
+            matches!(plan.change, WriteChange::Overwrite { added_lines, removed_lines: 0 } if added_lines > 0),
+            "got {:?}",
+            plan.change
+        );
+    }
+
     #[test]
     fn test_generate_yaml_indented_sequences() {
         let law = create_test_law();
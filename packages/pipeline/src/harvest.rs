@@ -147,6 +147,7 @@ pub async fn execute_harvest(
         tracing::info!(law_id = %law_id, date = %resolved_date, "downloading law XML from BWB");
         let bwb_source = regelrecht_harvester::BwbSource {
             max_size_mb: payload.max_size_mb,
+            ..Default::default()
         };
         let law = bwb_source
             .download(http_client, law_id, Some(&resolved_date))
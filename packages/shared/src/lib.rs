@@ -1,3 +1,5 @@
+pub mod money;
 pub mod regulatory_layer;
 
+pub use money::{parse_money, DEFAULT_MONEY_SCALE, MAX_MONEY_SCALE};
 pub use regulatory_layer::RegulatoryLayer;
@@ -0,0 +1,86 @@
+//! Parsing of money amounts into integer minor units.
+//!
+//! Amounts are stored as integers at a fixed decimal scale so that arithmetic
+//! on them stays exact. The default scale is 2 (eurocents): `"21.12"` becomes
+//! `2112`. Crates that read amounts from text (harvested law texts, request
+//! parameters) should parse through [`parse_money`] with the same scale, so
+//! that euros and eurocents are never mixed.
+
+/// Default number of decimals in a stored amount (eurocents).
+pub const DEFAULT_MONEY_SCALE: u32 = 2;
+
+/// Largest supported scale; `10^18` is the largest power of ten in an `i64`.
+pub const MAX_MONEY_SCALE: u32 = 18;
+
+/// Parse a decimal amount into an integer at `scale` decimals.
+///
+/// Accepts an optional sign, a leading `€`, and either `.` or `,` as the
+/// decimal separator (`"21.12"`, `"€ 21,12"`, `"-3"`). Digits beyond the scale
+/// are rounded half away from zero. Returns `None` for anything else,
+/// including thousands separators, a scale above [`MAX_MONEY_SCALE`] and
+/// amounts that do not fit in an `i64`.
+#[must_use]
+pub fn parse_money(text: &str, scale: u32) -> Option<i64> {
+    if scale > MAX_MONEY_SCALE {
+        return None;
+    }
+    let text = text.trim();
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let text = text.strip_prefix('€').unwrap_or(text).trim_start();
+
+    let (whole, fraction) = match text.find(['.', ',']) {
+        Some(pos) => (&text[..pos], &text[pos + 1..]),
+        None => (text, ""),
+    };
+    let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if whole.is_empty() || !all_digits(whole) || !all_digits(fraction) {
+        return None;
+    }
+
+    let factor = 10_i64.checked_pow(scale)?;
+    let mut minor = whole.parse::<i64>().ok()?.checked_mul(factor)?;
+    let mut digits = fraction.bytes().map(|b| i64::from(b - b'0'));
+    let mut place = factor;
+    for _ in 0..scale {
+        place /= 10;
+        minor = minor.checked_add(digits.next().unwrap_or(0) * place)?;
+    }
+    if digits.next().is_some_and(|d| d >= 5) {
+        minor = minor.checked_add(1)?;
+    }
+
+    Some(if negative { -minor } else { minor })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_money_at_default_scale() {
+        assert_eq!(parse_money("21.12", DEFAULT_MONEY_SCALE), Some(2112));
+        assert_eq!(parse_money("€ 21,12", DEFAULT_MONEY_SCALE), Some(2112));
+        assert_eq!(parse_money("21.1", DEFAULT_MONEY_SCALE), Some(2110));
+        assert_eq!(parse_money("-3", DEFAULT_MONEY_SCALE), Some(-300));
+        assert_eq!(parse_money("0.125", DEFAULT_MONEY_SCALE), Some(13));
+    }
+
+    #[test]
+    fn test_parse_money_at_scale_zero() {
+        assert_eq!(parse_money("21.12", 0), Some(21));
+        assert_eq!(parse_money("21.50", 0), Some(22));
+        assert_eq!(parse_money("-21.5", 0), Some(-22));
+    }
+
+    #[test]
+    fn test_parse_money_rejects_malformed_input() {
+        assert_eq!(parse_money("1.000,50", 2), None);
+        assert_eq!(parse_money("twintig", 2), None);
+        assert_eq!(parse_money(".5", 2), None);
+        assert_eq!(parse_money("1", MAX_MONEY_SCALE + 1), None);
+        assert_eq!(parse_money("99999999999999999999", 2), None);
+    }
+}
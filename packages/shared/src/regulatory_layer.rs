@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 /// Types of regulatory documents in Dutch law.
 ///
 /// Aligned with schema v0.5.2 regulatory_layer enum.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum RegulatoryLayer {
     /// Constitutional law (Grondwet).
     #[serde(rename = "GRONDWET")]